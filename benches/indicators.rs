@@ -1,11 +1,22 @@
 use bencher::{benchmark_group, benchmark_main, black_box, Bencher};
 use rand::Rng;
 use ta::indicators::{
-    AverageTrueRange, BollingerBands, ChandelierExit, CommodityChannelIndex, EfficiencyRatio,
-    ExponentialMovingAverage, FastStochastic, KeltnerChannel, Maximum, MeanAbsoluteDeviation,
-    Minimum, MoneyFlowIndex, MovingAverageConvergenceDivergence, OnBalanceVolume,
-    PercentagePriceOscillator, RateOfChange, RelativeStrengthIndex, SimpleMovingAverage,
-    SlowStochastic, StandardDeviation, TrueRange, WeightedMovingAverage, VolumeWeightedAveragePrice
+    AccumulationDistribution, Alligator, Aroon, AverageDirectionalIndex, AverageTrueRange,
+    AverageTrueRangePercent, AwesomeOscillator, BalanceOfPower, BollingerBands, CciSignal,
+    CenterOfGravity, ChaikinMoneyFlow, ChaikinOscillator, ChandelierExit, ChoppinessIndex,
+    CommodityChannelIndex, ConnorsRsi, CoppockCurve, DetrendedPriceOscillator, DisparityIndex,
+    DonchianChannel, DoubleExponentialMovingAverage, EaseOfMovement, EfficiencyRatio, ElderRay,
+    ExponentialMovingAverage, FastStochastic, FisherTransform, ForceIndex, Fractals, GannHiLo,
+    HeikinAshi, HistoricalVolatility, HullMovingAverage, Ichimoku, KaufmanAdaptiveMovingAverage,
+    KeltnerChannel, KlingerOscillator, KnowSureThing, LinearRegression, MassIndex, Maximum,
+    MeanAbsoluteDeviation, Minimum, Momentum, MoneyFlowIndex, MovingAverageConvergenceDivergence,
+    OnBalanceVolume, ParabolicSar, PercentRank, PercentagePriceOscillator, Qqe, RateOfChange,
+    RelativeStrengthIndex, RelativeVigorIndex, RelativeVolume, SchaffTrendCycle,
+    SimpleMovingAverage, SlowStochastic, StandardDeviation, StandardErrorBands,
+    StochasticOscillator, StochasticRsi, SuperTrend, TrailingStop, TriangularMovingAverage,
+    TripleExponentialMovingAverage, Trix, TrueRange, UltimateOscillator, VolumeOscillator,
+    VolumeWeightedAveragePrice, VolumeWeightedMovingAverage, VortexIndicator,
+    WeightedMovingAverage, WilliamsR, ZigZag,
 };
 use ta::{DataItem, Next};
 
@@ -26,7 +37,7 @@ fn rand_data_item() -> DataItem {
         .low(low)
         .close(close)
         .volume(volume)
-        .build()
+        .build(true)
         .unwrap()
 }
 
@@ -74,5 +85,59 @@ bench_indicators!(
     StandardDeviation,
     TrueRange,
     WeightedMovingAverage,
-    VolumeWeightedAveragePrice
+    VolumeWeightedAveragePrice,
+    ParabolicSar,
+    AverageDirectionalIndex,
+    StochasticOscillator,
+    DonchianChannel,
+    WilliamsR,
+    ChaikinMoneyFlow,
+    AccumulationDistribution,
+    Aroon,
+    SuperTrend,
+    VortexIndicator,
+    HullMovingAverage,
+    KaufmanAdaptiveMovingAverage,
+    Trix,
+    UltimateOscillator,
+    AwesomeOscillator,
+    FisherTransform,
+    CciSignal,
+    HeikinAshi,
+    ElderRay,
+    BalanceOfPower,
+    EaseOfMovement,
+    ForceIndex,
+    DetrendedPriceOscillator,
+    CoppockCurve,
+    KnowSureThing,
+    MassIndex,
+    RelativeVigorIndex,
+    ZigZag,
+    LinearRegression,
+    SchaffTrendCycle,
+    ConnorsRsi,
+    Alligator,
+    ChaikinOscillator,
+    KlingerOscillator,
+    TriangularMovingAverage,
+    DoubleExponentialMovingAverage,
+    TripleExponentialMovingAverage,
+    Momentum,
+    StochasticRsi,
+    Ichimoku,
+    VolumeWeightedMovingAverage,
+    StandardErrorBands,
+    GannHiLo,
+    TrailingStop,
+    ChoppinessIndex,
+    Qqe,
+    Fractals,
+    AverageTrueRangePercent,
+    PercentRank,
+    DisparityIndex,
+    CenterOfGravity,
+    VolumeOscillator,
+    RelativeVolume,
+    HistoricalVolatility
 );
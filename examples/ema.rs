@@ -15,7 +15,7 @@ fn main() {
             .low(low)
             .close(close)
             .volume(volume)
-            .build()
+            .build(true)
             .unwrap();
         let ema_val = ema.next(&dt);
         println!("{}: {} = {:2.2}", date, ema, ema_val);
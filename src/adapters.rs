@@ -0,0 +1,725 @@
+//! Wrappers that change how an indicator consumes inputs or reports outputs.
+
+use std::collections::VecDeque;
+use std::fmt;
+
+use crate::{Close, Current, DataItem, High, Low, Next, Open, Period, PriceSource, Ready, Reset};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Returns `None` until the wrapped indicator is ready.
+///
+/// Period based indicators return values during their warmup, while their window is not full
+/// yet. `Warmed` hides those values: it returns `None` until the [Ready] implementation of the
+/// wrapped indicator reports it is ready, for most indicators the first _period - 1_ inputs,
+/// and `Some(output)` from then on. Create it with [Warmed::new] or
+/// [warmed](crate::PeriodExt::warmed).
+///
+/// # Example
+///
+/// ```
+/// use ta::indicators::SimpleMovingAverage;
+/// use ta::{Next, PeriodExt};
+///
+/// let mut sma = SimpleMovingAverage::new(3).unwrap().warmed();
+/// assert_eq!(sma.next(1.0), None);
+/// assert_eq!(sma.next(2.0), None);
+/// assert_eq!(sma.next(3.0), Some(2.0));
+/// assert_eq!(sma.next(4.0), Some(3.0));
+/// ```
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone)]
+pub struct Warmed<N> {
+    indicator: N,
+}
+
+impl<N: Ready> Warmed<N> {
+    pub fn new(indicator: N) -> Self {
+        Self { indicator }
+    }
+
+    /// Whether enough inputs were consumed for the outputs to be valid.
+    pub fn is_warm(&self) -> bool {
+        self.indicator.is_ready()
+    }
+
+    pub fn inner(&self) -> &N {
+        &self.indicator
+    }
+
+    pub fn into_inner(self) -> N {
+        self.indicator
+    }
+}
+
+impl<N: Period> Period for Warmed<N> {
+    fn period(&self) -> usize {
+        self.indicator.period()
+    }
+}
+
+impl<T, N: Next<T> + Ready> Next<T> for Warmed<N> {
+    type Output = Option<N::Output>;
+
+    fn next(&mut self, input: T) -> Self::Output {
+        let output = self.indicator.next(input);
+        if self.is_warm() {
+            Some(output)
+        } else {
+            None
+        }
+    }
+}
+
+impl<N: Ready> Ready for Warmed<N> {
+    fn bars_seen(&self) -> usize {
+        self.indicator.bars_seen()
+    }
+
+    fn is_ready(&self) -> bool {
+        self.is_warm()
+    }
+}
+
+impl<N: Reset> Reset for Warmed<N> {
+    fn reset(&mut self) {
+        self.indicator.reset();
+    }
+}
+
+impl<N: Ready + Default> Default for Warmed<N> {
+    fn default() -> Self {
+        Self::new(N::default())
+    }
+}
+
+impl<N: fmt::Display> fmt::Display for Warmed<N> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.indicator.fmt(f)
+    }
+}
+
+/// Keeps the last output of the wrapped indicator to implement [Current] for it.
+///
+/// Indicators which can derive their last output from their state implement [Current]
+/// themselves. `Latest` adds it to any other indicator, at the cost of storing a copy of the
+/// output.
+///
+/// # Example
+///
+/// ```
+/// use ta::adapters::Latest;
+/// use ta::indicators::RateOfChange;
+/// use ta::{Current, Next};
+///
+/// let mut roc = Latest::new(RateOfChange::new(3).unwrap());
+/// assert_eq!(roc.current(), None);
+///
+/// let value = roc.next(10.0);
+/// assert_eq!(roc.current(), Some(value));
+/// ```
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone)]
+pub struct Latest<N, O = f64> {
+    indicator: N,
+    output: Option<O>,
+}
+
+impl<N, O> Latest<N, O> {
+    pub fn new(indicator: N) -> Self {
+        Self {
+            indicator,
+            output: None,
+        }
+    }
+
+    pub fn inner(&self) -> &N {
+        &self.indicator
+    }
+
+    pub fn into_inner(self) -> N {
+        self.indicator
+    }
+}
+
+impl<N: Period, O> Period for Latest<N, O> {
+    fn period(&self) -> usize {
+        self.indicator.period()
+    }
+}
+
+impl<T, N: Next<T, Output = O>, O: Clone> Next<T> for Latest<N, O> {
+    type Output = O;
+
+    fn next(&mut self, input: T) -> Self::Output {
+        let output = self.indicator.next(input);
+        self.output = Some(output.clone());
+        output
+    }
+}
+
+impl<N, O: Clone> Current for Latest<N, O> {
+    type Output = O;
+
+    fn current(&self) -> Option<Self::Output> {
+        self.output.clone()
+    }
+}
+
+impl<N: Reset, O> Reset for Latest<N, O> {
+    fn reset(&mut self) {
+        self.indicator.reset();
+        self.output = None;
+    }
+}
+
+impl<N: Default, O> Default for Latest<N, O> {
+    fn default() -> Self {
+        Self::new(N::default())
+    }
+}
+
+impl<N: fmt::Display, O> fmt::Display for Latest<N, O> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.indicator.fmt(f)
+    }
+}
+
+/// Returns the input together with the output of the wrapped indicator.
+///
+/// When indicators are chained the input of the first one is usually lost, e.g. the bar once
+/// it was turned into a price. `Tee` forwards a clone of every input alongside the output, so
+/// a pipeline can keep the bar at hand without storing it separately. References to bars are
+/// `Copy`, wrapping an indicator consuming `&T` costs nothing.
+///
+/// # Example
+///
+/// ```
+/// use ta::adapters::Tee;
+/// use ta::indicators::{MedianPrice, SimpleMovingAverage};
+/// use ta::{Close, DataItem, Next};
+///
+/// let mut median = Tee::new(MedianPrice::new());
+/// let mut sma = SimpleMovingAverage::new(2).unwrap();
+///
+/// let di = DataItem::builder()
+///     .open(10.0).high(12.0).low(8.0).close(11.0).volume(1000.0)
+///     .build(true).unwrap();
+///
+/// let (bar, price) = median.next(&di);
+/// assert_eq!(sma.next(price), 10.0);
+/// assert_eq!(bar.close(), 11.0);
+/// ```
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone)]
+pub struct Tee<N> {
+    indicator: N,
+}
+
+impl<N> Tee<N> {
+    pub fn new(indicator: N) -> Self {
+        Self { indicator }
+    }
+
+    pub fn inner(&self) -> &N {
+        &self.indicator
+    }
+
+    pub fn into_inner(self) -> N {
+        self.indicator
+    }
+}
+
+impl<N: Period> Period for Tee<N> {
+    fn period(&self) -> usize {
+        self.indicator.period()
+    }
+}
+
+impl<T: Clone, N: Next<T>> Next<T> for Tee<N> {
+    type Output = (T, N::Output);
+
+    fn next(&mut self, input: T) -> Self::Output {
+        let output = self.indicator.next(input.clone());
+        (input, output)
+    }
+}
+
+impl<N: Reset> Reset for Tee<N> {
+    fn reset(&mut self) {
+        self.indicator.reset();
+    }
+}
+
+impl<N: Default> Default for Tee<N> {
+    fn default() -> Self {
+        Self::new(N::default())
+    }
+}
+
+impl<N: fmt::Display> fmt::Display for Tee<N> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.indicator.fmt(f)
+    }
+}
+
+/// Delays a stream by _n_ inputs.
+///
+/// Returns the input consumed _n_ calls ago, or `None` for the first _n_ calls while there is
+/// no such input yet. A delay of 0 passes every input through. Displaced indicators such as
+/// the [Alligator](crate::indicators::Alligator) use it to shift their lines.
+///
+/// # Example
+///
+/// ```
+/// use ta::adapters::Delay;
+/// use ta::Next;
+///
+/// let mut delay = Delay::new(2);
+/// assert_eq!(delay.next(1.0), None);
+/// assert_eq!(delay.next(2.0), None);
+/// assert_eq!(delay.next(3.0), Some(1.0));
+/// assert_eq!(delay.next(4.0), Some(2.0));
+/// ```
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone)]
+pub struct Delay<T = f64> {
+    delay: usize,
+    deque: VecDeque<T>,
+}
+
+impl<T> Delay<T> {
+    pub fn new(delay: usize) -> Self {
+        Self {
+            delay,
+            deque: VecDeque::with_capacity(delay + 1),
+        }
+    }
+
+    pub fn delay(&self) -> usize {
+        self.delay
+    }
+
+    /// The oldest buffered input, the next one to be returned once the delay is filled.
+    ///
+    /// While `next` returns `None` this is the first input, which displaced indicators use
+    /// as a stand-in during their warmup.
+    pub fn oldest(&self) -> Option<&T> {
+        self.deque.front()
+    }
+}
+
+impl<T> Next<T> for Delay<T> {
+    type Output = Option<T>;
+
+    fn next(&mut self, input: T) -> Self::Output {
+        self.deque.push_back(input);
+        if self.deque.len() > self.delay {
+            self.deque.pop_front()
+        } else {
+            None
+        }
+    }
+}
+
+impl<T> Reset for Delay<T> {
+    fn reset(&mut self) {
+        self.deque.clear();
+    }
+}
+
+impl<T> fmt::Display for Delay<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "DELAY({})", self.delay)
+    }
+}
+
+/// Feeds the wrapped indicator with the [PriceSource] of every bar.
+///
+/// Indicators fed with bars take the close. `Sourced` selects another price of the bar, e.g.
+/// the typical price, and passes it to the `Next<f64>` implementation of the wrapped indicator.
+/// The moving averages create it with `with_source`.
+///
+/// # Example
+///
+/// ```
+/// use ta::adapters::Sourced;
+/// use ta::indicators::SimpleMovingAverage;
+/// use ta::{DataItem, Next, PriceSource};
+///
+/// let mut sma = Sourced::new(SimpleMovingAverage::new(2).unwrap(), PriceSource::HL2);
+///
+/// let di = DataItem::builder()
+///     .open(9.0).high(10.0).low(8.0).close(9.5).volume(1000.0)
+///     .build(true).unwrap();
+/// assert_eq!(sma.next(&di), 9.0);
+/// ```
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone)]
+pub struct Sourced<N> {
+    indicator: N,
+    source: PriceSource,
+}
+
+impl<N> Sourced<N> {
+    pub fn new(indicator: N, source: PriceSource) -> Self {
+        Self { indicator, source }
+    }
+
+    pub fn source(&self) -> PriceSource {
+        self.source
+    }
+
+    pub fn inner(&self) -> &N {
+        &self.indicator
+    }
+
+    pub fn into_inner(self) -> N {
+        self.indicator
+    }
+}
+
+impl<N: Period> Period for Sourced<N> {
+    fn period(&self) -> usize {
+        self.indicator.period()
+    }
+}
+
+impl<T: Open + High + Low + Close, N: Next<f64>> Next<&T> for Sourced<N> {
+    type Output = N::Output;
+
+    fn next(&mut self, input: &T) -> Self::Output {
+        self.indicator.next(self.source.select(input))
+    }
+}
+
+impl<N: Current> Current for Sourced<N> {
+    type Output = N::Output;
+
+    fn current(&self) -> Option<Self::Output> {
+        self.indicator.current()
+    }
+}
+
+impl<N: Ready> Ready for Sourced<N> {
+    fn bars_seen(&self) -> usize {
+        self.indicator.bars_seen()
+    }
+
+    fn is_ready(&self) -> bool {
+        self.indicator.is_ready()
+    }
+}
+
+impl<N: Reset> Reset for Sourced<N> {
+    fn reset(&mut self) {
+        self.indicator.reset();
+    }
+}
+
+impl<N: Default> Default for Sourced<N> {
+    fn default() -> Self {
+        Self::new(N::default(), PriceSource::default())
+    }
+}
+
+impl<N: fmt::Display> fmt::Display for Sourced<N> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.indicator.fmt(f)
+    }
+}
+
+/// Object safe interface of the indicators consuming a [DataItem] and returning `f64`.
+///
+/// [Next] is generic over its input, so `dyn Next` cannot be used to store different indicators
+/// together. `DynIndicator` is implemented for every indicator taking a `&DataItem` and
+/// returning `f64`, which makes `Vec<Box<dyn DynIndicator>>` possible. The trait lives in this
+/// module rather than the crate root, so importing `ta::*` does not make its `reset` clash
+/// with [Reset::reset].
+///
+/// # Example
+///
+/// ```
+/// use ta::adapters::DynIndicator;
+/// use ta::indicators::{SimpleMovingAverage, VolumeWeightedAveragePrice};
+/// use ta::DataItem;
+///
+/// let mut indicators: Vec<Box<dyn DynIndicator>> = vec![
+///     Box::new(SimpleMovingAverage::new(2).unwrap()),
+///     Box::new(VolumeWeightedAveragePrice::new()),
+/// ];
+///
+/// let di = DataItem::builder()
+///     .open(9.0).high(10.0).low(8.0).close(9.0).volume(1000.0)
+///     .build(true).unwrap();
+///
+/// let values: Vec<f64> = indicators.iter_mut().map(|i| i.update(&di)).collect();
+/// assert_eq!(values, [9.0, 9.0]);
+/// ```
+pub trait DynIndicator {
+    /// Consumes the next bar, like [Next::next].
+    fn update(&mut self, d: &DataItem) -> f64;
+
+    /// Resets the indicator, like [Reset::reset].
+    fn reset(&mut self);
+}
+
+impl<N> DynIndicator for N
+where
+    N: for<'a> Next<&'a DataItem, Output = f64> + Reset,
+{
+    fn update(&mut self, d: &DataItem) -> f64 {
+        self.next(d)
+    }
+
+    fn reset(&mut self) {
+        Reset::reset(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::indicators::{
+        MedianPrice, MovingAverageConvergenceDivergence as Macd, RelativeStrengthIndex,
+        SimpleMovingAverage, VolumeWeightedAveragePrice,
+    };
+    use crate::test_helper::*;
+    use crate::{Close, PeriodExt};
+
+    #[test]
+    fn test_next_sma() {
+        let mut sma = SimpleMovingAverage::new(4).unwrap().warmed();
+
+        assert_eq!(sma.next(4.0), None);
+        assert_eq!(sma.next(5.0), None);
+        assert_eq!(sma.next(6.0), None);
+        assert!(!sma.is_warm());
+        // the 4th input fills the window
+        assert_eq!(sma.next(6.0), Some(5.25));
+        assert!(sma.is_warm());
+        assert_eq!(sma.next(6.0), Some(5.75));
+    }
+
+    #[test]
+    fn test_next_vwap_rolling() {
+        let mut vwap = Warmed::new(VolumeWeightedAveragePrice::rolling(3).unwrap());
+        let bar = |close: f64| Bar::new().high(close).low(close).close(close).volume(100.0);
+
+        assert_eq!(vwap.next(&bar(10.0)), None);
+        assert_eq!(vwap.next(&bar(11.0)), None);
+        assert_eq!(vwap.next(&bar(12.0)), Some(11.0));
+        assert_eq!(vwap.next(&bar(13.0)), Some(12.0));
+
+        // a session VWAP has no window to fill
+        let mut vwap = VolumeWeightedAveragePrice::new().warmed();
+        assert_eq!(vwap.next(&bar(10.0)), Some(10.0));
+    }
+
+    #[test]
+    fn test_reset() {
+        let mut sma = SimpleMovingAverage::new(2).unwrap().warmed();
+
+        sma.next(1.0);
+        assert_eq!(sma.next(3.0), Some(2.0));
+        sma.reset();
+
+        assert_eq!(sma.next(5.0), None);
+        assert_eq!(sma.next(7.0), Some(6.0));
+    }
+
+    #[test]
+    fn test_ready() {
+        // the RSI needs _period_ changes, one input more than its period
+        let mut rsi = RelativeStrengthIndex::new(3).unwrap().warmed();
+        assert_eq!((rsi.bars_seen(), rsi.is_ready()), (0, false));
+
+        assert_eq!(rsi.next(1.0), None);
+        assert_eq!(rsi.next(2.0), None);
+        assert_eq!(rsi.next(3.0), None);
+        assert_eq!((rsi.bars_seen(), rsi.is_ready()), (3, false));
+        assert_eq!(rsi.next(4.0), Some(100.0));
+        assert_eq!((rsi.bars_seen(), rsi.is_ready()), (4, true));
+
+        rsi.reset();
+        assert_eq!((rsi.bars_seen(), rsi.is_ready()), (0, false));
+    }
+
+    #[test]
+    fn test_inner() {
+        let sma = SimpleMovingAverage::new(5).unwrap().warmed();
+        assert_eq!(sma.period(), 5);
+        assert_eq!(sma.inner().period(), 5);
+        assert_eq!(sma.into_inner().period(), 5);
+    }
+
+    #[test]
+    fn test_default() {
+        Warmed::<SimpleMovingAverage>::default();
+    }
+
+    #[test]
+    fn test_display() {
+        let sma = SimpleMovingAverage::new(5).unwrap().warmed();
+        assert_eq!(format!("{}", sma), "SMA(5)");
+    }
+
+    #[test]
+    fn test_latest() {
+        let mut rsi = Latest::new(RelativeStrengthIndex::new(3).unwrap());
+        assert_eq!(rsi.current(), None);
+
+        for input in [10.0, 10.5, 10.0, 9.5] {
+            let value = rsi.next(input);
+            assert_eq!(rsi.current(), Some(value));
+        }
+
+        Reset::reset(&mut rsi);
+        assert_eq!(rsi.current(), None);
+        assert_eq!(rsi.period(), 3);
+        assert_eq!(format!("{}", rsi), "RSI(3)");
+    }
+
+    #[test]
+    fn test_latest_struct_output() {
+        let mut macd = Latest::<Macd, _>::default();
+        let bar = Bar::new().close(10.0);
+
+        let value = macd.next(&bar);
+        assert_eq!(macd.current(), Some(value));
+        assert_eq!(format!("{}", macd.into_inner()), "MACD(12, 26, 9)");
+    }
+
+    #[test]
+    fn test_tee() {
+        let mut median = Tee::new(MedianPrice::new());
+        let mut sma = SimpleMovingAverage::new(2).unwrap();
+
+        let bars = [
+            Bar::new().high(12).low(8).close(11),
+            Bar::new().high(14).low(10).close(13),
+            Bar::new().high(13).low(11).close(11.5),
+        ];
+        let mut outputs = Vec::new();
+        for bar in bars.iter() {
+            let (input, price) = median.next(bar);
+            outputs.push((Close::close(input), sma.next(price)));
+        }
+        assert_eq!(outputs, [(11.0, 10.0), (13.0, 11.0), (11.5, 12.0)]);
+
+        // values are cloned
+        let mut sma = Tee::new(SimpleMovingAverage::new(2).unwrap());
+        assert_eq!(sma.next(4.0), (4.0, 4.0));
+        assert_eq!(sma.next(6.0), (6.0, 5.0));
+        sma.reset();
+        assert_eq!(sma.next(2.0), (2.0, 2.0));
+        assert_eq!(sma.period(), 2);
+        assert_eq!(format!("{}", sma), "SMA(2)");
+    }
+
+    #[test]
+    fn test_sourced() {
+        let mut sma = Sourced::new(SimpleMovingAverage::new(2).unwrap(), PriceSource::HLC3);
+        assert_eq!(sma.source(), PriceSource::HLC3);
+
+        assert_eq!(sma.next(&Bar::new().high(12).low(9).close(12)), 11.0);
+        assert_eq!(sma.next(&Bar::new().high(16).low(11).close(15)), 12.5);
+        assert_eq!(sma.current(), Some(12.5));
+        assert!(sma.is_ready());
+
+        Reset::reset(&mut sma);
+        assert_eq!(sma.next(&Bar::new().high(16).low(11).close(15)), 14.0);
+        assert_eq!(sma.period(), 2);
+        assert_eq!(format!("{}", sma), "SMA(2)");
+
+        let sma: Sourced<SimpleMovingAverage> = Sourced::default();
+        assert_eq!(sma.source(), PriceSource::Close);
+    }
+
+    #[test]
+    fn test_delay() {
+        let mut delay = Delay::new(3);
+        assert_eq!(delay.delay(), 3);
+        assert_eq!(delay.oldest(), None);
+
+        let values: Vec<Option<f64>> = [1.0, 2.0, 3.0, 4.0, 5.0, 6.0]
+            .iter()
+            .map(|&x| delay.next(x))
+            .collect();
+        assert_eq!(values, [None, None, None, Some(1.0), Some(2.0), Some(3.0)]);
+        assert_eq!(delay.oldest(), Some(&4.0));
+
+        delay.reset();
+        assert_eq!(delay.next(7.0), None);
+        assert_eq!(delay.oldest(), Some(&7.0));
+    }
+
+    #[test]
+    fn test_delay_zero() {
+        let mut delay = Delay::new(0);
+
+        for x in [1.0, 2.0, 3.0] {
+            assert_eq!(delay.next(x), Some(x));
+        }
+    }
+
+    #[test]
+    fn test_delay_warmup() {
+        for n in 1..10 {
+            let mut delay = Delay::new(n);
+            for i in 0..n {
+                assert_eq!(delay.next(i as f64), None);
+                assert_eq!(delay.oldest(), Some(&0.0));
+            }
+            assert_eq!(delay.next(n as f64), Some(0.0));
+        }
+    }
+
+    #[test]
+    fn test_delay_display() {
+        let delay: Delay = Delay::new(26);
+        assert_eq!(format!("{}", delay), "DELAY(26)");
+    }
+
+    #[test]
+    fn test_dyn_indicator() {
+        let mut indicators: Vec<Box<dyn DynIndicator>> = vec![
+            Box::new(SimpleMovingAverage::new(2).unwrap()),
+            Box::new(VolumeWeightedAveragePrice::new()),
+        ];
+
+        let bars = [
+            DataItem::builder()
+                .open(10.0)
+                .high(12.0)
+                .low(9.0)
+                .close(12.0)
+                .volume(100.0)
+                .build(true)
+                .unwrap(),
+            DataItem::builder()
+                .open(12.0)
+                .high(15.0)
+                .low(12.0)
+                .close(15.0)
+                .volume(300.0)
+                .build(true)
+                .unwrap(),
+        ];
+
+        let update = |indicators: &mut Vec<Box<dyn DynIndicator>>, bar: &DataItem| {
+            indicators
+                .iter_mut()
+                .map(|indicator| round(indicator.update(bar)))
+                .collect::<Vec<f64>>()
+        };
+
+        assert_eq!(update(&mut indicators, &bars[0]), [12.0, 11.0]);
+        assert_eq!(update(&mut indicators, &bars[1]), [13.5, 13.25]);
+
+        for indicator in indicators.iter_mut() {
+            indicator.reset();
+        }
+        assert_eq!(update(&mut indicators, &bars[1]), [15.0, 14.0]);
+    }
+}
@@ -0,0 +1,221 @@
+//! Detectors of one series crossing another.
+//!
+//! Both detectors consume a pair `(a, b)` per call, usually the current values of two
+//! indicators, and return `true` on the call where `a` crosses `b`.
+//!
+//! A cross is a change of the strict order of `a` and `b`. A pair where `a` equals `b` is a
+//! touch and does not change the order, so touching a line and moving back does not trigger,
+//! and moving through the line over several calls triggers only once. Pairs containing NaN
+//! are ignored as well.
+//!
+//! # Example
+//!
+//! ```
+//! use ta::cross::Crossover;
+//! use ta::indicators::{ExponentialMovingAverage, SimpleMovingAverage};
+//! use ta::Next;
+//!
+//! let mut ema = ExponentialMovingAverage::new(2).unwrap();
+//! let mut sma = SimpleMovingAverage::new(4).unwrap();
+//! let mut crossover = Crossover::new();
+//!
+//! let signals: Vec<bool> = [5.0, 4.0, 3.0, 4.0, 6.0]
+//!     .iter()
+//!     .map(|&price| crossover.next((ema.next(price), sma.next(price))))
+//!     .collect();
+//!
+//! assert_eq!(signals, [false, false, false, false, true]);
+//! ```
+
+use std::cmp::Ordering;
+
+use crate::{Next, Reset};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Detects `a` crossing above `b`.
+///
+/// Returns `true` when `a` is greater than `b` and was less than `b` the last time the two
+/// were not equal.
+///
+/// # Example
+///
+/// ```
+/// use ta::cross::Crossover;
+/// use ta::Next;
+///
+/// let mut crossover = Crossover::new();
+/// assert_eq!(crossover.next((1.0, 2.0)), false);
+/// assert_eq!(crossover.next((2.0, 2.0)), false);
+/// assert_eq!(crossover.next((3.0, 2.0)), true);
+/// assert_eq!(crossover.next((4.0, 2.0)), false);
+/// ```
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone, Default)]
+pub struct Crossover {
+    // whether `a` was above `b` the last time they were not equal
+    above: Option<bool>,
+}
+
+impl Crossover {
+    pub fn new() -> Self {
+        Self { above: None }
+    }
+}
+
+impl Next<(f64, f64)> for Crossover {
+    type Output = bool;
+
+    fn next(&mut self, (a, b): (f64, f64)) -> Self::Output {
+        crossed(&mut self.above, a, b, true)
+    }
+}
+
+impl Reset for Crossover {
+    fn reset(&mut self) {
+        self.above = None;
+    }
+}
+
+/// Detects `a` crossing below `b`.
+///
+/// Returns `true` when `a` is less than `b` and was greater than `b` the last time the two
+/// were not equal.
+///
+/// # Example
+///
+/// ```
+/// use ta::cross::Crossunder;
+/// use ta::Next;
+///
+/// let mut crossunder = Crossunder::new();
+/// assert_eq!(crossunder.next((3.0, 2.0)), false);
+/// assert_eq!(crossunder.next((1.0, 2.0)), true);
+/// assert_eq!(crossunder.next((0.0, 2.0)), false);
+/// ```
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone, Default)]
+pub struct Crossunder {
+    // whether `a` was above `b` the last time they were not equal
+    above: Option<bool>,
+}
+
+impl Crossunder {
+    pub fn new() -> Self {
+        Self { above: None }
+    }
+}
+
+impl Next<(f64, f64)> for Crossunder {
+    type Output = bool;
+
+    fn next(&mut self, (a, b): (f64, f64)) -> Self::Output {
+        crossed(&mut self.above, a, b, false)
+    }
+}
+
+impl Reset for Crossunder {
+    fn reset(&mut self) {
+        self.above = None;
+    }
+}
+
+// Updates the last strict order of `a` and `b` and tells whether `a` just moved to the
+// `upward` side of `b`.
+fn crossed(above: &mut Option<bool>, a: f64, b: f64, upward: bool) -> bool {
+    let is_above = match a.partial_cmp(&b) {
+        Some(Ordering::Greater) => true,
+        Some(Ordering::Less) => false,
+        // a touch or NaN
+        _ => return false,
+    };
+    let was_above = above.replace(is_above);
+    was_above == Some(!upward) && is_above == upward
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn run<N: Next<(f64, f64), Output = bool>>(detector: &mut N, a: &[f64], b: f64) -> Vec<bool> {
+        a.iter().map(|&a| detector.next((a, b))).collect()
+    }
+
+    #[test]
+    fn test_crossover() {
+        let mut crossover = Crossover::new();
+        let a = [1.0, 2.0, 3.0, 4.0, 1.0, 3.0];
+        let expected = [false, false, true, false, false, true];
+        assert_eq!(run(&mut crossover, &a, 2.5), expected);
+    }
+
+    #[test]
+    fn test_crossunder() {
+        let mut crossunder = Crossunder::new();
+        let a = [3.0, 2.0, 1.0, 0.0, 3.0, 1.0];
+        let expected = [false, false, true, false, false, true];
+        assert_eq!(run(&mut crossunder, &a, 1.5), expected);
+    }
+
+    #[test]
+    fn test_first_pair() {
+        // nothing to compare the first pair with
+        assert!(!Crossover::new().next((2.0, 1.0)));
+        assert!(!Crossunder::new().next((1.0, 2.0)));
+    }
+
+    #[test]
+    fn test_touch_without_cross() {
+        let mut crossover = Crossover::new();
+        let mut crossunder = Crossunder::new();
+
+        // a touches b from below and from above, but never crosses it
+        let a = [1.0, 2.0, 1.0, 2.0, 2.0, 1.0];
+        assert_eq!(run(&mut crossover, &a, 2.0), [false; 6]);
+        let a = [3.0, 2.0, 3.0, 2.0, 2.0, 3.0];
+        assert_eq!(run(&mut crossunder, &a, 2.0), [false; 6]);
+    }
+
+    #[test]
+    fn test_cross_through_equal() {
+        let mut crossover = Crossover::new();
+        let mut crossunder = Crossunder::new();
+
+        // crossing via a touch triggers once, on leaving the line
+        let a = [1.0, 2.0, 2.0, 3.0, 2.0, 3.0];
+        let expected = [false, false, false, true, false, false];
+        assert_eq!(run(&mut crossover, &a, 2.0), expected);
+
+        let a = [3.0, 2.0, 2.0, 1.0, 2.0, 1.0];
+        let expected = [false, false, false, true, false, false];
+        assert_eq!(run(&mut crossunder, &a, 2.0), expected);
+    }
+
+    #[test]
+    fn test_nan_is_ignored() {
+        let mut crossover = Crossover::new();
+        assert!(!crossover.next((1.0, 2.0)));
+        assert!(!crossover.next((f64::NAN, 2.0)));
+        assert!(crossover.next((3.0, 2.0)));
+    }
+
+    #[test]
+    fn test_reset() {
+        let mut crossover = Crossover::new();
+        let mut crossunder = Crossunder::new();
+
+        crossover.next((1.0, 2.0));
+        crossunder.next((3.0, 2.0));
+        crossover.reset();
+        crossunder.reset();
+
+        assert!(!crossover.next((3.0, 2.0)));
+        assert!(!crossunder.next((1.0, 2.0)));
+    }
+
+    #[test]
+    fn test_default() {
+        Crossover::default();
+        Crossunder::default();
+    }
+}
@@ -0,0 +1,302 @@
+//! Reading [DataItem]s from CSV, requires the `csv` feature.
+//!
+//! # Example
+//!
+//! ```
+//! use ta::csv::from_csv_reader;
+//! use ta::indicators::VolumeWeightedAveragePrice;
+//! use ta::Next;
+//!
+//! let data = "\
+//! date,open,high,low,close,volume
+//! 2017-01-03,757.92,758.76,747.70,753.67,3521100
+//! 2017-01-04,758.39,759.68,754.20,757.18,2510500
+//! ";
+//!
+//! let mut vwap = VolumeWeightedAveragePrice::new();
+//! let values: Vec<f64> = from_csv_reader(data.as_bytes())
+//!     .map(|item| vwap.next(&item.unwrap()))
+//!     .collect();
+//!
+//! // the typical price of the first day, then weighted by the volumes of both days
+//! assert_eq!((values[0] * 100.0).round() / 100.0, 753.38);
+//! assert_eq!((values[1] * 100.0).round() / 100.0, 754.89);
+//! ```
+
+use std::io;
+
+use crate::errors::{Result, TaError};
+use crate::DataItem;
+
+/// Names of the CSV columns a [DataItem] is read from.
+///
+/// Defaults to `open`, `high`, `low`, `close` and `volume`. Columns are matched exactly,
+/// other columns are ignored.
+///
+/// # Example
+///
+/// ```
+/// use ta::csv::CsvColumns;
+/// use ta::Close;
+///
+/// let data = "Date,Open,High,Low,Adj Close,Volume\n2017-01-03,10,12,9,11,1000\n";
+/// let columns = CsvColumns::new()
+///     .open("Open")
+///     .high("High")
+///     .low("Low")
+///     .close("Adj Close")
+///     .volume("Volume");
+///
+/// let items: Vec<_> = columns.read(data.as_bytes()).collect();
+/// assert_eq!(items[0].as_ref().unwrap().close(), 11.0);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CsvColumns {
+    open: String,
+    high: String,
+    low: String,
+    close: String,
+    volume: String,
+}
+
+impl CsvColumns {
+    pub fn new() -> Self {
+        Self {
+            open: "open".to_string(),
+            high: "high".to_string(),
+            low: "low".to_string(),
+            close: "close".to_string(),
+            volume: "volume".to_string(),
+        }
+    }
+
+    pub fn open(mut self, name: &str) -> Self {
+        self.open = name.to_string();
+        self
+    }
+
+    pub fn high(mut self, name: &str) -> Self {
+        self.high = name.to_string();
+        self
+    }
+
+    pub fn low(mut self, name: &str) -> Self {
+        self.low = name.to_string();
+        self
+    }
+
+    pub fn close(mut self, name: &str) -> Self {
+        self.close = name.to_string();
+        self
+    }
+
+    pub fn volume(mut self, name: &str) -> Self {
+        self.volume = name.to_string();
+        self
+    }
+
+    /// Reads the data items from CSV with a header row, one item per record.
+    pub fn read<R: io::Read>(&self, reader: R) -> CsvDataItems<R> {
+        CsvDataItems {
+            reader: ::csv::Reader::from_reader(reader),
+            columns: self.clone(),
+            indices: None,
+            record: ::csv::StringRecord::new(),
+            done: false,
+        }
+    }
+}
+
+impl Default for CsvColumns {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Reads the data items from CSV with a header row and the default
+/// [columns](CsvColumns).
+pub fn from_csv_reader<R: io::Read>(reader: R) -> CsvDataItems<R> {
+    CsvColumns::default().read(reader)
+}
+
+/// Iterator over the data items of a CSV, created by [from_csv_reader] or
+/// [CsvColumns::read].
+///
+/// Yields an error for every record that cannot be parsed or is not a valid data item:
+///
+/// * `TaError::DataItemIncomplete` - a column is missing from the header, the iterator ends
+///   after this error
+/// * `TaError::DataItemParse` - the CSV is malformed or a value is not a number
+/// * `TaError::DataItemInvalid` - the values do not form a valid data item, e.g. high is
+///   below low
+#[derive(Debug)]
+pub struct CsvDataItems<R> {
+    reader: ::csv::Reader<R>,
+    columns: CsvColumns,
+    // positions of open, high, low, close and volume in a record
+    indices: Option<[usize; 5]>,
+    record: ::csv::StringRecord,
+    done: bool,
+}
+
+impl<R: io::Read> CsvDataItems<R> {
+    fn indices(&mut self) -> Result<[usize; 5]> {
+        if let Some(indices) = self.indices {
+            return Ok(indices);
+        }
+
+        let headers = self.reader.headers().map_err(parse_error)?;
+        let position = |name: &str| {
+            headers
+                .iter()
+                .position(|header| header == name)
+                .ok_or(TaError::DataItemIncomplete)
+        };
+        let indices = [
+            position(&self.columns.open)?,
+            position(&self.columns.high)?,
+            position(&self.columns.low)?,
+            position(&self.columns.close)?,
+            position(&self.columns.volume)?,
+        ];
+        self.indices = Some(indices);
+        Ok(indices)
+    }
+}
+
+impl<R: io::Read> Iterator for CsvDataItems<R> {
+    type Item = Result<DataItem>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done {
+            return None;
+        }
+
+        let [open, high, low, close, volume] = match self.indices() {
+            Ok(indices) => indices,
+            Err(err) => {
+                self.done = true;
+                return Some(Err(err));
+            }
+        };
+
+        match self.reader.read_record(&mut self.record) {
+            Ok(true) => {}
+            Ok(false) => {
+                self.done = true;
+                return None;
+            }
+            Err(err) => {
+                // reading fails again and again once the underlying reader failed
+                self.done = err.is_io_error();
+                return Some(Err(parse_error(err)));
+            }
+        }
+
+        let field = |index: usize| -> Result<f64> {
+            let value = self.record.get(index).ok_or(TaError::DataItemIncomplete)?;
+            value
+                .trim()
+                .parse()
+                .map_err(|_| TaError::DataItemParse(format!("invalid number {:?}", value)))
+        };
+
+        let item = (|| {
+            DataItem::builder()
+                .open(field(open)?)
+                .high(field(high)?)
+                .low(field(low)?)
+                .close(field(close)?)
+                .volume(field(volume)?)
+                .build(true)
+        })();
+        Some(item)
+    }
+}
+
+fn parse_error(err: ::csv::Error) -> TaError {
+    TaError::DataItemParse(err.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::indicators::VolumeWeightedAveragePrice;
+    use crate::{Close, High, Next, Volume};
+
+    const DATA: &str = "\
+date,open,high,low,close,volume
+2017-01-03,10,12,8,11,100
+2017-01-04,11,14,10,13,300
+";
+
+    #[test]
+    fn test_from_csv_reader() {
+        let items: Vec<DataItem> = from_csv_reader(DATA.as_bytes())
+            .collect::<Result<_>>()
+            .unwrap();
+
+        assert_eq!(items.len(), 2);
+        assert_eq!(
+            items[0],
+            DataItem::try_from((10.0, 12.0, 8.0, 11.0, 100.0)).unwrap()
+        );
+        assert_eq!(items[1].high(), 14.0);
+        assert_eq!(items[1].volume(), 300.0);
+    }
+
+    #[test]
+    fn test_from_csv_reader_into_vwap() {
+        let mut vwap = VolumeWeightedAveragePrice::new();
+        let mut last = 0.0;
+        for item in from_csv_reader(DATA.as_bytes()) {
+            last = vwap.next(&item.unwrap());
+        }
+
+        let expected = (31.0 / 3.0 * 100.0 + 37.0 / 3.0 * 300.0) / 400.0;
+        assert!((last - expected).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_custom_columns() {
+        let data = "Close,Volume,Low,High,Open\n11,100,8,12,10\n";
+        let columns = CsvColumns::new()
+            .open("Open")
+            .high("High")
+            .low("Low")
+            .close("Close")
+            .volume("Volume");
+
+        let items: Vec<Result<DataItem>> = columns.read(data.as_bytes()).collect();
+        assert_eq!(items, [DataItem::try_from((10.0, 12.0, 8.0, 11.0, 100.0))]);
+    }
+
+    #[test]
+    fn test_missing_column() {
+        let data = "open,high,low,close\n10,12,8,11\n";
+        let items: Vec<Result<DataItem>> = from_csv_reader(data.as_bytes()).collect();
+        assert_eq!(items, [Err(TaError::DataItemIncomplete)]);
+    }
+
+    #[test]
+    fn test_invalid_records() {
+        let data = "\
+open,high,low,close,volume
+10,12,8,11,100
+10,abc,8,11,100
+10,8,12,11,100
+10,12,8
+11,14,10,13,300
+";
+        let items: Vec<Result<DataItem>> = from_csv_reader(data.as_bytes()).collect();
+
+        assert_eq!(items.len(), 5);
+        assert!(items[0].is_ok());
+        assert!(matches!(items[1], Err(TaError::DataItemParse(_))));
+        // high is below low
+        assert!(matches!(items[2], Err(TaError::DataItemInvalid { .. })));
+        // too few fields
+        assert!(matches!(items[3], Err(TaError::DataItemParse(_))));
+        assert_eq!(items[4].as_ref().unwrap().close(), 13.0);
+    }
+}
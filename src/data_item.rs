@@ -18,7 +18,7 @@ use serde::{Deserialize, Serialize};
 ///     .low(15.0)
 ///     .close(21.0)
 ///     .volume(7500.0)
-///     .build()
+///     .build(true)
 ///     .unwrap();
 ///
 /// assert_eq!(item.open(), 20.0);
@@ -74,6 +74,42 @@ impl Volume for DataItem {
     }
 }
 
+/// Builds a validated data item from `(open, high, low, close, volume)`.
+///
+/// # Example
+///
+/// ```
+/// use std::convert::TryFrom;
+/// use ta::{DataItem, High};
+///
+/// let item = DataItem::try_from((20.0, 25.0, 15.0, 21.0, 7500.0)).unwrap();
+/// assert_eq!(item.high(), 25.0);
+///
+/// assert!(DataItem::try_from((20.0, 15.0, 25.0, 21.0, 7500.0)).is_err());
+/// ```
+impl TryFrom<(f64, f64, f64, f64, f64)> for DataItem {
+    type Error = TaError;
+
+    fn try_from((open, high, low, close, volume): (f64, f64, f64, f64, f64)) -> Result<Self> {
+        DataItem::builder()
+            .open(open)
+            .high(high)
+            .low(low)
+            .close(close)
+            .volume(volume)
+            .build(true)
+    }
+}
+
+/// Builds a validated data item from `[open, high, low, close, volume]`.
+impl TryFrom<[f64; 5]> for DataItem {
+    type Error = TaError;
+
+    fn try_from([open, high, low, close, volume]: [f64; 5]) -> Result<Self> {
+        Self::try_from((open, high, low, close, volume))
+    }
+}
+
 pub struct DataItemBuilder {
     open: Option<f64>,
     high: Option<f64>,
@@ -130,20 +166,23 @@ impl DataItemBuilder {
                 volume,
             };
             // optionally bypass validation for weird pre-market candles where open == low == high == close?
-            if validate == false {
+            if !validate {
                 return Ok(item);
             }
-            if low <= open
-                && low <= close
-                && low <= high
-                && high >= open
-                && high >= close
-                && volume >= 0.0
-            {
-                Ok(item)
+            let reason = if [open, high, low, close, volume].iter().any(|v| v.is_nan()) {
+                "a value is NaN"
+            } else if low > high {
+                "high is below the low"
+            } else if low > open || low > close {
+                "low is above the open or close"
+            } else if high < open || high < close {
+                "high is below the open or close"
+            } else if volume < 0.0 {
+                "volume is negative"
             } else {
-                Err(TaError::DataItemInvalid)
-            }
+                return Ok(item);
+            };
+            Err(TaError::DataItemInvalid { reason })
         } else {
             Err(TaError::DataItemIncomplete)
         }
@@ -163,7 +202,7 @@ mod tests {
                 .low(low)
                 .close(close)
                 .volume(volume)
-                .build();
+                .build(true);
             assert!(result.is_ok());
         }
 
@@ -175,8 +214,8 @@ mod tests {
                 .low(low)
                 .close(close)
                 .volume(volume)
-                .build();
-            assert_eq!(result, Err(TaError::DataItemInvalid));
+                .build(true);
+            assert!(matches!(result, Err(TaError::DataItemInvalid { .. })));
         }
 
         let valid_records = vec![
@@ -192,6 +231,7 @@ mod tests {
         let invalid_records = vec![
             // open, high, low , close, volume
             (-1.0, 25.0, 15.0, 21.0, 7500.0),
+            (f64::NAN, 25.0, 15.0, 21.0, 7500.0),
             (20.0, -1.0, 15.0, 21.0, 7500.0),
             (20.0, 25.0, 15.0, -1.0, 7500.0),
             (20.0, 25.0, 15.0, 21.0, -1.0),
@@ -205,4 +245,46 @@ mod tests {
             assert_invalid(record)
         }
     }
+
+    #[test]
+    fn test_try_from_tuple() {
+        let item = DataItem::try_from((20.0, 25.0, 15.0, 21.0, 7500.0)).unwrap();
+        let expected = DataItem::builder()
+            .open(20.0)
+            .high(25.0)
+            .low(15.0)
+            .close(21.0)
+            .volume(7500.0)
+            .build(true)
+            .unwrap();
+        assert_eq!(item, expected);
+
+        // high < low
+        assert_eq!(
+            DataItem::try_from((20.0, 15.0, 25.0, 21.0, 7500.0)),
+            Err(TaError::DataItemInvalid {
+                reason: "high is below the low"
+            })
+        );
+        // negative volume
+        assert_eq!(
+            DataItem::try_from((20.0, 25.0, 15.0, 21.0, -1.0)),
+            Err(TaError::DataItemInvalid {
+                reason: "volume is negative"
+            })
+        );
+    }
+
+    #[test]
+    fn test_try_from_array() {
+        let item: DataItem = [20.0, 25.0, 15.0, 21.0, 7500.0].try_into().unwrap();
+        assert_eq!(item.open(), 20.0);
+        assert_eq!(item.high(), 25.0);
+        assert_eq!(item.low(), 15.0);
+        assert_eq!(item.close(), 21.0);
+        assert_eq!(item.volume(), 7500.0);
+
+        let result: Result<DataItem> = [20.0, 15.0, 25.0, 21.0, 7500.0].try_into();
+        assert!(matches!(result, Err(TaError::DataItemInvalid { .. })));
+    }
 }
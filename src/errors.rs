@@ -5,17 +5,40 @@ pub type Result<T> = std::result::Result<T, TaError>;
 
 #[derive(Debug, PartialEq, Eq, Clone)]
 pub enum TaError {
-    InvalidParameter,
+    /// A parameter of an indicator is out of its range.
+    InvalidParameter {
+        /// Name of the parameter, as in the documentation of the indicator.
+        name: &'static str,
+        /// The rejected value.
+        value: String,
+    },
     DataItemIncomplete,
-    DataItemInvalid,
+    /// The values of a data item are inconsistent, e.g. the high is below the low.
+    DataItemInvalid {
+        reason: &'static str,
+    },
+    DataItemParse(String),
+}
+
+impl TaError {
+    /// Creates a [TaError::InvalidParameter] for the parameter `name` with `value`.
+    pub fn invalid_parameter<V: Display>(name: &'static str, value: V) -> Self {
+        TaError::InvalidParameter {
+            name,
+            value: value.to_string(),
+        }
+    }
 }
 
 impl Display for TaError {
     fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
-        match *self {
-            TaError::InvalidParameter => write!(f, "invalid parameter"),
+        match self {
+            TaError::InvalidParameter { name, value } => {
+                write!(f, "invalid parameter {}: {}", name, value)
+            }
             TaError::DataItemIncomplete => write!(f, "data item is incomplete"),
-            TaError::DataItemInvalid => write!(f, "data item is invalid"),
+            TaError::DataItemInvalid { reason } => write!(f, "data item is invalid: {}", reason),
+            TaError::DataItemParse(reason) => write!(f, "data item can not be parsed: {}", reason),
         }
     }
 }
@@ -23,9 +46,50 @@ impl Display for TaError {
 impl Error for TaError {
     fn source(&self) -> Option<&(dyn Error + 'static)> {
         match *self {
-            TaError::InvalidParameter => None,
+            TaError::InvalidParameter { .. } => None,
             TaError::DataItemIncomplete => None,
-            TaError::DataItemInvalid => None,
+            TaError::DataItemInvalid { .. } => None,
+            TaError::DataItemParse(_) => None,
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::indicators::{ExponentialMovingAverage, KaufmanAdaptiveMovingAverage};
+    use crate::DataItem;
+
+    #[test]
+    fn test_invalid_parameter() {
+        let err = ExponentialMovingAverage::new(0).unwrap_err();
+        assert_eq!(err, TaError::invalid_parameter("period", 0));
+        assert_eq!(err.to_string(), "invalid parameter period: 0");
+
+        // the offending one of several parameters
+        let err = KaufmanAdaptiveMovingAverage::new(10, 2, 1).unwrap_err();
+        assert_eq!(err.to_string(), "invalid parameter slow_period: 1");
+    }
+
+    #[test]
+    fn test_data_item_invalid() {
+        let err = DataItem::builder()
+            .open(20.0)
+            .high(15.0)
+            .low(25.0)
+            .close(21.0)
+            .volume(7500.0)
+            .build(true)
+            .unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "data item is invalid: high is below the low"
+        );
+    }
+
+    #[test]
+    fn test_source() {
+        let err: Box<dyn Error> = Box::new(TaError::DataItemIncomplete);
+        assert!(err.source().is_none());
+    }
+}
@@ -1,11 +1,74 @@
+use crate::{Close, High, Low};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
 /// Returns the largest of 3 given numbers.
 pub fn max3(a: f64, b: f64, c: f64) -> f64 {
     a.max(b).max(c)
 }
 
+/// Returns the money flow multiplier of a bar, or 0 when the bar has no range.
+pub fn money_flow_multiplier<T: High + Low + Close>(bar: &T) -> f64 {
+    let range = bar.high() - bar.low();
+    if range == 0.0 {
+        0.0
+    } else {
+        ((bar.close() - bar.low()) - (bar.high() - bar.close())) / range
+    }
+}
+
+/// Wilder's smoothing, seeded with the average of the first `period` values.
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone)]
+pub struct WilderSmoothing {
+    period: usize,
+    count: usize,
+    current: f64,
+}
+
+impl WilderSmoothing {
+    pub fn new(period: usize) -> Self {
+        Self {
+            period,
+            count: 0,
+            current: 0.0,
+        }
+    }
+
+    pub fn next(&mut self, input: f64) -> f64 {
+        if self.count < self.period {
+            self.count += 1;
+            self.current += (input - self.current) / self.count as f64;
+        } else {
+            self.current = (self.current * (self.period - 1) as f64 + input) / self.period as f64;
+        }
+        self.current
+    }
+
+    /// The last smoothed value, 0 before the first value.
+    pub fn current(&self) -> f64 {
+        self.current
+    }
+
+    /// Whether the first `period` values were averaged.
+    pub fn is_seeded(&self) -> bool {
+        self.count >= self.period
+    }
+
+    pub fn reset(&mut self) {
+        self.count = 0;
+        self.current = 0.0;
+    }
+
+    pub fn period(&self) -> usize {
+        self.period
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::test_helper::*;
 
     #[test]
     fn test_max3() {
@@ -13,4 +76,39 @@ mod tests {
         assert_eq!(max3(2.0, 3.0, 1.0), 3.0);
         assert_eq!(max3(2.0, 1.0, 3.0), 3.0);
     }
+
+    #[test]
+    fn test_money_flow_multiplier() {
+        assert_eq!(
+            money_flow_multiplier(&Bar::new().high(10).low(8).close(10)),
+            1.0
+        );
+        assert_eq!(
+            money_flow_multiplier(&Bar::new().high(10).low(8).close(8)),
+            -1.0
+        );
+        assert_eq!(
+            money_flow_multiplier(&Bar::new().high(10).low(8).close(9.5)),
+            0.5
+        );
+        assert_eq!(
+            money_flow_multiplier(&Bar::new().high(5).low(5).close(5)),
+            0.0
+        );
+    }
+
+    #[test]
+    fn test_wilder_smoothing() {
+        let mut smoothing = WilderSmoothing::new(3);
+        // the average of the first values
+        assert_eq!(smoothing.next(3.0), 3.0);
+        assert_eq!(smoothing.next(6.0), 4.5);
+        assert_eq!(smoothing.next(9.0), 6.0);
+        // then (previous * (period - 1) + input) / period
+        assert_eq!(smoothing.next(12.0), 8.0);
+
+        smoothing.reset();
+        assert_eq!(smoothing.next(1.0), 1.0);
+        assert_eq!(smoothing.period(), 3);
+    }
 }
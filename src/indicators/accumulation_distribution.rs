@@ -0,0 +1,200 @@
+use std::fmt;
+
+use crate::helpers::money_flow_multiplier;
+use crate::{Close, Current, High, Low, Next, Ready, Reset, Volume};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Accumulation/Distribution Line (A/D).
+///
+/// A cumulative volume based indicator developed by Marc Chaikin. Every bar adds its
+/// money flow volume, which is positive when the close is in the upper half of the bar's
+/// range and negative when it is in the lower half.
+///
+/// # Formula
+///
+/// Money Flow Multiplier (MFM) = ((close - low) - (high - close)) / (high - low)
+///
+/// A/D<sub>t</sub> = A/D<sub>t-1</sub> + MFM * volume
+///
+/// A bar with high equal to low adds nothing.
+///
+/// # Example
+///
+/// ```
+/// use ta::indicators::AccumulationDistribution;
+/// use ta::{DataItem, Next};
+///
+/// let mut ad = AccumulationDistribution::new();
+///
+/// let di1 = DataItem::builder()
+///     .open(9.0).high(10.0).low(8.0).close(9.5).volume(1000.0)
+///     .build(true).unwrap();
+/// let di2 = DataItem::builder()
+///     .open(9.5).high(10.0).low(9.0).close(9.0).volume(300.0)
+///     .build(true).unwrap();
+///
+/// assert_eq!(ad.next(&di1), 500.0);
+/// assert_eq!(ad.next(&di2), 200.0);
+/// ```
+///
+/// # Links
+///
+/// * [Accumulation/distribution index, Wikipedia](https://en.wikipedia.org/wiki/Accumulation/distribution_index)
+/// * [Accumulation/Distribution Line, StockCharts](https://school.stockcharts.com/doku.php?id=technical_indicators:accumulation_distribution_line)
+///
+#[doc(alias = "AD")]
+#[doc(alias = "ADL")]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone)]
+pub struct AccumulationDistribution {
+    ad: f64,
+    #[cfg_attr(feature = "serde", serde(default))]
+    bars_seen: usize,
+}
+
+impl AccumulationDistribution {
+    pub fn new() -> Self {
+        Self {
+            ad: 0.0,
+            bars_seen: 0,
+        }
+    }
+}
+
+impl<T: High + Low + Close + Volume> Next<&T> for AccumulationDistribution {
+    type Output = f64;
+
+    fn next(&mut self, input: &T) -> f64 {
+        self.bars_seen = self.bars_seen.saturating_add(1);
+        self.ad += money_flow_multiplier(input) * input.volume();
+        self.ad
+    }
+}
+
+impl Default for AccumulationDistribution {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl fmt::Display for AccumulationDistribution {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "AD")
+    }
+}
+
+impl Current for AccumulationDistribution {
+    type Output = f64;
+
+    fn current(&self) -> Option<Self::Output> {
+        match self.bars_seen {
+            0 => None,
+            _ => Some(self.ad),
+        }
+    }
+}
+
+impl Ready for AccumulationDistribution {
+    fn bars_seen(&self) -> usize {
+        self.bars_seen
+    }
+
+    fn is_ready(&self) -> bool {
+        self.bars_seen > 0
+    }
+}
+
+impl Reset for AccumulationDistribution {
+    fn reset(&mut self) {
+        self.bars_seen = 0;
+        self.ad = 0.0;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helper::*;
+
+    #[test]
+    fn test_next_bar() {
+        let mut ad = AccumulationDistribution::new();
+
+        // mfm = 0.5, mfv = 500
+        let bar1 = Bar::new().high(10).low(8).close(9.5).volume(1000.0);
+        assert_eq!(ad.next(&bar1), 500.0);
+
+        // mfm = -1, mfv = -2000
+        let bar2 = Bar::new().high(12).low(10).close(10).volume(2000.0);
+        assert_eq!(ad.next(&bar2), -1500.0);
+
+        // high == low, adds nothing
+        let bar3 = Bar::new().high(11).low(11).close(11).volume(5000.0);
+        assert_eq!(ad.next(&bar3), -1500.0);
+
+        // mfm = 0.6, mfv = 600
+        let bar4 = Bar::new().high(11).low(6).close(10).volume(1000.0);
+        assert_eq!(round(ad.next(&bar4)), -900.0);
+    }
+
+    #[test]
+    fn test_reset() {
+        let mut ad = AccumulationDistribution::new();
+
+        let bar1 = Bar::new().high(10).low(8).close(9.5).volume(1000.0);
+        let bar2 = Bar::new().high(12).low(10).close(10).volume(2000.0);
+
+        assert_eq!(ad.next(&bar1), 500.0);
+        assert_eq!(ad.next(&bar2), -1500.0);
+
+        ad.reset();
+
+        assert_eq!(ad.next(&bar1), 500.0);
+        assert_eq!(ad.next(&bar2), -1500.0);
+    }
+
+    #[test]
+    fn test_current() {
+        let bars = [
+            Bar::new().high(81).low(80).close(81).volume(95.0),
+            Bar::new().high(81).low(80).close(80).volume(90.0),
+            Bar::new().high(82).low(80).close(81.5).volume(40.0),
+        ];
+
+        let mut ad = AccumulationDistribution::new();
+        assert_eq!(ad.current(), None);
+
+        for bar in &bars {
+            let value = ad.next(bar);
+            assert_eq!(ad.current(), Some(value));
+        }
+
+        ad.reset();
+        assert_eq!(ad.current(), None);
+    }
+
+    #[test]
+    fn test_ready() {
+        let mut ad = AccumulationDistribution::new();
+        assert_eq!((ad.bars_seen(), ad.is_ready()), (0, false));
+
+        // a cumulative indicator is ready after the first bar
+        ad.next(&Bar::new().high(2).low(1).close(1.5).volume(100.0));
+        assert_eq!((ad.bars_seen(), ad.is_ready()), (1, true));
+
+        ad.reset();
+        assert_eq!((ad.bars_seen(), ad.is_ready()), (0, false));
+    }
+
+    #[test]
+    fn test_default() {
+        AccumulationDistribution::default();
+    }
+
+    #[test]
+    fn test_display() {
+        let ad = AccumulationDistribution::new();
+        assert_eq!(format!("{}", ad), "AD");
+    }
+}
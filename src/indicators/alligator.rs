@@ -0,0 +1,276 @@
+use std::fmt;
+
+use crate::adapters::Delay;
+use crate::errors::{Result, TaError};
+use crate::helpers::WilderSmoothing;
+use crate::{High, Low, Next, Period, Ready, Reset};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Williams Alligator.
+///
+/// Developed by Bill Williams, the alligator consists of three smoothed moving averages of the
+/// median price, each displaced into the future. While the market has no trend the lines are
+/// intertwined, the "sleeping alligator". In a trend the lines spread apart and order by
+/// their speed: the lips cross the price first, then the teeth and the jaw.
+///
+/// # Formula
+///
+/// Median price = (high + low) / 2
+///
+/// Jaw = SMMA(jaw) of the median price, displaced by 8 bars
+///
+/// Teeth = SMMA(teeth) of the median price, displaced by 5 bars
+///
+/// Lips = SMMA(lips) of the median price, displaced by 3 bars
+///
+/// Where _SMMA_ is the smoothed moving average, Wilder's smoothing seeded with the average of
+/// the first values. A line displaced by _n_ bars returns the average computed _n_ inputs
+/// ago, until then the first average.
+///
+/// # Parameters
+///
+/// * _jaw_ - period of the jaw (integer greater than 0). Default is 13.
+/// * _teeth_ - period of the teeth (integer greater than 0). Default is 8.
+/// * _lips_ - period of the lips (integer greater than 0). Default is 5.
+///
+/// # Example
+///
+/// ```
+/// use ta::indicators::Alligator;
+/// use ta::{DataItem, Next};
+///
+/// let mut alligator = Alligator::default();
+///
+/// let di = DataItem::builder()
+///     .open(10.0).high(12.0).low(10.0).close(11.0).volume(1000.0)
+///     .build(true).unwrap();
+///
+/// let out = alligator.next(&di);
+/// assert_eq!((out.jaw, out.teeth, out.lips), (11.0, 11.0, 11.0));
+/// ```
+///
+/// # Links
+///
+/// * [Williams Alligator, Investopedia](https://www.investopedia.com/articles/trading/072115/exploring-williams-alligator-indicator.asp)
+///
+#[doc(alias = "Gator")]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone)]
+pub struct Alligator {
+    jaw: DisplacedLine,
+    teeth: DisplacedLine,
+    lips: DisplacedLine,
+    #[cfg_attr(feature = "serde", serde(default))]
+    bars_seen: usize,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct AlligatorOutput {
+    pub jaw: f64,
+    pub teeth: f64,
+    pub lips: f64,
+}
+
+impl From<AlligatorOutput> for (f64, f64, f64) {
+    fn from(alligator: AlligatorOutput) -> Self {
+        (alligator.jaw, alligator.teeth, alligator.lips)
+    }
+}
+
+impl Alligator {
+    pub fn new(jaw: usize, teeth: usize, lips: usize) -> Result<Self> {
+        for (name, value) in [("jaw", jaw), ("teeth", teeth), ("lips", lips)] {
+            if value == 0 {
+                return Err(TaError::invalid_parameter(name, value));
+            }
+        }
+
+        Ok(Self {
+            jaw: DisplacedLine::new(jaw, 8),
+            teeth: DisplacedLine::new(teeth, 5),
+            lips: DisplacedLine::new(lips, 3),
+            bars_seen: 0,
+        })
+    }
+}
+
+/// The period of the jaw.
+impl Period for Alligator {
+    fn period(&self) -> usize {
+        self.jaw.smma.period()
+    }
+}
+
+impl<T: High + Low> Next<&T> for Alligator {
+    type Output = AlligatorOutput;
+
+    fn next(&mut self, input: &T) -> Self::Output {
+        self.bars_seen = self.bars_seen.saturating_add(1);
+        let median = (input.high() + input.low()) / 2.0;
+
+        AlligatorOutput {
+            jaw: self.jaw.next(median),
+            teeth: self.teeth.next(median),
+            lips: self.lips.next(median),
+        }
+    }
+}
+
+impl Ready for Alligator {
+    fn bars_seen(&self) -> usize {
+        self.bars_seen
+    }
+
+    fn is_ready(&self) -> bool {
+        // every line is complete once its displaced average is
+        [&self.jaw, &self.teeth, &self.lips]
+            .iter()
+            .all(|line| self.bars_seen >= line.smma.period() + line.delay.delay())
+    }
+}
+
+impl Reset for Alligator {
+    fn reset(&mut self) {
+        self.bars_seen = 0;
+        self.jaw.reset();
+        self.teeth.reset();
+        self.lips.reset();
+    }
+}
+
+impl Default for Alligator {
+    fn default() -> Self {
+        Self::new(13, 8, 5).unwrap()
+    }
+}
+
+impl fmt::Display for Alligator {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "ALLIGATOR({}, {}, {})",
+            self.jaw.smma.period(),
+            self.teeth.smma.period(),
+            self.lips.smma.period()
+        )
+    }
+}
+
+/// A smoothed moving average returning the value of `displacement` inputs ago.
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone)]
+struct DisplacedLine {
+    smma: WilderSmoothing,
+    delay: Delay,
+}
+
+impl DisplacedLine {
+    fn new(period: usize, displacement: usize) -> Self {
+        Self {
+            smma: WilderSmoothing::new(period),
+            delay: Delay::new(displacement),
+        }
+    }
+
+    fn next(&mut self, input: f64) -> f64 {
+        let smma = self.smma.next(input);
+        // until the delay is filled the first average stands in
+        match self.delay.next(smma) {
+            Some(displaced) => displaced,
+            None => *self.delay.oldest().unwrap(),
+        }
+    }
+
+    fn reset(&mut self) {
+        self.smma.reset();
+        self.delay.reset();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helper::*;
+
+    #[test]
+    fn test_new() {
+        assert!(Alligator::new(0, 8, 5).is_err());
+        assert!(Alligator::new(13, 0, 5).is_err());
+        assert!(Alligator::new(13, 8, 0).is_err());
+        assert!(Alligator::new(13, 8, 5).is_ok());
+    }
+
+    #[test]
+    fn test_next() {
+        let mut alligator = Alligator::new(2, 2, 2).unwrap();
+
+        // the lips are displaced by 3 bars
+        let lips: Vec<f64> = [10.0, 12.0, 14.0, 16.0, 18.0, 20.0]
+            .iter()
+            .map(|&median| {
+                alligator
+                    .next(&Bar::new().high(median + 1.0).low(median - 1.0))
+                    .lips
+            })
+            .collect();
+        assert_eq!(lips, [10.0, 10.0, 10.0, 10.0, 11.0, 12.5]);
+    }
+
+    #[test]
+    fn test_next_trend() {
+        let mut alligator = Alligator::default();
+
+        let mut out = alligator.next(&Bar::new().high(101).low(99));
+        for i in 1..40 {
+            out = alligator.next(&Bar::new().high(101.0 + i as f64).low(99.0 + i as f64));
+        }
+        // in an uptrend the fastest line is the highest
+        assert!(out.lips > out.teeth && out.teeth > out.jaw);
+
+        for i in 1..40 {
+            out = alligator.next(&Bar::new().high(140.0 - i as f64).low(138.0 - i as f64));
+        }
+        // and in a downtrend the lowest
+        assert!(out.lips < out.teeth && out.teeth < out.jaw);
+    }
+
+    #[test]
+    fn test_reset() {
+        let mut alligator = Alligator::new(2, 2, 2).unwrap();
+
+        alligator.next(&Bar::new().high(11).low(9));
+        alligator.next(&Bar::new().high(21).low(19));
+        alligator.reset();
+
+        let out = alligator.next(&Bar::new().high(31).low(29));
+        assert_eq!((out.jaw, out.teeth, out.lips), (30.0, 30.0, 30.0));
+    }
+
+    #[test]
+    fn test_ready() {
+        let mut gator = Alligator::new(3, 2, 1).unwrap();
+        let bar = Bar::new().high(10).low(8).close(9);
+        for _ in 0..10 {
+            gator.next(&bar);
+        }
+        assert_eq!((gator.bars_seen(), gator.is_ready()), (10, false));
+        // the jaw is displaced by 8 bars
+        gator.next(&bar);
+        assert_eq!((gator.bars_seen(), gator.is_ready()), (11, true));
+
+        gator.reset();
+        assert_eq!((gator.bars_seen(), gator.is_ready()), (0, false));
+    }
+
+    #[test]
+    fn test_default() {
+        Alligator::default();
+    }
+
+    #[test]
+    fn test_display() {
+        let alligator = Alligator::new(13, 8, 5).unwrap();
+        assert_eq!(format!("{}", alligator), "ALLIGATOR(13, 8, 5)");
+    }
+}
@@ -0,0 +1,266 @@
+use std::fmt;
+
+use crate::errors::{Result, TaError};
+use crate::window::MonotonicWindow;
+use crate::{High, Low, Next, Period, Ready, Reset};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Aroon Up/Down and Aroon Oscillator.
+///
+/// Developed by Tushar Chande, the Aroon indicator measures how many bars have passed since
+/// the highest high and the lowest low of the last _period_ + 1 bars. Aroon Up is 100 on the
+/// bar making a new high and falls towards 0 as the high gets older, Aroon Down does the same
+/// for lows.
+///
+/// # Formula
+///
+/// Aroon Up = 100 * (period - bars since the highest high) / period
+///
+/// Aroon Down = 100 * (period - bars since the lowest low) / period
+///
+/// Aroon Oscillator = Aroon Up - Aroon Down
+///
+/// When the same extreme occurs more than once in the window, the most recent one is used.
+///
+/// # Parameters
+///
+/// * _period_ - number of bars (integer greater than 0). Default is 25.
+///
+/// # Example
+///
+/// ```
+/// use ta::indicators::Aroon;
+/// use ta::{DataItem, Next};
+///
+/// let mut aroon = Aroon::new(2).unwrap();
+///
+/// let bar = |high, low| {
+///     DataItem::builder()
+///         .open(low).high(high).low(low).close(low).volume(1000.0)
+///         .build(true).unwrap()
+/// };
+///
+/// aroon.next(&bar(10.0, 8.0));
+/// let out = aroon.next(&bar(9.0, 7.0));
+///
+/// assert_eq!(out.up, 50.0);
+/// assert_eq!(out.down, 100.0);
+/// assert_eq!(out.oscillator, -50.0);
+/// ```
+///
+/// # Links
+///
+/// * [Aroon, StockCharts](https://school.stockcharts.com/doku.php?id=technical_indicators:aroon)
+///
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone)]
+pub struct Aroon {
+    period: usize,
+    window: MonotonicWindow,
+    #[cfg_attr(feature = "serde", serde(default))]
+    bars_seen: usize,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct AroonOutput {
+    pub up: f64,
+    pub down: f64,
+    pub oscillator: f64,
+}
+
+impl From<AroonOutput> for (f64, f64, f64) {
+    fn from(ao: AroonOutput) -> Self {
+        (ao.up, ao.down, ao.oscillator)
+    }
+}
+
+impl Aroon {
+    pub fn new(period: usize) -> Result<Self> {
+        match period {
+            0 => Err(TaError::invalid_parameter("period", period)),
+            _ => Ok(Self {
+                period,
+                // the window holds period + 1 bars
+                window: MonotonicWindow::new(period + 1)?,
+                bars_seen: 0,
+            }),
+        }
+    }
+
+    fn aroon(&self, bars_since: usize) -> f64 {
+        100.0 * (self.period - bars_since) as f64 / self.period as f64
+    }
+}
+
+impl Period for Aroon {
+    fn period(&self) -> usize {
+        self.period
+    }
+}
+
+impl<T: High + Low> Next<&T> for Aroon {
+    type Output = AroonOutput;
+
+    fn next(&mut self, input: &T) -> Self::Output {
+        self.bars_seen = self.bars_seen.saturating_add(1);
+        self.window.push_range(input.low(), input.high());
+        let up = self.aroon(self.window.since_max());
+        let down = self.aroon(self.window.since_min());
+
+        AroonOutput {
+            up,
+            down,
+            oscillator: up - down,
+        }
+    }
+}
+
+impl Ready for Aroon {
+    fn bars_seen(&self) -> usize {
+        self.bars_seen
+    }
+
+    fn is_ready(&self) -> bool {
+        // the window holds _period + 1_ bars
+        self.bars_seen > self.period
+    }
+}
+
+impl Reset for Aroon {
+    fn reset(&mut self) {
+        self.bars_seen = 0;
+        self.window.reset();
+    }
+}
+
+impl Default for Aroon {
+    fn default() -> Self {
+        Self::new(25).unwrap()
+    }
+}
+
+impl fmt::Display for Aroon {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "AROON({})", self.period)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helper::*;
+
+    #[test]
+    fn test_new() {
+        assert!(Aroon::new(0).is_err());
+        assert!(Aroon::new(1).is_ok());
+        assert!(Aroon::new(25).is_ok());
+    }
+
+    #[test]
+    fn test_next() {
+        let mut aroon = Aroon::new(4).unwrap();
+
+        #[rustfmt::skip]
+        let bars = [
+            // high, low, up, down
+            (10.0, 8.0, 100.0, 100.0),
+            (11.0, 9.0, 100.0, 75.0),
+            (10.5, 8.5, 75.0, 50.0),
+            (10.0, 7.5, 50.0, 100.0),
+            (9.5, 8.0, 25.0, 75.0),
+            (9.0, 8.2, 0.0, 50.0),    // the high of 11.0 is 4 bars old
+            (9.2, 8.4, 0.0, 25.0),    // 11.0 left the window, 10.5 is 4 bars old
+            (9.8, 8.3, 0.0, 0.0),
+            (9.8, 8.6, 100.0, 0.0),   // equal highs, the most recent one counts
+        ];
+
+        for (high, low, up, down) in bars {
+            let out = aroon.next(&Bar::new().high(high).low(low));
+            assert_eq!(out.up, up);
+            assert_eq!(out.down, down);
+            assert_eq!(out.oscillator, up - down);
+        }
+    }
+
+    #[test]
+    fn test_next_new_high() {
+        let mut aroon = Aroon::new(3).unwrap();
+
+        for i in 0..10 {
+            let x = i as f64;
+            let out = aroon.next(&Bar::new().high(10.0 + x).low(5.0 - (x * 2.0).sin()));
+            assert_eq!(out.up, 100.0);
+        }
+    }
+
+    #[test]
+    fn test_next_matches_naive() {
+        let period = 5;
+        let mut aroon = Aroon::new(period).unwrap();
+
+        let highs: Vec<f64> = (0..60).map(|i| ((i * 37) % 17) as f64).collect();
+        let lows: Vec<f64> = (0..60).map(|i| ((i * 23) % 13) as f64).collect();
+
+        for i in 0..highs.len() {
+            let out = aroon.next(&Bar::new().high(highs[i]).low(lows[i]));
+
+            let from = i.saturating_sub(period);
+            let mut high_index = from;
+            let mut low_index = from;
+            for j in from..=i {
+                if highs[j] >= highs[high_index] {
+                    high_index = j;
+                }
+                if lows[j] <= lows[low_index] {
+                    low_index = j;
+                }
+            }
+            let up = 100.0 * (period - (i - high_index)) as f64 / period as f64;
+            let down = 100.0 * (period - (i - low_index)) as f64 / period as f64;
+            assert_eq!(out.up, up);
+            assert_eq!(out.down, down);
+        }
+    }
+
+    #[test]
+    fn test_reset() {
+        let mut aroon = Aroon::new(3).unwrap();
+
+        aroon.next(&Bar::new().high(10).low(8));
+        aroon.next(&Bar::new().high(9).low(7));
+        aroon.reset();
+
+        let out = aroon.next(&Bar::new().high(5).low(4));
+        assert_eq!(out.up, 100.0);
+        assert_eq!(out.down, 100.0);
+    }
+
+    #[test]
+    fn test_ready() {
+        let mut aroon = Aroon::new(3).unwrap();
+        let bar = Bar::new().high(10).low(8).close(9);
+        for _ in 0..3 {
+            aroon.next(&bar);
+        }
+        assert_eq!((aroon.bars_seen(), aroon.is_ready()), (3, false));
+        // the fourth input fills the window
+        aroon.next(&bar);
+        assert_eq!((aroon.bars_seen(), aroon.is_ready()), (4, true));
+
+        aroon.reset();
+        assert_eq!((aroon.bars_seen(), aroon.is_ready()), (0, false));
+    }
+
+    #[test]
+    fn test_default() {
+        Aroon::default();
+    }
+
+    #[test]
+    fn test_display() {
+        let aroon = Aroon::new(25).unwrap();
+        assert_eq!(format!("{}", aroon), "AROON(25)");
+    }
+}
@@ -0,0 +1,349 @@
+use std::fmt;
+
+use crate::errors::{Result, TaError};
+use crate::helpers::WilderSmoothing;
+use crate::indicators::TrueRange;
+use crate::{Close, High, Low, Next, Period, Ready, Reset};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Average directional index (ADX).
+///
+/// A trend strength indicator developed by J. Welles Wilder. The positive and negative
+/// directional indicators (+DI and −DI) measure how much of the true range is made of upward
+/// and downward movement, while ADX measures how strong the trend is regardless of its
+/// direction.
+///
+/// # Formula
+///
+/// +DM<sub>t</sub> = high<sub>t</sub> - high<sub>t-1</sub>, if it is greater than
+/// low<sub>t-1</sub> - low<sub>t</sub> and greater than 0, otherwise 0
+///
+/// −DM<sub>t</sub> = low<sub>t-1</sub> - low<sub>t</sub>, if it is greater than
+/// high<sub>t</sub> - high<sub>t-1</sub> and greater than 0, otherwise 0
+///
+/// +DI<sub>t</sub> = 100 * W(+DM)<sub>t</sub> / W(TR)<sub>t</sub>
+///
+/// −DI<sub>t</sub> = 100 * W(−DM)<sub>t</sub> / W(TR)<sub>t</sub>
+///
+/// DX<sub>t</sub> = 100 * |+DI<sub>t</sub> - −DI<sub>t</sub>| / (+DI<sub>t</sub> + −DI<sub>t</sub>)
+///
+/// ADX<sub>t</sub> = W(DX)<sub>t</sub>
+///
+/// Where:
+///
+/// * _TR_ - [true range](struct.TrueRange.html)
+/// * _W_ - Wilder's smoothing over _period_. The first _period_ values are averaged, after
+///   that W<sub>t</sub> = (W<sub>t-1</sub> * (period - 1) + value<sub>t</sub>) / period
+///
+/// The first bar has no previous bar to compare with, so all outputs are 0. The DX is only
+/// smoothed once the DIs are seeded with _period_ true ranges, until then the ADX is 0. During
+/// the next _period_ bars it is the average of the DX values so far, from bar _2 * period_ on
+/// it is Wilder's ADX.
+///
+/// # Parameters
+///
+/// * _period_ - smoothing period (integer greater than 0). Default is 14.
+///
+/// # Example
+///
+/// ```
+/// use ta::indicators::AverageDirectionalIndex;
+/// use ta::{DataItem, Next};
+///
+/// let mut adx = AverageDirectionalIndex::new(14).unwrap();
+///
+/// let bar = |high, low, close| {
+///     DataItem::builder()
+///         .open(close).high(high).low(low).close(close).volume(1000.0)
+///         .build(true).unwrap()
+/// };
+///
+/// adx.next(&bar(10.0, 9.0, 9.5));
+/// let out = adx.next(&bar(11.0, 9.5, 10.5));
+///
+/// assert_eq!(out.plus_di, 100.0 * 1.0 / 1.5);
+/// assert_eq!(out.minus_di, 0.0);
+/// // the DIs are not seeded yet
+/// assert_eq!(out.adx, 0.0);
+/// ```
+///
+/// # Links
+///
+/// * [Average directional movement index, Wikipedia](https://en.wikipedia.org/wiki/Average_directional_movement_index)
+/// * [ADX, StockCharts](https://school.stockcharts.com/doku.php?id=technical_indicators:average_directional_index_adx)
+///
+#[doc(alias = "ADX")]
+#[doc(alias = "DMI")]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone)]
+pub struct AverageDirectionalIndex {
+    period: usize,
+    true_range: TrueRange,
+    tr: WilderSmoothing,
+    plus_dm: WilderSmoothing,
+    minus_dm: WilderSmoothing,
+    dx: WilderSmoothing,
+    prev: Option<(f64, f64)>,
+    #[cfg_attr(feature = "serde", serde(default))]
+    bars_seen: usize,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct AverageDirectionalIndexOutput {
+    pub adx: f64,
+    pub plus_di: f64,
+    pub minus_di: f64,
+}
+
+impl From<AverageDirectionalIndexOutput> for (f64, f64, f64) {
+    fn from(ao: AverageDirectionalIndexOutput) -> Self {
+        (ao.adx, ao.plus_di, ao.minus_di)
+    }
+}
+
+impl AverageDirectionalIndex {
+    pub fn new(period: usize) -> Result<Self> {
+        match period {
+            0 => Err(TaError::invalid_parameter("period", period)),
+            _ => Ok(Self {
+                period,
+                true_range: TrueRange::new(),
+                tr: WilderSmoothing::new(period),
+                plus_dm: WilderSmoothing::new(period),
+                minus_dm: WilderSmoothing::new(period),
+                dx: WilderSmoothing::new(period),
+                prev: None,
+                bars_seen: 0,
+            }),
+        }
+    }
+}
+
+impl Period for AverageDirectionalIndex {
+    fn period(&self) -> usize {
+        self.period
+    }
+}
+
+impl<T: High + Low + Close> Next<&T> for AverageDirectionalIndex {
+    type Output = AverageDirectionalIndexOutput;
+
+    fn next(&mut self, input: &T) -> Self::Output {
+        self.bars_seen = self.bars_seen.saturating_add(1);
+        let high = input.high();
+        let low = input.low();
+        let true_range = self.true_range.next(input);
+
+        let (prev_high, prev_low) = match self.prev.replace((high, low)) {
+            Some(prev) => prev,
+            None => {
+                return AverageDirectionalIndexOutput {
+                    adx: 0.0,
+                    plus_di: 0.0,
+                    minus_di: 0.0,
+                }
+            }
+        };
+
+        let up_move = high - prev_high;
+        let down_move = prev_low - low;
+        let plus_dm = if up_move > down_move && up_move > 0.0 {
+            up_move
+        } else {
+            0.0
+        };
+        let minus_dm = if down_move > up_move && down_move > 0.0 {
+            down_move
+        } else {
+            0.0
+        };
+
+        let tr = self.tr.next(true_range);
+        let plus_dm = self.plus_dm.next(plus_dm);
+        let minus_dm = self.minus_dm.next(minus_dm);
+
+        let (plus_di, minus_di) = if tr > 0.0 {
+            (100.0 * plus_dm / tr, 100.0 * minus_dm / tr)
+        } else {
+            (0.0, 0.0)
+        };
+
+        let di_sum = plus_di + minus_di;
+        let dx = if di_sum > 0.0 {
+            100.0 * (plus_di - minus_di).abs() / di_sum
+        } else {
+            0.0
+        };
+
+        let adx = if self.tr.is_seeded() {
+            self.dx.next(dx)
+        } else {
+            0.0
+        };
+
+        AverageDirectionalIndexOutput {
+            adx,
+            plus_di,
+            minus_di,
+        }
+    }
+}
+
+impl Ready for AverageDirectionalIndex {
+    fn bars_seen(&self) -> usize {
+        self.bars_seen
+    }
+
+    fn is_ready(&self) -> bool {
+        self.dx.is_seeded()
+    }
+}
+
+impl Reset for AverageDirectionalIndex {
+    fn reset(&mut self) {
+        self.bars_seen = 0;
+        self.true_range.reset();
+        self.tr.reset();
+        self.plus_dm.reset();
+        self.minus_dm.reset();
+        self.dx.reset();
+        self.prev = None;
+    }
+}
+
+impl Default for AverageDirectionalIndex {
+    fn default() -> Self {
+        Self::new(14).unwrap()
+    }
+}
+
+impl fmt::Display for AverageDirectionalIndex {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "ADX({})", self.period)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helper::*;
+
+    #[test]
+    fn test_new() {
+        assert!(AverageDirectionalIndex::new(0).is_err());
+        assert!(AverageDirectionalIndex::new(1).is_ok());
+        assert!(AverageDirectionalIndex::new(14).is_ok());
+    }
+
+    #[test]
+    fn test_next() {
+        let mut adx = AverageDirectionalIndex::new(14).unwrap();
+
+        #[rustfmt::skip]
+        let data = [
+            // high, low, close, adx, +di, -di
+            // the ADX column of the StockCharts sample starts at bar 28
+            (30.20, 29.41, 29.87, None, 0.0, 0.0),
+            (30.28, 29.32, 30.24, None, 0.0, 9.375),
+            (30.45, 29.96, 30.10, None, 11.724, 6.207),
+            (29.35, 28.74, 28.90, None, 6.05, 46.619),
+            (29.35, 28.56, 28.92, None, 4.722, 41.389),
+            (29.29, 28.41, 28.48, None, 3.795, 36.607),
+            (28.83, 28.08, 28.56, None, 3.25, 37.667),
+            (28.73, 27.43, 27.56, None, 2.603, 40.123),
+            (28.67, 27.66, 28.47, None, 2.225, 34.293),
+            (28.85, 27.83, 28.28, None, 4.042, 30.254),
+            (28.64, 27.40, 27.49, None, 3.535, 30.808),
+            (27.68, 27.09, 27.23, None, 3.337, 32.031),
+            (27.21, 26.18, 26.35, None, 3.033, 37.002),
+            (26.87, 26.13, 26.33, None, 2.85, 35.179),
+            (27.41, 26.63, 27.03, None, 6.662, 32.335),
+            (26.94, 26.13, 26.22, None, 6.211, 33.906),
+            (26.52, 25.43, 26.01, None, 5.708, 36.363),
+            (26.52, 25.35, 25.46, None, 5.219, 33.834),
+            (27.09, 25.88, 27.03, None, 8.607, 29.98),
+            (27.69, 26.96, 27.45, None, 12.44, 28.418),
+            (28.45, 27.14, 28.36, None, 16.607, 25.82),
+            (28.53, 28.01, 28.43, None, 16.561, 24.848),
+            (28.67, 27.88, 27.95, None, 16.628, 23.408),
+            (29.01, 27.99, 29.01, None, 17.822, 21.598),
+            (29.87, 28.76, 29.38, None, 22.606, 19.866),
+            (29.80, 29.14, 29.36, None, 21.502, 18.896),
+            (29.75, 28.71, 28.91, None, 19.856, 20.614),
+            (30.65, 28.93, 30.61, Some(33.708), 23.718, 18.116),
+            (30.60, 30.03, 30.05, Some(32.257), 22.729, 17.361),
+            (30.76, 29.39, 30.19, Some(30.018), 20.55, 20.175),
+        ];
+
+        for (high, low, close, expected_adx, plus_di, minus_di) in data {
+            let out = adx.next(&Bar::new().high(high).low(low).close(close));
+            if let Some(expected_adx) = expected_adx {
+                assert_eq!(round(out.adx), expected_adx);
+            }
+            assert_eq!(round(out.plus_di), plus_di);
+            assert_eq!(round(out.minus_di), minus_di);
+        }
+    }
+
+    #[test]
+    fn test_next_flat() {
+        let mut adx = AverageDirectionalIndex::new(3).unwrap();
+        let bar = Bar::new().high(5).low(5).close(5);
+
+        for _ in 0..5 {
+            assert_eq!(
+                adx.next(&bar),
+                AverageDirectionalIndexOutput {
+                    adx: 0.0,
+                    plus_di: 0.0,
+                    minus_di: 0.0,
+                }
+            );
+        }
+    }
+
+    #[test]
+    fn test_reset() {
+        let mut adx = AverageDirectionalIndex::new(3).unwrap();
+
+        adx.next(&Bar::new().high(10).low(9).close(9.5));
+        adx.next(&Bar::new().high(11).low(9.5).close(10.5));
+        adx.next(&Bar::new().high(9).low(8).close(8.5));
+
+        adx.reset();
+        let out = adx.next(&Bar::new().high(11).low(9.5).close(10.5));
+        assert_eq!(out.adx, 0.0);
+        let out = adx.next(&Bar::new().high(12).low(10).close(11));
+        assert_eq!(out.plus_di, 50.0);
+        assert_eq!(out.minus_di, 0.0);
+        assert_eq!(out.adx, 0.0);
+    }
+
+    #[test]
+    fn test_ready() {
+        let mut adx = AverageDirectionalIndex::new(3).unwrap();
+        let bar = Bar::new().high(10).low(8).close(9);
+        for _ in 0..5 {
+            adx.next(&bar);
+        }
+        assert_eq!((adx.bars_seen(), adx.is_ready()), (5, false));
+        // the first DX average is complete with the sixth input
+        adx.next(&bar);
+        assert_eq!((adx.bars_seen(), adx.is_ready()), (6, true));
+
+        adx.reset();
+        assert_eq!((adx.bars_seen(), adx.is_ready()), (0, false));
+    }
+
+    #[test]
+    fn test_default() {
+        AverageDirectionalIndex::default();
+    }
+
+    #[test]
+    fn test_display() {
+        let adx = AverageDirectionalIndex::new(14).unwrap();
+        assert_eq!(format!("{}", adx), "ADX(14)");
+    }
+}
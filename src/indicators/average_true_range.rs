@@ -2,7 +2,7 @@ use std::fmt;
 
 use crate::errors::Result;
 use crate::indicators::{ExponentialMovingAverage, TrueRange};
-use crate::{Close, High, Low, Next, Period, Reset};
+use crate::{Close, Current, High, Low, Next, Period, Ready, Reset};
 
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
@@ -52,7 +52,7 @@ use serde::{Deserialize, Serialize};
 ///             .close(close)
 ///             .open(open)
 ///             .volume(1000.0)
-///             .build().unwrap();
+///             .build(true).unwrap();
 ///         assert_approx_eq!(indicator.next(&di), atr);
 ///     }
 /// }
@@ -95,6 +95,24 @@ impl<T: High + Low + Close> Next<&T> for AverageTrueRange {
     }
 }
 
+impl Current for AverageTrueRange {
+    type Output = f64;
+
+    fn current(&self) -> Option<Self::Output> {
+        self.ema.current()
+    }
+}
+
+impl Ready for AverageTrueRange {
+    fn bars_seen(&self) -> usize {
+        self.ema.bars_seen()
+    }
+
+    fn is_ready(&self) -> bool {
+        self.ema.is_ready()
+    }
+}
+
 impl Reset for AverageTrueRange {
     fn reset(&mut self) {
         self.true_range.reset();
@@ -154,6 +172,41 @@ mod tests {
         assert_eq!(atr.next(&bar3), 45.0);
     }
 
+    #[test]
+    fn test_current() {
+        let bars = [
+            Bar::new().high(10).low(7.5).close(9),
+            Bar::new().high(11).low(9).close(9.5),
+            Bar::new().high(9).low(5).close(8),
+        ];
+
+        let mut atr = AverageTrueRange::new(3).unwrap();
+        assert_eq!(atr.current(), None);
+
+        for bar in &bars {
+            let value = atr.next(bar);
+            assert_eq!(atr.current(), Some(value));
+        }
+
+        atr.reset();
+        assert_eq!(atr.current(), None);
+    }
+
+    #[test]
+    fn test_ready() {
+        let mut atr = AverageTrueRange::new(3).unwrap();
+        let bar = Bar::new().high(10).low(8).close(9);
+        for _ in 0..2 {
+            atr.next(&bar);
+        }
+        assert_eq!((atr.bars_seen(), atr.is_ready()), (2, false));
+        atr.next(&bar);
+        assert_eq!((atr.bars_seen(), atr.is_ready()), (3, true));
+
+        atr.reset();
+        assert_eq!((atr.bars_seen(), atr.is_ready()), (0, false));
+    }
+
     #[test]
     fn test_default() {
         AverageTrueRange::default();
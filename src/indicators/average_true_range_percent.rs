@@ -0,0 +1,200 @@
+use std::fmt;
+
+use crate::errors::Result;
+use crate::indicators::AverageTrueRange;
+use crate::{Close, High, Low, Next, Period, Ready, Reset};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Average true range percent (ATRP).
+///
+/// The [average true range](struct.AverageTrueRange.html) as a percentage of the close. The
+/// ATR is expressed in price units, so it can not be compared between instruments trading at
+/// different prices, or for one instrument over a long time. The ATRP can.
+///
+/// # Formula
+///
+/// ATRP = ATR(period) / close * 100
+///
+/// A close of 0 yields 0.
+///
+/// # Parameters
+///
+/// * _period_ - smoothing period of the ATR (integer greater than 0). Default is 14.
+///
+/// # Example
+///
+/// ```
+/// use ta::indicators::AverageTrueRangePercent;
+/// use ta::{DataItem, Next};
+///
+/// let mut atrp = AverageTrueRangePercent::new(3).unwrap();
+///
+/// let di = DataItem::builder()
+///     .open(9.0).high(11.0).low(9.0).close(10.0).volume(1000.0)
+///     .build(true).unwrap();
+///
+/// // a true range of 2 on a close of 10
+/// assert_eq!(atrp.next(&di), 20.0);
+/// ```
+///
+/// # Links
+///
+/// * [Average True Range, StockCharts](https://school.stockcharts.com/doku.php?id=technical_indicators:average_true_range_atr)
+///
+#[doc(alias = "ATRP")]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone)]
+pub struct AverageTrueRangePercent {
+    atr: AverageTrueRange,
+}
+
+impl AverageTrueRangePercent {
+    pub fn new(period: usize) -> Result<Self> {
+        Ok(Self {
+            atr: AverageTrueRange::new(period)?,
+        })
+    }
+}
+
+impl Period for AverageTrueRangePercent {
+    fn period(&self) -> usize {
+        self.atr.period()
+    }
+}
+
+impl<T: High + Low + Close> Next<&T> for AverageTrueRangePercent {
+    type Output = f64;
+
+    fn next(&mut self, input: &T) -> Self::Output {
+        let atr = self.atr.next(input);
+        if input.close() == 0.0 {
+            0.0
+        } else {
+            atr / input.close() * 100.0
+        }
+    }
+}
+
+impl Ready for AverageTrueRangePercent {
+    fn bars_seen(&self) -> usize {
+        self.atr.bars_seen()
+    }
+
+    fn is_ready(&self) -> bool {
+        self.atr.is_ready()
+    }
+}
+
+impl Reset for AverageTrueRangePercent {
+    fn reset(&mut self) {
+        self.atr.reset();
+    }
+}
+
+impl Default for AverageTrueRangePercent {
+    fn default() -> Self {
+        Self::new(14).unwrap()
+    }
+}
+
+impl fmt::Display for AverageTrueRangePercent {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "ATRP({})", self.atr.period())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helper::*;
+
+    #[test]
+    fn test_new() {
+        assert!(AverageTrueRangePercent::new(0).is_err());
+        assert!(AverageTrueRangePercent::new(1).is_ok());
+    }
+
+    #[test]
+    fn test_next() {
+        let mut atrp = AverageTrueRangePercent::new(3).unwrap();
+
+        let bar1 = Bar::new().high(10).low(7.5).close(9);
+        let bar2 = Bar::new().high(11).low(9).close(9.5);
+        let bar3 = Bar::new().high(9).low(5).close(8);
+
+        // atr: 2.5, 2.25, 3.375
+        assert_eq!(round(atrp.next(&bar1)), 27.778);
+        assert_eq!(round(atrp.next(&bar2)), 23.684);
+        assert_eq!(round(atrp.next(&bar3)), 42.188);
+    }
+
+    #[test]
+    fn test_next_price_levels() {
+        let mut cheap = AverageTrueRangePercent::default();
+        let mut expensive = AverageTrueRangePercent::default();
+
+        for i in 0..30 {
+            let swing = 1.0 + (i % 4) as f64 * 0.5;
+            let price = 10.0 + (i % 3) as f64;
+
+            let out = cheap.next(&Bar::new().high(price + swing).low(price).close(price));
+            let scaled = price * 100.0;
+            let expected = expensive.next(
+                &Bar::new()
+                    .high(scaled + swing * 100.0)
+                    .low(scaled)
+                    .close(scaled),
+            );
+
+            // the same moves at a hundred times the price
+            assert_eq!(round(out), round(expected));
+        }
+    }
+
+    #[test]
+    fn test_next_zero_close() {
+        let mut atrp = AverageTrueRangePercent::new(3).unwrap();
+        assert_eq!(atrp.next(&Bar::new().high(1).low(0).close(0)), 0.0);
+    }
+
+    #[test]
+    fn test_reset() {
+        let mut atrp = AverageTrueRangePercent::new(3).unwrap();
+
+        atrp.next(&Bar::new().high(10).low(7.5).close(9));
+        atrp.next(&Bar::new().high(11).low(9).close(9.5));
+        atrp.reset();
+
+        assert_eq!(
+            round(atrp.next(&Bar::new().high(10).low(7.5).close(9))),
+            27.778
+        );
+    }
+
+    #[test]
+    fn test_ready() {
+        let mut atr = AverageTrueRangePercent::new(3).unwrap();
+        let bar = Bar::new().high(10).low(8).close(9);
+        for _ in 0..2 {
+            atr.next(&bar);
+        }
+        assert_eq!((atr.bars_seen(), atr.is_ready()), (2, false));
+        atr.next(&bar);
+        assert_eq!((atr.bars_seen(), atr.is_ready()), (3, true));
+
+        atr.reset();
+        assert_eq!((atr.bars_seen(), atr.is_ready()), (0, false));
+    }
+
+    #[test]
+    fn test_default() {
+        AverageTrueRangePercent::default();
+    }
+
+    #[test]
+    fn test_display() {
+        let atrp = AverageTrueRangePercent::new(14).unwrap();
+        assert_eq!(format!("{}", atrp), "ATRP(14)");
+    }
+}
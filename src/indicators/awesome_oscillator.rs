@@ -0,0 +1,224 @@
+use std::fmt;
+
+use crate::errors::{Result, TaError};
+use crate::indicators::SimpleMovingAverage;
+use crate::{High, Low, Next, Period, Ready, Reset};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Awesome Oscillator (AO).
+///
+/// Developed by Bill Williams, the awesome oscillator is the difference between a short and
+/// a long simple moving average of the median price. It shows the momentum of the market,
+/// a cross of the zero line signals a change of momentum.
+///
+/// # Formula
+///
+/// Median price = (high + low) / 2
+///
+/// AO = SMA(short) of median price - SMA(long) of median price
+///
+/// Where _SMA_ is the [simple moving average](struct.SimpleMovingAverage.html).
+///
+/// # Parameters
+///
+/// * _short_ - period of the short SMA (integer greater than 0). Default is 5.
+/// * _long_ - period of the long SMA (integer not less than _short_). Default is 34.
+///
+/// # Example
+///
+/// ```
+/// use ta::indicators::AwesomeOscillator;
+/// use ta::{DataItem, Next};
+///
+/// let mut ao = AwesomeOscillator::new(2, 4).unwrap();
+///
+/// let bar = |high, low| {
+///     DataItem::builder()
+///         .open(low).high(high).low(low).close(high).volume(1000.0)
+///         .build(true).unwrap()
+/// };
+///
+/// assert_eq!(ao.next(&bar(10.5, 9.5)), 0.0);
+/// assert_eq!(ao.next(&bar(11.5, 10.5)), 0.0);
+/// assert_eq!(ao.next(&bar(12.5, 11.5)), 0.5);
+/// ```
+///
+/// # Links
+///
+/// * [Awesome Oscillator, TradingView](https://www.tradingview.com/support/solutions/43000501826-awesome-oscillator-ao/)
+///
+#[doc(alias = "AO")]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone)]
+pub struct AwesomeOscillator {
+    short_sma: SimpleMovingAverage,
+    long_sma: SimpleMovingAverage,
+}
+
+impl AwesomeOscillator {
+    pub fn new(short: usize, long: usize) -> Result<Self> {
+        if short > long {
+            return Err(TaError::invalid_parameter("long", long));
+        }
+        Ok(Self {
+            short_sma: SimpleMovingAverage::new(short)?,
+            long_sma: SimpleMovingAverage::new(long)?,
+        })
+    }
+}
+
+impl Period for AwesomeOscillator {
+    fn period(&self) -> usize {
+        self.long_sma.period()
+    }
+}
+
+impl<T: High + Low> Next<&T> for AwesomeOscillator {
+    type Output = f64;
+
+    fn next(&mut self, input: &T) -> Self::Output {
+        let median_price = (input.high() + input.low()) / 2.0;
+        self.short_sma.next(median_price) - self.long_sma.next(median_price)
+    }
+}
+
+impl Ready for AwesomeOscillator {
+    fn bars_seen(&self) -> usize {
+        self.long_sma.bars_seen()
+    }
+
+    fn is_ready(&self) -> bool {
+        self.long_sma.is_ready()
+    }
+}
+
+impl Reset for AwesomeOscillator {
+    fn reset(&mut self) {
+        self.short_sma.reset();
+        self.long_sma.reset();
+    }
+}
+
+impl Default for AwesomeOscillator {
+    fn default() -> Self {
+        Self::new(5, 34).unwrap()
+    }
+}
+
+impl fmt::Display for AwesomeOscillator {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "AO({}, {})",
+            self.short_sma.period(),
+            self.long_sma.period()
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helper::*;
+
+    #[test]
+    fn test_new() {
+        assert!(AwesomeOscillator::new(0, 34).is_err());
+        assert!(AwesomeOscillator::new(5, 0).is_err());
+        assert!(AwesomeOscillator::new(34, 5).is_err());
+        assert!(AwesomeOscillator::new(1, 1).is_ok());
+        assert!(AwesomeOscillator::new(5, 34).is_ok());
+    }
+
+    #[test]
+    fn test_next() {
+        let mut ao = AwesomeOscillator::new(2, 4).unwrap();
+
+        let data = [
+            // median price, ao
+            (10.0, 0.0),
+            (11.0, 0.0),
+            (12.0, 0.5),
+            (13.0, 1.0),
+            (12.0, 0.5),
+            (11.0, -0.5),
+            (10.0, -1.0),
+            (9.0, -1.0),
+            (10.0, -0.5),
+            (11.0, 0.5),
+            (12.0, 1.0),
+            (13.0, 1.0),
+        ];
+
+        for (median_price, expected) in data {
+            assert_eq!(
+                round(ao.next(&Bar::new().high(median_price + 0.5).low(median_price - 0.5))),
+                expected
+            );
+        }
+    }
+
+    #[test]
+    fn test_next_zero_crossovers() {
+        let mut ao = AwesomeOscillator::new(2, 4).unwrap();
+        let median_prices = [
+            10.0, 11.0, 12.0, 13.0, 12.0, 11.0, 10.0, 9.0, 10.0, 11.0, 12.0,
+        ];
+
+        let mut prev = 0.0;
+        let mut crossed_down = vec![];
+        let mut crossed_up = vec![];
+        for (i, &median_price) in median_prices.iter().enumerate() {
+            let value = ao.next(&Bar::new().high(median_price + 0.5).low(median_price - 0.5));
+            if prev > 0.0 && value < 0.0 {
+                crossed_down.push(i);
+            } else if prev < 0.0 && value > 0.0 {
+                crossed_up.push(i);
+            }
+            prev = value;
+        }
+
+        assert_eq!(crossed_down, [5]);
+        assert_eq!(crossed_up, [9]);
+    }
+
+    #[test]
+    fn test_reset() {
+        let mut ao = AwesomeOscillator::new(2, 4).unwrap();
+
+        ao.next(&Bar::new().high(10.5).low(9.5));
+        ao.next(&Bar::new().high(20.5).low(19.5));
+        ao.reset();
+
+        assert_eq!(ao.next(&Bar::new().high(10.5).low(9.5)), 0.0);
+        assert_eq!(ao.next(&Bar::new().high(11.5).low(10.5)), 0.0);
+        assert_eq!(ao.next(&Bar::new().high(12.5).low(11.5)), 0.5);
+    }
+
+    #[test]
+    fn test_ready() {
+        let mut ao = AwesomeOscillator::new(2, 3).unwrap();
+        let bar = Bar::new().high(10).low(8).close(9);
+        for _ in 0..2 {
+            ao.next(&bar);
+        }
+        assert_eq!((ao.bars_seen(), ao.is_ready()), (2, false));
+        ao.next(&bar);
+        assert_eq!((ao.bars_seen(), ao.is_ready()), (3, true));
+
+        ao.reset();
+        assert_eq!((ao.bars_seen(), ao.is_ready()), (0, false));
+    }
+
+    #[test]
+    fn test_default() {
+        AwesomeOscillator::default();
+    }
+
+    #[test]
+    fn test_display() {
+        let ao = AwesomeOscillator::new(5, 34).unwrap();
+        assert_eq!(format!("{}", ao), "AO(5, 34)");
+    }
+}
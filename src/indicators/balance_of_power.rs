@@ -0,0 +1,246 @@
+use std::fmt;
+
+use crate::errors::Result;
+use crate::indicators::SimpleMovingAverage;
+use crate::{Close, High, Low, Next, Open, Period, Ready, Reset};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Balance of Power (BOP).
+///
+/// Developed by Igor Livshin, the balance of power measures the strength of the buyers
+/// against the sellers by how far the close moved from the open relative to the bar's range.
+/// It is bounded between -1 and 1.
+///
+/// # Formula
+///
+/// BOP = (close - open) / (high - low)
+///
+/// A bar with high equal to low yields 0. [with_smoothing](Self::with_smoothing) averages
+/// the values with a [simple moving average](struct.SimpleMovingAverage.html).
+///
+/// # Parameters
+///
+/// * _smoothing_ - period of the SMA (integer greater than 0). Optional, by default the
+///   values are not smoothed.
+///
+/// # Example
+///
+/// ```
+/// use ta::indicators::BalanceOfPower;
+/// use ta::{DataItem, Next};
+///
+/// let mut bop = BalanceOfPower::new();
+///
+/// let di = DataItem::builder()
+///     .open(10.0).high(12.0).low(8.0).close(11.0).volume(1000.0)
+///     .build(true).unwrap();
+///
+/// assert_eq!(bop.next(&di), 0.25);
+/// ```
+///
+/// # Links
+///
+/// * [Balance of Power, TradingView](https://www.tradingview.com/support/solutions/43000589100-balance-of-power-bop/)
+///
+#[doc(alias = "BOP")]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone)]
+pub struct BalanceOfPower {
+    sma: Option<SimpleMovingAverage>,
+    #[cfg_attr(feature = "serde", serde(default))]
+    bars_seen: usize,
+}
+
+impl BalanceOfPower {
+    pub fn new() -> Self {
+        Self {
+            sma: None,
+            bars_seen: 0,
+        }
+    }
+
+    pub fn with_smoothing(smoothing: usize) -> Result<Self> {
+        Ok(Self {
+            sma: Some(SimpleMovingAverage::new(smoothing)?),
+            bars_seen: 0,
+        })
+    }
+}
+
+/// The smoothing period, 1 when the values are not smoothed.
+impl Period for BalanceOfPower {
+    fn period(&self) -> usize {
+        self.sma.as_ref().map_or(1, |sma| sma.period())
+    }
+}
+
+impl<T: Open + High + Low + Close> Next<&T> for BalanceOfPower {
+    type Output = f64;
+
+    fn next(&mut self, input: &T) -> Self::Output {
+        self.bars_seen = self.bars_seen.saturating_add(1);
+        let range = input.high() - input.low();
+        let bop = if range == 0.0 {
+            0.0
+        } else {
+            (input.close() - input.open()) / range
+        };
+
+        match self.sma.as_mut() {
+            Some(sma) => sma.next(bop),
+            None => bop,
+        }
+    }
+}
+
+impl Ready for BalanceOfPower {
+    fn bars_seen(&self) -> usize {
+        self.bars_seen
+    }
+
+    fn is_ready(&self) -> bool {
+        self.bars_seen >= self.period()
+    }
+}
+
+impl Reset for BalanceOfPower {
+    fn reset(&mut self) {
+        self.bars_seen = 0;
+        if let Some(sma) = self.sma.as_mut() {
+            sma.reset();
+        }
+    }
+}
+
+impl Default for BalanceOfPower {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl fmt::Display for BalanceOfPower {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self.sma.as_ref() {
+            Some(sma) => write!(f, "BOP({})", sma.period()),
+            None => write!(f, "BOP"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helper::*;
+
+    #[test]
+    fn test_new() {
+        assert!(BalanceOfPower::with_smoothing(0).is_err());
+        assert!(BalanceOfPower::with_smoothing(1).is_ok());
+    }
+
+    #[test]
+    fn test_next() {
+        let mut bop = BalanceOfPower::new();
+
+        let data = [
+            // open, high, low, close, bop
+            (10.0, 12.0, 8.0, 11.0, 0.25),
+            (11.0, 12.0, 10.0, 10.0, -0.5),
+            // closes at the high after opening at the low
+            (10.0, 13.0, 10.0, 13.0, 1.0),
+            (13.0, 13.0, 9.0, 9.0, -1.0),
+            (9.0, 10.0, 8.0, 9.0, 0.0),
+        ];
+
+        for (open, high, low, close, expected) in data {
+            let value = bop.next(&Bar::new().open(open).high(high).low(low).close(close));
+            assert!((-1.0..=1.0).contains(&value));
+            assert_eq!(value, expected);
+        }
+    }
+
+    #[test]
+    fn test_next_zero_range() {
+        let mut bop = BalanceOfPower::new();
+        assert_eq!(
+            bop.next(&Bar::new().open(10).high(10).low(10).close(10)),
+            0.0
+        );
+
+        let mut bop = BalanceOfPower::with_smoothing(2).unwrap();
+        assert_eq!(
+            bop.next(&Bar::new().open(10).high(12).low(8).close(11)),
+            0.25
+        );
+        assert_eq!(
+            bop.next(&Bar::new().open(10).high(10).low(10).close(10)),
+            0.125
+        );
+    }
+
+    #[test]
+    fn test_next_smoothed() {
+        let mut bop = BalanceOfPower::with_smoothing(2).unwrap();
+
+        assert_eq!(
+            bop.next(&Bar::new().open(10).high(12).low(8).close(11)),
+            0.25
+        );
+        assert_eq!(
+            bop.next(&Bar::new().open(11).high(12).low(10).close(10)),
+            -0.125
+        );
+        assert_eq!(
+            bop.next(&Bar::new().open(10).high(13).low(10).close(13)),
+            0.25
+        );
+    }
+
+    #[test]
+    fn test_reset() {
+        let mut bop = BalanceOfPower::with_smoothing(2).unwrap();
+
+        bop.next(&Bar::new().open(10).high(12).low(8).close(11));
+        bop.next(&Bar::new().open(11).high(12).low(10).close(10));
+        bop.reset();
+
+        assert_eq!(
+            bop.next(&Bar::new().open(10).high(13).low(10).close(13)),
+            1.0
+        );
+    }
+
+    #[test]
+    fn test_period() {
+        assert_eq!(BalanceOfPower::new().period(), 1);
+        assert_eq!(BalanceOfPower::with_smoothing(14).unwrap().period(), 14);
+    }
+
+    #[test]
+    fn test_ready() {
+        let mut bop = BalanceOfPower::with_smoothing(3).unwrap();
+        let bar = Bar::new().open(9).high(10).low(8).close(9.5);
+        for _ in 0..2 {
+            bop.next(&bar);
+        }
+        assert_eq!((bop.bars_seen(), bop.is_ready()), (2, false));
+        bop.next(&bar);
+        assert_eq!((bop.bars_seen(), bop.is_ready()), (3, true));
+
+        bop.reset();
+        assert_eq!((bop.bars_seen(), bop.is_ready()), (0, false));
+    }
+
+    #[test]
+    fn test_default() {
+        BalanceOfPower::default();
+    }
+
+    #[test]
+    fn test_display() {
+        assert_eq!(format!("{}", BalanceOfPower::new()), "BOP");
+        let bop = BalanceOfPower::with_smoothing(14).unwrap();
+        assert_eq!(format!("{}", bop), "BOP(14)");
+    }
+}
@@ -2,7 +2,7 @@ use std::fmt;
 
 use crate::errors::Result;
 use crate::indicators::StandardDeviation as Sd;
-use crate::{Close, Next, Period, Reset};
+use crate::{Close, Current, Next, Period, Ready, Reset};
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
@@ -40,6 +40,10 @@ use serde::{Deserialize, Serialize};
 /// assert_eq!(out_1.average, 3.5);
 /// assert_eq!(out_1.upper, 6.5);
 /// assert_eq!(out_1.lower, 0.5);
+///
+/// // where the price is relative to the bands, and how wide they are
+/// assert_eq!(out_1.percent_b(5.0), 0.75);
+/// assert_eq!(out_1.bandwidth(), 6.0 / 3.5);
 /// ```
 ///
 /// # Links
@@ -61,6 +65,38 @@ pub struct BollingerBandsOutput {
     pub lower: f64,
 }
 
+impl BollingerBandsOutput {
+    /// Position of the price relative to the bands, %B.
+    ///
+    /// %B = (price - lower) / (upper - lower)
+    ///
+    /// 0 is the lower band, 1 the upper band, above 1 the price is above the upper band. When
+    /// the bands have no width, e.g. on the first input, the price is on the average and 0.5 is
+    /// returned.
+    pub fn percent_b(&self, price: f64) -> f64 {
+        let width = self.upper - self.lower;
+        if width == 0.0 {
+            0.5
+        } else {
+            (price - self.lower) / width
+        }
+    }
+
+    /// Width of the bands relative to the average.
+    ///
+    /// Bandwidth = (upper - lower) / average
+    ///
+    /// A low bandwidth marks a squeeze, a period of low volatility. Returns 0 when the average
+    /// is 0.
+    pub fn bandwidth(&self) -> f64 {
+        if self.average == 0.0 {
+            0.0
+        } else {
+            (self.upper - self.lower) / self.average
+        }
+    }
+}
+
 impl BollingerBands {
     pub fn new(period: usize, multiplier: f64) -> Result<Self> {
         Ok(Self {
@@ -73,6 +109,16 @@ impl BollingerBands {
     pub fn multiplier(&self) -> f64 {
         self.multiplier
     }
+
+    fn bands(&self, sd: f64) -> BollingerBandsOutput {
+        let mean = self.sd.mean();
+
+        BollingerBandsOutput {
+            average: mean,
+            upper: mean + sd * self.multiplier,
+            lower: mean - sd * self.multiplier,
+        }
+    }
 }
 
 impl Period for BollingerBands {
@@ -86,13 +132,7 @@ impl Next<f64> for BollingerBands {
 
     fn next(&mut self, input: f64) -> Self::Output {
         let sd = self.sd.next(input);
-        let mean = self.sd.mean();
-
-        Self::Output {
-            average: mean,
-            upper: mean + sd * self.multiplier,
-            lower: mean - sd * self.multiplier,
-        }
+        self.bands(sd)
     }
 }
 
@@ -104,6 +144,24 @@ impl<T: Close> Next<&T> for BollingerBands {
     }
 }
 
+impl Current for BollingerBands {
+    type Output = BollingerBandsOutput;
+
+    fn current(&self) -> Option<Self::Output> {
+        self.sd.current().map(|sd| self.bands(sd))
+    }
+}
+
+impl Ready for BollingerBands {
+    fn bars_seen(&self) -> usize {
+        self.sd.bars_seen()
+    }
+
+    fn is_ready(&self) -> bool {
+        self.sd.is_ready()
+    }
+}
+
 impl Reset for BollingerBands {
     fn reset(&mut self) {
         self.sd.reset();
@@ -161,6 +219,58 @@ mod tests {
         assert_eq!(round(d.lower), -0.395);
     }
 
+    #[test]
+    fn test_percent_b() {
+        let mut bb = BollingerBands::new(10, 2.0_f64).unwrap();
+
+        // no width yet
+        assert_eq!(bb.next(10.0).percent_b(10.0), 0.5);
+
+        let inputs = [
+            10.5, 9.5, 10.0, 10.2, 10.4, 9.8, 10.1, 9.9, 10.3, 13.0, 10.0,
+        ];
+        let values: Vec<f64> = inputs
+            .iter()
+            .map(|&x| round(bb.next(x).percent_b(x)))
+            .collect();
+
+        // the jump to 13 closes above the upper band
+        assert_eq!(
+            values,
+            [0.75, 0.194, 0.5, 0.623, 0.73, 0.299, 0.531, 0.374, 0.703, 1.214, 0.414]
+        );
+        assert!(values[..9].iter().all(|&b| (0.0..=1.0).contains(&b)));
+    }
+
+    #[test]
+    fn test_bandwidth() {
+        let mut bb = BollingerBands::new(3, 2.0_f64).unwrap();
+
+        let inputs = [10.0, 12.0, 8.0, 11.0, 10.0, 10.0, 10.0, 9.0, 13.0];
+        let bandwidths: Vec<f64> = inputs
+            .iter()
+            .map(|&x| round(bb.next(x).bandwidth()))
+            .collect();
+
+        // the bands squeeze to no width while the price stays flat
+        assert_eq!(
+            bandwidths,
+            [0.0, 0.364, 0.653, 0.658, 0.516, 0.182, 0.0, 0.195, 0.637]
+        );
+        let min = bandwidths[1..]
+            .iter()
+            .cloned()
+            .fold(f64::INFINITY, f64::min);
+        assert_eq!(min, bandwidths[6]);
+
+        let out = BollingerBandsOutput {
+            average: 0.0,
+            upper: 1.0,
+            lower: -1.0,
+        };
+        assert_eq!(out.bandwidth(), 0.0);
+    }
+
     #[test]
     fn test_reset() {
         let mut bb = BollingerBands::new(5, 2.0_f64).unwrap();
@@ -188,6 +298,34 @@ mod tests {
         assert_eq!(out.lower, 3.0);
     }
 
+    #[test]
+    fn test_current() {
+        let mut bb = BollingerBands::new(3, 2.0).unwrap();
+        assert_eq!(bb.current(), None);
+
+        for input in [2.0, 5.0, 1.0, 6.25] {
+            let value = bb.next(input);
+            assert_eq!(bb.current(), Some(value));
+        }
+
+        bb.reset();
+        assert_eq!(bb.current(), None);
+    }
+
+    #[test]
+    fn test_ready() {
+        let mut bb = BollingerBands::new(3, 2.0).unwrap();
+        for _ in 0..2 {
+            bb.next(2.0);
+        }
+        assert_eq!((bb.bars_seen(), bb.is_ready()), (2, false));
+        bb.next(2.0);
+        assert_eq!((bb.bars_seen(), bb.is_ready()), (3, true));
+
+        bb.reset();
+        assert_eq!((bb.bars_seen(), bb.is_ready()), (0, false));
+    }
+
     #[test]
     fn test_default() {
         BollingerBands::default();
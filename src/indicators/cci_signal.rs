@@ -0,0 +1,309 @@
+use std::fmt;
+
+use crate::errors::{Result, TaError};
+use crate::indicators::CommodityChannelIndex;
+use crate::{Close, High, Low, Next, Period, Ready, Reset};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Trading signal emitted by [CciSignal](struct.CciSignal.html).
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum CciSignalEvent {
+    /// CCI crossed above the overbought level.
+    EnterLong,
+    /// CCI crossed back below the overbought level.
+    ExitLong,
+    /// CCI crossed below the oversold level.
+    EnterShort,
+    /// CCI crossed back above the oversold level.
+    ExitShort,
+    /// No threshold was crossed on this bar.
+    #[default]
+    None,
+}
+
+/// Signals of the commodity channel index crossing its overbought and oversold levels.
+///
+/// Wraps a [commodity channel index](struct.CommodityChannelIndex.html) and turns its values
+/// into discrete events. An event is emitted only on the bar where CCI crosses a level, the
+/// bars after it emit [CciSignalEvent::None](enum.CciSignalEvent.html#variant.None) until the
+/// next crossing.
+///
+/// # Formula
+///
+/// * _EnterLong_ - CCI<sub>t-1</sub> <= overbought and CCI<sub>t</sub> > overbought
+/// * _ExitLong_ - CCI<sub>t-1</sub> > overbought and CCI<sub>t</sub> <= overbought
+/// * _EnterShort_ - CCI<sub>t-1</sub> >= oversold and CCI<sub>t</sub> < oversold
+/// * _ExitShort_ - CCI<sub>t-1</sub> < oversold and CCI<sub>t</sub> >= oversold
+///
+/// When CCI jumps over both levels within one bar the entry is emitted. The first bar never
+/// emits a signal.
+///
+/// # Parameters
+///
+/// * _period_ - period of the CCI (integer greater than 0). Default is 20.
+/// * _overbought_ - upper level (finite number greater than _oversold_). Default is 100.
+/// * _oversold_ - lower level (finite number). Default is -100.
+///
+/// # Example
+///
+/// ```
+/// use ta::indicators::{CciSignal, CciSignalEvent};
+/// use ta::{DataItem, Next};
+///
+/// let mut signal = CciSignal::new(4, 100.0, -100.0).unwrap();
+///
+/// let bar = |price| {
+///     DataItem::builder()
+///         .open(price).high(price).low(price).close(price).volume(1000.0)
+///         .build(true).unwrap()
+/// };
+///
+/// for _ in 0..4 {
+///     assert_eq!(signal.next(&bar(10.0)), CciSignalEvent::None);
+/// }
+/// assert_eq!(signal.next(&bar(11.0)), CciSignalEvent::EnterLong);
+/// assert_eq!(signal.next(&bar(12.0)), CciSignalEvent::None);
+/// assert_eq!(signal.next(&bar(12.0)), CciSignalEvent::ExitLong);
+/// ```
+///
+/// # Links
+///
+/// * [CCI trading signals, StockCharts](https://school.stockcharts.com/doku.php?id=technical_indicators:commodity_channel_index_cci)
+///
+#[doc(alias = "CCI")]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone)]
+pub struct CciSignal {
+    cci: CommodityChannelIndex,
+    overbought: f64,
+    oversold: f64,
+    prev: Option<f64>,
+}
+
+impl CciSignal {
+    pub fn new(period: usize, overbought: f64, oversold: f64) -> Result<Self> {
+        if !overbought.is_finite() {
+            return Err(TaError::invalid_parameter("overbought", overbought));
+        }
+        let is_valid = oversold.is_finite() && overbought > oversold;
+        if !is_valid {
+            return Err(TaError::invalid_parameter("oversold", oversold));
+        }
+        Ok(Self {
+            cci: CommodityChannelIndex::new(period)?,
+            overbought,
+            oversold,
+            prev: None,
+        })
+    }
+
+    pub fn overbought(&self) -> f64 {
+        self.overbought
+    }
+
+    pub fn oversold(&self) -> f64 {
+        self.oversold
+    }
+}
+
+impl Period for CciSignal {
+    fn period(&self) -> usize {
+        self.cci.period()
+    }
+}
+
+impl<T: Close + High + Low> Next<&T> for CciSignal {
+    type Output = CciSignalEvent;
+
+    fn next(&mut self, input: &T) -> Self::Output {
+        let cci = self.cci.next(input);
+        let prev = match self.prev.replace(cci) {
+            Some(prev) => prev,
+            None => return CciSignalEvent::None,
+        };
+
+        if prev <= self.overbought && cci > self.overbought {
+            CciSignalEvent::EnterLong
+        } else if prev >= self.oversold && cci < self.oversold {
+            CciSignalEvent::EnterShort
+        } else if prev > self.overbought && cci <= self.overbought {
+            CciSignalEvent::ExitLong
+        } else if prev < self.oversold && cci >= self.oversold {
+            CciSignalEvent::ExitShort
+        } else {
+            CciSignalEvent::None
+        }
+    }
+}
+
+impl Ready for CciSignal {
+    fn bars_seen(&self) -> usize {
+        self.cci.bars_seen()
+    }
+
+    fn is_ready(&self) -> bool {
+        self.cci.is_ready()
+    }
+}
+
+impl Reset for CciSignal {
+    fn reset(&mut self) {
+        self.cci.reset();
+        self.prev = None;
+    }
+}
+
+impl Default for CciSignal {
+    fn default() -> Self {
+        Self::new(20, 100.0, -100.0).unwrap()
+    }
+}
+
+impl fmt::Display for CciSignal {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "CCI_SIGNAL({}, {}, {})",
+            self.cci.period(),
+            self.overbought,
+            self.oversold
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helper::*;
+    use CciSignalEvent::*;
+
+    #[test]
+    fn test_new() {
+        assert!(CciSignal::new(0, 100.0, -100.0).is_err());
+        assert!(CciSignal::new(20, -100.0, 100.0).is_err());
+        assert!(CciSignal::new(20, 100.0, 100.0).is_err());
+        assert!(CciSignal::new(20, f64::NAN, -100.0).is_err());
+        assert!(CciSignal::new(20, 100.0, f64::NEG_INFINITY).is_err());
+        assert!(CciSignal::new(1, 100.0, -100.0).is_ok());
+    }
+
+    #[test]
+    fn test_next() {
+        let mut signal = CciSignal::new(4, 100.0, -100.0).unwrap();
+
+        let data = [
+            // price, CCI(4), signal
+            (10.0, None),       // 0
+            (10.0, None),       // 0
+            (10.0, None),       // 0
+            (10.0, None),       // 0
+            (11.0, EnterLong),  // 133.333
+            (12.0, None),       // 111.111
+            (12.0, ExitLong),   // 66.667
+            (12.0, None),       // 44.444
+            (11.0, EnterShort), // -133.333
+            (10.0, None),       // -111.111
+            (10.0, ExitShort),  // -66.667
+            (10.0, None),       // -44.444
+            (11.0, EnterLong),  // 133.333
+            (12.0, None),       // 111.111
+        ];
+
+        for (price, expected) in data {
+            assert_eq!(
+                signal.next(&Bar::new().high(price).low(price).close(price)),
+                expected
+            );
+        }
+    }
+
+    #[test]
+    fn test_next_fires_once_per_crossing() {
+        let mut signal = CciSignal::new(4, 100.0, -100.0).unwrap();
+        let prices = [
+            10.0, 10.0, 10.0, 10.0, 11.0, 12.0, 12.0, 12.0, 11.0, 10.0, 10.0, 10.0, 11.0, 12.0,
+        ];
+
+        let events: Vec<CciSignalEvent> = prices
+            .iter()
+            .map(|&price| signal.next(&Bar::new().high(price).low(price).close(price)))
+            .filter(|&event| event != None)
+            .collect();
+
+        assert_eq!(
+            events,
+            [EnterLong, ExitLong, EnterShort, ExitShort, EnterLong]
+        );
+    }
+
+    #[test]
+    fn test_next_jump_over_both_levels() {
+        let mut signal = CciSignal::new(4, 100.0, -100.0).unwrap();
+
+        for price in [10.0, 10.0, 10.0, 10.0, 11.0, 12.0, 12.0, 12.0] {
+            signal.next(&Bar::new().high(price).low(price).close(price));
+        }
+        assert_eq!(
+            signal.next(&Bar::new().high(11).low(11).close(11)),
+            EnterShort
+        );
+        assert_eq!(signal.next(&Bar::new().high(10).low(10).close(10)), None);
+        // from -111.111 straight up to 120, the long entry wins over the short exit
+        assert_eq!(
+            signal.next(&Bar::new().high(14).low(14).close(14)),
+            EnterLong
+        );
+    }
+
+    #[test]
+    fn test_reset() {
+        let mut signal = CciSignal::new(4, 100.0, -100.0).unwrap();
+
+        for price in [10.0, 10.0, 10.0, 10.0, 11.0] {
+            signal.next(&Bar::new().high(price).low(price).close(price));
+        }
+        signal.reset();
+
+        for _ in 0..4 {
+            assert_eq!(signal.next(&Bar::new().high(10).low(10).close(10)), None);
+        }
+        assert_eq!(
+            signal.next(&Bar::new().high(11).low(11).close(11)),
+            EnterLong
+        );
+    }
+
+    #[test]
+    fn test_period() {
+        let signal = CciSignal::new(14, 100.0, -100.0).unwrap();
+        assert_eq!(signal.period(), 14);
+    }
+
+    #[test]
+    fn test_ready() {
+        let mut signal = CciSignal::new(3, 100.0, -100.0).unwrap();
+        let bar = Bar::new().high(10).low(8).close(9);
+        for _ in 0..2 {
+            signal.next(&bar);
+        }
+        assert_eq!((signal.bars_seen(), signal.is_ready()), (2, false));
+        signal.next(&bar);
+        assert_eq!((signal.bars_seen(), signal.is_ready()), (3, true));
+
+        signal.reset();
+        assert_eq!((signal.bars_seen(), signal.is_ready()), (0, false));
+    }
+
+    #[test]
+    fn test_default() {
+        CciSignal::default();
+    }
+
+    #[test]
+    fn test_display() {
+        let signal = CciSignal::new(20, 100.0, -100.0).unwrap();
+        assert_eq!(format!("{}", signal), "CCI_SIGNAL(20, 100, -100)");
+    }
+}
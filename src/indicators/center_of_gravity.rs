@@ -0,0 +1,270 @@
+use std::fmt;
+
+use crate::errors::{Result, TaError};
+use crate::{Close, Next, Period, Ready, Reset};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Center of Gravity oscillator (COG).
+///
+/// Developed by John Ehlers, the oscillator locates the balance point of the prices in the
+/// window, weighting the most recent price least. With little lag it turns together with or
+/// slightly ahead of the price, rising towards 0 while the recent prices are high and falling
+/// while they are low.
+///
+/// # Formula
+///
+/// COG = -(sum of (i + 1) * price<sub>i</sub>) / (sum of price<sub>i</sub>)
+///
+/// Where _price<sub>0</sub>_ is the current price and _price<sub>i</sub>_ the price _i_ bars
+/// ago, for _i_ from 0 to _period - 1_. Both sums are updated incrementally and rebuilt once
+/// per window, a sum of 0 yields 0.
+///
+/// # Parameters
+///
+/// * _period_ - number of prices (integer greater than 0). Default is 10.
+///
+/// # Example
+///
+/// ```
+/// use ta::indicators::CenterOfGravity;
+/// use ta::Next;
+///
+/// let mut cog = CenterOfGravity::new(3).unwrap();
+/// assert_eq!(cog.next(2.0), -1.0);
+/// // -(1 * 6 + 2 * 2) / (6 + 2)
+/// assert_eq!(cog.next(6.0), -1.25);
+/// ```
+///
+/// # Links
+///
+/// * [The CG Oscillator, John Ehlers](https://www.mesasoftware.com/papers/TheCGOscillator.pdf)
+///
+#[doc(alias = "COG")]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone)]
+pub struct CenterOfGravity {
+    period: usize,
+    index: usize,
+    weighted_sum: f64,
+    sum: f64,
+    deque: Box<[f64]>,
+    #[cfg_attr(feature = "serde", serde(default))]
+    bars_seen: usize,
+}
+
+impl CenterOfGravity {
+    pub fn new(period: usize) -> Result<Self> {
+        match period {
+            0 => Err(TaError::invalid_parameter("period", period)),
+            _ => Ok(Self {
+                period,
+                index: 0,
+                weighted_sum: 0.0,
+                sum: 0.0,
+                deque: vec![0.0; period].into_boxed_slice(),
+                bars_seen: 0,
+            }),
+        }
+    }
+}
+
+impl Period for CenterOfGravity {
+    fn period(&self) -> usize {
+        self.period
+    }
+}
+
+impl Next<f64> for CenterOfGravity {
+    type Output = f64;
+
+    fn next(&mut self, input: f64) -> Self::Output {
+        self.bars_seen = self.bars_seen.saturating_add(1);
+        let old_val = self.deque[self.index];
+        self.deque[self.index] = input;
+
+        self.index = if self.index + 1 < self.period {
+            self.index + 1
+        } else {
+            0
+        };
+
+        // every price in the window moves one bar back, its weight grows by 1, and the
+        // oldest one drops out with a weight of period + 1
+        self.weighted_sum += self.sum + input - (self.period + 1) as f64 * old_val;
+        self.sum += input - old_val;
+
+        if self.index == 0 {
+            // the weighted sum takes the rounding errors of the sum along on every input, both
+            // are rebuilt once per window. Starting over at 0 the deque runs from the oldest
+            // price, weighted by period, to the current one.
+            self.sum = self.deque.iter().sum();
+            self.weighted_sum = self
+                .deque
+                .iter()
+                .enumerate()
+                .map(|(i, &price)| (self.period - i) as f64 * price)
+                .sum();
+        }
+
+        if self.sum == 0.0 {
+            0.0
+        } else {
+            -self.weighted_sum / self.sum
+        }
+    }
+}
+
+impl<T: Close> Next<&T> for CenterOfGravity {
+    type Output = f64;
+
+    fn next(&mut self, input: &T) -> Self::Output {
+        self.next(input.close())
+    }
+}
+
+impl Ready for CenterOfGravity {
+    fn bars_seen(&self) -> usize {
+        self.bars_seen
+    }
+
+    fn is_ready(&self) -> bool {
+        self.bars_seen >= self.period
+    }
+}
+
+impl Reset for CenterOfGravity {
+    fn reset(&mut self) {
+        self.bars_seen = 0;
+        self.index = 0;
+        self.weighted_sum = 0.0;
+        self.sum = 0.0;
+        for i in 0..self.period {
+            self.deque[i] = 0.0;
+        }
+    }
+}
+
+impl Default for CenterOfGravity {
+    fn default() -> Self {
+        Self::new(10).unwrap()
+    }
+}
+
+impl fmt::Display for CenterOfGravity {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "COG({})", self.period)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helper::*;
+
+    test_indicator!(CenterOfGravity);
+
+    #[test]
+    fn test_new() {
+        assert!(CenterOfGravity::new(0).is_err());
+        assert!(CenterOfGravity::new(1).is_ok());
+    }
+
+    #[test]
+    fn test_next() {
+        let mut cog = CenterOfGravity::new(4).unwrap();
+
+        let inputs = [
+            10.0, 11.0, 12.0, 13.0, 14.0, 13.0, 12.0, 11.0, 10.0, 11.0, 12.0, 13.0, 14.0,
+        ];
+        let expected = [
+            -1.0, -1.476, -1.939, -2.391, -2.4, -2.462, -2.538, -2.6, -2.609, -2.545, -2.455,
+            -2.391, -2.4,
+        ];
+        let outputs: Vec<f64> = inputs.iter().map(|&input| round(cog.next(input))).collect();
+        assert_eq!(outputs, expected);
+
+        // after the warm up it bottoms together with the price and peaks a bar ahead of it
+        let turns: Vec<usize> = (4..outputs.len() - 1)
+            .filter(|&i| {
+                let (prev, out, next) = (outputs[i - 1], outputs[i], outputs[i + 1]);
+                (out > prev && out > next) || (out < prev && out < next)
+            })
+            .collect();
+        assert_eq!(turns, [8, 11]);
+
+        let mut cog = CenterOfGravity::new(4).unwrap();
+        assert_eq!(cog.next(&Bar::new().close(10)), -1.0);
+        assert_eq!(round(cog.next(&Bar::new().close(11))), -1.476);
+    }
+
+    #[test]
+    fn test_next_zero_sum() {
+        let mut cog = CenterOfGravity::new(2).unwrap();
+
+        assert_eq!(cog.next(0.0), 0.0);
+        cog.next(3.0);
+        assert_eq!(cog.next(-3.0), 0.0);
+    }
+
+    #[test]
+    fn test_next_long_series() {
+        let mut cog = CenterOfGravity::new(10).unwrap();
+        let inputs: Vec<f64> = (0..2_000_000)
+            .map(|i| 50000.0 + (i as f64 * 0.01).sin() * 1000.0 + 0.001 * i as f64)
+            .collect();
+
+        let mut out = 0.0;
+        for &input in &inputs {
+            out = cog.next(input);
+        }
+
+        // the last 10 inputs, the current one weighted by 1
+        let window = &inputs[inputs.len() - 10..];
+        let weighted: f64 = window
+            .iter()
+            .rev()
+            .zip(1..)
+            .map(|(&p, w)| w as f64 * p)
+            .sum();
+        let expected = -weighted / window.iter().sum::<f64>();
+        assert!((out - expected).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_reset() {
+        let mut cog = CenterOfGravity::new(4).unwrap();
+
+        cog.next(10.0);
+        cog.next(11.0);
+        cog.reset();
+
+        assert_eq!(cog.next(10.0), -1.0);
+        assert_eq!(round(cog.next(11.0)), -1.476);
+    }
+
+    #[test]
+    fn test_ready() {
+        let mut cog = CenterOfGravity::new(3).unwrap();
+        for _ in 0..2 {
+            cog.next(1.0);
+        }
+        assert_eq!((cog.bars_seen(), cog.is_ready()), (2, false));
+        cog.next(1.0);
+        assert_eq!((cog.bars_seen(), cog.is_ready()), (3, true));
+
+        cog.reset();
+        assert_eq!((cog.bars_seen(), cog.is_ready()), (0, false));
+    }
+
+    #[test]
+    fn test_default() {
+        CenterOfGravity::default();
+    }
+
+    #[test]
+    fn test_display() {
+        let cog = CenterOfGravity::new(10).unwrap();
+        assert_eq!(format!("{}", cog), "COG(10)");
+    }
+}
@@ -0,0 +1,235 @@
+use std::fmt;
+
+use crate::errors::{Result, TaError};
+use crate::helpers::money_flow_multiplier;
+use crate::{Close, High, Low, Next, Period, Ready, Reset, Volume};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Chaikin Money Flow (CMF).
+///
+/// Developed by Marc Chaikin, CMF measures the amount of money flow volume over the last
+/// _period_ bars. It oscillates between -1 and 1, positive values indicate buying pressure
+/// and negative values indicate selling pressure.
+///
+/// # Formula
+///
+/// Money Flow Multiplier (MFM) = ((close - low) - (high - close)) / (high - low)
+///
+/// Money Flow Volume (MFV) = MFM * volume
+///
+/// CMF = sum of MFV over _period_ / sum of volume over _period_
+///
+/// A bar with high equal to low has a multiplier of 0. When there is no volume in the
+/// period CMF is 0.
+///
+/// # Parameters
+///
+/// * _period_ - number of bars (integer greater than 0). Default is 20.
+///
+/// # Example
+///
+/// ```
+/// use ta::indicators::ChaikinMoneyFlow;
+/// use ta::{DataItem, Next};
+///
+/// let mut cmf = ChaikinMoneyFlow::new(3).unwrap();
+///
+/// let di = DataItem::builder()
+///     .open(9.0).high(10.0).low(8.0).close(9.5).volume(1000.0)
+///     .build(true).unwrap();
+///
+/// assert_eq!(cmf.next(&di), 0.5);
+/// ```
+///
+/// # Links
+///
+/// * [Chaikin Money Flow, StockCharts](https://school.stockcharts.com/doku.php?id=technical_indicators:chaikin_money_flow_cmf)
+///
+#[doc(alias = "CMF")]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone)]
+pub struct ChaikinMoneyFlow {
+    period: usize,
+    index: usize,
+    money_flow_volume_sum: f64,
+    volume_sum: f64,
+    // money flow volume and volume of the bars in the window
+    deque: Box<[(f64, f64)]>,
+    #[cfg_attr(feature = "serde", serde(default))]
+    bars_seen: usize,
+}
+
+impl ChaikinMoneyFlow {
+    pub fn new(period: usize) -> Result<Self> {
+        match period {
+            0 => Err(TaError::invalid_parameter("period", period)),
+            _ => Ok(Self {
+                period,
+                index: 0,
+                money_flow_volume_sum: 0.0,
+                volume_sum: 0.0,
+                deque: vec![(0.0, 0.0); period].into_boxed_slice(),
+                bars_seen: 0,
+            }),
+        }
+    }
+}
+
+impl Period for ChaikinMoneyFlow {
+    fn period(&self) -> usize {
+        self.period
+    }
+}
+
+impl<T: High + Low + Close + Volume> Next<&T> for ChaikinMoneyFlow {
+    type Output = f64;
+
+    fn next(&mut self, input: &T) -> Self::Output {
+        self.bars_seen = self.bars_seen.saturating_add(1);
+        let volume = input.volume();
+        let money_flow_volume = money_flow_multiplier(input) * volume;
+
+        let (old_money_flow_volume, old_volume) = self.deque[self.index];
+        self.deque[self.index] = (money_flow_volume, volume);
+
+        self.index = if self.index + 1 < self.period {
+            self.index + 1
+        } else {
+            0
+        };
+
+        self.money_flow_volume_sum += money_flow_volume - old_money_flow_volume;
+        self.volume_sum += volume - old_volume;
+
+        if self.volume_sum > 0.0 {
+            self.money_flow_volume_sum / self.volume_sum
+        } else {
+            0.0
+        }
+    }
+}
+
+impl Ready for ChaikinMoneyFlow {
+    fn bars_seen(&self) -> usize {
+        self.bars_seen
+    }
+
+    fn is_ready(&self) -> bool {
+        self.bars_seen >= self.period
+    }
+}
+
+impl Reset for ChaikinMoneyFlow {
+    fn reset(&mut self) {
+        self.bars_seen = 0;
+        self.index = 0;
+        self.money_flow_volume_sum = 0.0;
+        self.volume_sum = 0.0;
+        for i in 0..self.period {
+            self.deque[i] = (0.0, 0.0);
+        }
+    }
+}
+
+impl Default for ChaikinMoneyFlow {
+    fn default() -> Self {
+        Self::new(20).unwrap()
+    }
+}
+
+impl fmt::Display for ChaikinMoneyFlow {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "CMF({})", self.period)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helper::*;
+
+    #[test]
+    fn test_new() {
+        assert!(ChaikinMoneyFlow::new(0).is_err());
+        assert!(ChaikinMoneyFlow::new(1).is_ok());
+    }
+
+    #[test]
+    fn test_next_bar() {
+        let mut cmf = ChaikinMoneyFlow::new(3).unwrap();
+
+        // mfm = 0.5, mfv = 500
+        let bar1 = Bar::new().high(10).low(8).close(9.5).volume(1000.0);
+        assert_eq!(cmf.next(&bar1), 0.5);
+
+        // mfm = -1, mfv = -2000
+        let bar2 = Bar::new().high(12).low(10).close(10).volume(2000.0);
+        assert_eq!(round(cmf.next(&bar2)), -0.5);
+
+        // mfm = 0.6, mfv = 600
+        let bar3 = Bar::new().high(11).low(6).close(10).volume(1000.0);
+        assert_eq!(round(cmf.next(&bar3)), -0.225);
+
+        // bar1 leaves the window, mfm = 1, mfv = 3000
+        let bar4 = Bar::new().high(12).low(11).close(12).volume(3000.0);
+        assert_eq!(round(cmf.next(&bar4)), 0.267);
+    }
+
+    #[test]
+    fn test_next_flat_bar() {
+        let mut cmf = ChaikinMoneyFlow::new(3).unwrap();
+
+        // high == low, the multiplier is 0
+        let flat = Bar::new().high(10).low(10).close(10).volume(1000.0);
+        assert_eq!(cmf.next(&flat), 0.0);
+
+        // the flat bar volume still counts in the denominator
+        let bar = Bar::new().high(10).low(8).close(10).volume(1000.0);
+        assert_eq!(cmf.next(&bar), 0.5);
+    }
+
+    #[test]
+    fn test_next_no_volume() {
+        let mut cmf = ChaikinMoneyFlow::new(3).unwrap();
+        let bar = Bar::new().high(10).low(8).close(10).volume(0.0);
+        assert_eq!(cmf.next(&bar), 0.0);
+    }
+
+    #[test]
+    fn test_reset() {
+        let mut cmf = ChaikinMoneyFlow::new(3).unwrap();
+
+        cmf.next(&Bar::new().high(12).low(10).close(10).volume(2000.0));
+        cmf.reset();
+
+        let bar = Bar::new().high(10).low(8).close(9.5).volume(1000.0);
+        assert_eq!(cmf.next(&bar), 0.5);
+    }
+
+    #[test]
+    fn test_ready() {
+        let mut cmf = ChaikinMoneyFlow::new(3).unwrap();
+        let bar = Bar::new().high(10).low(8).close(9).volume(100.0);
+        for _ in 0..2 {
+            cmf.next(&bar);
+        }
+        assert_eq!((cmf.bars_seen(), cmf.is_ready()), (2, false));
+        cmf.next(&bar);
+        assert_eq!((cmf.bars_seen(), cmf.is_ready()), (3, true));
+
+        cmf.reset();
+        assert_eq!((cmf.bars_seen(), cmf.is_ready()), (0, false));
+    }
+
+    #[test]
+    fn test_default() {
+        ChaikinMoneyFlow::default();
+    }
+
+    #[test]
+    fn test_display() {
+        let cmf = ChaikinMoneyFlow::new(20).unwrap();
+        assert_eq!(format!("{}", cmf), "CMF(20)");
+    }
+}
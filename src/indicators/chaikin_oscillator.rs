@@ -0,0 +1,229 @@
+use std::fmt;
+
+use crate::errors::{Result, TaError};
+use crate::indicators::{AccumulationDistribution, ExponentialMovingAverage as Ema};
+use crate::{Close, High, Low, Next, Period, Ready, Reset, Volume};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Chaikin Oscillator.
+///
+/// Developed by Marc Chaikin, the oscillator measures the momentum of the
+/// [accumulation/distribution line](struct.AccumulationDistribution.html) as the difference
+/// of its fast and slow exponential moving averages. It crosses above zero when buying
+/// pressure accelerates and below zero when selling pressure does.
+///
+/// # Formula
+///
+/// Chaikin Oscillator = EMA(fast) of A/D - EMA(slow) of A/D
+///
+/// Where:
+///
+/// * _A/D_ - [accumulation/distribution line](struct.AccumulationDistribution.html)
+/// * _EMA_ - [exponential moving average](struct.ExponentialMovingAverage.html)
+///
+/// # Parameters
+///
+/// * _fast_ - period of the fast EMA (integer greater than 0). Default is 3.
+/// * _slow_ - period of the slow EMA (integer greater than or equal to _fast_). Default is 10.
+///
+/// # Example
+///
+/// ```
+/// use ta::indicators::ChaikinOscillator;
+/// use ta::{DataItem, Next};
+///
+/// let mut cho = ChaikinOscillator::new(1, 3).unwrap();
+///
+/// let di1 = DataItem::builder()
+///     .open(9.0).high(10.0).low(8.0).close(9.5).volume(1000.0)
+///     .build(true).unwrap();
+/// let di2 = DataItem::builder()
+///     .open(9.5).high(10.0).low(8.0).close(9.5).volume(1000.0)
+///     .build(true).unwrap();
+///
+/// assert_eq!(cho.next(&di1), 0.0);
+/// assert_eq!(cho.next(&di2), 250.0);
+/// ```
+///
+/// # Links
+///
+/// * [Chaikin Oscillator, StockCharts](https://school.stockcharts.com/doku.php?id=technical_indicators:chaikin_oscillator)
+///
+#[doc(alias = "CHO")]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone)]
+pub struct ChaikinOscillator {
+    ad: AccumulationDistribution,
+    fast_ema: Ema,
+    slow_ema: Ema,
+}
+
+impl ChaikinOscillator {
+    pub fn new(fast: usize, slow: usize) -> Result<Self> {
+        if fast > slow {
+            return Err(TaError::invalid_parameter("slow", slow));
+        }
+        Ok(Self {
+            ad: AccumulationDistribution::new(),
+            fast_ema: Ema::new(fast)?,
+            slow_ema: Ema::new(slow)?,
+        })
+    }
+}
+
+impl Period for ChaikinOscillator {
+    fn period(&self) -> usize {
+        self.slow_ema.period()
+    }
+}
+
+impl<T: High + Low + Close + Volume> Next<&T> for ChaikinOscillator {
+    type Output = f64;
+
+    fn next(&mut self, input: &T) -> Self::Output {
+        let ad = self.ad.next(input);
+        self.fast_ema.next(ad) - self.slow_ema.next(ad)
+    }
+}
+
+impl Ready for ChaikinOscillator {
+    fn bars_seen(&self) -> usize {
+        self.ad.bars_seen()
+    }
+
+    fn is_ready(&self) -> bool {
+        self.fast_ema.is_ready() && self.slow_ema.is_ready()
+    }
+}
+
+impl Reset for ChaikinOscillator {
+    fn reset(&mut self) {
+        self.ad.reset();
+        self.fast_ema.reset();
+        self.slow_ema.reset();
+    }
+}
+
+impl Default for ChaikinOscillator {
+    fn default() -> Self {
+        Self::new(3, 10).unwrap()
+    }
+}
+
+impl fmt::Display for ChaikinOscillator {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "CHO({}, {})",
+            self.fast_ema.period(),
+            self.slow_ema.period()
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helper::*;
+
+    #[test]
+    fn test_new() {
+        assert!(ChaikinOscillator::new(0, 10).is_err());
+        assert!(ChaikinOscillator::new(10, 3).is_err());
+        assert!(ChaikinOscillator::new(3, 10).is_ok());
+    }
+
+    #[test]
+    fn test_next() {
+        let mut cho = ChaikinOscillator::new(3, 10).unwrap();
+
+        // a/d: 500, -1500, -1500, -900, -150
+        assert_eq!(
+            round(cho.next(&Bar::new().high(10).low(8).close(9.5).volume(1000.0))),
+            0.0
+        );
+        assert_eq!(
+            round(cho.next(&Bar::new().high(12).low(10).close(10).volume(2000.0))),
+            -636.364
+        );
+        assert_eq!(
+            round(cho.next(&Bar::new().high(11).low(11).close(11).volume(5000.0))),
+            -838.843
+        );
+        assert_eq!(
+            round(cho.next(&Bar::new().high(11).low(6).close(10).volume(1000.0))),
+            -654.508
+        );
+        assert_eq!(
+            round(cho.next(&Bar::new().high(12).low(10).close(11.5).volume(1500.0))),
+            -280.961
+        );
+    }
+
+    #[test]
+    fn test_next_crossovers() {
+        let mut cho = ChaikinOscillator::default();
+
+        // closes near the high for 10 bars, near the low for 10 bars, then near the high again
+        let values: Vec<f64> = (0..30)
+            .map(|i| {
+                let close = if (10..20).contains(&i) { 9.2 } else { 10.8 };
+                cho.next(&Bar::new().high(11).low(9).close(close).volume(1000.0))
+            })
+            .collect();
+
+        // the oscillator turns as soon as the a/d line does
+        assert!(values[10] < values[9]);
+        assert!(values[20] > values[19]);
+
+        // and crosses the zero line a few bars later
+        assert!(values[1..14].iter().all(|&value| value > 0.0));
+        assert!(values[14..23].iter().all(|&value| value < 0.0));
+        assert!(values[23..].iter().all(|&value| value > 0.0));
+    }
+
+    #[test]
+    fn test_reset() {
+        let mut cho = ChaikinOscillator::new(3, 10).unwrap();
+
+        cho.next(&Bar::new().high(10).low(8).close(9.5).volume(1000.0));
+        cho.next(&Bar::new().high(12).low(10).close(10).volume(2000.0));
+        cho.reset();
+
+        assert_eq!(
+            round(cho.next(&Bar::new().high(10).low(8).close(9.5).volume(1000.0))),
+            0.0
+        );
+        assert_eq!(
+            round(cho.next(&Bar::new().high(12).low(10).close(10).volume(2000.0))),
+            -636.364
+        );
+    }
+
+    #[test]
+    fn test_ready() {
+        let mut osc = ChaikinOscillator::new(2, 3).unwrap();
+        let bar = Bar::new().high(10).low(8).close(9).volume(100.0);
+        for _ in 0..2 {
+            osc.next(&bar);
+        }
+        assert_eq!((osc.bars_seen(), osc.is_ready()), (2, false));
+        osc.next(&bar);
+        assert_eq!((osc.bars_seen(), osc.is_ready()), (3, true));
+
+        osc.reset();
+        assert_eq!((osc.bars_seen(), osc.is_ready()), (0, false));
+    }
+
+    #[test]
+    fn test_default() {
+        ChaikinOscillator::default();
+    }
+
+    #[test]
+    fn test_display() {
+        let cho = ChaikinOscillator::new(3, 10).unwrap();
+        assert_eq!(format!("{}", cho), "CHO(3, 10)");
+    }
+}
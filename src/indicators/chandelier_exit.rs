@@ -4,8 +4,9 @@ use std::fmt;
 use serde::{Deserialize, Serialize};
 
 use crate::errors::Result;
-use crate::indicators::{AverageTrueRange, Maximum, Minimum};
-use crate::{Close, High, Low, Next, Period, Reset};
+use crate::indicators::AverageTrueRange;
+use crate::window::MonotonicWindow;
+use crate::{Close, High, Low, Next, Period, Ready, Reset};
 
 /// Chandelier Exit (CE).
 ///
@@ -31,9 +32,9 @@ use crate::{Close, High, Low, Next, Period, Reset};
 /// use ta::{Next, DataItem};
 ///
 /// let value1 = DataItem::builder()
-/// .open(21.0).high(22.0).low(20.0).close(21.0).volume(1.0).build().unwrap();
+/// .open(21.0).high(22.0).low(20.0).close(21.0).volume(1.0).build(true).unwrap();
 /// let value2 = DataItem::builder()
-/// .open(23.0).high(24.0).low(22.0).close(23.0).volume(1.0).build().unwrap();
+/// .open(23.0).high(24.0).low(22.0).close(23.0).volume(1.0).build(true).unwrap();
 ///
 /// let mut ce = ChandelierExit::default();
 ///
@@ -55,8 +56,7 @@ use crate::{Close, High, Low, Next, Period, Reset};
 #[derive(Debug, Clone)]
 pub struct ChandelierExit {
     atr: AverageTrueRange,
-    min: Minimum,
-    max: Maximum,
+    window: MonotonicWindow,
     multiplier: f64,
 }
 
@@ -64,8 +64,7 @@ impl ChandelierExit {
     pub fn new(period: usize, multiplier: f64) -> Result<Self> {
         Ok(Self {
             atr: AverageTrueRange::new(period)?,
-            min: Minimum::new(period)?,
-            max: Maximum::new(period)?,
+            window: MonotonicWindow::new(period)?,
             multiplier,
         })
     }
@@ -98,8 +97,7 @@ impl<T: Low + High + Close> Next<&T> for ChandelierExit {
 
     fn next(&mut self, input: &T) -> Self::Output {
         let atr = self.atr.next(input) * self.multiplier;
-        let min = self.min.next(input);
-        let max = self.max.next(input);
+        let (min, max) = self.window.push_range(input.low(), input.high());
 
         ChandelierExitOutput {
             long: max - atr,
@@ -108,11 +106,20 @@ impl<T: Low + High + Close> Next<&T> for ChandelierExit {
     }
 }
 
+impl Ready for ChandelierExit {
+    fn bars_seen(&self) -> usize {
+        self.atr.bars_seen()
+    }
+
+    fn is_ready(&self) -> bool {
+        self.atr.is_ready()
+    }
+}
+
 impl Reset for ChandelierExit {
     fn reset(&mut self) {
         self.atr.reset();
-        self.min.reset();
-        self.max.reset();
+        self.window.reset();
     }
 }
 
@@ -172,6 +179,31 @@ mod tests {
         assert_eq!(round(ce.next(&bar6).into()), (2.92, 7.08));
     }
 
+    #[test]
+    fn test_next_long_exit() {
+        let mut ce = Ce::new(5, 2.0).unwrap();
+
+        // every bar makes a new high with the same range, the long exit trails it
+        let mut prev_long = f64::MIN;
+        for i in 0..6 {
+            let low = 10.0 + i as f64;
+            let out = ce.next(&Bar::new().high(low + 1.0).low(low).close(low + 0.5));
+            assert!(out.long > prev_long);
+            prev_long = out.long;
+        }
+
+        // wider bars below the highest high increase the ATR and the distance to the exit
+        let highest_high = 16.0;
+        let mut prev_distance = highest_high - prev_long;
+        for range in [3.0, 5.0, 7.0] {
+            let bar = Bar::new().high(15.5).low(15.5 - range).close(15.0);
+            let out = ce.next(&bar);
+            let distance = highest_high - out.long;
+            assert!(distance > prev_distance);
+            prev_distance = distance;
+        }
+    }
+
     #[test]
     fn test_reset() {
         let mut ce = Ce::new(5, 2.0).unwrap();
@@ -188,6 +220,21 @@ mod tests {
         assert_eq!(round(ce.next(&bar2).into()), (1.33, 4.67));
     }
 
+    #[test]
+    fn test_ready() {
+        let mut exit = ChandelierExit::new(3, 3.0).unwrap();
+        let bar = Bar::new().high(10).low(8).close(9);
+        for _ in 0..2 {
+            exit.next(&bar);
+        }
+        assert_eq!((exit.bars_seen(), exit.is_ready()), (2, false));
+        exit.next(&bar);
+        assert_eq!((exit.bars_seen(), exit.is_ready()), (3, true));
+
+        exit.reset();
+        assert_eq!((exit.bars_seen(), exit.is_ready()), (0, false));
+    }
+
     #[test]
     fn test_default() {
         Ce::default();
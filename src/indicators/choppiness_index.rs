@@ -0,0 +1,276 @@
+use std::fmt;
+
+use crate::errors::{Result, TaError};
+use crate::indicators::TrueRange;
+use crate::window::MonotonicWindow;
+use crate::{Close, High, Low, Next, Period, Ready, Reset};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Choppiness Index (CHOP).
+///
+/// Developed by E.W. Dreiss, the index tells whether the market is trending or moving
+/// sideways, not the direction of the trend. It compares the distance the price travelled bar
+/// by bar, the sum of the true ranges, with the range it covered in total. In a strong trend
+/// every bar extends the range and the index is close to 0, in a choppy market the bars
+/// overlap and it approaches 100.
+///
+/// # Formula
+///
+/// CHOP = 100 * log10(Σ TR / (highest high - lowest low)) / log10(n)
+///
+/// Where _TR_ is the [true range](struct.TrueRange.html) and the sum, the highest high and the
+/// lowest low are taken over the last _n_ bars, at most _period_. With a single bar or when
+/// the window has no range the index is 50.
+///
+/// # Parameters
+///
+/// * _period_ - number of bars (integer greater than 1). Default is 14.
+///
+/// # Example
+///
+/// ```
+/// use ta::indicators::ChoppinessIndex;
+/// use ta::{DataItem, Next};
+///
+/// let mut chop = ChoppinessIndex::new(3).unwrap();
+///
+/// let mut next = |low: f64, high: f64| {
+///     let di = DataItem::builder()
+///         .open(low).high(high).low(low).close(high).volume(1000.0)
+///         .build(true).unwrap();
+///     chop.next(&di)
+/// };
+///
+/// assert_eq!(next(9.0, 10.0), 50.0);
+/// // every bar extends the range
+/// assert_eq!(next(10.0, 11.0), 0.0);
+/// assert_eq!(next(11.0, 12.0), 0.0);
+/// ```
+///
+/// # Links
+///
+/// * [Choppiness Index, TradingView](https://www.tradingview.com/support/solutions/43000501980-choppiness-index-chop/)
+///
+#[doc(alias = "CHOP")]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone)]
+pub struct ChoppinessIndex {
+    period: usize,
+    index: usize,
+    count: usize,
+    true_range: TrueRange,
+    true_range_sum: f64,
+    deque: Box<[f64]>,
+    window: MonotonicWindow,
+    #[cfg_attr(feature = "serde", serde(default))]
+    bars_seen: usize,
+}
+
+impl ChoppinessIndex {
+    pub fn new(period: usize) -> Result<Self> {
+        match period {
+            0 | 1 => Err(TaError::invalid_parameter("period", period)),
+            _ => Ok(Self {
+                period,
+                index: 0,
+                count: 0,
+                true_range: TrueRange::new(),
+                true_range_sum: 0.0,
+                deque: vec![0.0; period].into_boxed_slice(),
+                window: MonotonicWindow::new(period)?,
+                bars_seen: 0,
+            }),
+        }
+    }
+}
+
+impl Period for ChoppinessIndex {
+    fn period(&self) -> usize {
+        self.period
+    }
+}
+
+impl<T: High + Low + Close> Next<&T> for ChoppinessIndex {
+    type Output = f64;
+
+    fn next(&mut self, input: &T) -> Self::Output {
+        self.bars_seen = self.bars_seen.saturating_add(1);
+        let true_range = self.true_range.next(input);
+        let (lowest, highest) = self.window.push_range(input.low(), input.high());
+
+        let old_val = self.deque[self.index];
+        self.deque[self.index] = true_range;
+
+        self.index = if self.index + 1 < self.period {
+            self.index + 1
+        } else {
+            0
+        };
+
+        if self.count < self.period {
+            self.count += 1;
+        }
+        self.true_range_sum += true_range - old_val;
+
+        let range = highest - lowest;
+        if self.count < 2 || range <= 0.0 {
+            return 50.0;
+        }
+
+        // the sum can fall below the range by a rounding error
+        let ratio = (self.true_range_sum / range).max(1.0);
+        100.0 * ratio.log10() / (self.count as f64).log10()
+    }
+}
+
+impl Ready for ChoppinessIndex {
+    fn bars_seen(&self) -> usize {
+        self.bars_seen
+    }
+
+    fn is_ready(&self) -> bool {
+        self.bars_seen >= self.period
+    }
+}
+
+impl Reset for ChoppinessIndex {
+    fn reset(&mut self) {
+        self.bars_seen = 0;
+        self.index = 0;
+        self.count = 0;
+        self.true_range.reset();
+        self.true_range_sum = 0.0;
+        for i in 0..self.period {
+            self.deque[i] = 0.0;
+        }
+        self.window.reset();
+    }
+}
+
+impl Default for ChoppinessIndex {
+    fn default() -> Self {
+        Self::new(14).unwrap()
+    }
+}
+
+impl fmt::Display for ChoppinessIndex {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "CHOP({})", self.period)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helper::*;
+
+    #[test]
+    fn test_new() {
+        assert!(ChoppinessIndex::new(0).is_err());
+        assert!(ChoppinessIndex::new(1).is_err());
+        assert!(ChoppinessIndex::new(2).is_ok());
+    }
+
+    #[test]
+    fn test_next() {
+        let mut chop = ChoppinessIndex::new(3).unwrap();
+
+        assert_eq!(
+            round(chop.next(&Bar::new().high(11).low(9).close(10))),
+            50.0
+        );
+        assert_eq!(
+            round(chop.next(&Bar::new().high(12).low(10).close(11))),
+            41.504
+        );
+        assert_eq!(
+            round(chop.next(&Bar::new().high(11).low(9).close(9.5))),
+            63.093
+        );
+        assert_eq!(
+            round(chop.next(&Bar::new().high(13).low(10).close(12.5))),
+            57.218
+        );
+        assert_eq!(
+            round(chop.next(&Bar::new().high(12).low(11).close(11.5))),
+            50.938
+        );
+    }
+
+    #[test]
+    fn test_next_trend_and_range() {
+        let mut trend = ChoppinessIndex::default();
+        let mut range = ChoppinessIndex::default();
+
+        let (mut trending, mut ranging) = (0.0, 0.0);
+        for i in 0..50 {
+            let price = 100.0 + 2.0 * i as f64;
+            trending = trend.next(
+                &Bar::new()
+                    .high(price + 1.0)
+                    .low(price - 1.0)
+                    .close(price + 0.5),
+            );
+
+            let price = if i % 2 == 0 { 100.0 } else { 101.0 };
+            ranging = range.next(&Bar::new().high(price + 1.0).low(price - 1.0).close(price));
+        }
+
+        assert!(trending < 30.0);
+        assert!(ranging > 70.0);
+    }
+
+    #[test]
+    fn test_next_no_range() {
+        let mut chop = ChoppinessIndex::new(3).unwrap();
+
+        for _ in 0..5 {
+            assert_eq!(chop.next(&Bar::new().high(10).low(10).close(10)), 50.0);
+        }
+    }
+
+    #[test]
+    fn test_reset() {
+        let mut chop = ChoppinessIndex::new(3).unwrap();
+
+        chop.next(&Bar::new().high(11).low(9).close(10));
+        chop.next(&Bar::new().high(15).low(12).close(14));
+        chop.reset();
+
+        assert_eq!(
+            round(chop.next(&Bar::new().high(11).low(9).close(10))),
+            50.0
+        );
+        assert_eq!(
+            round(chop.next(&Bar::new().high(12).low(10).close(11))),
+            41.504
+        );
+    }
+
+    #[test]
+    fn test_ready() {
+        let mut ci = ChoppinessIndex::new(3).unwrap();
+        let bar = Bar::new().high(10).low(8).close(9);
+        for _ in 0..2 {
+            ci.next(&bar);
+        }
+        assert_eq!((ci.bars_seen(), ci.is_ready()), (2, false));
+        ci.next(&bar);
+        assert_eq!((ci.bars_seen(), ci.is_ready()), (3, true));
+
+        ci.reset();
+        assert_eq!((ci.bars_seen(), ci.is_ready()), (0, false));
+    }
+
+    #[test]
+    fn test_default() {
+        ChoppinessIndex::default();
+    }
+
+    #[test]
+    fn test_display() {
+        let chop = ChoppinessIndex::new(14).unwrap();
+        assert_eq!(format!("{}", chop), "CHOP(14)");
+    }
+}
@@ -5,7 +5,7 @@ use serde::{Deserialize, Serialize};
 
 use crate::errors::Result;
 use crate::indicators::{MeanAbsoluteDeviation, SimpleMovingAverage};
-use crate::{Close, High, Low, Next, Period, Reset};
+use crate::{Close, High, Low, Next, Period, Ready, Reset};
 
 /// Commodity Channel Index (CCI)
 ///
@@ -66,6 +66,16 @@ impl<T: Close + High + Low> Next<&T> for CommodityChannelIndex {
     }
 }
 
+impl Ready for CommodityChannelIndex {
+    fn bars_seen(&self) -> usize {
+        self.sma.bars_seen()
+    }
+
+    fn is_ready(&self) -> bool {
+        self.sma.is_ready() && self.mad.is_ready()
+    }
+}
+
 impl Reset for CommodityChannelIndex {
     fn reset(&mut self) {
         self.sma.reset();
@@ -135,6 +145,21 @@ mod tests {
         assert_eq!(round(cci.next(&bar2)), 66.667);
     }
 
+    #[test]
+    fn test_ready() {
+        let mut cci = CommodityChannelIndex::new(3).unwrap();
+        let bar = Bar::new().high(10).low(8).close(9);
+        for _ in 0..2 {
+            cci.next(&bar);
+        }
+        assert_eq!((cci.bars_seen(), cci.is_ready()), (2, false));
+        cci.next(&bar);
+        assert_eq!((cci.bars_seen(), cci.is_ready()), (3, true));
+
+        cci.reset();
+        assert_eq!((cci.bars_seen(), cci.is_ready()), (0, false));
+    }
+
     #[test]
     fn test_default() {
         CommodityChannelIndex::default();
@@ -0,0 +1,279 @@
+use std::fmt;
+
+use crate::errors::Result;
+use crate::indicators::{PercentRank, RelativeStrengthIndex as Rsi, RsiSmoothing};
+use crate::{Close, Next, Period, Ready, Reset};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Connors RSI (CRSI).
+///
+/// Developed by Larry Connors, the Connors RSI averages three components measuring the
+/// momentum of the price, the length of its up and down streaks and the size of its last
+/// change. It returns values in the range of 0..100.
+///
+/// # Formula
+///
+/// CRSI = (RSI(rsi_period) of price + RSI(streak_period) of streak + percent rank) / 3
+///
+/// Where:
+///
+/// * _RSI_ - [relative strength index](struct.RelativeStrengthIndex.html) with Wilder's
+///   smoothing
+/// * _streak_ - number of consecutive closes above the previous one, negative for closes
+///   below the previous one and 0 for an unchanged close.
+/// * _percent rank_ - [percent rank](struct.PercentRank.html) of the current one-bar return
+///   against the last _rank_period_ one-bar returns. It is 0 until a previous return is
+///   available, a return from a close of 0 counts as 0.
+///
+/// # Parameters
+///
+/// * _rsi_period_ - period of the price RSI (integer greater than 0). Default is 3.
+/// * _streak_period_ - period of the streak RSI (integer greater than 0). Default is 2.
+/// * _rank_period_ - number of returns to rank against (integer greater than 0). Default
+///   is 100.
+///
+/// # Example
+///
+/// ```
+/// use ta::indicators::ConnorsRsi;
+/// use ta::Next;
+///
+/// let mut crsi = ConnorsRsi::new(3, 2, 100).unwrap();
+/// assert_eq!(crsi.next(10.0).round(), 33.0);
+/// assert_eq!(crsi.next(11.0).round(), 67.0);
+/// ```
+///
+/// # Links
+///
+/// * [ConnorsRSI, StockCharts](https://school.stockcharts.com/doku.php?id=technical_indicators:connorsrsi)
+///
+#[doc(alias = "CRSI")]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone)]
+pub struct ConnorsRsi {
+    price_rsi: Rsi,
+    streak_rsi: Rsi,
+    rank: PercentRank,
+    prev_close: Option<f64>,
+    streak: f64,
+}
+
+impl ConnorsRsi {
+    pub fn new(rsi_period: usize, streak_period: usize, rank_period: usize) -> Result<Self> {
+        Ok(Self {
+            price_rsi: Rsi::new(rsi_period)?.with_smoothing(RsiSmoothing::Wilder),
+            streak_rsi: Rsi::new(streak_period)?.with_smoothing(RsiSmoothing::Wilder),
+            rank: PercentRank::new(rank_period)?,
+            prev_close: None,
+            streak: 0.0,
+        })
+    }
+}
+
+/// The rank period.
+impl Period for ConnorsRsi {
+    fn period(&self) -> usize {
+        self.rank.period()
+    }
+}
+
+impl Next<f64> for ConnorsRsi {
+    type Output = f64;
+
+    fn next(&mut self, input: f64) -> Self::Output {
+        let rank = match self.prev_close.replace(input) {
+            Some(prev_close) => {
+                self.streak = if input > prev_close {
+                    self.streak.max(0.0) + 1.0
+                } else if input < prev_close {
+                    self.streak.min(0.0) - 1.0
+                } else {
+                    0.0
+                };
+                let change = if prev_close == 0.0 {
+                    0.0
+                } else {
+                    (input - prev_close) / prev_close * 100.0
+                };
+                self.rank.next(change)
+            }
+            None => 0.0,
+        };
+
+        let price_rsi = self.price_rsi.next(input);
+        let streak_rsi = self.streak_rsi.next(self.streak);
+        (price_rsi + streak_rsi + rank) / 3.0
+    }
+}
+
+impl<T: Close> Next<&T> for ConnorsRsi {
+    type Output = f64;
+
+    fn next(&mut self, input: &T) -> Self::Output {
+        self.next(input.close())
+    }
+}
+
+impl Ready for ConnorsRsi {
+    fn bars_seen(&self) -> usize {
+        self.price_rsi.bars_seen()
+    }
+
+    fn is_ready(&self) -> bool {
+        self.price_rsi.is_ready() && self.streak_rsi.is_ready() && self.rank.is_ready()
+    }
+}
+
+impl Reset for ConnorsRsi {
+    fn reset(&mut self) {
+        self.price_rsi.reset();
+        self.streak_rsi.reset();
+        self.rank.reset();
+        self.prev_close = None;
+        self.streak = 0.0;
+    }
+}
+
+impl Default for ConnorsRsi {
+    fn default() -> Self {
+        Self::new(3, 2, 100).unwrap()
+    }
+}
+
+impl fmt::Display for ConnorsRsi {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "CRSI({}, {}, {})",
+            self.price_rsi.period(),
+            self.streak_rsi.period(),
+            self.rank.period()
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helper::*;
+
+    test_indicator!(ConnorsRsi);
+
+    #[test]
+    fn test_new() {
+        assert!(ConnorsRsi::new(0, 2, 100).is_err());
+        assert!(ConnorsRsi::new(3, 0, 100).is_err());
+        assert!(ConnorsRsi::new(3, 2, 0).is_err());
+        assert!(ConnorsRsi::new(3, 2, 100).is_ok());
+    }
+
+    #[test]
+    fn test_next() {
+        let mut crsi = ConnorsRsi::new(3, 2, 4).unwrap();
+
+        #[rustfmt::skip]
+        let data = [
+            // close, streak, connors rsi
+            (10.0, 0.0, 33.333),
+            (11.0, 1.0, 66.667),
+            (12.0, 2.0, 66.667),
+            // the streak is reset by unchanged closes
+            (12.0, 0.0, 44.444),
+            (12.0, 0.0, 44.444),
+            (11.0, -1.0, 20.448),
+            (10.5, -2.0, 21.784),
+            // the streak turns, the return is the highest of the last 4
+            (11.0, 1.0, 77.094),
+            (11.5, 2.0, 76.054),
+            (12.0, 3.0, 73.091),
+        ];
+
+        for (close, streak, expected) in data {
+            assert_eq!(round(crsi.next(close)), expected);
+            assert_eq!(crsi.streak, streak);
+        }
+    }
+
+    #[test]
+    fn test_next_reference() {
+        let mut crsi = ConnorsRsi::new(3, 2, 10).unwrap();
+
+        // the closes of the StockCharts ADX sample, the expected values are computed from
+        // Connors' definition with Wilder's RSIs seeded with the average of the first changes
+        #[rustfmt::skip]
+        let closes = [
+            29.87, 30.24, 30.10, 28.90, 28.92, 28.48, 28.56, 27.56, 28.47, 28.28, 27.49, 27.23,
+            26.35, 26.33, 27.03, 26.22, 26.01, 25.46, 27.03, 27.45, 28.36, 28.43, 27.95, 29.01,
+            29.38, 29.36, 28.91, 30.61, 30.05, 30.19,
+        ];
+        // from the 12th close on 10 previous returns are ranked
+        let expected = [
+            24.609, 12.721, 28.154, 75.614, 34.417, 38.184, 27.235, 82.502, 79.747, 88.186, 79.693,
+            35.693, 73.291, 66.039, 48.768, 29.868, 81.481, 33.738, 61.382,
+        ];
+
+        let outputs: Vec<f64> = closes.iter().map(|&c| round(crsi.next(c))).collect();
+        assert_eq!(outputs[11..], expected);
+    }
+
+    #[test]
+    fn test_next_zero_close() {
+        let mut crsi = ConnorsRsi::new(3, 2, 4).unwrap();
+
+        // the returns from a close of 0 are ranked as 0, the return of 100% ranks above them
+        crsi.next(0.0);
+        crsi.next(0.0);
+        assert!(crsi.next(1.0).is_finite());
+        assert_eq!(crsi.next(2.0), 100.0);
+    }
+
+    #[test]
+    fn test_next_bounds() {
+        let mut crsi = ConnorsRsi::default();
+
+        for i in 0..300 {
+            let close = 100.0 + 10.0 * (i as f64 / 7.0).sin() + (i % 3) as f64;
+            let value = crsi.next(&Bar::new().close(close));
+            assert!((0.0..=100.0).contains(&value));
+        }
+    }
+
+    #[test]
+    fn test_reset() {
+        let mut crsi = ConnorsRsi::new(3, 2, 4).unwrap();
+
+        crsi.next(10.0);
+        crsi.next(12.0);
+        crsi.reset();
+
+        assert_eq!(round(crsi.next(10.0)), 33.333);
+        assert_eq!(round(crsi.next(11.0)), 66.667);
+    }
+
+    #[test]
+    fn test_ready() {
+        let mut crsi = ConnorsRsi::new(3, 2, 4).unwrap();
+        for _ in 0..5 {
+            crsi.next(1.0);
+        }
+        assert_eq!((crsi.bars_seen(), crsi.is_ready()), (5, false));
+        // the sixth input is the first change ranked against four previous ones
+        crsi.next(1.0);
+        assert_eq!((crsi.bars_seen(), crsi.is_ready()), (6, true));
+
+        crsi.reset();
+        assert_eq!((crsi.bars_seen(), crsi.is_ready()), (0, false));
+    }
+
+    #[test]
+    fn test_default() {
+        ConnorsRsi::default();
+    }
+
+    #[test]
+    fn test_display() {
+        let crsi = ConnorsRsi::new(3, 2, 100).unwrap();
+        assert_eq!(format!("{}", crsi), "CRSI(3, 2, 100)");
+    }
+}
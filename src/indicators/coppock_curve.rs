@@ -0,0 +1,207 @@
+use std::fmt;
+
+use crate::errors::Result;
+use crate::indicators::{RateOfChange, WeightedMovingAverage};
+use crate::{Close, Next, Period, Ready, Reset};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Coppock Curve.
+///
+/// Developed by Edwin Coppock as a long term momentum indicator to identify the bottoms of
+/// the market. A cross above the zero line signals the start of a recovery.
+///
+/// # Formula
+///
+/// Coppock = WMA(wma_period) of (ROC(roc1) + ROC(roc2))
+///
+/// Where:
+///
+/// * _ROC_ - [rate of change](struct.RateOfChange.html)
+/// * _WMA_ - [weighted moving average](struct.WeightedMovingAverage.html)
+///
+/// # Parameters
+///
+/// * _roc1_ - period of the long ROC (integer greater than 0). Default is 14.
+/// * _roc2_ - period of the short ROC (integer greater than 0). Default is 11.
+/// * _wma_period_ - period of the WMA (integer greater than 0). Default is 10.
+///
+/// # Example
+///
+/// ```
+/// use ta::indicators::CoppockCurve;
+/// use ta::Next;
+///
+/// let mut coppock = CoppockCurve::new(2, 1, 1).unwrap();
+/// assert_eq!(coppock.next(10.0), 0.0);
+/// assert_eq!(coppock.next(11.0), 20.0);
+/// ```
+///
+/// # Links
+///
+/// * [Coppock Curve, StockCharts](https://school.stockcharts.com/doku.php?id=technical_indicators:coppock_curve)
+///
+#[doc(alias = "Coppock")]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone)]
+pub struct CoppockCurve {
+    roc1: RateOfChange,
+    roc2: RateOfChange,
+    wma: WeightedMovingAverage,
+}
+
+impl CoppockCurve {
+    pub fn new(roc1: usize, roc2: usize, wma_period: usize) -> Result<Self> {
+        Ok(Self {
+            roc1: RateOfChange::new(roc1)?,
+            roc2: RateOfChange::new(roc2)?,
+            wma: WeightedMovingAverage::new(wma_period)?,
+        })
+    }
+}
+
+/// The longer of the two ROC periods.
+impl Period for CoppockCurve {
+    fn period(&self) -> usize {
+        self.roc1.period().max(self.roc2.period())
+    }
+}
+
+impl Next<f64> for CoppockCurve {
+    type Output = f64;
+
+    fn next(&mut self, input: f64) -> Self::Output {
+        let roc = self.roc1.next(input) + self.roc2.next(input);
+        self.wma.next(roc)
+    }
+}
+
+impl<T: Close> Next<&T> for CoppockCurve {
+    type Output = f64;
+
+    fn next(&mut self, input: &T) -> Self::Output {
+        self.next(input.close())
+    }
+}
+
+impl Ready for CoppockCurve {
+    fn bars_seen(&self) -> usize {
+        self.roc1.bars_seen()
+    }
+
+    fn is_ready(&self) -> bool {
+        self.roc1.is_ready() && self.roc2.is_ready() && self.wma.is_ready()
+    }
+}
+
+impl Reset for CoppockCurve {
+    fn reset(&mut self) {
+        self.roc1.reset();
+        self.roc2.reset();
+        self.wma.reset();
+    }
+}
+
+impl Default for CoppockCurve {
+    fn default() -> Self {
+        Self::new(14, 11, 10).unwrap()
+    }
+}
+
+impl fmt::Display for CoppockCurve {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "COPPOCK({}, {}, {})",
+            self.roc1.period(),
+            self.roc2.period(),
+            self.wma.period()
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helper::*;
+
+    test_indicator!(CoppockCurve);
+
+    #[test]
+    fn test_new() {
+        assert!(CoppockCurve::new(0, 11, 10).is_err());
+        assert!(CoppockCurve::new(14, 0, 10).is_err());
+        assert!(CoppockCurve::new(14, 11, 0).is_err());
+        assert!(CoppockCurve::new(14, 11, 10).is_ok());
+    }
+
+    #[test]
+    fn test_next() {
+        let mut coppock = CoppockCurve::new(2, 1, 2).unwrap();
+
+        assert_eq!(round(coppock.next(10.0)), 0.0);
+        // ROCs of 10 and 10, WMA of 0 and 20
+        assert_eq!(round(coppock.next(11.0)), 13.333);
+        // ROCs of 20 and 9.091, WMA of 20 and 29.091
+        assert_eq!(round(coppock.next(12.0)), 26.061);
+    }
+
+    #[test]
+    fn test_next_recovery() {
+        let mut coppock = CoppockCurve::default();
+
+        // a decline to the bottom at bar 29 and a recovery twice as fast
+        let prices = (0..30)
+            .map(|i| 100.0 - i as f64)
+            .chain((1..40).map(|i| 71.0 + 2.0 * i as f64));
+        let values: Vec<f64> = prices.map(|price| coppock.next(price)).collect();
+
+        // the curve lags the bottom and crosses the zero line 8 bars later
+        assert!(values[1..37].iter().all(|&value| value < 0.0));
+        assert!(values[37..].iter().all(|&value| value > 0.0));
+    }
+
+    #[test]
+    fn test_reset() {
+        let mut coppock = CoppockCurve::new(2, 1, 2).unwrap();
+
+        coppock.next(10.0);
+        coppock.next(20.0);
+        coppock.reset();
+
+        assert_eq!(round(coppock.next(10.0)), 0.0);
+        assert_eq!(round(coppock.next(11.0)), 13.333);
+    }
+
+    #[test]
+    fn test_period() {
+        assert_eq!(CoppockCurve::new(14, 11, 10).unwrap().period(), 14);
+        assert_eq!(CoppockCurve::new(11, 14, 10).unwrap().period(), 14);
+    }
+
+    #[test]
+    fn test_ready() {
+        let mut coppock = CoppockCurve::new(3, 2, 2).unwrap();
+        for _ in 0..3 {
+            coppock.next(1.0);
+        }
+        assert_eq!((coppock.bars_seen(), coppock.is_ready()), (3, false));
+        // the longer rate of change is ready with the fourth input
+        coppock.next(1.0);
+        assert_eq!((coppock.bars_seen(), coppock.is_ready()), (4, true));
+
+        coppock.reset();
+        assert_eq!((coppock.bars_seen(), coppock.is_ready()), (0, false));
+    }
+
+    #[test]
+    fn test_default() {
+        CoppockCurve::default();
+    }
+
+    #[test]
+    fn test_display() {
+        let coppock = CoppockCurve::new(14, 11, 10).unwrap();
+        assert_eq!(format!("{}", coppock), "COPPOCK(14, 11, 10)");
+    }
+}
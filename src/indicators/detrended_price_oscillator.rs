@@ -0,0 +1,201 @@
+use std::fmt;
+
+use crate::adapters::Delay;
+use crate::errors::Result;
+use crate::indicators::SimpleMovingAverage;
+use crate::{Close, Next, Period, Ready, Reset};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Detrended Price Oscillator (DPO).
+///
+/// Removes the trend from the price by comparing it to a moving average displaced back in
+/// time, which makes the cycles in the price easier to see.
+///
+/// # Formula
+///
+/// DPO<sub>t</sub> = price<sub>t</sub> - SMA(period)<sub>t - shift</sub>
+///
+/// shift = period / 2 + 1
+///
+/// Where _SMA_ is the [simple moving average](struct.SimpleMovingAverage.html). Until _shift_
+/// averages were computed, the first average is used instead of the displaced one.
+///
+/// # Parameters
+///
+/// * _period_ - period of the SMA (integer greater than 0). Default is 20.
+///
+/// # Example
+///
+/// ```
+/// use ta::indicators::DetrendedPriceOscillator;
+/// use ta::Next;
+///
+/// let mut dpo = DetrendedPriceOscillator::new(2).unwrap();
+/// assert_eq!(dpo.next(10.0), 0.0);
+/// assert_eq!(dpo.next(12.0), 2.0);
+/// assert_eq!(dpo.next(14.0), 4.0);
+/// assert_eq!(dpo.next(16.0), 5.0);
+/// ```
+///
+/// # Links
+///
+/// * [Detrended Price Oscillator, StockCharts](https://school.stockcharts.com/doku.php?id=technical_indicators:detrended_price_osci)
+///
+#[doc(alias = "DPO")]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone)]
+pub struct DetrendedPriceOscillator {
+    sma: SimpleMovingAverage,
+    delay: Delay,
+}
+
+impl DetrendedPriceOscillator {
+    pub fn new(period: usize) -> Result<Self> {
+        Ok(Self {
+            sma: SimpleMovingAverage::new(period)?,
+            delay: Delay::new(period / 2 + 1),
+        })
+    }
+}
+
+impl Period for DetrendedPriceOscillator {
+    fn period(&self) -> usize {
+        self.sma.period()
+    }
+}
+
+impl Next<f64> for DetrendedPriceOscillator {
+    type Output = f64;
+
+    fn next(&mut self, input: f64) -> Self::Output {
+        let sma = self.sma.next(input);
+        // until the delay is filled the first average stands in
+        let displaced = match self.delay.next(sma) {
+            Some(displaced) => displaced,
+            None => *self.delay.oldest().unwrap(),
+        };
+
+        input - displaced
+    }
+}
+
+impl<T: Close> Next<&T> for DetrendedPriceOscillator {
+    type Output = f64;
+
+    fn next(&mut self, input: &T) -> Self::Output {
+        self.next(input.close())
+    }
+}
+
+impl Ready for DetrendedPriceOscillator {
+    fn bars_seen(&self) -> usize {
+        self.sma.bars_seen()
+    }
+
+    fn is_ready(&self) -> bool {
+        // the displaced average has to be complete
+        self.sma.bars_seen() >= self.sma.period() + self.delay.delay()
+    }
+}
+
+impl Reset for DetrendedPriceOscillator {
+    fn reset(&mut self) {
+        self.sma.reset();
+        self.delay.reset();
+    }
+}
+
+impl Default for DetrendedPriceOscillator {
+    fn default() -> Self {
+        Self::new(20).unwrap()
+    }
+}
+
+impl fmt::Display for DetrendedPriceOscillator {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "DPO({})", self.sma.period())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helper::*;
+
+    test_indicator!(DetrendedPriceOscillator);
+
+    #[test]
+    fn test_new() {
+        assert!(DetrendedPriceOscillator::new(0).is_err());
+        assert!(DetrendedPriceOscillator::new(1).is_ok());
+    }
+
+    #[test]
+    fn test_next() {
+        let mut dpo = DetrendedPriceOscillator::new(4).unwrap();
+
+        // the SMA is displaced by 3 bars
+        assert_eq!(dpo.next(1.0), 0.0);
+        assert_eq!(dpo.next(2.0), 1.0);
+        assert_eq!(dpo.next(3.0), 2.0);
+        assert_eq!(dpo.next(4.0), 3.0);
+        assert_eq!(dpo.next(5.0), 3.5);
+        assert_eq!(dpo.next(6.0), 4.0);
+        assert_eq!(dpo.next(7.0), 4.5);
+        assert_eq!(dpo.next(8.0), 4.5);
+    }
+
+    #[test]
+    fn test_next_linear_trend() {
+        let mut dpo = DetrendedPriceOscillator::new(20).unwrap();
+
+        // the SMA fills after 20 bars and its displaced value after 11 more
+        let values: Vec<f64> = (0..60)
+            .map(|i| dpo.next(&Bar::new().close(100.0 + 0.5 * i as f64)))
+            .collect();
+
+        // 0.5 * (shift + (period - 1) / 2)
+        for value in &values[30..] {
+            assert_eq!(round(*value), 10.25);
+        }
+    }
+
+    #[test]
+    fn test_reset() {
+        let mut dpo = DetrendedPriceOscillator::new(4).unwrap();
+
+        dpo.next(1.0);
+        dpo.next(2.0);
+        dpo.reset();
+
+        assert_eq!(dpo.next(10.0), 0.0);
+        assert_eq!(dpo.next(11.0), 1.0);
+    }
+
+    #[test]
+    fn test_ready() {
+        let mut dpo = DetrendedPriceOscillator::new(4).unwrap();
+        for _ in 0..6 {
+            dpo.next(1.0);
+        }
+        assert_eq!((dpo.bars_seen(), dpo.is_ready()), (6, false));
+        // the average of three inputs ago is complete
+        dpo.next(1.0);
+        assert_eq!((dpo.bars_seen(), dpo.is_ready()), (7, true));
+
+        dpo.reset();
+        assert_eq!((dpo.bars_seen(), dpo.is_ready()), (0, false));
+    }
+
+    #[test]
+    fn test_default() {
+        DetrendedPriceOscillator::default();
+    }
+
+    #[test]
+    fn test_display() {
+        let dpo = DetrendedPriceOscillator::new(20).unwrap();
+        assert_eq!(format!("{}", dpo), "DPO(20)");
+    }
+}
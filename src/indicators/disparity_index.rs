@@ -0,0 +1,199 @@
+use std::fmt;
+
+use crate::errors::Result;
+use crate::indicators::SimpleMovingAverage as Sma;
+use crate::{Close, Next, Period, Ready, Reset};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Disparity Index.
+///
+/// Developed by Steve Nison, the index measures how far the price is from its moving average
+/// in percent. It is positive above the average and negative below it, extreme values hint
+/// that the price has moved too far too fast.
+///
+/// # Formula
+///
+/// Disparity = (price - SMA(period)) / SMA(period) * 100
+///
+/// Where _SMA_ is the [simple moving average](struct.SimpleMovingAverage.html) of the price.
+/// An SMA of 0 yields 0.
+///
+/// # Parameters
+///
+/// * _period_ - period of the SMA (integer greater than 0). Default is 14.
+///
+/// # Example
+///
+/// ```
+/// use ta::indicators::DisparityIndex;
+/// use ta::Next;
+///
+/// let mut disparity = DisparityIndex::new(2).unwrap();
+/// assert_eq!(disparity.next(9.0), 0.0);
+/// // 11 is 10% above the average of 10
+/// assert_eq!(disparity.next(11.0), 10.0);
+/// ```
+///
+/// # Links
+///
+/// * [Disparity Index, Investopedia](https://www.investopedia.com/terms/d/disparityindex.asp)
+///
+#[doc(alias = "DI")]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone)]
+pub struct DisparityIndex {
+    sma: Sma,
+}
+
+impl DisparityIndex {
+    pub fn new(period: usize) -> Result<Self> {
+        Ok(Self {
+            sma: Sma::new(period)?,
+        })
+    }
+}
+
+impl Period for DisparityIndex {
+    fn period(&self) -> usize {
+        self.sma.period()
+    }
+}
+
+impl Next<f64> for DisparityIndex {
+    type Output = f64;
+
+    fn next(&mut self, input: f64) -> Self::Output {
+        let sma = self.sma.next(input);
+        if sma == 0.0 {
+            0.0
+        } else {
+            (input - sma) / sma * 100.0
+        }
+    }
+}
+
+impl<T: Close> Next<&T> for DisparityIndex {
+    type Output = f64;
+
+    fn next(&mut self, input: &T) -> Self::Output {
+        self.next(input.close())
+    }
+}
+
+impl Ready for DisparityIndex {
+    fn bars_seen(&self) -> usize {
+        self.sma.bars_seen()
+    }
+
+    fn is_ready(&self) -> bool {
+        self.sma.is_ready()
+    }
+}
+
+impl Reset for DisparityIndex {
+    fn reset(&mut self) {
+        self.sma.reset();
+    }
+}
+
+impl Default for DisparityIndex {
+    fn default() -> Self {
+        Self::new(14).unwrap()
+    }
+}
+
+impl fmt::Display for DisparityIndex {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "DISPARITY({})", self.sma.period())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helper::*;
+
+    test_indicator!(DisparityIndex);
+
+    #[test]
+    fn test_new() {
+        assert!(DisparityIndex::new(0).is_err());
+        assert!(DisparityIndex::new(1).is_ok());
+    }
+
+    #[test]
+    fn test_next() {
+        let mut disparity = DisparityIndex::new(3).unwrap();
+
+        assert_eq!(disparity.next(10.0), 0.0);
+        assert_eq!(round(disparity.next(12.0)), 9.091);
+        assert_eq!(round(disparity.next(8.0)), -20.0);
+        // the price equals its average
+        assert_eq!(disparity.next(10.0), 0.0);
+
+        let mut disparity = DisparityIndex::new(3).unwrap();
+        assert_eq!(disparity.next(&Bar::new().close(10)), 0.0);
+        assert_eq!(round(disparity.next(&Bar::new().close(12))), 9.091);
+    }
+
+    #[test]
+    fn test_next_sign() {
+        let mut disparity = DisparityIndex::new(5).unwrap();
+
+        for _ in 0..5 {
+            assert_eq!(disparity.next(20.0), 0.0);
+        }
+        assert!(disparity.next(25.0) > 0.0);
+
+        let mut disparity = DisparityIndex::new(5).unwrap();
+        disparity.next(20.0);
+        assert!(disparity.next(15.0) < 0.0);
+    }
+
+    #[test]
+    fn test_next_zero_sma() {
+        let mut disparity = DisparityIndex::new(2).unwrap();
+
+        assert_eq!(disparity.next(0.0), 0.0);
+        disparity.next(2.0);
+        assert_eq!(disparity.next(-2.0), 0.0);
+    }
+
+    #[test]
+    fn test_reset() {
+        let mut disparity = DisparityIndex::new(3).unwrap();
+
+        disparity.next(10.0);
+        disparity.next(40.0);
+        disparity.reset();
+
+        assert_eq!(disparity.next(10.0), 0.0);
+        assert_eq!(round(disparity.next(12.0)), 9.091);
+    }
+
+    #[test]
+    fn test_ready() {
+        let mut di = DisparityIndex::new(3).unwrap();
+        for _ in 0..2 {
+            di.next(1.0);
+        }
+        assert_eq!((di.bars_seen(), di.is_ready()), (2, false));
+        di.next(1.0);
+        assert_eq!((di.bars_seen(), di.is_ready()), (3, true));
+
+        di.reset();
+        assert_eq!((di.bars_seen(), di.is_ready()), (0, false));
+    }
+
+    #[test]
+    fn test_default() {
+        DisparityIndex::default();
+    }
+
+    #[test]
+    fn test_display() {
+        let disparity = DisparityIndex::new(14).unwrap();
+        assert_eq!(format!("{}", disparity), "DISPARITY(14)");
+    }
+}
@@ -0,0 +1,241 @@
+use std::fmt;
+
+use crate::errors::Result;
+use crate::window::MonotonicWindow;
+use crate::{High, Low, Next, Period, Ready, Reset};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Donchian Channel (DC).
+///
+/// A channel formed by the highest high and the lowest low of the last _period_ bars,
+/// developed by Richard Donchian.
+///
+/// # Formula
+///
+/// DC is composed as:
+///
+///  * _DC<sub>Upper Band</sub>_ = highest high of the last _period_ bars
+///  * _DC<sub>Lower Band</sub>_ = lowest low of the last _period_ bars
+///  * _DC<sub>Middle Band</sub>_ = (upper + lower) / 2
+///
+/// # Parameters
+///
+/// * _period_ - number of bars (integer greater than 0). Default is 20.
+///
+/// # Example
+///
+/// ```
+/// use ta::indicators::DonchianChannel;
+/// use ta::{DataItem, Next};
+///
+/// let mut dc = DonchianChannel::new(2).unwrap();
+///
+/// let bar = |high, low| {
+///     DataItem::builder()
+///         .open(low).high(high).low(low).close(low).volume(1000.0)
+///         .build(true).unwrap()
+/// };
+///
+/// dc.next(&bar(10.0, 8.0));
+/// let out = dc.next(&bar(11.0, 9.0));
+///
+/// assert_eq!(out.upper, 11.0);
+/// assert_eq!(out.middle, 9.5);
+/// assert_eq!(out.lower, 8.0);
+/// ```
+///
+/// # Links
+///
+/// * [Donchian channel, Wikipedia](https://en.wikipedia.org/wiki/Donchian_channel)
+///
+#[doc(alias = "DC")]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone)]
+pub struct DonchianChannel {
+    period: usize,
+    window: MonotonicWindow,
+    #[cfg_attr(feature = "serde", serde(default))]
+    bars_seen: usize,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct DonchianChannelOutput {
+    pub upper: f64,
+    pub middle: f64,
+    pub lower: f64,
+}
+
+impl From<DonchianChannelOutput> for (f64, f64, f64) {
+    fn from(dco: DonchianChannelOutput) -> Self {
+        (dco.upper, dco.middle, dco.lower)
+    }
+}
+
+impl DonchianChannel {
+    pub fn new(period: usize) -> Result<Self> {
+        Ok(Self {
+            period,
+            window: MonotonicWindow::new(period)?,
+            bars_seen: 0,
+        })
+    }
+
+    fn output(upper: f64, lower: f64) -> DonchianChannelOutput {
+        DonchianChannelOutput {
+            upper,
+            middle: (upper + lower) / 2.0,
+            lower,
+        }
+    }
+}
+
+impl Period for DonchianChannel {
+    fn period(&self) -> usize {
+        self.period
+    }
+}
+
+impl Next<f64> for DonchianChannel {
+    type Output = DonchianChannelOutput;
+
+    fn next(&mut self, input: f64) -> Self::Output {
+        self.bars_seen = self.bars_seen.saturating_add(1);
+        let (lower, upper) = self.window.push(input);
+        Self::output(upper, lower)
+    }
+}
+
+impl<T: High + Low> Next<&T> for DonchianChannel {
+    type Output = DonchianChannelOutput;
+
+    fn next(&mut self, input: &T) -> Self::Output {
+        self.bars_seen = self.bars_seen.saturating_add(1);
+        let (lower, upper) = self.window.push_range(input.low(), input.high());
+        Self::output(upper, lower)
+    }
+}
+
+impl Ready for DonchianChannel {
+    fn bars_seen(&self) -> usize {
+        self.bars_seen
+    }
+
+    fn is_ready(&self) -> bool {
+        self.bars_seen >= self.period
+    }
+}
+
+impl Reset for DonchianChannel {
+    fn reset(&mut self) {
+        self.bars_seen = 0;
+        self.window.reset();
+    }
+}
+
+impl Default for DonchianChannel {
+    fn default() -> Self {
+        Self::new(20).unwrap()
+    }
+}
+
+impl fmt::Display for DonchianChannel {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "DC({})", self.period)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helper::*;
+
+    test_indicator!(DonchianChannel);
+
+    #[test]
+    fn test_new() {
+        assert!(DonchianChannel::new(0).is_err());
+        assert!(DonchianChannel::new(1).is_ok());
+        assert!(DonchianChannel::new(20).is_ok());
+    }
+
+    #[test]
+    fn test_next_bar() {
+        let mut dc = DonchianChannel::new(3).unwrap();
+
+        let bars = [
+            // high, low, upper, middle, lower
+            (10.0, 8.0, 10.0, 9.0, 8.0),
+            (12.0, 9.0, 12.0, 10.0, 8.0),
+            (11.0, 7.0, 12.0, 9.5, 7.0),
+            (9.0, 8.5, 12.0, 9.5, 7.0),
+            (8.0, 7.5, 11.0, 9.0, 7.0),
+            (8.5, 7.8, 9.0, 8.25, 7.5),
+            (13.0, 8.0, 13.0, 10.25, 7.5),
+        ];
+
+        for (high, low, upper, middle, lower) in bars {
+            let out = dc.next(&Bar::new().high(high).low(low));
+            assert_eq!(out.upper, upper);
+            assert_eq!(out.middle, middle);
+            assert_eq!(out.lower, lower);
+        }
+    }
+
+    #[test]
+    fn test_next_brackets_window() {
+        let period = 5;
+        let mut dc = DonchianChannel::new(period).unwrap();
+
+        let highs = [5.0, 7.0, 6.5, 9.0, 8.0, 4.0, 4.5, 6.0, 5.5, 3.0, 3.5, 8.5];
+        let lows = [4.0, 6.0, 5.0, 7.5, 6.0, 2.0, 3.0, 5.0, 4.5, 1.0, 2.5, 7.0];
+
+        for i in 0..highs.len() {
+            let out = dc.next(&Bar::new().high(highs[i]).low(lows[i]));
+            let from = (i + 1).saturating_sub(period);
+            let max = highs[from..=i].iter().cloned().fold(f64::MIN, f64::max);
+            let min = lows[from..=i].iter().cloned().fold(f64::MAX, f64::min);
+            assert_eq!(out.upper, max);
+            assert_eq!(out.lower, min);
+        }
+    }
+
+    #[test]
+    fn test_reset() {
+        let mut dc = DonchianChannel::new(3).unwrap();
+
+        dc.next(&Bar::new().high(10).low(8));
+        dc.next(&Bar::new().high(12).low(6));
+        dc.reset();
+
+        let out = dc.next(&Bar::new().high(9).low(8.5));
+        assert_eq!(out.upper, 9.0);
+        assert_eq!(out.lower, 8.5);
+    }
+
+    #[test]
+    fn test_ready() {
+        let mut dc = DonchianChannel::new(3).unwrap();
+        let bar = Bar::new().high(10).low(8).close(9);
+        for _ in 0..2 {
+            dc.next(&bar);
+        }
+        assert_eq!((dc.bars_seen(), dc.is_ready()), (2, false));
+        dc.next(&bar);
+        assert_eq!((dc.bars_seen(), dc.is_ready()), (3, true));
+
+        dc.reset();
+        assert_eq!((dc.bars_seen(), dc.is_ready()), (0, false));
+    }
+
+    #[test]
+    fn test_default() {
+        DonchianChannel::default();
+    }
+
+    #[test]
+    fn test_display() {
+        let dc = DonchianChannel::new(20).unwrap();
+        assert_eq!(format!("{}", dc), "DC(20)");
+    }
+}
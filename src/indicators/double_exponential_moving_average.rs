@@ -0,0 +1,219 @@
+use std::fmt;
+
+use crate::errors::Result;
+use crate::indicators::ExponentialMovingAverage as Ema;
+use crate::{Close, Current, Next, Period, Ready, Reset};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Double exponential moving average (DEMA).
+///
+/// Developed by Patrick Mulloy, the DEMA reduces the lag of an
+/// [exponential moving average](struct.ExponentialMovingAverage.html) by subtracting the lag
+/// of a second smoothing from it. Despite the name, it is not an EMA of an EMA.
+///
+/// # Formula
+///
+/// DEMA = 2 * EMA(period) - EMA(period) of EMA(period)
+///
+/// # Parameters
+///
+/// * _period_ - smoothing period of the EMAs (integer greater than 0). Default is 9.
+///
+/// # Example
+///
+/// ```
+/// use ta::indicators::DoubleExponentialMovingAverage;
+/// use ta::Next;
+///
+/// let mut dema = DoubleExponentialMovingAverage::new(3).unwrap();
+/// assert_eq!(dema.next(10.0), 10.0);
+/// assert_eq!(dema.next(11.0), 10.75);
+/// assert_eq!(dema.next(12.0), 11.75);
+/// ```
+///
+/// # Links
+///
+/// * [Double exponential moving average, Wikipedia](https://en.wikipedia.org/wiki/Double_exponential_moving_average)
+///
+#[doc(alias = "DEMA")]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone)]
+pub struct DoubleExponentialMovingAverage {
+    ema1: Ema,
+    ema2: Ema,
+}
+
+impl DoubleExponentialMovingAverage {
+    pub fn new(period: usize) -> Result<Self> {
+        Ok(Self {
+            ema1: Ema::new(period)?,
+            ema2: Ema::new(period)?,
+        })
+    }
+}
+
+impl Period for DoubleExponentialMovingAverage {
+    fn period(&self) -> usize {
+        self.ema1.period()
+    }
+}
+
+impl Next<f64> for DoubleExponentialMovingAverage {
+    type Output = f64;
+
+    fn next(&mut self, input: f64) -> Self::Output {
+        let ema1 = self.ema1.next(input);
+        let ema2 = self.ema2.next(ema1);
+        2.0 * ema1 - ema2
+    }
+}
+
+impl<T: Close> Next<&T> for DoubleExponentialMovingAverage {
+    type Output = f64;
+
+    fn next(&mut self, input: &T) -> Self::Output {
+        self.next(input.close())
+    }
+}
+
+impl Current for DoubleExponentialMovingAverage {
+    type Output = f64;
+
+    fn current(&self) -> Option<Self::Output> {
+        Some(2.0 * self.ema1.current()? - self.ema2.current()?)
+    }
+}
+
+impl Ready for DoubleExponentialMovingAverage {
+    fn bars_seen(&self) -> usize {
+        self.ema1.bars_seen()
+    }
+
+    fn is_ready(&self) -> bool {
+        self.ema1.is_ready() && self.ema2.is_ready()
+    }
+}
+
+impl Reset for DoubleExponentialMovingAverage {
+    fn reset(&mut self) {
+        self.ema1.reset();
+        self.ema2.reset();
+    }
+}
+
+impl Default for DoubleExponentialMovingAverage {
+    fn default() -> Self {
+        Self::new(9).unwrap()
+    }
+}
+
+impl fmt::Display for DoubleExponentialMovingAverage {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "DEMA({})", self.ema1.period())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helper::*;
+
+    test_indicator!(DoubleExponentialMovingAverage);
+
+    #[test]
+    fn test_new() {
+        assert!(DoubleExponentialMovingAverage::new(0).is_err());
+        assert!(DoubleExponentialMovingAverage::new(1).is_ok());
+    }
+
+    #[test]
+    fn test_next() {
+        let mut dema = DoubleExponentialMovingAverage::new(3).unwrap();
+
+        let inputs = [10.0, 11.0, 12.0, 13.0, 12.0, 15.0, 20.0, 18.0];
+        let values: Vec<f64> = inputs.iter().map(|&x| round(dema.next(x))).collect();
+        assert_eq!(
+            values,
+            [10.0, 10.75, 11.75, 12.813, 12.375, 14.422, 18.828, 18.723]
+        );
+
+        let mut dema = DoubleExponentialMovingAverage::new(3).unwrap();
+        assert_eq!(dema.next(&Bar::new().close(4)), 4.0);
+        assert_eq!(dema.next(&Bar::new().close(6)), 5.5);
+    }
+
+    #[test]
+    fn test_next_ramp() {
+        let mut dema = DoubleExponentialMovingAverage::new(10).unwrap();
+        let mut ema = Ema::new(10).unwrap();
+
+        // on a ramp the DEMA is closer to the input than the EMA
+        for i in 0..40 {
+            let input = i as f64;
+            let (dema, ema) = (dema.next(input), ema.next(input));
+            if i > 0 {
+                assert!(ema < dema && dema < input);
+            }
+        }
+
+        // and both converge on a constant input
+        for _ in 0..100 {
+            dema.next(50.0);
+            ema.next(50.0);
+        }
+        assert_eq!(round(dema.next(50.0)), 50.0);
+        assert_eq!(round(ema.next(50.0)), 50.0);
+    }
+
+    #[test]
+    fn test_reset() {
+        let mut dema = DoubleExponentialMovingAverage::new(3).unwrap();
+
+        dema.next(10.0);
+        dema.next(20.0);
+        dema.reset();
+
+        assert_eq!(dema.next(10.0), 10.0);
+        assert_eq!(dema.next(11.0), 10.75);
+    }
+
+    #[test]
+    fn test_current() {
+        let mut dema = DoubleExponentialMovingAverage::new(3).unwrap();
+        assert_eq!(dema.current(), None);
+
+        for input in [2.0, 3.0, 4.2, 7.0, 6.7, 6.5] {
+            let value = dema.next(input);
+            assert_eq!(dema.current(), Some(value));
+        }
+
+        dema.reset();
+        assert_eq!(dema.current(), None);
+    }
+
+    #[test]
+    fn test_ready() {
+        let mut dema = DoubleExponentialMovingAverage::new(3).unwrap();
+        for _ in 0..2 {
+            dema.next(1.0);
+        }
+        assert_eq!((dema.bars_seen(), dema.is_ready()), (2, false));
+        dema.next(1.0);
+        assert_eq!((dema.bars_seen(), dema.is_ready()), (3, true));
+
+        dema.reset();
+        assert_eq!((dema.bars_seen(), dema.is_ready()), (0, false));
+    }
+
+    #[test]
+    fn test_default() {
+        DoubleExponentialMovingAverage::default();
+    }
+
+    #[test]
+    fn test_display() {
+        let dema = DoubleExponentialMovingAverage::new(9).unwrap();
+        assert_eq!(format!("{}", dema), "DEMA(9)");
+    }
+}
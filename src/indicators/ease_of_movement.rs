@@ -0,0 +1,231 @@
+use std::fmt;
+
+use crate::errors::Result;
+use crate::indicators::SimpleMovingAverage;
+use crate::{High, Low, Next, Period, Ready, Reset, Volume};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Ease of Movement (EMV).
+///
+/// Developed by Richard Arms, the ease of movement relates the change of the price to the
+/// volume. It is high when the price rises on low volume and low when it falls on low volume.
+///
+/// # Formula
+///
+/// Distance moved = (high<sub>t</sub> + low<sub>t</sub>) / 2 - (high<sub>t-1</sub> + low<sub>t-1</sub>) / 2
+///
+/// Box ratio = (volume / 100,000,000) / (high - low)
+///
+/// EMV = SMA(period) of (distance moved / box ratio)
+///
+/// Where _SMA_ is the [simple moving average](struct.SimpleMovingAverage.html). The first bar,
+/// bars without any volume and bars with high equal to low move 0.
+///
+/// # Parameters
+///
+/// * _period_ - smoothing period (integer greater than 0). Default is 14.
+///
+/// # Example
+///
+/// ```
+/// use ta::indicators::EaseOfMovement;
+/// use ta::{DataItem, Next};
+///
+/// let mut emv = EaseOfMovement::new(1).unwrap();
+///
+/// let di1 = DataItem::builder()
+///     .open(10.0).high(11.0).low(9.0).close(10.0).volume(100_000_000.0)
+///     .build(true).unwrap();
+/// let di2 = DataItem::builder()
+///     .open(11.0).high(12.0).low(10.0).close(11.0).volume(100_000_000.0)
+///     .build(true).unwrap();
+///
+/// assert_eq!(emv.next(&di1), 0.0);
+/// assert_eq!(emv.next(&di2), 2.0);
+/// ```
+///
+/// # Links
+///
+/// * [Ease of Movement, StockCharts](https://school.stockcharts.com/doku.php?id=technical_indicators:ease_of_movement_emv)
+///
+#[doc(alias = "EMV")]
+#[doc(alias = "EOM")]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone)]
+pub struct EaseOfMovement {
+    sma: SimpleMovingAverage,
+    prev_midpoint: Option<f64>,
+}
+
+const VOLUME_SCALE: f64 = 100_000_000.0;
+
+impl EaseOfMovement {
+    pub fn new(period: usize) -> Result<Self> {
+        Ok(Self {
+            sma: SimpleMovingAverage::new(period)?,
+            prev_midpoint: None,
+        })
+    }
+}
+
+impl Period for EaseOfMovement {
+    fn period(&self) -> usize {
+        self.sma.period()
+    }
+}
+
+impl<T: High + Low + Volume> Next<&T> for EaseOfMovement {
+    type Output = f64;
+
+    fn next(&mut self, input: &T) -> Self::Output {
+        let midpoint = (input.high() + input.low()) / 2.0;
+        let range = input.high() - input.low();
+        let volume = input.volume();
+
+        let emv = match self.prev_midpoint.replace(midpoint) {
+            Some(prev_midpoint) if volume > 0.0 => {
+                (midpoint - prev_midpoint) * range / (volume / VOLUME_SCALE)
+            }
+            _ => 0.0,
+        };
+
+        self.sma.next(emv)
+    }
+}
+
+impl Ready for EaseOfMovement {
+    fn bars_seen(&self) -> usize {
+        self.sma.bars_seen()
+    }
+
+    fn is_ready(&self) -> bool {
+        // the first input has no previous midpoint to move from
+        self.sma.bars_seen() > self.sma.period()
+    }
+}
+
+impl Reset for EaseOfMovement {
+    fn reset(&mut self) {
+        self.sma.reset();
+        self.prev_midpoint = None;
+    }
+}
+
+impl Default for EaseOfMovement {
+    fn default() -> Self {
+        Self::new(14).unwrap()
+    }
+}
+
+impl fmt::Display for EaseOfMovement {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "EMV({})", self.sma.period())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helper::*;
+
+    #[test]
+    fn test_new() {
+        assert!(EaseOfMovement::new(0).is_err());
+        assert!(EaseOfMovement::new(1).is_ok());
+    }
+
+    #[test]
+    fn test_next() {
+        let mut emv = EaseOfMovement::new(2).unwrap();
+
+        let data = [
+            // high, low, volume (millions), emv
+            (11.0, 9.0, 100.0, 0.0),
+            (12.0, 10.0, 100.0, 1.0),
+            (12.0, 11.0, 50.0, 1.5),
+            (11.0, 10.0, 200.0, 0.25),
+        ];
+
+        for (high, low, volume, expected) in data {
+            let value = emv.next(&Bar::new().high(high).low(low).volume(volume * 1_000_000.0));
+            assert_eq!(round(value), expected);
+        }
+    }
+
+    #[test]
+    fn test_next_rising() {
+        let mut emv = EaseOfMovement::new(3).unwrap();
+
+        // every bar moves up by 1, but it takes more and more volume
+        let mut values = vec![];
+        for i in 0..8 {
+            let low = 10.0 + i as f64;
+            let volume = 1_000_000.0 * (i + 1) as f64;
+            values.push(emv.next(&Bar::new().high(low + 2.0).low(low).volume(volume)));
+        }
+
+        assert!(values[1..].iter().all(|&value| value > 0.0));
+        // the rise gets harder, once the window is full the EMV falls
+        assert!(values[3..].windows(2).all(|pair| pair[1] < pair[0]));
+    }
+
+    #[test]
+    fn test_next_zero_range_and_volume() {
+        let mut emv = EaseOfMovement::new(1).unwrap();
+
+        emv.next(&Bar::new().high(11).low(9).volume(100.0));
+        // no volume
+        assert_eq!(emv.next(&Bar::new().high(13).low(11).volume(0.0)), 0.0);
+        // no range
+        assert_eq!(emv.next(&Bar::new().high(14).low(14).volume(100.0)), 0.0);
+        assert!(emv
+            .next(&Bar::new().high(15).low(13).volume(100.0))
+            .is_finite());
+    }
+
+    #[test]
+    fn test_reset() {
+        let mut emv = EaseOfMovement::new(1).unwrap();
+
+        emv.next(&Bar::new().high(11).low(9).volume(100_000_000.0));
+        emv.next(&Bar::new().high(12).low(10).volume(100_000_000.0));
+        emv.reset();
+
+        assert_eq!(
+            emv.next(&Bar::new().high(20).low(18).volume(100_000_000.0)),
+            0.0
+        );
+        assert_eq!(
+            emv.next(&Bar::new().high(21).low(19).volume(100_000_000.0)),
+            2.0
+        );
+    }
+
+    #[test]
+    fn test_ready() {
+        let mut emv = EaseOfMovement::new(3).unwrap();
+        let bar = Bar::new().high(10).low(8).close(9).volume(100.0);
+        for _ in 0..3 {
+            emv.next(&bar);
+        }
+        assert_eq!((emv.bars_seen(), emv.is_ready()), (3, false));
+        // the fourth input completes the third change
+        emv.next(&bar);
+        assert_eq!((emv.bars_seen(), emv.is_ready()), (4, true));
+
+        emv.reset();
+        assert_eq!((emv.bars_seen(), emv.is_ready()), (0, false));
+    }
+
+    #[test]
+    fn test_default() {
+        EaseOfMovement::default();
+    }
+
+    #[test]
+    fn test_display() {
+        let emv = EaseOfMovement::new(14).unwrap();
+        assert_eq!(format!("{}", emv), "EMV(14)");
+    }
+}
@@ -1,7 +1,7 @@
 use std::fmt;
 
 use crate::errors::{Result, TaError};
-use crate::traits::{Close, Next, Period, Reset};
+use crate::traits::{Close, Next, Period, Ready, Reset};
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
@@ -28,7 +28,6 @@ use serde::{Deserialize, Serialize};
 /// assert_eq!(er.next(18.0), 0.8);
 /// assert_eq!(er.next(19.0), 0.75);
 /// ```
-
 #[doc(alias = "ER")]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[derive(Debug, Clone)]
@@ -37,17 +36,20 @@ pub struct EfficiencyRatio {
     index: usize,
     count: usize,
     deque: Box<[f64]>,
+    #[cfg_attr(feature = "serde", serde(default))]
+    bars_seen: usize,
 }
 
 impl EfficiencyRatio {
     pub fn new(period: usize) -> Result<Self> {
         match period {
-            0 => Err(TaError::InvalidParameter),
+            0 => Err(TaError::invalid_parameter("period", period)),
             _ => Ok(Self {
                 period,
                 index: 0,
                 count: 0,
                 deque: vec![0.0; period].into_boxed_slice(),
+                bars_seen: 0,
             }),
         }
     }
@@ -63,6 +65,7 @@ impl Next<f64> for EfficiencyRatio {
     type Output = f64;
 
     fn next(&mut self, input: f64) -> f64 {
+        self.bars_seen = self.bars_seen.saturating_add(1);
         let first = if self.count >= self.period {
             self.deque[self.index]
         } else {
@@ -100,8 +103,20 @@ impl<T: Close> Next<&T> for EfficiencyRatio {
     }
 }
 
+impl Ready for EfficiencyRatio {
+    fn bars_seen(&self) -> usize {
+        self.bars_seen
+    }
+
+    fn is_ready(&self) -> bool {
+        // the direction is measured over _period_ changes
+        self.bars_seen > self.period
+    }
+}
+
 impl Reset for EfficiencyRatio {
     fn reset(&mut self) {
+        self.bars_seen = 0;
         self.index = 0;
         self.count = 0;
         for i in 0..self.period {
@@ -164,6 +179,21 @@ mod tests {
         assert_eq!(round(er.next(3.0)), 0.0);
     }
 
+    #[test]
+    fn test_ready() {
+        let mut er = EfficiencyRatio::new(3).unwrap();
+        for _ in 0..3 {
+            er.next(1.0);
+        }
+        assert_eq!((er.bars_seen(), er.is_ready()), (3, false));
+        // the fourth input is compared with the first
+        er.next(1.0);
+        assert_eq!((er.bars_seen(), er.is_ready()), (4, true));
+
+        er.reset();
+        assert_eq!((er.bars_seen(), er.is_ready()), (0, false));
+    }
+
     #[test]
     fn test_display() {
         let er = EfficiencyRatio::new(17).unwrap();
@@ -0,0 +1,217 @@
+use std::fmt;
+
+use crate::errors::Result;
+use crate::indicators::ExponentialMovingAverage;
+use crate::{Close, High, Low, Next, Period, Ready, Reset};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Elder Ray Index, also known as Bull and Bear Power.
+///
+/// Developed by Alexander Elder, it measures how far the buyers push the high above and the
+/// sellers push the low below the consensus of value, an EMA of the close.
+///
+/// # Formula
+///
+/// Bull Power = high - EMA(period) of close
+///
+/// Bear Power = low - EMA(period) of close
+///
+/// Where _EMA_ is the [exponential moving average](struct.ExponentialMovingAverage.html).
+///
+/// # Parameters
+///
+/// * _period_ - period of the EMA (integer greater than 0). Default is 13.
+///
+/// # Example
+///
+/// ```
+/// use ta::indicators::ElderRay;
+/// use ta::{DataItem, Next};
+///
+/// let mut elder_ray = ElderRay::new(13).unwrap();
+///
+/// let di = DataItem::builder()
+///     .open(10.0).high(12.0).low(9.0).close(11.0).volume(1000.0)
+///     .build(true).unwrap();
+///
+/// let out = elder_ray.next(&di);
+/// assert_eq!(out.bull_power, 1.0);
+/// assert_eq!(out.bear_power, -2.0);
+/// ```
+///
+/// # Links
+///
+/// * [Elder-Ray Index, StockCharts](https://school.stockcharts.com/doku.php?id=technical_indicators:elder_ray_index)
+///
+#[doc(alias = "ERI")]
+#[doc(alias = "BullPower")]
+#[doc(alias = "BearPower")]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone)]
+pub struct ElderRay {
+    ema: ExponentialMovingAverage,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ElderRayOutput {
+    pub bull_power: f64,
+    pub bear_power: f64,
+}
+
+impl From<ElderRayOutput> for (f64, f64) {
+    fn from(er: ElderRayOutput) -> Self {
+        (er.bull_power, er.bear_power)
+    }
+}
+
+impl ElderRay {
+    pub fn new(period: usize) -> Result<Self> {
+        Ok(Self {
+            ema: ExponentialMovingAverage::new(period)?,
+        })
+    }
+}
+
+impl Period for ElderRay {
+    fn period(&self) -> usize {
+        self.ema.period()
+    }
+}
+
+impl<T: High + Low + Close> Next<&T> for ElderRay {
+    type Output = ElderRayOutput;
+
+    fn next(&mut self, input: &T) -> Self::Output {
+        let ema = self.ema.next(input.close());
+
+        ElderRayOutput {
+            bull_power: input.high() - ema,
+            bear_power: input.low() - ema,
+        }
+    }
+}
+
+impl Ready for ElderRay {
+    fn bars_seen(&self) -> usize {
+        self.ema.bars_seen()
+    }
+
+    fn is_ready(&self) -> bool {
+        self.ema.is_ready()
+    }
+}
+
+impl Reset for ElderRay {
+    fn reset(&mut self) {
+        self.ema.reset();
+    }
+}
+
+impl Default for ElderRay {
+    fn default() -> Self {
+        Self::new(13).unwrap()
+    }
+}
+
+impl fmt::Display for ElderRay {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "ERI({})", self.ema.period())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helper::*;
+
+    #[test]
+    fn test_new() {
+        assert!(ElderRay::new(0).is_err());
+        assert!(ElderRay::new(1).is_ok());
+    }
+
+    #[test]
+    fn test_next() {
+        let mut elder_ray = ElderRay::new(3).unwrap();
+
+        let data = [
+            // high, low, close, bull power, bear power
+            (12.0, 9.0, 11.0, 1.0, -2.0),
+            (13.0, 10.0, 12.0, 1.5, -1.5),
+            (12.5, 10.5, 11.0, 1.25, -0.75),
+            (11.0, 9.0, 9.5, 0.625, -1.375),
+        ];
+
+        for (high, low, close, bull_power, bear_power) in data {
+            let out = elder_ray.next(&Bar::new().high(high).low(low).close(close));
+            assert_eq!(round(out.bull_power), bull_power);
+            assert_eq!(round(out.bear_power), bear_power);
+        }
+    }
+
+    #[test]
+    fn test_next_trend() {
+        let mut elder_ray = ElderRay::new(5).unwrap();
+        let bar = |close: f64| Bar::new().high(close + 1.0).low(close - 1.0).close(close);
+
+        // a downtrend, the lows stay below the EMA
+        for i in 0..10 {
+            let out = elder_ray.next(&bar(100.0 - 2.0 * i as f64));
+            assert!(out.bear_power < 0.0);
+        }
+
+        // the price recovers, first the highs and then the lows climb above the lagging EMA
+        let outputs: Vec<ElderRayOutput> = (1..=10)
+            .map(|i| elder_ray.next(&bar(82.0 + 3.0 * i as f64)))
+            .collect();
+        let bull_positive = outputs.iter().position(|out| out.bull_power > 0.0);
+        let bear_positive = outputs.iter().position(|out| out.bear_power > 0.0);
+
+        assert!(outputs[0].bear_power < 0.0);
+        assert!(bull_positive.unwrap() < bear_positive.unwrap());
+        assert!(outputs[bull_positive.unwrap()..]
+            .iter()
+            .all(|out| out.bull_power > 0.0));
+        assert!(outputs[9].bear_power > 0.0);
+    }
+
+    #[test]
+    fn test_reset() {
+        let mut elder_ray = ElderRay::new(3).unwrap();
+
+        elder_ray.next(&Bar::new().high(12).low(9).close(11));
+        elder_ray.next(&Bar::new().high(20).low(18).close(19));
+        elder_ray.reset();
+
+        let out = elder_ray.next(&Bar::new().high(12).low(9).close(11));
+        assert_eq!(out.bull_power, 1.0);
+        assert_eq!(out.bear_power, -2.0);
+    }
+
+    #[test]
+    fn test_ready() {
+        let mut er = ElderRay::new(3).unwrap();
+        let bar = Bar::new().high(10).low(8).close(9);
+        for _ in 0..2 {
+            er.next(&bar);
+        }
+        assert_eq!((er.bars_seen(), er.is_ready()), (2, false));
+        er.next(&bar);
+        assert_eq!((er.bars_seen(), er.is_ready()), (3, true));
+
+        er.reset();
+        assert_eq!((er.bars_seen(), er.is_ready()), (0, false));
+    }
+
+    #[test]
+    fn test_default() {
+        ElderRay::default();
+    }
+
+    #[test]
+    fn test_display() {
+        let elder_ray = ElderRay::new(13).unwrap();
+        assert_eq!(format!("{}", elder_ray), "ERI(13)");
+    }
+}
@@ -1,10 +1,23 @@
 use std::fmt;
 
+use crate::adapters::Sourced;
 use crate::errors::{Result, TaError};
-use crate::{Close, Next, Period, Reset};
+use crate::{Close, Current, Float, Next, Period, PriceSource, Ready, Reset};
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
+/// How an [ExponentialMovingAverage] gets its first value.
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum EmaSeed {
+    /// The first input is the first value.
+    #[default]
+    FirstValue,
+    /// The simple moving average of the first _period_ inputs is the first value. Until then
+    /// the average of the inputs seen so far is returned.
+    SmaBootstrap,
+}
+
 /// An exponential moving average (EMA), also known as an exponentially weighted moving average
 /// (EWMA).
 ///
@@ -30,83 +43,174 @@ use serde::{Deserialize, Serialize};
 ///
 /// * _period_ - number of periods
 ///
+/// By default the EMA is seeded with the first input, which gives the first value a large
+/// weight for a while. [with_seed](Self::with_seed) with [EmaSeed::SmaBootstrap] seeds it
+/// with the SMA of the first _period_ inputs instead, the common convention of charting
+/// platforms. During the bootstrap the average of the inputs so far is returned, by
+/// [next](Next::next) and [current](Current::current) alike, [is_ready](Ready::is_ready)
+/// tells when the seed is complete.
+///
 /// # Parameters
 ///
 /// * _period_ - number of periods (integer greater than 0)
 ///
+/// Fed with bars it consumes the close, [with_source](Self::with_source) wraps it in a
+/// [Sourced] to select another [PriceSource].
+///
+/// The average computes with `f64` by default, [new_with_precision](Self::new_with_precision)
+/// and [with_precision](Self::with_precision) select `f32`, see [Float].
+///
 /// # Example
 ///
 /// ```
-/// use ta::indicators::ExponentialMovingAverage;
-/// use ta::Next;
+/// use ta::indicators::{EmaSeed, ExponentialMovingAverage};
+/// use ta::{Current, Next};
 ///
 /// let mut ema = ExponentialMovingAverage::new(3).unwrap();
 /// assert_eq!(ema.next(2.0), 2.0);
 /// assert_eq!(ema.next(5.0), 3.5);
 /// assert_eq!(ema.next(1.0), 2.25);
 /// assert_eq!(ema.next(6.25), 4.25);
+///
+/// let mut ema = ExponentialMovingAverage::new(3).unwrap().with_seed(EmaSeed::SmaBootstrap);
+/// assert_eq!(ema.next(2.0), 2.0);
+/// assert_eq!(ema.next(5.0), 3.5);
+/// assert_eq!(ema.current(), Some(3.5));
+/// // seeded with the SMA of the first 3 inputs
+/// assert_eq!(ema.next(8.0), 5.0);
+/// assert_eq!(ema.next(7.0), 6.0);
 /// ```
 ///
 /// # Links
 ///
 /// * [Exponential moving average, Wikipedia](https://en.wikipedia.org/wiki/Moving_average#Exponential_moving_average)
 ///
-
 #[doc(alias = "EMA")]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[derive(Debug, Clone)]
-pub struct ExponentialMovingAverage {
+pub struct ExponentialMovingAverage<F = f64> {
     period: usize,
-    k: f64,
-    current: f64,
+    k: F,
+    current: F,
     is_new: bool,
+    #[cfg_attr(feature = "serde", serde(default))]
+    bars_seen: usize,
+    #[cfg_attr(feature = "serde", serde(default))]
+    seed: EmaSeed,
 }
 
 impl ExponentialMovingAverage {
     pub fn new(period: usize) -> Result<Self> {
+        Self::new_with_precision(period)
+    }
+}
+
+impl<F: Float> ExponentialMovingAverage<F> {
+    /// Like [new](ExponentialMovingAverage::new), computing with the float type of the
+    /// indicator, e.g. `ExponentialMovingAverage::<f32>::new_with_precision(9)`.
+    pub fn new_with_precision(period: usize) -> Result<Self> {
         match period {
-            0 => Err(TaError::InvalidParameter),
+            0 => Err(TaError::invalid_parameter("period", period)),
             _ => Ok(Self {
                 period,
-                k: 2.0 / (period + 1) as f64,
-                current: 0.0,
+                k: F::from_f64(2.0 / (period + 1) as f64),
+                current: F::zero(),
                 is_new: true,
+                bars_seen: 0,
+                seed: EmaSeed::default(),
             }),
         }
     }
+
+    /// Converts the average, including its current state, to compute with another floating
+    /// point type.
+    pub fn with_precision<G: Float>(self) -> ExponentialMovingAverage<G> {
+        ExponentialMovingAverage {
+            period: self.period,
+            k: G::from_f64(self.k.to_f64()),
+            current: G::from_f64(self.current.to_f64()),
+            is_new: self.is_new,
+            bars_seen: self.bars_seen,
+            seed: self.seed,
+        }
+    }
+
+    pub fn with_seed(mut self, seed: EmaSeed) -> Self {
+        self.seed = seed;
+        self
+    }
+
+    pub fn seed(&self) -> EmaSeed {
+        self.seed
+    }
+
+    /// Feeds the moving average with the given price of every bar.
+    pub fn with_source(self, source: PriceSource) -> Sourced<Self> {
+        Sourced::new(self, source)
+    }
 }
 
-impl Period for ExponentialMovingAverage {
+impl<F> Period for ExponentialMovingAverage<F> {
     fn period(&self) -> usize {
         self.period
     }
 }
 
-impl Next<f64> for ExponentialMovingAverage {
-    type Output = f64;
+impl<F: Float> Next<f64> for ExponentialMovingAverage<F> {
+    type Output = F;
 
     fn next(&mut self, input: f64) -> Self::Output {
-        if self.is_new {
-            self.is_new = false;
-            self.current = input;
-        } else {
-            self.current = self.k * input + (1.0 - self.k) * self.current;
+        let input = F::from_f64(input);
+        self.bars_seen = self.bars_seen.saturating_add(1);
+        match self.seed {
+            EmaSeed::FirstValue if self.is_new => {
+                self.is_new = false;
+                self.current = input;
+            }
+            EmaSeed::SmaBootstrap if self.bars_seen <= self.period => {
+                // the running mean of the inputs becomes the SMA seed
+                self.current += (input - self.current) / F::from_f64(self.bars_seen as f64);
+                self.is_new = false;
+            }
+            _ => self.current = self.k * input + (F::from_f64(1.0) - self.k) * self.current,
         }
         self.current
     }
 }
 
-impl<T: Close> Next<&T> for ExponentialMovingAverage {
-    type Output = f64;
+impl<F: Float, T: Close> Next<&T> for ExponentialMovingAverage<F> {
+    type Output = F;
 
     fn next(&mut self, input: &T) -> Self::Output {
         self.next(input.close())
     }
 }
 
-impl Reset for ExponentialMovingAverage {
+impl<F: Float> Current for ExponentialMovingAverage<F> {
+    type Output = F;
+
+    fn current(&self) -> Option<Self::Output> {
+        match self.is_new {
+            true => None,
+            false => Some(self.current),
+        }
+    }
+}
+
+impl<F> Ready for ExponentialMovingAverage<F> {
+    fn bars_seen(&self) -> usize {
+        self.bars_seen
+    }
+
+    fn is_ready(&self) -> bool {
+        self.bars_seen >= self.period
+    }
+}
+
+impl<F: Float> Reset for ExponentialMovingAverage<F> {
     fn reset(&mut self) {
-        self.current = 0.0;
+        self.bars_seen = 0;
+        self.current = F::zero();
         self.is_new = true;
     }
 }
@@ -117,7 +221,7 @@ impl Default for ExponentialMovingAverage {
     }
 }
 
-impl fmt::Display for ExponentialMovingAverage {
+impl<F> fmt::Display for ExponentialMovingAverage<F> {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         write!(f, "EMA({})", self.period)
     }
@@ -127,6 +231,7 @@ impl fmt::Display for ExponentialMovingAverage {
 mod tests {
     use super::*;
     use crate::test_helper::*;
+    use assert_approx_eq::assert_approx_eq;
 
     test_indicator!(ExponentialMovingAverage);
 
@@ -152,6 +257,68 @@ mod tests {
         assert_eq!(ema.next(&bar2), 3.5);
     }
 
+    #[test]
+    fn test_next_sma_bootstrap() {
+        let mut ema = ExponentialMovingAverage::new(3)
+            .unwrap()
+            .with_seed(EmaSeed::SmaBootstrap);
+
+        assert_eq!(ema.next(2.0), 2.0);
+        assert_eq!(ema.next(5.0), 3.5);
+        assert_eq!(ema.current(), Some(3.5));
+        assert!(!ema.is_ready());
+        assert_eq!(ema.next(8.0), 5.0);
+        assert!(ema.is_ready());
+        assert_eq!(ema.current(), Some(5.0));
+        assert_eq!(ema.next(7.0), 6.0);
+
+        ema.reset();
+        assert_eq!(ema.current(), None);
+        assert_eq!(ema.next(4.0), 4.0);
+        assert_eq!(ema.current(), Some(4.0));
+    }
+
+    #[test]
+    fn test_next_seeds_converge() {
+        let mut first_value = ExponentialMovingAverage::new(3).unwrap();
+        let mut bootstrap = ExponentialMovingAverage::new(3)
+            .unwrap()
+            .with_seed(EmaSeed::SmaBootstrap);
+        assert_eq!(first_value.seed(), EmaSeed::FirstValue);
+
+        for input in [2.0, 5.0, 8.0] {
+            first_value.next(input);
+            bootstrap.next(input);
+        }
+        // the first value seed is biased towards the first input
+        assert_eq!(first_value.current(), Some(5.75));
+        assert_eq!(bootstrap.current(), Some(5.0));
+
+        // the gap shrinks by (1 - k) with every input
+        let mut gap = 0.75;
+        for input in [7.0, 6.0, 9.0, 4.0, 3.0, 8.0, 10.0, 5.0] {
+            gap *= 0.5;
+            assert_eq!(
+                round(first_value.next(input) - bootstrap.next(input)),
+                round(gap)
+            );
+        }
+    }
+
+    #[test]
+    fn test_next_with_source() {
+        let mut ema = ExponentialMovingAverage::new(3)
+            .unwrap()
+            .with_source(PriceSource::High);
+        assert_eq!(ema.source(), PriceSource::High);
+
+        assert_eq!(ema.next(&Bar::new().high(12).low(8).close(11)), 12.0);
+        assert_eq!(
+            round(ema.next(&Bar::new().high(14).low(10).close(13))),
+            13.0
+        );
+    }
+
     #[test]
     fn test_reset() {
         let mut ema = ExponentialMovingAverage::new(5).unwrap();
@@ -166,6 +333,53 @@ mod tests {
         assert_eq!(ema.next(4.0), 4.0);
     }
 
+    #[test]
+    fn test_current() {
+        let mut ema = ExponentialMovingAverage::new(3).unwrap();
+        assert_eq!(ema.current(), None);
+
+        for input in [2.0, 5.0, 1.0, 6.25] {
+            let value = ema.next(input);
+            assert_eq!(ema.current(), Some(value));
+        }
+
+        ema.reset();
+        assert_eq!(ema.current(), None);
+    }
+
+    #[test]
+    fn test_ready() {
+        let mut ema = ExponentialMovingAverage::new(3).unwrap();
+        assert_eq!((ema.bars_seen(), ema.is_ready()), (0, false));
+
+        ema.next(1.0);
+        ema.next(2.0);
+        assert_eq!((ema.bars_seen(), ema.is_ready()), (2, false));
+        // the third input fills the period
+        ema.next(3.0);
+        assert_eq!((ema.bars_seen(), ema.is_ready()), (3, true));
+
+        ema.reset();
+        assert_eq!((ema.bars_seen(), ema.is_ready()), (0, false));
+    }
+
+    #[test]
+    fn test_next_f32() {
+        let mut ema = ExponentialMovingAverage::new(3).unwrap();
+        let mut ema_f32 = ExponentialMovingAverage::<f32>::new_with_precision(3).unwrap();
+
+        for input in [2.0, 5.0, 1.0, 6.25, 4.0, 7.5] {
+            let expected = ema.next(input);
+            let actual: f32 = ema_f32.next(input);
+            assert_approx_eq!(actual as f64, expected, 1e-5);
+        }
+
+        // the state is converted along with the average
+        let mut converted = ema.clone().with_precision::<f32>();
+        assert_eq!(converted.current(), Some(ema.current().unwrap() as f32));
+        assert_approx_eq!(converted.next(3.0) as f64, ema.next(3.0), 1e-5);
+    }
+
     #[test]
     fn test_default() {
         ExponentialMovingAverage::default();
@@ -1,8 +1,8 @@
 use std::fmt;
 
 use crate::errors::Result;
-use crate::indicators::{Maximum, Minimum};
-use crate::{Close, High, Low, Next, Period, Reset};
+use crate::window::MonotonicWindow;
+use crate::{Close, High, Low, Next, Period, Ready, Reset};
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
@@ -44,16 +44,17 @@ use serde::{Deserialize, Serialize};
 #[derive(Debug, Clone)]
 pub struct FastStochastic {
     period: usize,
-    minimum: Minimum,
-    maximum: Maximum,
+    window: MonotonicWindow,
+    #[cfg_attr(feature = "serde", serde(default))]
+    bars_seen: usize,
 }
 
 impl FastStochastic {
     pub fn new(period: usize) -> Result<Self> {
         Ok(Self {
             period,
-            minimum: Minimum::new(period)?,
-            maximum: Maximum::new(period)?,
+            window: MonotonicWindow::new(period)?,
+            bars_seen: 0,
         })
     }
 }
@@ -68,8 +69,8 @@ impl Next<f64> for FastStochastic {
     type Output = f64;
 
     fn next(&mut self, input: f64) -> Self::Output {
-        let min = self.minimum.next(input);
-        let max = self.maximum.next(input);
+        self.bars_seen = self.bars_seen.saturating_add(1);
+        let (min, max) = self.window.push(input);
 
         if min == max {
             // When only 1 input was given, than min and max are the same,
@@ -85,8 +86,8 @@ impl<T: High + Low + Close> Next<&T> for FastStochastic {
     type Output = f64;
 
     fn next(&mut self, input: &T) -> Self::Output {
-        let highest = self.maximum.next(input.high());
-        let lowest = self.minimum.next(input.low());
+        self.bars_seen = self.bars_seen.saturating_add(1);
+        let (lowest, highest) = self.window.push_range(input.low(), input.high());
         let close = input.close();
 
         if highest == lowest {
@@ -98,10 +99,20 @@ impl<T: High + Low + Close> Next<&T> for FastStochastic {
     }
 }
 
+impl Ready for FastStochastic {
+    fn bars_seen(&self) -> usize {
+        self.bars_seen
+    }
+
+    fn is_ready(&self) -> bool {
+        self.bars_seen >= self.period
+    }
+}
+
 impl Reset for FastStochastic {
     fn reset(&mut self) {
-        self.minimum.reset();
-        self.maximum.reset();
+        self.bars_seen = 0;
+        self.window.reset();
     }
 }
 
@@ -174,6 +185,21 @@ mod tests {
         assert_eq!(indicator.next(12.5), 25.0);
     }
 
+    #[test]
+    fn test_ready() {
+        let mut stoch = FastStochastic::new(3).unwrap();
+        let bar = Bar::new().high(10).low(8).close(9);
+        for _ in 0..2 {
+            stoch.next(&bar);
+        }
+        assert_eq!((stoch.bars_seen(), stoch.is_ready()), (2, false));
+        stoch.next(&bar);
+        assert_eq!((stoch.bars_seen(), stoch.is_ready()), (3, true));
+
+        stoch.reset();
+        assert_eq!((stoch.bars_seen(), stoch.is_ready()), (0, false));
+    }
+
     #[test]
     fn test_default() {
         FastStochastic::default();
@@ -0,0 +1,276 @@
+use std::fmt;
+
+use crate::errors::Result;
+use crate::indicators::{Maximum, Minimum};
+use crate::{High, Low, Next, Period, Ready, Reset};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Fisher Transform.
+///
+/// Developed by John Ehlers, the Fisher transform converts the median price into a nearly
+/// Gaussian distribution, which makes turning points easier to spot. The trigger line is the
+/// Fisher value of the previous bar.
+///
+/// # Formula
+///
+/// X<sub>t</sub> = 0.66 * ((median price - lowest) / (highest - lowest) - 0.5) + 0.67 * X<sub>t-1</sub>
+///
+/// Fisher<sub>t</sub> = 0.5 * ln((1 + X<sub>t</sub>) / (1 - X<sub>t</sub>)) + 0.5 * Fisher<sub>t-1</sub>
+///
+/// Trigger<sub>t</sub> = Fisher<sub>t-1</sub>
+///
+/// Where:
+///
+/// * _median price_ = (high + low) / 2
+/// * _highest_, _lowest_ - highest and lowest median price of the last _period_ bars
+///
+/// _X_ is clamped to [-0.999, 0.999] so the logarithm never blows up when the price stays
+/// at the window extreme. When the highest and the lowest median price are equal the
+/// normalized price is 0.
+///
+/// # Parameters
+///
+/// * _period_ - number of bars (integer greater than 0). Default is 9.
+///
+/// # Example
+///
+/// ```
+/// use ta::indicators::FisherTransform;
+/// use ta::{DataItem, Next};
+///
+/// let mut fisher = FisherTransform::new(3).unwrap();
+///
+/// let bar = |high, low| {
+///     DataItem::builder()
+///         .open(low).high(high).low(low).close(high).volume(1000.0)
+///         .build(true).unwrap()
+/// };
+///
+/// let out = fisher.next(&bar(10.5, 9.5));
+/// assert_eq!(out.fisher, 0.0);
+/// assert_eq!(out.trigger, 0.0);
+///
+/// let out = fisher.next(&bar(11.5, 10.5));
+/// assert!(out.fisher > 0.0);
+/// assert_eq!(out.trigger, 0.0);
+/// ```
+///
+/// # Links
+///
+/// * [Fisher Transform, StockCharts](https://school.stockcharts.com/doku.php?id=technical_indicators:fisher_transform)
+///
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone)]
+pub struct FisherTransform {
+    period: usize,
+    maximum: Maximum,
+    minimum: Minimum,
+    value: f64,
+    fisher: f64,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct FisherTransformOutput {
+    pub fisher: f64,
+    pub trigger: f64,
+}
+
+impl From<FisherTransformOutput> for (f64, f64) {
+    fn from(fo: FisherTransformOutput) -> Self {
+        (fo.fisher, fo.trigger)
+    }
+}
+
+const MAX_VALUE: f64 = 0.999;
+
+impl FisherTransform {
+    pub fn new(period: usize) -> Result<Self> {
+        Ok(Self {
+            period,
+            maximum: Maximum::new(period)?,
+            minimum: Minimum::new(period)?,
+            value: 0.0,
+            fisher: 0.0,
+        })
+    }
+}
+
+impl Period for FisherTransform {
+    fn period(&self) -> usize {
+        self.period
+    }
+}
+
+impl<T: High + Low> Next<&T> for FisherTransform {
+    type Output = FisherTransformOutput;
+
+    fn next(&mut self, input: &T) -> Self::Output {
+        let median_price = (input.high() + input.low()) / 2.0;
+        let highest = self.maximum.next(median_price);
+        let lowest = self.minimum.next(median_price);
+
+        let normalized = if highest == lowest {
+            0.0
+        } else {
+            (median_price - lowest) / (highest - lowest) - 0.5
+        };
+
+        self.value = (0.66 * normalized + 0.67 * self.value).clamp(-MAX_VALUE, MAX_VALUE);
+
+        let trigger = self.fisher;
+        self.fisher = 0.5 * ((1.0 + self.value) / (1.0 - self.value)).ln() + 0.5 * trigger;
+
+        FisherTransformOutput {
+            fisher: self.fisher,
+            trigger,
+        }
+    }
+}
+
+impl Ready for FisherTransform {
+    fn bars_seen(&self) -> usize {
+        self.maximum.bars_seen()
+    }
+
+    fn is_ready(&self) -> bool {
+        self.maximum.is_ready() && self.minimum.is_ready()
+    }
+}
+
+impl Reset for FisherTransform {
+    fn reset(&mut self) {
+        self.maximum.reset();
+        self.minimum.reset();
+        self.value = 0.0;
+        self.fisher = 0.0;
+    }
+}
+
+impl Default for FisherTransform {
+    fn default() -> Self {
+        Self::new(9).unwrap()
+    }
+}
+
+impl fmt::Display for FisherTransform {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "FISHER({})", self.period)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helper::*;
+
+    #[test]
+    fn test_new() {
+        assert!(FisherTransform::new(0).is_err());
+        assert!(FisherTransform::new(1).is_ok());
+    }
+
+    #[test]
+    fn test_next() {
+        let mut fisher = FisherTransform::new(3).unwrap();
+
+        let data = [
+            // median price, fisher, trigger
+            (10.0, 0.0, 0.0),
+            (11.0, 0.343, 0.0),
+            (12.0, 0.791, 0.343),
+            (11.0, 0.435, 0.791),
+            (10.0, -0.096, 0.435),
+            (9.0, -0.643, -0.096),
+            (10.0, -0.349, -0.643),
+            (12.0, 0.148, -0.349),
+            (13.0, 0.676, 0.148),
+            (14.0, 1.188, 0.676),
+        ];
+
+        for (median_price, expected_fisher, trigger) in data {
+            let out = fisher.next(&Bar::new().high(median_price + 0.5).low(median_price - 0.5));
+            assert_eq!(round(out.fisher), expected_fisher);
+            assert_eq!(round(out.trigger), trigger);
+        }
+    }
+
+    #[test]
+    fn test_next_clamped() {
+        let mut fisher = FisherTransform::new(5).unwrap();
+        // 0.5 * ln((1 + 0.999) / (1 - 0.999)) / (1 - 0.5)
+        let limit = 1999_f64.ln();
+
+        // every bar is the window high, the normalized price approaches 1
+        for i in 0..100 {
+            let out = fisher.next(&Bar::new().high(10.5 + i as f64).low(9.5 + i as f64));
+            assert!(out.fisher.is_finite());
+            assert!(out.fisher <= limit + 1e-9);
+        }
+        assert_eq!(
+            round(fisher.next(&Bar::new().high(110.5).low(109.5)).fisher),
+            round(limit)
+        );
+
+        // and the window low, approaching -1
+        for i in 0..100 {
+            let out = fisher.next(&Bar::new().high(-9.5 - i as f64).low(-10.5 - i as f64));
+            assert!(out.fisher.is_finite());
+            assert!(out.fisher >= -limit - 1e-9);
+        }
+        assert_eq!(
+            round(fisher.next(&Bar::new().high(-109.5).low(-110.5)).fisher),
+            round(-limit)
+        );
+    }
+
+    #[test]
+    fn test_next_flat() {
+        let mut fisher = FisherTransform::new(3).unwrap();
+        for _ in 0..5 {
+            let out = fisher.next(&Bar::new().high(10.5).low(9.5));
+            assert_eq!(out.fisher, 0.0);
+            assert_eq!(out.trigger, 0.0);
+        }
+    }
+
+    #[test]
+    fn test_reset() {
+        let mut fisher = FisherTransform::new(3).unwrap();
+
+        fisher.next(&Bar::new().high(10.5).low(9.5));
+        fisher.next(&Bar::new().high(15.5).low(14.5));
+        fisher.reset();
+
+        assert_eq!(fisher.next(&Bar::new().high(10.5).low(9.5)).fisher, 0.0);
+        let out = fisher.next(&Bar::new().high(11.5).low(10.5));
+        assert_eq!(round(out.fisher), 0.343);
+        assert_eq!(out.trigger, 0.0);
+    }
+
+    #[test]
+    fn test_ready() {
+        let mut fisher = FisherTransform::new(3).unwrap();
+        let bar = Bar::new().high(10).low(8).close(9);
+        for _ in 0..2 {
+            fisher.next(&bar);
+        }
+        assert_eq!((fisher.bars_seen(), fisher.is_ready()), (2, false));
+        fisher.next(&bar);
+        assert_eq!((fisher.bars_seen(), fisher.is_ready()), (3, true));
+
+        fisher.reset();
+        assert_eq!((fisher.bars_seen(), fisher.is_ready()), (0, false));
+    }
+
+    #[test]
+    fn test_default() {
+        FisherTransform::default();
+    }
+
+    #[test]
+    fn test_display() {
+        let fisher = FisherTransform::new(9).unwrap();
+        assert_eq!(format!("{}", fisher), "FISHER(9)");
+    }
+}
@@ -0,0 +1,198 @@
+use std::fmt;
+
+use crate::errors::Result;
+use crate::indicators::ExponentialMovingAverage;
+use crate::{Close, Next, Period, Ready, Reset, Volume};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Force Index (FI).
+///
+/// Developed by Alexander Elder, the force index combines the direction and the extent of a
+/// price change with the volume behind it.
+///
+/// # Formula
+///
+/// FI = EMA(period) of ((close<sub>t</sub> - close<sub>t-1</sub>) * volume<sub>t</sub>)
+///
+/// Where _EMA_ is the [exponential moving average](struct.ExponentialMovingAverage.html).
+/// The first bar has no previous close, its force is 0.
+///
+/// # Parameters
+///
+/// * _period_ - smoothing period (integer greater than 0). Default is 13.
+///
+/// # Example
+///
+/// ```
+/// use ta::indicators::ForceIndex;
+/// use ta::{DataItem, Next};
+///
+/// let mut fi = ForceIndex::new(1).unwrap();
+///
+/// let di1 = DataItem::builder()
+///     .open(10.0).high(10.0).low(10.0).close(10.0).volume(1000.0)
+///     .build(true).unwrap();
+/// let di2 = DataItem::builder()
+///     .open(10.0).high(11.0).low(10.0).close(11.0).volume(2000.0)
+///     .build(true).unwrap();
+///
+/// assert_eq!(fi.next(&di1), 0.0);
+/// assert_eq!(fi.next(&di2), 2000.0);
+/// ```
+///
+/// # Links
+///
+/// * [Force Index, StockCharts](https://school.stockcharts.com/doku.php?id=technical_indicators:force_index)
+///
+#[doc(alias = "FI")]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone)]
+pub struct ForceIndex {
+    ema: ExponentialMovingAverage,
+    prev_close: Option<f64>,
+}
+
+impl ForceIndex {
+    pub fn new(period: usize) -> Result<Self> {
+        Ok(Self {
+            ema: ExponentialMovingAverage::new(period)?,
+            prev_close: None,
+        })
+    }
+}
+
+impl Period for ForceIndex {
+    fn period(&self) -> usize {
+        self.ema.period()
+    }
+}
+
+impl<T: Close + Volume> Next<&T> for ForceIndex {
+    type Output = f64;
+
+    fn next(&mut self, input: &T) -> Self::Output {
+        let close = input.close();
+        let force = match self.prev_close.replace(close) {
+            Some(prev_close) => (close - prev_close) * input.volume(),
+            None => 0.0,
+        };
+
+        self.ema.next(force)
+    }
+}
+
+impl Ready for ForceIndex {
+    fn bars_seen(&self) -> usize {
+        self.ema.bars_seen()
+    }
+
+    fn is_ready(&self) -> bool {
+        // the first input has no previous close to move from
+        self.ema.bars_seen() > self.ema.period()
+    }
+}
+
+impl Reset for ForceIndex {
+    fn reset(&mut self) {
+        self.ema.reset();
+        self.prev_close = None;
+    }
+}
+
+impl Default for ForceIndex {
+    fn default() -> Self {
+        Self::new(13).unwrap()
+    }
+}
+
+impl fmt::Display for ForceIndex {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "FI({})", self.ema.period())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helper::*;
+
+    #[test]
+    fn test_new() {
+        assert!(ForceIndex::new(0).is_err());
+        assert!(ForceIndex::new(1).is_ok());
+    }
+
+    #[test]
+    fn test_next() {
+        let mut fi = ForceIndex::new(3).unwrap();
+
+        let data = [
+            // close, volume, force index
+            (10.0, 1000.0, 0.0),
+            (11.0, 2000.0, 1000.0),
+            (10.5, 1000.0, 250.0),
+            (10.5, 3000.0, 125.0),
+            (12.0, 2000.0, 1562.5),
+        ];
+
+        for (close, volume, expected) in data {
+            assert_eq!(fi.next(&Bar::new().close(close).volume(volume)), expected);
+        }
+    }
+
+    #[test]
+    fn test_next_volume_spike() {
+        let mut fi = ForceIndex::new(3).unwrap();
+
+        let mut value = 0.0;
+        for i in 0..10 {
+            value = fi.next(&Bar::new().close(10.0 + 0.1 * i as f64).volume(1000.0));
+        }
+        // a small steady rise on normal volume
+        assert!(value > 0.0 && value < 150.0);
+
+        // a breakout on ten times the volume
+        let spike = fi.next(&Bar::new().close(12).volume(10_000.0));
+        assert!(spike > 50.0 * value);
+    }
+
+    #[test]
+    fn test_reset() {
+        let mut fi = ForceIndex::new(3).unwrap();
+
+        fi.next(&Bar::new().close(10).volume(1000.0));
+        fi.next(&Bar::new().close(11).volume(2000.0));
+        fi.reset();
+
+        assert_eq!(fi.next(&Bar::new().close(20).volume(1000.0)), 0.0);
+        assert_eq!(fi.next(&Bar::new().close(21).volume(2000.0)), 1000.0);
+    }
+
+    #[test]
+    fn test_ready() {
+        let mut fi = ForceIndex::new(3).unwrap();
+        let bar = Bar::new().high(10).low(8).close(9).volume(100.0);
+        for _ in 0..3 {
+            fi.next(&bar);
+        }
+        assert_eq!((fi.bars_seen(), fi.is_ready()), (3, false));
+        // the fourth input completes the third change
+        fi.next(&bar);
+        assert_eq!((fi.bars_seen(), fi.is_ready()), (4, true));
+
+        fi.reset();
+        assert_eq!((fi.bars_seen(), fi.is_ready()), (0, false));
+    }
+
+    #[test]
+    fn test_default() {
+        ForceIndex::default();
+    }
+
+    #[test]
+    fn test_display() {
+        let fi = ForceIndex::new(13).unwrap();
+        assert_eq!(format!("{}", fi), "FI(13)");
+    }
+}
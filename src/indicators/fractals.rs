@@ -0,0 +1,375 @@
+use std::collections::VecDeque;
+use std::fmt;
+
+use crate::errors::{Result, TaError};
+use crate::{High, Low, Next, Period, Ready, Reset};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Williams Fractals.
+///
+/// Developed by Bill Williams, a fractal marks a local turning point: an up fractal is a bar
+/// with a higher high than the bars around it, a down fractal a bar with a lower low. They are
+/// often used together with the [Alligator](struct.Alligator.html), a fractal outside of the
+/// alligator's mouth signals a breakout.
+///
+/// # Formula
+///
+/// Up fractal = the center bar of the last _window_ bars has a high greater than the highs of
+/// all the other bars
+///
+/// Down fractal = the center bar of the last _window_ bars has a low less than the lows of all
+/// the other bars
+///
+/// A bar which is both, an outside bar engulfing all its neighbours, is reported as both: the
+/// up fractal first and the down fractal by the next call. The next center bar has the outside
+/// bar among its neighbours, so it cannot be a fractal itself and no fractal is lost.
+///
+/// # Lag
+///
+/// A fractal is only confirmed once _window / 2_ bars followed the center bar, 2 bars with the
+/// default window. It is returned by the call consuming the last of them, the fractal itself
+/// lies _window / 2_ bars back, one bar more for the down fractal of an outside bar.
+/// _bars_ago_ tells how far back it lies.
+///
+/// # Parameters
+///
+/// * _window_ - number of bars, the center bar and as many bars on each side (odd integer
+///   greater than 1). Optional, default is 5.
+///
+/// # Example
+///
+/// ```
+/// use ta::indicators::{Fractal, FractalKind, Fractals};
+/// use ta::{DataItem, Next};
+///
+/// let mut fractals = Fractals::with_window(3).unwrap();
+///
+/// let mut next = |high: f64| {
+///     let di = DataItem::builder()
+///         .open(high).high(high).low(high - 1.0).close(high).volume(1000.0)
+///         .build(true).unwrap();
+///     fractals.next(&di)
+/// };
+///
+/// assert_eq!(next(10.0), None);
+/// assert_eq!(next(12.0), None);
+/// // the bar after the 12 confirms it
+/// let fractal = Fractal { kind: FractalKind::Up, price: 12.0, bars_ago: 1 };
+/// assert_eq!(next(11.0), Some(fractal));
+/// ```
+///
+/// # Links
+///
+/// * [Fractal Indicator, Investopedia](https://www.investopedia.com/terms/f/fractal.asp)
+///
+#[doc(alias = "Williams Fractal")]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone)]
+pub struct Fractals {
+    window: usize,
+    // (high, low) of the last window bars
+    bars: VecDeque<(f64, f64)>,
+    #[cfg_attr(feature = "serde", serde(default))]
+    bars_seen: usize,
+    // the low of an outside bar, returned by the next call
+    #[cfg_attr(feature = "serde", serde(default))]
+    pending_low: Option<f64>,
+}
+
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FractalKind {
+    /// A local high.
+    Up,
+    /// A local low.
+    Down,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Fractal {
+    pub kind: FractalKind,
+    /// The high of an up fractal, the low of a down fractal.
+    pub price: f64,
+    /// Number of bars consumed after the fractal bar, _window / 2_ or one more for the down
+    /// fractal of an outside bar.
+    pub bars_ago: usize,
+}
+
+impl Fractals {
+    pub fn new() -> Self {
+        Self::with_window(5).unwrap()
+    }
+
+    pub fn with_window(window: usize) -> Result<Self> {
+        if window < 3 || window.is_multiple_of(2) {
+            return Err(TaError::invalid_parameter("window", window));
+        }
+        Ok(Self {
+            window,
+            bars: VecDeque::with_capacity(window),
+            bars_seen: 0,
+            pending_low: None,
+        })
+    }
+}
+
+/// The window size.
+impl Period for Fractals {
+    fn period(&self) -> usize {
+        self.window
+    }
+}
+
+impl<T: High + Low> Next<&T> for Fractals {
+    type Output = Option<Fractal>;
+
+    fn next(&mut self, input: &T) -> Self::Output {
+        self.bars_seen = self.bars_seen.saturating_add(1);
+        if self.bars.len() == self.window {
+            self.bars.pop_front();
+        }
+        self.bars.push_back((input.high(), input.low()));
+        if self.bars.len() < self.window {
+            return None;
+        }
+
+        let center = self.window / 2;
+        if let Some(price) = self.pending_low.take() {
+            // the outside bar is a neighbour of the center, which is no fractal then
+            return Some(Fractal {
+                kind: FractalKind::Down,
+                price,
+                bars_ago: center + 1,
+            });
+        }
+
+        let (high, low) = self.bars[center];
+        let others = || {
+            self.bars
+                .iter()
+                .enumerate()
+                .filter(move |&(i, _)| i != center)
+                .map(|(_, &bar)| bar)
+        };
+
+        let is_up = others().all(|(h, _)| h < high);
+        let is_down = others().all(|(_, l)| l > low);
+        if is_up && is_down {
+            self.pending_low = Some(low);
+        }
+
+        if is_up {
+            Some(Fractal {
+                kind: FractalKind::Up,
+                price: high,
+                bars_ago: center,
+            })
+        } else if is_down {
+            Some(Fractal {
+                kind: FractalKind::Down,
+                price: low,
+                bars_ago: center,
+            })
+        } else {
+            None
+        }
+    }
+}
+
+impl Ready for Fractals {
+    fn bars_seen(&self) -> usize {
+        self.bars_seen
+    }
+
+    fn is_ready(&self) -> bool {
+        self.bars_seen >= self.window
+    }
+}
+
+impl Reset for Fractals {
+    fn reset(&mut self) {
+        self.bars_seen = 0;
+        self.bars.clear();
+        self.pending_low = None;
+    }
+}
+
+impl Default for Fractals {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl fmt::Display for Fractals {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "FRACTALS({})", self.window)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helper::*;
+
+    fn up(price: f64, bars_ago: usize) -> Option<Fractal> {
+        Some(Fractal {
+            kind: FractalKind::Up,
+            price,
+            bars_ago,
+        })
+    }
+
+    fn down(price: f64, bars_ago: usize) -> Option<Fractal> {
+        Some(Fractal {
+            kind: FractalKind::Down,
+            price,
+            bars_ago,
+        })
+    }
+
+    #[test]
+    fn test_new() {
+        assert!(Fractals::with_window(0).is_err());
+        assert!(Fractals::with_window(1).is_err());
+        assert!(Fractals::with_window(4).is_err());
+        assert!(Fractals::with_window(3).is_ok());
+        assert_eq!(Fractals::new().period(), 5);
+    }
+
+    #[test]
+    fn test_next_up() {
+        let mut fractals = Fractals::new();
+
+        assert_eq!(fractals.next(&Bar::new().high(10).low(9)), None);
+        assert_eq!(fractals.next(&Bar::new().high(11).low(10)), None);
+        // the peak
+        assert_eq!(fractals.next(&Bar::new().high(13).low(11)), None);
+        assert_eq!(fractals.next(&Bar::new().high(12).low(10.5)), None);
+        // confirmed 2 bars later
+        assert_eq!(fractals.next(&Bar::new().high(11.5).low(10)), up(13.0, 2));
+        assert_eq!(fractals.next(&Bar::new().high(11).low(9.5)), None);
+    }
+
+    #[test]
+    fn test_next_down() {
+        let mut fractals = Fractals::new();
+
+        let lows = [10.0, 9.0, 8.0, 9.5, 10.0, 11.0];
+        let outputs: Vec<_> = lows
+            .iter()
+            .map(|&low| fractals.next(&Bar::new().high(low + 2.0).low(low)))
+            .collect();
+        assert_eq!(outputs, [None, None, None, None, down(8.0, 2), None]);
+    }
+
+    #[test]
+    fn test_next_ties() {
+        let mut fractals = Fractals::with_window(3).unwrap();
+
+        fractals.next(&Bar::new().high(10).low(8));
+        fractals.next(&Bar::new().high(12).low(9));
+        // an equal high is no fractal
+        assert_eq!(fractals.next(&Bar::new().high(12).low(9)), None);
+        assert_eq!(fractals.next(&Bar::new().high(11).low(9.5)), None);
+
+        // an outside bar is an up fractal, then a down fractal
+        fractals.next(&Bar::new().high(14).low(7));
+        assert_eq!(fractals.next(&Bar::new().high(11).low(9)), up(14.0, 1));
+        assert_eq!(fractals.next(&Bar::new().high(10).low(8)), down(7.0, 2));
+        assert_eq!(fractals.next(&Bar::new().high(9).low(7.5)), None);
+    }
+
+    #[test]
+    fn test_next_outside_bar() {
+        let mut fractals = Fractals::new();
+
+        let bars = [
+            (10.0, 9.0),
+            (11.0, 10.0),
+            // the outside bar
+            (13.0, 8.0),
+            (12.0, 10.5),
+            (11.5, 10.0),
+            (11.0, 9.5),
+            (10.5, 9.0),
+            (10.0, 7.5),
+            (11.0, 9.0),
+            (12.0, 10.0),
+        ];
+        let outputs: Vec<_> = bars
+            .iter()
+            .map(|&(high, low)| fractals.next(&Bar::new().high(high).low(low)))
+            .collect();
+
+        assert_eq!(
+            outputs,
+            [
+                None,
+                None,
+                None,
+                None,
+                up(13.0, 2),
+                down(8.0, 3),
+                None,
+                None,
+                None,
+                down(7.5, 2)
+            ]
+        );
+    }
+
+    #[test]
+    fn test_reset_outside_bar() {
+        let mut fractals = Fractals::with_window(3).unwrap();
+
+        fractals.next(&Bar::new().high(10).low(9));
+        fractals.next(&Bar::new().high(12).low(7));
+        assert_eq!(fractals.next(&Bar::new().high(11).low(8)), up(12.0, 1));
+        // the pending low is discarded
+        fractals.reset();
+
+        fractals.next(&Bar::new().high(10).low(9));
+        fractals.next(&Bar::new().high(11).low(9.5));
+        assert_eq!(fractals.next(&Bar::new().high(10.5).low(9.8)), up(11.0, 1));
+    }
+
+    #[test]
+    fn test_reset() {
+        let mut fractals = Fractals::with_window(3).unwrap();
+
+        fractals.next(&Bar::new().high(10).low(9));
+        fractals.next(&Bar::new().high(12).low(10));
+        fractals.reset();
+
+        assert_eq!(fractals.next(&Bar::new().high(11).low(9)), None);
+        assert_eq!(fractals.next(&Bar::new().high(10).low(8)), None);
+        assert_eq!(fractals.next(&Bar::new().high(10.5).low(9)), down(8.0, 1));
+    }
+
+    #[test]
+    fn test_ready() {
+        let mut fractals = Fractals::with_window(3).unwrap();
+        let bar = Bar::new().high(10).low(8).close(9);
+        for _ in 0..2 {
+            fractals.next(&bar);
+        }
+        assert_eq!((fractals.bars_seen(), fractals.is_ready()), (2, false));
+        // the third bar completes the window around the second
+        fractals.next(&bar);
+        assert_eq!((fractals.bars_seen(), fractals.is_ready()), (3, true));
+
+        fractals.reset();
+        assert_eq!((fractals.bars_seen(), fractals.is_ready()), (0, false));
+    }
+
+    #[test]
+    fn test_default() {
+        Fractals::default();
+    }
+
+    #[test]
+    fn test_display() {
+        assert_eq!(format!("{}", Fractals::new()), "FRACTALS(5)");
+    }
+}
@@ -0,0 +1,215 @@
+use std::fmt;
+
+use crate::errors::Result;
+use crate::indicators::SimpleMovingAverage as Sma;
+use crate::{Close, High, Low, Next, Period, Ready, Reset};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Gann HiLo Activator.
+///
+/// Developed by Robert Krausz, a trend following line which trails the price with the moving
+/// average of the lows in an uptrend and with the moving average of the highs in a downtrend.
+/// The line switches sides when the close crosses the opposite average.
+///
+/// # Formula
+///
+/// An uptrend turns into a downtrend when the close is below the SMA of the lows, a downtrend
+/// turns into an uptrend when the close is above the SMA of the highs. The first bar starts an
+/// uptrend.
+///
+/// Value = SMA of the lows in an uptrend, SMA of the highs in a downtrend
+///
+/// Where _SMA_ is the [simple moving average](struct.SimpleMovingAverage.html) over _period_
+/// bars, including the current bar.
+///
+/// # Parameters
+///
+/// * _period_ - number of bars (integer greater than 0). Default is 10.
+///
+/// # Example
+///
+/// ```
+/// use ta::indicators::GannHiLo;
+/// use ta::{DataItem, Next};
+///
+/// let mut hilo = GannHiLo::new(2).unwrap();
+///
+/// let di = DataItem::builder()
+///     .open(10.0).high(11.0).low(9.0).close(10.0).volume(1000.0)
+///     .build(true).unwrap();
+/// let out = hilo.next(&di);
+/// assert_eq!((out.value, out.is_long), (9.0, true));
+///
+/// // the close falls below the average of the lows
+/// let di = DataItem::builder()
+///     .open(10.0).high(10.0).low(8.0).close(8.4).volume(1000.0)
+///     .build(true).unwrap();
+/// let out = hilo.next(&di);
+/// assert_eq!((out.value, out.is_long), (10.5, false));
+/// ```
+///
+/// # Links
+///
+/// * [Gann HiLo Activator, TradingView](https://www.tradingview.com/support/solutions/43000714370-gann-hilo-activator/)
+///
+#[doc(alias = "HiLo")]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone)]
+pub struct GannHiLo {
+    high: Sma,
+    low: Sma,
+    is_long: bool,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct GannHiLoOutput {
+    pub value: f64,
+    pub is_long: bool,
+}
+
+impl From<GannHiLoOutput> for (f64, bool) {
+    fn from(gho: GannHiLoOutput) -> Self {
+        (gho.value, gho.is_long)
+    }
+}
+
+impl GannHiLo {
+    pub fn new(period: usize) -> Result<Self> {
+        Ok(Self {
+            high: Sma::new(period)?,
+            low: Sma::new(period)?,
+            is_long: true,
+        })
+    }
+}
+
+impl Period for GannHiLo {
+    fn period(&self) -> usize {
+        self.high.period()
+    }
+}
+
+impl<T: High + Low + Close> Next<&T> for GannHiLo {
+    type Output = GannHiLoOutput;
+
+    fn next(&mut self, input: &T) -> Self::Output {
+        let close = input.close();
+        let high = self.high.next(input.high());
+        let low = self.low.next(input.low());
+
+        if self.is_long && close < low {
+            self.is_long = false;
+        } else if !self.is_long && close > high {
+            self.is_long = true;
+        }
+
+        GannHiLoOutput {
+            value: if self.is_long { low } else { high },
+            is_long: self.is_long,
+        }
+    }
+}
+
+impl Ready for GannHiLo {
+    fn bars_seen(&self) -> usize {
+        self.high.bars_seen()
+    }
+
+    fn is_ready(&self) -> bool {
+        self.high.is_ready() && self.low.is_ready()
+    }
+}
+
+impl Reset for GannHiLo {
+    fn reset(&mut self) {
+        self.high.reset();
+        self.low.reset();
+        self.is_long = true;
+    }
+}
+
+impl Default for GannHiLo {
+    fn default() -> Self {
+        Self::new(10).unwrap()
+    }
+}
+
+impl fmt::Display for GannHiLo {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "HILO({})", self.high.period())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helper::*;
+
+    #[test]
+    fn test_new() {
+        assert!(GannHiLo::new(0).is_err());
+        assert!(GannHiLo::new(1).is_ok());
+        assert!(GannHiLo::new(10).is_ok());
+    }
+
+    #[test]
+    fn test_next() {
+        let mut hilo = GannHiLo::new(2).unwrap();
+
+        let data = [
+            // high, low, close, value, is_long
+            (11.0, 9.0, 10.0, 9.0, true),
+            (12.0, 10.0, 11.5, 9.5, true),
+            (11.0, 9.0, 9.4, 11.5, false), // close below the average of the lows, flip
+            (10.0, 8.0, 9.0, 10.5, false),
+            (11.0, 9.0, 10.5, 10.5, false), // touching the average of the highs is no cross
+            (12.0, 10.0, 11.6, 9.5, true),  // close above the average of the highs, flip
+        ];
+
+        for (high, low, close, value, is_long) in data {
+            let bar = Bar::new().high(high).low(low).close(close);
+            let out = hilo.next(&bar);
+            assert_eq!((round(out.value), out.is_long), (value, is_long));
+        }
+    }
+
+    #[test]
+    fn test_reset() {
+        let mut hilo = GannHiLo::new(2).unwrap();
+
+        hilo.next(&Bar::new().high(11.0).low(9.0).close(10.0));
+        let out = hilo.next(&Bar::new().high(10.0).low(8.0).close(8.4));
+        assert!(!out.is_long);
+        hilo.reset();
+
+        let out = hilo.next(&Bar::new().high(10.0).low(8.0).close(9.0));
+        assert_eq!((out.value, out.is_long), (8.0, true));
+    }
+
+    #[test]
+    fn test_ready() {
+        let mut hilo = GannHiLo::new(3).unwrap();
+        let bar = Bar::new().high(10).low(8).close(9);
+        for _ in 0..2 {
+            hilo.next(&bar);
+        }
+        assert_eq!((hilo.bars_seen(), hilo.is_ready()), (2, false));
+        hilo.next(&bar);
+        assert_eq!((hilo.bars_seen(), hilo.is_ready()), (3, true));
+
+        hilo.reset();
+        assert_eq!((hilo.bars_seen(), hilo.is_ready()), (0, false));
+    }
+
+    #[test]
+    fn test_default() {
+        GannHiLo::default();
+    }
+
+    #[test]
+    fn test_display() {
+        let hilo = GannHiLo::new(10).unwrap();
+        assert_eq!(format!("{}", hilo), "HILO(10)");
+    }
+}
@@ -0,0 +1,211 @@
+use std::fmt;
+
+use crate::{Close, DataItem, High, Low, Next, Open, Reset, Volume};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Heikin-Ashi candles.
+///
+/// Transforms regular candles into Heikin-Ashi candles, which average the price action of the
+/// current and the previous bar. The resulting candles filter out noise and make trends easier
+/// to see, and since the output is a [DataItem](../struct.DataItem.html) it can be fed into
+/// any other indicator. The volume is passed through unchanged.
+///
+/// # Formula
+///
+/// HA close<sub>t</sub> = (open<sub>t</sub> + high<sub>t</sub> + low<sub>t</sub> + close<sub>t</sub>) / 4
+///
+/// HA open<sub>t</sub> = (HA open<sub>t-1</sub> + HA close<sub>t-1</sub>) / 2
+///
+/// HA high<sub>t</sub> = max(high<sub>t</sub>, HA open<sub>t</sub>, HA close<sub>t</sub>)
+///
+/// HA low<sub>t</sub> = min(low<sub>t</sub>, HA open<sub>t</sub>, HA close<sub>t</sub>)
+///
+/// The first HA open is (open + close) / 2 of the first bar.
+///
+/// # Example
+///
+/// ```
+/// use ta::indicators::HeikinAshi;
+/// use ta::{Close, DataItem, Next, Open};
+///
+/// let mut ha = HeikinAshi::new();
+///
+/// let di = DataItem::builder()
+///     .open(10.0).high(12.0).low(9.0).close(11.0).volume(1000.0)
+///     .build(true).unwrap();
+///
+/// let candle = ha.next(&di);
+/// assert_eq!(candle.open(), 10.5);
+/// assert_eq!(candle.close(), 10.5);
+///
+/// let candle = ha.next(&di);
+/// assert_eq!(candle.open(), 10.5);
+/// ```
+///
+/// # Links
+///
+/// * [Heikin-Ashi, Investopedia](https://www.investopedia.com/trading/heikin-ashi-better-candlestick/)
+///
+#[doc(alias = "HA")]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone)]
+pub struct HeikinAshi {
+    // HA open and HA close of the previous candle
+    prev: Option<(f64, f64)>,
+}
+
+impl HeikinAshi {
+    pub fn new() -> Self {
+        Self { prev: None }
+    }
+}
+
+impl<T: Open + High + Low + Close + Volume> Next<&T> for HeikinAshi {
+    type Output = DataItem;
+
+    fn next(&mut self, input: &T) -> Self::Output {
+        let close = (input.open() + input.high() + input.low() + input.close()) / 4.0;
+        let open = match self.prev {
+            Some((prev_open, prev_close)) => (prev_open + prev_close) / 2.0,
+            None => (input.open() + input.close()) / 2.0,
+        };
+        self.prev = Some((open, close));
+
+        DataItem::builder()
+            .open(open)
+            .high(input.high().max(open).max(close))
+            .low(input.low().min(open).min(close))
+            .close(close)
+            .volume(input.volume())
+            .build(false)
+            .unwrap()
+    }
+}
+
+impl Reset for HeikinAshi {
+    fn reset(&mut self) {
+        self.prev = None;
+    }
+}
+
+impl Default for HeikinAshi {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl fmt::Display for HeikinAshi {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "HA")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helper::*;
+
+    #[test]
+    fn test_next() {
+        let mut ha = HeikinAshi::new();
+
+        #[rustfmt::skip]
+        let data = [
+            // open, high, low, close, ha open, ha high, ha low, ha close
+            (10.0, 12.0, 9.0, 11.0, 10.5, 12.0, 9.0, 10.5),
+            (11.0, 13.0, 10.0, 12.0, 10.5, 13.0, 10.0, 11.5),
+            (12.0, 12.5, 11.0, 11.5, 11.0, 12.5, 11.0, 11.75),
+            (11.5, 11.5, 9.0, 9.5, 11.375, 11.5, 9.0, 10.375),
+            (9.5, 10.0, 8.0, 8.5, 10.875, 10.875, 8.0, 9.0),
+        ];
+
+        for (open, high, low, close, ha_open, ha_high, ha_low, ha_close) in data {
+            let candle = ha.next(
+                &Bar::new()
+                    .open(open)
+                    .high(high)
+                    .low(low)
+                    .close(close)
+                    .volume(100.0),
+            );
+            assert_eq!(round(candle.open()), ha_open);
+            assert_eq!(round(candle.high()), ha_high);
+            assert_eq!(round(candle.low()), ha_low);
+            assert_eq!(round(candle.close()), ha_close);
+            assert_eq!(candle.volume(), 100.0);
+        }
+    }
+
+    #[test]
+    fn test_next_seed() {
+        let mut ha = HeikinAshi::new();
+
+        // the first HA open is the midpoint of the first bar's open and close
+        let candle = ha.next(&Bar::new().open(20).high(25).low(15).close(10).volume(100.0));
+        assert_eq!(candle.open(), 15.0);
+        assert_eq!(candle.close(), 17.5);
+        assert_eq!(candle.high(), 25.0);
+        assert_eq!(candle.low(), 15.0);
+    }
+
+    #[test]
+    fn test_next_recursive_open() {
+        let mut ha = HeikinAshi::new();
+
+        let bars = [
+            Bar::new().open(10).high(12).low(9).close(11).volume(100.0),
+            Bar::new().open(11).high(13).low(10).close(12).volume(100.0),
+            Bar::new()
+                .open(12)
+                .high(12.5)
+                .low(11)
+                .close(11.5)
+                .volume(100.0),
+            Bar::new()
+                .open(11.5)
+                .high(11.5)
+                .low(9)
+                .close(9.5)
+                .volume(100.0),
+            Bar::new()
+                .open(9.5)
+                .high(10)
+                .low(8)
+                .close(8.5)
+                .volume(100.0),
+        ];
+
+        let mut prev = ha.next(&bars[0]);
+        for bar in &bars[1..] {
+            let candle = ha.next(bar);
+            assert_eq!(candle.open(), (prev.open() + prev.close()) / 2.0);
+            assert!(candle.high() >= candle.open().max(candle.close()));
+            assert!(candle.low() <= candle.open().min(candle.close()));
+            prev = candle;
+        }
+    }
+
+    #[test]
+    fn test_reset() {
+        let mut ha = HeikinAshi::new();
+
+        ha.next(&Bar::new().open(10).high(12).low(9).close(11).volume(100.0));
+        ha.next(&Bar::new().open(11).high(13).low(10).close(12).volume(100.0));
+        ha.reset();
+
+        let candle = ha.next(&Bar::new().open(20).high(25).low(15).close(10).volume(100.0));
+        assert_eq!(candle.open(), 15.0);
+    }
+
+    #[test]
+    fn test_default() {
+        HeikinAshi::default();
+    }
+
+    #[test]
+    fn test_display() {
+        let ha = HeikinAshi::new();
+        assert_eq!(format!("{}", ha), "HA");
+    }
+}
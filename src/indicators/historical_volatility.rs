@@ -0,0 +1,236 @@
+use std::fmt;
+
+use crate::errors::{Result, TaError};
+use crate::indicators::StandardDeviation as Sd;
+use crate::{Close, Next, Period, Ready, Reset};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Historical volatility (HV), close to close.
+///
+/// The annualized standard deviation of the logarithmic returns, the volatility the price
+/// actually showed, in contrast to the implied volatility priced into options. It is returned
+/// as a fraction, 0.2 is a volatility of 20%.
+///
+/// # Formula
+///
+/// HV = SD(period) of ln(close / previous close) * sqrt(annualization)
+///
+/// Where _SD_ is the sample [standard deviation](struct.StandardDeviation.html). The
+/// annualization is the number of bars per year, 252 trading days for daily bars. The first
+/// close has no return and yields 0, a close which is not positive has no logarithmic return
+/// and yields the previous value again.
+///
+/// # Parameters
+///
+/// * _period_ - number of returns (integer greater than 0). Default is 20.
+/// * _annualization_ - number of bars per year (greater than 0). Default is 252.
+///
+/// # Example
+///
+/// ```
+/// use ta::indicators::HistoricalVolatility;
+/// use ta::Next;
+///
+/// let mut hv = HistoricalVolatility::new(2, 1.0).unwrap();
+/// assert_eq!(hv.next(100.0), 0.0);
+/// assert_eq!(hv.next(110.0), 0.0);
+/// // the returns ln(1.1) and -ln(1.1)
+/// let expected = 1.1f64.ln() * 2f64.sqrt();
+/// assert!((hv.next(100.0) - expected).abs() < 1e-12);
+/// ```
+///
+/// # Links
+///
+/// * [Historical Volatility, Investopedia](https://www.investopedia.com/terms/h/historicalvolatility.asp)
+///
+#[doc(alias = "HV")]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone)]
+pub struct HistoricalVolatility {
+    annualization: f64,
+    sd: Sd,
+    prev_close: Option<f64>,
+    current: f64,
+    #[cfg_attr(feature = "serde", serde(default))]
+    bars_seen: usize,
+}
+
+impl HistoricalVolatility {
+    pub fn new(period: usize, annualization: f64) -> Result<Self> {
+        let is_valid = annualization > 0.0 && annualization.is_finite();
+        if !is_valid {
+            return Err(TaError::invalid_parameter("annualization", annualization));
+        }
+        Ok(Self {
+            annualization,
+            sd: Sd::sample(period)?,
+            prev_close: None,
+            current: 0.0,
+            bars_seen: 0,
+        })
+    }
+
+    pub fn annualization(&self) -> f64 {
+        self.annualization
+    }
+}
+
+/// The number of returns.
+impl Period for HistoricalVolatility {
+    fn period(&self) -> usize {
+        self.sd.period()
+    }
+}
+
+impl Next<f64> for HistoricalVolatility {
+    type Output = f64;
+
+    fn next(&mut self, input: f64) -> Self::Output {
+        self.bars_seen = self.bars_seen.saturating_add(1);
+        if let Some(prev_close) = self.prev_close {
+            if prev_close > 0.0 && input > 0.0 {
+                let log_return = (input / prev_close).ln();
+                self.current = self.sd.next(log_return) * self.annualization.sqrt();
+            }
+        }
+        self.prev_close = Some(input);
+        self.current
+    }
+}
+
+impl<T: Close> Next<&T> for HistoricalVolatility {
+    type Output = f64;
+
+    fn next(&mut self, input: &T) -> Self::Output {
+        self.next(input.close())
+    }
+}
+
+impl Ready for HistoricalVolatility {
+    fn bars_seen(&self) -> usize {
+        self.bars_seen
+    }
+
+    fn is_ready(&self) -> bool {
+        self.sd.is_ready()
+    }
+}
+
+impl Reset for HistoricalVolatility {
+    fn reset(&mut self) {
+        self.bars_seen = 0;
+        self.sd.reset();
+        self.prev_close = None;
+        self.current = 0.0;
+    }
+}
+
+impl Default for HistoricalVolatility {
+    fn default() -> Self {
+        Self::new(20, 252.0).unwrap()
+    }
+}
+
+impl fmt::Display for HistoricalVolatility {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "HV({}, {})", self.sd.period(), self.annualization)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helper::*;
+
+    test_indicator!(HistoricalVolatility);
+
+    #[test]
+    fn test_new() {
+        assert!(HistoricalVolatility::new(0, 252.0).is_err());
+        assert!(HistoricalVolatility::new(20, 0.0).is_err());
+        assert!(HistoricalVolatility::new(20, -252.0).is_err());
+        assert!(HistoricalVolatility::new(20, f64::INFINITY).is_err());
+        assert!(HistoricalVolatility::new(1, 1.0).is_ok());
+    }
+
+    #[test]
+    fn test_next() {
+        let mut hv = HistoricalVolatility::new(3, 252.0).unwrap();
+
+        // log returns: 0.0198, -0.00985, 0.03884, -0.01923, 0.00966, 0.03774, -0.0093
+        let closes = [100.0, 102.0, 101.0, 105.0, 103.0, 104.0, 108.0, 107.0];
+        let expected = [0.0, 0.0, 0.333, 0.39, 0.495, 0.461, 0.452, 0.376];
+        for (&close, expected) in closes.iter().zip(expected) {
+            assert_eq!(round(hv.next(close)), expected);
+        }
+
+        let mut hv = HistoricalVolatility::new(3, 252.0).unwrap();
+        assert_eq!(hv.next(&Bar::new().close(100)), 0.0);
+        assert_eq!(hv.next(&Bar::new().close(102)), 0.0);
+        assert_eq!(round(hv.next(&Bar::new().close(101))), 0.333);
+    }
+
+    #[test]
+    fn test_next_annualization() {
+        let mut daily = HistoricalVolatility::new(3, 1.0).unwrap();
+        let mut annual = HistoricalVolatility::new(3, 252.0).unwrap();
+
+        for close in [100.0, 102.0, 101.0, 105.0] {
+            let expected = daily.next(close) * 252f64.sqrt();
+            assert_eq!(round(annual.next(close)), round(expected));
+        }
+    }
+
+    #[test]
+    fn test_next_non_positive() {
+        let mut hv = HistoricalVolatility::new(3, 1.0).unwrap();
+
+        hv.next(100.0);
+        hv.next(110.0);
+        let out = hv.next(100.0);
+        // no return from or to a price of 0
+        assert_eq!(hv.next(0.0), out);
+        assert_eq!(hv.next(100.0), out);
+    }
+
+    #[test]
+    fn test_reset() {
+        let mut hv = HistoricalVolatility::new(3, 252.0).unwrap();
+
+        hv.next(100.0);
+        hv.next(120.0);
+        hv.next(90.0);
+        hv.reset();
+
+        assert_eq!(hv.next(100.0), 0.0);
+        assert_eq!(hv.next(102.0), 0.0);
+        assert_eq!(round(hv.next(101.0)), 0.333);
+    }
+
+    #[test]
+    fn test_ready() {
+        let mut hv = HistoricalVolatility::new(3, 252.0).unwrap();
+        for _ in 0..3 {
+            hv.next(1.0);
+        }
+        assert_eq!((hv.bars_seen(), hv.is_ready()), (3, false));
+        // the fourth input completes the third return
+        hv.next(1.0);
+        assert_eq!((hv.bars_seen(), hv.is_ready()), (4, true));
+
+        hv.reset();
+        assert_eq!((hv.bars_seen(), hv.is_ready()), (0, false));
+    }
+
+    #[test]
+    fn test_default() {
+        HistoricalVolatility::default();
+    }
+
+    #[test]
+    fn test_display() {
+        let hv = HistoricalVolatility::new(20, 252.0).unwrap();
+        assert_eq!(format!("{}", hv), "HV(20, 252)");
+    }
+}
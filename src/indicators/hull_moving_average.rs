@@ -0,0 +1,239 @@
+use std::fmt;
+
+use crate::errors::Result;
+use crate::indicators::WeightedMovingAverage;
+use crate::{Close, Current, Next, Period, Ready, Reset};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Hull moving average (HMA).
+///
+/// A moving average developed by Alan Hull that reduces lag while keeping the curve smooth.
+///
+/// # Formula
+///
+/// HMA = WMA(sqrt(n)) of (2 * WMA(n / 2) - WMA(n))
+///
+/// Where:
+///
+/// * _WMA_ - [weighted moving average](struct.WeightedMovingAverage.html)
+/// * _n_ - period, _n / 2_ and _sqrt(n)_ are rounded down (but at least 1)
+///
+/// # Parameters
+///
+/// * _period_ - number of periods (integer greater than 0). Default is 9.
+///
+/// # Example
+///
+/// ```
+/// use ta::indicators::HullMovingAverage;
+/// use ta::Next;
+///
+/// let mut hma = HullMovingAverage::new(4).unwrap();
+/// assert_eq!(hma.next(10.0), 10.0);
+/// assert_eq!(hma.next(11.0).round(), 10.0);
+/// assert_eq!(hma.next(12.0).round(), 12.0);
+/// assert_eq!(hma.next(13.0).round(), 13.0);
+/// ```
+///
+/// # Links
+///
+/// * [Hull moving average, Alan Hull](https://alanhull.com/hull-moving-average)
+///
+#[doc(alias = "HMA")]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone)]
+pub struct HullMovingAverage {
+    period: usize,
+    half_wma: WeightedMovingAverage,
+    full_wma: WeightedMovingAverage,
+    sqrt_wma: WeightedMovingAverage,
+}
+
+impl HullMovingAverage {
+    pub fn new(period: usize) -> Result<Self> {
+        let full_wma = WeightedMovingAverage::new(period)?;
+        let half_period = (period / 2).max(1);
+        let sqrt_period = ((period as f64).sqrt() as usize).max(1);
+
+        Ok(Self {
+            period,
+            half_wma: WeightedMovingAverage::new(half_period)?,
+            full_wma,
+            sqrt_wma: WeightedMovingAverage::new(sqrt_period)?,
+        })
+    }
+}
+
+impl Period for HullMovingAverage {
+    fn period(&self) -> usize {
+        self.period
+    }
+}
+
+impl Next<f64> for HullMovingAverage {
+    type Output = f64;
+
+    fn next(&mut self, input: f64) -> Self::Output {
+        let half = self.half_wma.next(input);
+        let full = self.full_wma.next(input);
+        self.sqrt_wma.next(2.0 * half - full)
+    }
+}
+
+impl<T: Close> Next<&T> for HullMovingAverage {
+    type Output = f64;
+
+    fn next(&mut self, input: &T) -> Self::Output {
+        self.next(input.close())
+    }
+}
+
+impl Current for HullMovingAverage {
+    type Output = f64;
+
+    fn current(&self) -> Option<Self::Output> {
+        self.sqrt_wma.current()
+    }
+}
+
+impl Ready for HullMovingAverage {
+    fn bars_seen(&self) -> usize {
+        self.full_wma.bars_seen()
+    }
+
+    fn is_ready(&self) -> bool {
+        self.half_wma.is_ready() && self.full_wma.is_ready() && self.sqrt_wma.is_ready()
+    }
+}
+
+impl Reset for HullMovingAverage {
+    fn reset(&mut self) {
+        self.half_wma.reset();
+        self.full_wma.reset();
+        self.sqrt_wma.reset();
+    }
+}
+
+impl Default for HullMovingAverage {
+    fn default() -> Self {
+        Self::new(9).unwrap()
+    }
+}
+
+impl fmt::Display for HullMovingAverage {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "HMA({})", self.period)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::indicators::SimpleMovingAverage;
+    use crate::test_helper::*;
+
+    test_indicator!(HullMovingAverage);
+
+    #[test]
+    fn test_new() {
+        assert!(HullMovingAverage::new(0).is_err());
+        assert!(HullMovingAverage::new(1).is_ok());
+        assert!(HullMovingAverage::new(9).is_ok());
+    }
+
+    #[test]
+    fn test_next() {
+        let mut hma = HullMovingAverage::new(4).unwrap();
+
+        assert_eq!(hma.next(10.0), 10.0);
+        assert_eq!(round(hma.next(11.0)), 10.444);
+        assert_eq!(round(hma.next(12.0)), 11.556);
+        assert_eq!(round(hma.next(13.0)), 12.889);
+        assert_eq!(round(hma.next(12.0)), 12.756);
+        assert_eq!(round(hma.next(15.0)), 13.889);
+        assert_eq!(round(hma.next(20.0)), 18.511);
+        assert_eq!(round(hma.next(18.0)), 20.111);
+
+        let mut hma = HullMovingAverage::new(4).unwrap();
+        let bar1 = Bar::new().close(10);
+        let bar2 = Bar::new().close(11);
+        assert_eq!(hma.next(&bar1), 10.0);
+        assert_eq!(round(hma.next(&bar2)), 10.444);
+    }
+
+    #[test]
+    fn test_next_step() {
+        let mut hma = HullMovingAverage::new(9).unwrap();
+        let mut sma = SimpleMovingAverage::new(9).unwrap();
+
+        for _ in 0..20 {
+            hma.next(0.0);
+            sma.next(0.0);
+        }
+
+        for _ in 0..5 {
+            let hma_value = hma.next(10.0);
+            let sma_value = sma.next(10.0);
+            assert!(hma_value > sma_value);
+        }
+    }
+
+    #[test]
+    fn test_period_one() {
+        let mut hma = HullMovingAverage::new(1).unwrap();
+        assert_eq!(hma.next(3.0), 3.0);
+        assert_eq!(hma.next(7.0), 7.0);
+    }
+
+    #[test]
+    fn test_reset() {
+        let mut hma = HullMovingAverage::new(4).unwrap();
+
+        hma.next(10.0);
+        hma.next(20.0);
+        hma.reset();
+
+        assert_eq!(hma.next(10.0), 10.0);
+        assert_eq!(round(hma.next(11.0)), 10.444);
+    }
+
+    #[test]
+    fn test_current() {
+        let mut hma = HullMovingAverage::new(4).unwrap();
+        assert_eq!(hma.current(), None);
+
+        for input in [2.0, 3.0, 4.2, 7.0, 6.7, 6.5] {
+            let value = hma.next(input);
+            assert_eq!(hma.current(), Some(value));
+        }
+
+        hma.reset();
+        assert_eq!(hma.current(), None);
+    }
+
+    #[test]
+    fn test_ready() {
+        let mut hma = HullMovingAverage::new(4).unwrap();
+        for _ in 0..3 {
+            hma.next(1.0);
+        }
+        assert_eq!((hma.bars_seen(), hma.is_ready()), (3, false));
+        hma.next(1.0);
+        assert_eq!((hma.bars_seen(), hma.is_ready()), (4, true));
+
+        hma.reset();
+        assert_eq!((hma.bars_seen(), hma.is_ready()), (0, false));
+    }
+
+    #[test]
+    fn test_default() {
+        HullMovingAverage::default();
+    }
+
+    #[test]
+    fn test_display() {
+        let hma = HullMovingAverage::new(9).unwrap();
+        assert_eq!(format!("{}", hma), "HMA(9)");
+    }
+}
@@ -0,0 +1,333 @@
+use std::fmt;
+
+use crate::adapters::Delay;
+use crate::errors::{Result, TaError};
+use crate::window::MonotonicWindow;
+use crate::{Close, High, Low, Next, Period, Ready, Reset};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Ichimoku Cloud (Ichimoku Kinko Hyo).
+///
+/// Developed by Goichi Hosoda, the indicator combines several midpoints of the recent price
+/// range. The conversion line (tenkan-sen) and the base line (kijun-sen) follow the price, the
+/// two leading spans (senkou span A and B) form the cloud, an area of support and resistance,
+/// and the lagging span (chikou span) compares the close with the past price.
+///
+/// # Formula
+///
+/// Tenkan-sen = (highest high + lowest low) / 2 of the last _tenkan_ bars
+///
+/// Kijun-sen = (highest high + lowest low) / 2 of the last _kijun_ bars
+///
+/// Senkou span A = (tenkan-sen + kijun-sen) / 2, displaced _kijun_ bars ahead
+///
+/// Senkou span B = (highest high + lowest low) / 2 of the last _senkou_b_ bars, displaced
+/// _kijun_ bars ahead
+///
+/// Chikou span = close, displaced _kijun_ bars behind
+///
+/// # Displacement
+///
+/// On a chart the leading spans are plotted _kijun_ bars ahead of the bar they are computed
+/// on, and the lagging span _kijun_ bars behind. The indicator returns the values belonging to
+/// the current bar instead:
+///
+/// * _senkou_a_ and _senkou_b_ are the spans computed _kijun_ bars ago, the cloud drawn under
+///   the current bar. Until _kijun_ bars have been seen the spans of the first bar are returned.
+/// * _chikou_ is the close of the current bar, it is plotted under the bar _kijun_ bars ago.
+///
+/// # Parameters
+///
+/// * _tenkan_ - period of the conversion line (integer greater than 0). Default is 9.
+/// * _kijun_ - period of the base line and the displacement (integer greater than or equal to
+///   _tenkan_). Default is 26.
+/// * _senkou_b_ - period of the senkou span B (integer greater than or equal to _kijun_).
+///   Default is 52.
+///
+/// # Example
+///
+/// ```
+/// use ta::indicators::Ichimoku;
+/// use ta::{DataItem, Next};
+///
+/// let mut ichimoku = Ichimoku::new(1, 2, 3).unwrap();
+///
+/// let di = DataItem::builder()
+///     .open(10.0).high(12.0).low(8.0).close(11.0).volume(1000.0)
+///     .build(true).unwrap();
+/// ichimoku.next(&di);
+///
+/// let di = DataItem::builder()
+///     .open(11.0).high(15.0).low(11.0).close(14.0).volume(1000.0)
+///     .build(true).unwrap();
+/// let out = ichimoku.next(&di);
+///
+/// assert_eq!(out.tenkan_sen, 13.0);
+/// assert_eq!(out.kijun_sen, 11.5);
+/// // still the spans of the first bar
+/// assert_eq!((out.senkou_a, out.senkou_b), (10.0, 10.0));
+/// assert_eq!(out.chikou, 14.0);
+/// ```
+///
+/// # Links
+///
+/// * [Ichimoku Cloud, StockCharts](https://school.stockcharts.com/doku.php?id=technical_indicators:ichimoku_cloud)
+///
+#[doc(alias = "Ichimoku Kinko Hyo")]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone)]
+pub struct Ichimoku {
+    tenkan: MonotonicWindow,
+    kijun: MonotonicWindow,
+    senkou_b: MonotonicWindow,
+    periods: (usize, usize, usize),
+    // (senkou span A, senkou span B) of the last kijun bars
+    spans: Delay<(f64, f64)>,
+    #[cfg_attr(feature = "serde", serde(default))]
+    bars_seen: usize,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct IchimokuOutput {
+    pub tenkan_sen: f64,
+    pub kijun_sen: f64,
+    pub senkou_a: f64,
+    pub senkou_b: f64,
+    pub chikou: f64,
+}
+
+impl From<IchimokuOutput> for (f64, f64, f64, f64, f64) {
+    fn from(io: IchimokuOutput) -> Self {
+        (
+            io.tenkan_sen,
+            io.kijun_sen,
+            io.senkou_a,
+            io.senkou_b,
+            io.chikou,
+        )
+    }
+}
+
+impl Ichimoku {
+    pub fn new(tenkan: usize, kijun: usize, senkou_b: usize) -> Result<Self> {
+        if tenkan > kijun {
+            return Err(TaError::invalid_parameter("kijun", kijun));
+        }
+        if kijun > senkou_b {
+            return Err(TaError::invalid_parameter("senkou_b", senkou_b));
+        }
+        Ok(Self {
+            tenkan: MonotonicWindow::new(tenkan)?,
+            kijun: MonotonicWindow::new(kijun)?,
+            senkou_b: MonotonicWindow::new(senkou_b)?,
+            periods: (tenkan, kijun, senkou_b),
+            spans: Delay::new(kijun),
+            bars_seen: 0,
+        })
+    }
+}
+
+/// The number of bars the current senkou span B depends on, _senkou_b_ + _kijun_.
+impl Period for Ichimoku {
+    fn period(&self) -> usize {
+        self.periods.2 + self.periods.1
+    }
+}
+
+impl<T: High + Low + Close> Next<&T> for Ichimoku {
+    type Output = IchimokuOutput;
+
+    fn next(&mut self, input: &T) -> Self::Output {
+        self.bars_seen = self.bars_seen.saturating_add(1);
+        let midpoint = |(low, high): (f64, f64)| (high + low) / 2.0;
+
+        let tenkan_sen = midpoint(self.tenkan.push_range(input.low(), input.high()));
+        let kijun_sen = midpoint(self.kijun.push_range(input.low(), input.high()));
+        let spans = (
+            (tenkan_sen + kijun_sen) / 2.0,
+            midpoint(self.senkou_b.push_range(input.low(), input.high())),
+        );
+
+        // until the delay is filled the spans of the first bar stand in
+        let (senkou_a, senkou_b) = match self.spans.next(spans) {
+            Some(displaced) => displaced,
+            None => *self.spans.oldest().unwrap(),
+        };
+
+        IchimokuOutput {
+            tenkan_sen,
+            kijun_sen,
+            senkou_a,
+            senkou_b,
+            chikou: input.close(),
+        }
+    }
+}
+
+impl Ready for Ichimoku {
+    fn bars_seen(&self) -> usize {
+        self.bars_seen
+    }
+
+    fn is_ready(&self) -> bool {
+        self.bars_seen >= self.period()
+    }
+}
+
+impl Reset for Ichimoku {
+    fn reset(&mut self) {
+        self.bars_seen = 0;
+        self.tenkan.reset();
+        self.kijun.reset();
+        self.senkou_b.reset();
+        self.spans.reset();
+    }
+}
+
+impl Default for Ichimoku {
+    fn default() -> Self {
+        Self::new(9, 26, 52).unwrap()
+    }
+}
+
+impl fmt::Display for Ichimoku {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let (tenkan, kijun, senkou_b) = self.periods;
+        write!(f, "ICHIMOKU({}, {}, {})", tenkan, kijun, senkou_b)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helper::*;
+
+    fn midpoint(bars: &[Bar]) -> f64 {
+        let high = bars
+            .iter()
+            .map(|b| b.high())
+            .fold(f64::NEG_INFINITY, f64::max);
+        let low = bars.iter().map(|b| b.low()).fold(f64::INFINITY, f64::min);
+        (high + low) / 2.0
+    }
+
+    #[test]
+    fn test_new() {
+        assert!(Ichimoku::new(0, 26, 52).is_err());
+        assert!(Ichimoku::new(27, 26, 52).is_err());
+        assert!(Ichimoku::new(9, 53, 52).is_err());
+        assert!(Ichimoku::new(9, 26, 52).is_ok());
+        assert!(Ichimoku::new(1, 1, 1).is_ok());
+    }
+
+    #[test]
+    fn test_next() {
+        let mut ichimoku = Ichimoku::new(2, 3, 4).unwrap();
+
+        let bars = [
+            Bar::new().high(12).low(8).close(11),
+            Bar::new().high(15).low(11).close(14),
+            Bar::new().high(14).low(10).close(11),
+            Bar::new().high(11).low(7).close(8),
+            Bar::new().high(10).low(6).close(9),
+            Bar::new().high(13).low(9).close(12),
+        ];
+        // (tenkan, kijun, senkou_a, senkou_b)
+        let expected = [
+            (10.0, 10.0, 10.0, 10.0),
+            (11.5, 11.5, 10.0, 10.0),
+            (12.5, 11.5, 10.0, 10.0),
+            (10.5, 11.0, 10.0, 10.0),
+            // the spans of the second bar
+            (8.5, 10.0, 11.5, 11.5),
+            (9.5, 9.5, 12.0, 11.5),
+        ];
+
+        for (bar, expected) in bars.iter().zip(expected) {
+            let out = ichimoku.next(bar);
+            assert_eq!(
+                (out.tenkan_sen, out.kijun_sen, out.senkou_a, out.senkou_b),
+                expected
+            );
+            assert_eq!(out.chikou, bar.close());
+        }
+    }
+
+    #[test]
+    fn test_next_midpoints() {
+        let mut ichimoku = Ichimoku::default();
+
+        let bars: Vec<Bar> = (0..120)
+            .map(|i| {
+                let mid = 100.0 + ((i * 37) % 23) as f64 - (i / 3) as f64;
+                Bar::new()
+                    .high(mid + ((i * 7) % 5) as f64)
+                    .low(mid - ((i * 11) % 4) as f64)
+                    .close(mid)
+            })
+            .collect();
+
+        let mut spans = Vec::new();
+        for (i, bar) in bars.iter().enumerate() {
+            let out = ichimoku.next(bar);
+
+            let tenkan_sen = midpoint(&bars[(i + 1).saturating_sub(9)..=i]);
+            let kijun_sen = midpoint(&bars[(i + 1).saturating_sub(26)..=i]);
+            let senkou_b = midpoint(&bars[(i + 1).saturating_sub(52)..=i]);
+            assert_eq!(out.tenkan_sen, tenkan_sen);
+            assert_eq!(out.kijun_sen, kijun_sen);
+            spans.push(((tenkan_sen + kijun_sen) / 2.0, senkou_b));
+
+            // the spans are displaced by the kijun period
+            assert_eq!((out.senkou_a, out.senkou_b), spans[i.saturating_sub(26)]);
+        }
+    }
+
+    #[test]
+    fn test_reset() {
+        let mut ichimoku = Ichimoku::new(2, 3, 4).unwrap();
+
+        ichimoku.next(&Bar::new().high(12).low(8).close(11));
+        ichimoku.next(&Bar::new().high(15).low(11).close(14));
+        ichimoku.reset();
+
+        let out = ichimoku.next(&Bar::new().high(15).low(11).close(14));
+        assert_eq!(
+            (out.tenkan_sen, out.kijun_sen, out.senkou_a, out.senkou_b),
+            (13.0, 13.0, 13.0, 13.0)
+        );
+    }
+
+    #[test]
+    fn test_period() {
+        assert_eq!(Ichimoku::default().period(), 78);
+    }
+
+    #[test]
+    fn test_ready() {
+        let mut ichimoku = Ichimoku::new(1, 2, 3).unwrap();
+        let bar = Bar::new().high(10).low(8).close(9);
+        for _ in 0..4 {
+            ichimoku.next(&bar);
+        }
+        assert_eq!((ichimoku.bars_seen(), ichimoku.is_ready()), (4, false));
+        // the spans are displaced by the kijun period
+        ichimoku.next(&bar);
+        assert_eq!((ichimoku.bars_seen(), ichimoku.is_ready()), (5, true));
+
+        ichimoku.reset();
+        assert_eq!((ichimoku.bars_seen(), ichimoku.is_ready()), (0, false));
+    }
+
+    #[test]
+    fn test_default() {
+        Ichimoku::default();
+    }
+
+    #[test]
+    fn test_display() {
+        let ichimoku = Ichimoku::new(9, 26, 52).unwrap();
+        assert_eq!(format!("{}", ichimoku), "ICHIMOKU(9, 26, 52)");
+    }
+}
@@ -0,0 +1,291 @@
+use std::fmt;
+
+use crate::errors::{Result, TaError};
+use crate::indicators::EfficiencyRatio;
+use crate::{Close, Current, Next, Period, Ready, Reset};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Kaufman's adaptive moving average (KAMA).
+///
+/// A moving average developed by Perry Kaufman that adapts its smoothing to the market noise.
+/// When the price moves efficiently in one direction KAMA follows it closely, when the price
+/// moves sideways KAMA flattens.
+///
+/// # Formula
+///
+/// SC<sub>t</sub> = (ER<sub>t</sub> * (fast SC - slow SC) + slow SC)<sup>2</sup>
+///
+/// KAMA<sub>t</sub> = KAMA<sub>t-1</sub> + SC<sub>t</sub> * (price<sub>t</sub> - KAMA<sub>t-1</sub>)
+///
+/// Where:
+///
+/// * _ER_ - [efficiency ratio](struct.EfficiencyRatio.html) of the last _er_period_ price
+///   changes, 0 when the price did not move
+/// * _fast SC_ = 2 / (fast_period + 1)
+/// * _slow SC_ = 2 / (slow_period + 1)
+///
+/// The first KAMA value is the first price.
+///
+/// # Parameters
+///
+/// * _er_period_ - number of price changes for the efficiency ratio (integer greater than 0).
+///   Default is 10.
+/// * _fast_period_ - period of the fastest EMA (integer greater than 0). Default is 2.
+/// * _slow_period_ - period of the slowest EMA (integer not less than _fast_period_).
+///   Default is 30.
+///
+/// # Example
+///
+/// ```
+/// use ta::indicators::KaufmanAdaptiveMovingAverage;
+/// use ta::Next;
+///
+/// let mut kama = KaufmanAdaptiveMovingAverage::new(3, 2, 10).unwrap();
+/// assert_eq!(kama.next(10.0), 10.0);
+/// assert_eq!(kama.next(11.0).round(), 10.0);
+/// assert_eq!(kama.next(12.0).round(), 11.0);
+/// ```
+///
+/// # Links
+///
+/// * [KAMA, StockCharts](https://school.stockcharts.com/doku.php?id=technical_indicators:kaufman_s_adaptive_moving_average)
+///
+#[doc(alias = "KAMA")]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone)]
+pub struct KaufmanAdaptiveMovingAverage {
+    er_period: usize,
+    fast_period: usize,
+    slow_period: usize,
+    fast_sc: f64,
+    slow_sc: f64,
+    efficiency_ratio: EfficiencyRatio,
+    current: Option<f64>,
+}
+
+impl KaufmanAdaptiveMovingAverage {
+    pub fn new(er_period: usize, fast_period: usize, slow_period: usize) -> Result<Self> {
+        if er_period == 0 {
+            return Err(TaError::invalid_parameter("er_period", er_period));
+        }
+        if fast_period == 0 {
+            return Err(TaError::invalid_parameter("fast_period", fast_period));
+        }
+        if slow_period < fast_period {
+            return Err(TaError::invalid_parameter("slow_period", slow_period));
+        }
+        Ok(Self {
+            er_period,
+            fast_period,
+            slow_period,
+            fast_sc: 2.0 / (fast_period + 1) as f64,
+            slow_sc: 2.0 / (slow_period + 1) as f64,
+            efficiency_ratio: EfficiencyRatio::new(er_period)?,
+            current: None,
+        })
+    }
+}
+
+impl Period for KaufmanAdaptiveMovingAverage {
+    fn period(&self) -> usize {
+        self.er_period
+    }
+}
+
+impl Next<f64> for KaufmanAdaptiveMovingAverage {
+    type Output = f64;
+
+    fn next(&mut self, input: f64) -> Self::Output {
+        let er = self.efficiency_ratio.next(input);
+        // no price movement at all in the window
+        let er = if er.is_nan() { 0.0 } else { er };
+
+        let kama = match self.current {
+            None => input,
+            Some(prev) => {
+                let sc = (er * (self.fast_sc - self.slow_sc) + self.slow_sc).powi(2);
+                prev + sc * (input - prev)
+            }
+        };
+        self.current = Some(kama);
+        kama
+    }
+}
+
+impl<T: Close> Next<&T> for KaufmanAdaptiveMovingAverage {
+    type Output = f64;
+
+    fn next(&mut self, input: &T) -> Self::Output {
+        self.next(input.close())
+    }
+}
+
+impl Current for KaufmanAdaptiveMovingAverage {
+    type Output = f64;
+
+    fn current(&self) -> Option<Self::Output> {
+        self.current
+    }
+}
+
+impl Ready for KaufmanAdaptiveMovingAverage {
+    fn bars_seen(&self) -> usize {
+        self.efficiency_ratio.bars_seen()
+    }
+
+    fn is_ready(&self) -> bool {
+        self.efficiency_ratio.is_ready()
+    }
+}
+
+impl Reset for KaufmanAdaptiveMovingAverage {
+    fn reset(&mut self) {
+        self.efficiency_ratio.reset();
+        self.current = None;
+    }
+}
+
+impl Default for KaufmanAdaptiveMovingAverage {
+    fn default() -> Self {
+        Self::new(10, 2, 30).unwrap()
+    }
+}
+
+impl fmt::Display for KaufmanAdaptiveMovingAverage {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "KAMA({}, {}, {})",
+            self.er_period, self.fast_period, self.slow_period
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helper::*;
+
+    test_indicator!(KaufmanAdaptiveMovingAverage);
+
+    #[test]
+    fn test_new() {
+        assert!(KaufmanAdaptiveMovingAverage::new(0, 2, 30).is_err());
+        assert!(KaufmanAdaptiveMovingAverage::new(10, 0, 30).is_err());
+        assert!(KaufmanAdaptiveMovingAverage::new(10, 30, 2).is_err());
+        assert!(KaufmanAdaptiveMovingAverage::new(1, 1, 1).is_ok());
+        assert!(KaufmanAdaptiveMovingAverage::new(10, 2, 30).is_ok());
+    }
+
+    #[test]
+    fn test_next() {
+        let mut kama = KaufmanAdaptiveMovingAverage::new(3, 2, 10).unwrap();
+
+        let data = [
+            (10.0, 10.0),
+            (11.0, 10.444),
+            (12.0, 11.136),
+            (11.0, 11.12),
+            (13.0, 11.458),
+            (14.0, 11.916),
+            (13.0, 12.111),
+            (13.0, 12.14),
+            (15.0, 12.478),
+            (16.0, 14.043),
+        ];
+
+        for (input, expected) in data {
+            assert_eq!(round(kama.next(input)), expected);
+        }
+    }
+
+    #[test]
+    fn test_next_trend() {
+        let mut kama = KaufmanAdaptiveMovingAverage::default();
+
+        let mut value = 0.0;
+        for i in 0..50 {
+            value = kama.next(100.0 + i as f64);
+        }
+
+        // efficiency ratio is 1, KAMA lags the price of 149 by a couple of points only
+        assert!(149.0 - value < 2.0);
+    }
+
+    #[test]
+    fn test_next_choppy() {
+        let mut kama = KaufmanAdaptiveMovingAverage::default();
+
+        for i in 0..50 {
+            let input = if i % 2 == 0 { 100.0 } else { 102.0 };
+            kama.next(input);
+        }
+
+        // choppy input has an efficiency ratio close to 0, KAMA barely moves
+        let a = kama.next(100.0);
+        let b = kama.next(102.0);
+        let c = kama.next(100.0);
+        assert!((b - a).abs() < 0.01);
+        assert!((c - b).abs() < 0.01);
+    }
+
+    #[test]
+    fn test_next_flat() {
+        let mut kama = KaufmanAdaptiveMovingAverage::new(3, 2, 10).unwrap();
+        for _ in 0..10 {
+            assert_eq!(kama.next(5.0), 5.0);
+        }
+    }
+
+    #[test]
+    fn test_reset() {
+        let mut kama = KaufmanAdaptiveMovingAverage::new(3, 2, 10).unwrap();
+
+        kama.next(10.0);
+        kama.next(20.0);
+        kama.reset();
+
+        assert_eq!(kama.next(10.0), 10.0);
+        assert_eq!(round(kama.next(11.0)), 10.444);
+    }
+
+    #[test]
+    fn test_current() {
+        let mut kama = KaufmanAdaptiveMovingAverage::default();
+        assert_eq!(kama.current(), None);
+
+        for input in [2.0, 3.0, 4.2, 7.0, 6.7, 6.5] {
+            let value = kama.next(input);
+            assert_eq!(kama.current(), Some(value));
+        }
+
+        kama.reset();
+        assert_eq!(kama.current(), None);
+    }
+
+    #[test]
+    fn test_ready() {
+        let mut kama = KaufmanAdaptiveMovingAverage::new(3, 2, 30).unwrap();
+        for _ in 0..3 {
+            kama.next(1.0);
+        }
+        assert_eq!((kama.bars_seen(), kama.is_ready()), (3, false));
+        kama.next(1.0);
+        assert_eq!((kama.bars_seen(), kama.is_ready()), (4, true));
+
+        kama.reset();
+        assert_eq!((kama.bars_seen(), kama.is_ready()), (0, false));
+    }
+
+    #[test]
+    fn test_default() {
+        KaufmanAdaptiveMovingAverage::default();
+    }
+
+    #[test]
+    fn test_display() {
+        let kama = KaufmanAdaptiveMovingAverage::new(10, 2, 30).unwrap();
+        assert_eq!(format!("{}", kama), "KAMA(10, 2, 30)");
+    }
+}
@@ -2,7 +2,7 @@ use std::fmt;
 
 use crate::errors::Result;
 use crate::indicators::{AverageTrueRange, ExponentialMovingAverage};
-use crate::{Close, High, Low, Next, Period, Reset};
+use crate::{Close, Current, High, Low, Next, Period, Ready, Reset};
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
@@ -22,6 +22,17 @@ use serde::{Deserialize, Serialize};
 ///  * _KC<sub>Upper Band</sub>_ = EMA + ATR of observation * multipler (usually 2.0)
 ///  * _KC<sub>Lower Band</sub>_ = EMA - ATR of observation * multipler (usually 2.0)
 ///
+/// When the input is a bar, the EMA is calculated from its typical price
+/// (high + low + close) / 3.
+///
+/// # Parameters
+///
+/// * _period_ - period of both EMA and ATR (integer greater than 0). Default is 10.
+/// * _multiplier_ - ATR multiplier. Default is 2.0.
+///
+/// [`KeltnerChannel::with_periods`] accepts separate _ema_period_ and _atr_period_,
+/// the [`Period`] of the channel is then the larger of the two.
+///
 /// # Example
 ///
 ///```
@@ -50,7 +61,6 @@ use serde::{Deserialize, Serialize};
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[derive(Debug, Clone)]
 pub struct KeltnerChannel {
-    period: usize,
     multiplier: f64,
     atr: AverageTrueRange,
     ema: ExponentialMovingAverage,
@@ -65,14 +75,25 @@ pub struct KeltnerChannelOutput {
 
 impl KeltnerChannel {
     pub fn new(period: usize, multiplier: f64) -> Result<Self> {
+        Self::with_periods(period, period, multiplier)
+    }
+
+    pub fn with_periods(ema_period: usize, atr_period: usize, multiplier: f64) -> Result<Self> {
         Ok(Self {
-            period,
             multiplier,
-            atr: AverageTrueRange::new(period)?,
-            ema: ExponentialMovingAverage::new(period)?,
+            atr: AverageTrueRange::new(atr_period)?,
+            ema: ExponentialMovingAverage::new(ema_period)?,
         })
     }
 
+    pub fn ema_period(&self) -> usize {
+        self.ema.period()
+    }
+
+    pub fn atr_period(&self) -> usize {
+        self.atr.period()
+    }
+
     pub fn multiplier(&self) -> f64 {
         self.multiplier
     }
@@ -80,7 +101,7 @@ impl KeltnerChannel {
 
 impl Period for KeltnerChannel {
     fn period(&self) -> usize {
-        self.period
+        self.ema.period().max(self.atr.period())
     }
 }
 
@@ -116,6 +137,31 @@ impl<T: Close + High + Low> Next<&T> for KeltnerChannel {
     }
 }
 
+impl Current for KeltnerChannel {
+    type Output = KeltnerChannelOutput;
+
+    fn current(&self) -> Option<Self::Output> {
+        let average = self.ema.current()?;
+        let atr = self.atr.current()?;
+
+        Some(KeltnerChannelOutput {
+            average,
+            upper: average + atr * self.multiplier,
+            lower: average - atr * self.multiplier,
+        })
+    }
+}
+
+impl Ready for KeltnerChannel {
+    fn bars_seen(&self) -> usize {
+        self.ema.bars_seen()
+    }
+
+    fn is_ready(&self) -> bool {
+        self.atr.is_ready() && self.ema.is_ready()
+    }
+}
+
 impl Reset for KeltnerChannel {
     fn reset(&mut self) {
         self.atr.reset();
@@ -131,7 +177,17 @@ impl Default for KeltnerChannel {
 
 impl fmt::Display for KeltnerChannel {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "KC({}, {})", self.period, self.multiplier)
+        if self.ema.period() == self.atr.period() {
+            write!(f, "KC({}, {})", self.ema.period(), self.multiplier)
+        } else {
+            write!(
+                f,
+                "KC({}, {}, {})",
+                self.ema.period(),
+                self.atr.period(),
+                self.multiplier
+            )
+        }
     }
 }
 
@@ -149,6 +205,41 @@ mod tests {
         assert!(KeltnerChannel::new(2, 2_f64).is_ok());
     }
 
+    #[test]
+    fn test_with_periods() {
+        assert!(KeltnerChannel::with_periods(0, 10, 2_f64).is_err());
+        assert!(KeltnerChannel::with_periods(20, 0, 2_f64).is_err());
+
+        let kc = KeltnerChannel::with_periods(20, 10, 2_f64).unwrap();
+        assert_eq!(kc.ema_period(), 20);
+        assert_eq!(kc.atr_period(), 10);
+        assert_eq!(kc.period(), 20);
+
+        let kc = KeltnerChannel::with_periods(5, 10, 2_f64).unwrap();
+        assert_eq!(kc.period(), 10);
+    }
+
+    #[test]
+    fn test_next_bands() {
+        let mut kc = KeltnerChannel::with_periods(5, 3, 2.0_f64).unwrap();
+
+        let mut prev_width = 0.0;
+        for i in 0..6 {
+            let close = 10.0 + i as f64 * 0.1;
+            let bar = Bar::new().high(close + 0.5).low(close - 0.5).close(close);
+            let out = kc.next(&bar);
+            assert!(out.upper > out.average);
+            assert!(out.average > out.lower);
+            prev_width = out.upper - out.lower;
+        }
+
+        // true range spikes, the channel widens
+        let out = kc.next(&Bar::new().high(13).low(9).close(11));
+        assert!(out.upper > out.average);
+        assert!(out.average > out.lower);
+        assert!(out.upper - out.lower > prev_width);
+    }
+
     #[test]
     fn test_next() {
         let mut kc = KeltnerChannel::new(3, 2.0_f64).unwrap();
@@ -224,6 +315,41 @@ mod tests {
         assert_eq!(out.upper, 3.0);
     }
 
+    #[test]
+    fn test_current() {
+        let bars = [
+            Bar::new().high(10).low(7.5).close(9),
+            Bar::new().high(11).low(9).close(9.5),
+            Bar::new().high(9).low(5).close(8),
+        ];
+
+        let mut kc = KeltnerChannel::new(3, 2.0).unwrap();
+        assert_eq!(kc.current(), None);
+
+        for bar in &bars {
+            let value = kc.next(bar);
+            assert_eq!(kc.current(), Some(value));
+        }
+
+        kc.reset();
+        assert_eq!(kc.current(), None);
+    }
+
+    #[test]
+    fn test_ready() {
+        let mut kc = KeltnerChannel::new(3, 2.0).unwrap();
+        let bar = Bar::new().high(10).low(8).close(9);
+        for _ in 0..2 {
+            kc.next(&bar);
+        }
+        assert_eq!((kc.bars_seen(), kc.is_ready()), (2, false));
+        kc.next(&bar);
+        assert_eq!((kc.bars_seen(), kc.is_ready()), (3, true));
+
+        kc.reset();
+        assert_eq!((kc.bars_seen(), kc.is_ready()), (0, false));
+    }
+
     #[test]
     fn test_default() {
         KeltnerChannel::default();
@@ -233,5 +359,8 @@ mod tests {
     fn test_display() {
         let kc = KeltnerChannel::new(10, 3.0_f64).unwrap();
         assert_eq!(format!("{}", kc), "KC(10, 3)");
+
+        let kc = KeltnerChannel::with_periods(20, 10, 2.5_f64).unwrap();
+        assert_eq!(format!("{}", kc), "KC(20, 10, 2.5)");
     }
 }
@@ -0,0 +1,327 @@
+use std::fmt;
+
+use crate::errors::{Result, TaError};
+use crate::indicators::ExponentialMovingAverage as Ema;
+use crate::{Close, High, Low, Next, Period, Ready, Reset, Volume};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Klinger Volume Oscillator (KVO).
+///
+/// Developed by Stephen Klinger, the oscillator compares the volume flowing in and out of a
+/// security over a long and a short term. The volume of every bar is weighted by the direction
+/// of the trend and by how the bar's range relates to the range accumulated during the trend.
+///
+/// # Formula
+///
+/// Trend = +1 if (high + low + close) > previous (high + low + close), -1 otherwise
+///
+/// DM = high - low
+///
+/// CM = CM<sub>t-1</sub> + DM if the trend is unchanged, DM<sub>t-1</sub> + DM otherwise
+///
+/// VF = volume * |2 * (DM / CM - 1)| * trend * 100
+///
+/// KVO = EMA(fast) of VF - EMA(slow) of VF
+///
+/// Signal = EMA(signal) of KVO
+///
+/// Where _EMA_ is the [exponential moving average](struct.ExponentialMovingAverage.html).
+/// The first bar has no previous bar, its volume force is 0, as is the volume force of a bar
+/// with a CM of 0.
+///
+/// # Parameters
+///
+/// * _fast_ - period of the fast EMA (integer greater than 0). Default is 34.
+/// * _slow_ - period of the slow EMA (integer greater than or equal to _fast_). Default is 55.
+/// * _signal_ - period of the signal EMA (integer greater than 0). Default is 13.
+///
+/// # Example
+///
+/// ```
+/// use ta::indicators::KlingerOscillator;
+/// use ta::{DataItem, Next};
+///
+/// let mut kvo = KlingerOscillator::new(2, 4, 3).unwrap();
+///
+/// let di1 = DataItem::builder()
+///     .open(10.0).high(11.0).low(9.0).close(10.0).volume(1000.0)
+///     .build(true).unwrap();
+/// let di2 = DataItem::builder()
+///     .open(10.0).high(12.0).low(10.0).close(11.0).volume(1200.0)
+///     .build(true).unwrap();
+///
+/// assert_eq!(kvo.next(&di1).kvo, 0.0);
+/// assert_eq!(kvo.next(&di2).kvo.round(), 32000.0);
+/// ```
+///
+/// # Links
+///
+/// * [Klinger Oscillator, StockCharts](https://school.stockcharts.com/doku.php?id=technical_indicators:klinger_oscillator)
+///
+#[doc(alias = "KVO")]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone)]
+pub struct KlingerOscillator {
+    fast_ema: Ema,
+    slow_ema: Ema,
+    signal_ema: Ema,
+    prev: Option<VolumeForceState>,
+}
+
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone)]
+struct VolumeForceState {
+    hlc: f64,
+    dm: f64,
+    cm: f64,
+    trend: f64,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct KlingerOscillatorOutput {
+    pub kvo: f64,
+    pub signal: f64,
+}
+
+impl From<KlingerOscillatorOutput> for (f64, f64) {
+    fn from(kvo: KlingerOscillatorOutput) -> Self {
+        (kvo.kvo, kvo.signal)
+    }
+}
+
+impl KlingerOscillator {
+    pub fn new(fast: usize, slow: usize, signal: usize) -> Result<Self> {
+        if fast > slow {
+            return Err(TaError::invalid_parameter("slow", slow));
+        }
+        Ok(Self {
+            fast_ema: Ema::new(fast)?,
+            slow_ema: Ema::new(slow)?,
+            signal_ema: Ema::new(signal)?,
+            prev: None,
+        })
+    }
+}
+
+impl Period for KlingerOscillator {
+    fn period(&self) -> usize {
+        self.slow_ema.period()
+    }
+}
+
+impl<T: High + Low + Close + Volume> Next<&T> for KlingerOscillator {
+    type Output = KlingerOscillatorOutput;
+
+    fn next(&mut self, input: &T) -> Self::Output {
+        let hlc = input.high() + input.low() + input.close();
+        let dm = input.high() - input.low();
+
+        let (force, state) = match self.prev.take() {
+            Some(prev) => {
+                let trend = if hlc > prev.hlc { 1.0 } else { -1.0 };
+                let cm = if trend == prev.trend {
+                    prev.cm + dm
+                } else {
+                    prev.dm + dm
+                };
+                let force = if cm > 0.0 {
+                    input.volume() * (2.0 * (dm / cm - 1.0)).abs() * trend * 100.0
+                } else {
+                    0.0
+                };
+                (force, VolumeForceState { hlc, dm, cm, trend })
+            }
+            // no trend yet, the next bar starts a new one
+            None => (
+                0.0,
+                VolumeForceState {
+                    hlc,
+                    dm,
+                    cm: dm,
+                    trend: 0.0,
+                },
+            ),
+        };
+        self.prev = Some(state);
+
+        let kvo = self.fast_ema.next(force) - self.slow_ema.next(force);
+        KlingerOscillatorOutput {
+            kvo,
+            signal: self.signal_ema.next(kvo),
+        }
+    }
+}
+
+impl Ready for KlingerOscillator {
+    fn bars_seen(&self) -> usize {
+        self.slow_ema.bars_seen()
+    }
+
+    fn is_ready(&self) -> bool {
+        self.fast_ema.is_ready() && self.slow_ema.is_ready() && self.signal_ema.is_ready()
+    }
+}
+
+impl Reset for KlingerOscillator {
+    fn reset(&mut self) {
+        self.fast_ema.reset();
+        self.slow_ema.reset();
+        self.signal_ema.reset();
+        self.prev = None;
+    }
+}
+
+impl Default for KlingerOscillator {
+    fn default() -> Self {
+        Self::new(34, 55, 13).unwrap()
+    }
+}
+
+impl fmt::Display for KlingerOscillator {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "KVO({}, {}, {})",
+            self.fast_ema.period(),
+            self.slow_ema.period(),
+            self.signal_ema.period()
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helper::*;
+
+    fn round(kvo: KlingerOscillatorOutput) -> (f64, f64) {
+        (
+            crate::test_helper::round(kvo.kvo),
+            crate::test_helper::round(kvo.signal),
+        )
+    }
+
+    #[test]
+    fn test_new() {
+        assert!(KlingerOscillator::new(0, 55, 13).is_err());
+        assert!(KlingerOscillator::new(55, 34, 13).is_err());
+        assert!(KlingerOscillator::new(34, 55, 0).is_err());
+        assert!(KlingerOscillator::new(34, 55, 13).is_ok());
+    }
+
+    #[test]
+    fn test_next() {
+        let mut kvo = KlingerOscillator::new(2, 4, 3).unwrap();
+
+        let bars = [
+            Bar::new().high(11).low(9).close(10).volume(1000.0),
+            // uptrend, vf = 120000
+            Bar::new().high(12).low(10).close(11).volume(1200.0),
+            // the trend continues, cm = 4, vf = 106666.667
+            Bar::new().high(13).low(11).close(12).volume(800.0),
+            // the trend turns, cm = 4, vf = -150000
+            Bar::new().high(12).low(10).close(11).volume(1500.0),
+            Bar::new().high(12).low(10).close(11.5).volume(1000.0),
+            Bar::new().high(13).low(11).close(12.5).volume(2000.0),
+        ];
+        let expected = [
+            (0.0, 0.0),
+            (32000.0, 16000.0),
+            (26311.111, 21155.556),
+            (-50287.407, -14565.926),
+            (14469.531, -48.198),
+            (68006.821, 33979.312),
+        ];
+
+        for (bar, expected) in bars.iter().zip(expected) {
+            assert_eq!(round(kvo.next(bar)), expected);
+        }
+    }
+
+    #[test]
+    fn test_next_volume_surge() {
+        let mut kvo = KlingerOscillator::default();
+        let mut calm = KlingerOscillator::default();
+
+        for i in 0..60 {
+            let price = 100.0 + i as f64;
+            let bar = Bar::new()
+                .high(price + 1.0)
+                .low(price - 1.0)
+                .close(price)
+                .volume(1000.0);
+            assert!(kvo.next(&bar).kvo >= 0.0);
+            calm.next(&bar);
+        }
+
+        // a down bar on a volume surge flips the sign, the same bar on usual volume does not
+        assert!(
+            kvo.next(&Bar::new().high(159).low(155).close(156).volume(20000.0))
+                .kvo
+                < 0.0
+        );
+        assert!(
+            calm.next(&Bar::new().high(159).low(155).close(156).volume(1000.0))
+                .kvo
+                > 0.0
+        );
+    }
+
+    #[test]
+    fn test_next_no_range() {
+        let mut kvo = KlingerOscillator::new(2, 4, 3).unwrap();
+
+        for _ in 0..5 {
+            assert_eq!(
+                round(kvo.next(&Bar::new().high(10).low(10).close(10).volume(1000.0))),
+                (0.0, 0.0)
+            );
+        }
+    }
+
+    #[test]
+    fn test_reset() {
+        let mut kvo = KlingerOscillator::new(2, 4, 3).unwrap();
+
+        kvo.next(&Bar::new().high(11).low(9).close(10).volume(1000.0));
+        kvo.next(&Bar::new().high(12).low(10).close(11).volume(1200.0));
+        kvo.reset();
+
+        assert_eq!(
+            round(kvo.next(&Bar::new().high(11).low(9).close(10).volume(1000.0))),
+            (0.0, 0.0)
+        );
+        assert_eq!(
+            round(kvo.next(&Bar::new().high(12).low(10).close(11).volume(1200.0))),
+            (32000.0, 16000.0)
+        );
+    }
+
+    #[test]
+    fn test_ready() {
+        let mut kvo = KlingerOscillator::new(2, 4, 3).unwrap();
+        let bar = Bar::new().high(10).low(8).close(9).volume(100.0);
+        for _ in 0..3 {
+            kvo.next(&bar);
+        }
+        assert_eq!((kvo.bars_seen(), kvo.is_ready()), (3, false));
+        // the slow EMA is the last to fill its period
+        kvo.next(&bar);
+        assert_eq!((kvo.bars_seen(), kvo.is_ready()), (4, true));
+
+        kvo.reset();
+        assert_eq!((kvo.bars_seen(), kvo.is_ready()), (0, false));
+    }
+
+    #[test]
+    fn test_default() {
+        KlingerOscillator::default();
+    }
+
+    #[test]
+    fn test_display() {
+        let kvo = KlingerOscillator::new(34, 55, 13).unwrap();
+        assert_eq!(format!("{}", kvo), "KVO(34, 55, 13)");
+    }
+}
@@ -0,0 +1,271 @@
+use std::fmt;
+
+use crate::errors::Result;
+use crate::indicators::{RateOfChange, SimpleMovingAverage};
+use crate::{Close, Next, Period, Ready, Reset};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Know Sure Thing (KST).
+///
+/// Developed by Martin Pring, the KST is a momentum oscillator combining four rates of change
+/// over different time frames. Each ROC is smoothed and weighted, the longer time frames
+/// weigh more. A cross of the KST and its signal line signals a change of the momentum.
+///
+/// # Formula
+///
+/// KST = 1 * SMA(sma<sub>1</sub>) of ROC(roc<sub>1</sub>) + 2 * SMA(sma<sub>2</sub>) of ROC(roc<sub>2</sub>)
+///     + 3 * SMA(sma<sub>3</sub>) of ROC(roc<sub>3</sub>) + 4 * SMA(sma<sub>4</sub>) of ROC(roc<sub>4</sub>)
+///
+/// Signal = SMA(signal) of KST
+///
+/// Where:
+///
+/// * _ROC_ - [rate of change](struct.RateOfChange.html)
+/// * _SMA_ - [simple moving average](struct.SimpleMovingAverage.html)
+///
+/// # Parameters
+///
+/// * _roc_periods_ - periods of the four ROCs (integers greater than 0). Default is
+///   [10, 15, 20, 30].
+/// * _sma_periods_ - periods of the SMAs smoothing each ROC (integers greater than 0). Default
+///   is [10, 10, 10, 15].
+/// * _signal_period_ - period of the signal SMA (integer greater than 0). Default is 9.
+///
+/// # Example
+///
+/// ```
+/// use ta::indicators::KnowSureThing;
+/// use ta::Next;
+///
+/// let mut kst = KnowSureThing::new([1, 2, 3, 4], [1, 1, 1, 1], 2).unwrap();
+///
+/// let out = kst.next(10.0);
+/// assert_eq!((out.kst, out.signal), (0.0, 0.0));
+///
+/// // every ROC compares 11 to 10
+/// let out = kst.next(11.0);
+/// assert_eq!((out.kst.round(), out.signal.round()), (100.0, 50.0));
+/// ```
+///
+/// # Links
+///
+/// * [Know Sure Thing, StockCharts](https://school.stockcharts.com/doku.php?id=technical_indicators:know_sure_thing_kst)
+///
+#[doc(alias = "KST")]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone)]
+pub struct KnowSureThing {
+    components: [(RateOfChange, SimpleMovingAverage); 4],
+    signal: SimpleMovingAverage,
+}
+
+impl KnowSureThing {
+    pub fn new(
+        roc_periods: [usize; 4],
+        sma_periods: [usize; 4],
+        signal_period: usize,
+    ) -> Result<Self> {
+        let component = |i: usize| -> Result<(RateOfChange, SimpleMovingAverage)> {
+            Ok((
+                RateOfChange::new(roc_periods[i])?,
+                SimpleMovingAverage::new(sma_periods[i])?,
+            ))
+        };
+
+        Ok(Self {
+            components: [component(0)?, component(1)?, component(2)?, component(3)?],
+            signal: SimpleMovingAverage::new(signal_period)?,
+        })
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct KnowSureThingOutput {
+    pub kst: f64,
+    pub signal: f64,
+}
+
+impl From<KnowSureThingOutput> for (f64, f64) {
+    fn from(kst: KnowSureThingOutput) -> Self {
+        (kst.kst, kst.signal)
+    }
+}
+
+/// The longest of the ROC periods.
+impl Period for KnowSureThing {
+    fn period(&self) -> usize {
+        self.components
+            .iter()
+            .map(|(roc, _)| roc.period())
+            .max()
+            .unwrap()
+    }
+}
+
+impl Next<f64> for KnowSureThing {
+    type Output = KnowSureThingOutput;
+
+    fn next(&mut self, input: f64) -> Self::Output {
+        let mut kst = 0.0;
+        for (weight, (roc, sma)) in self.components.iter_mut().enumerate() {
+            kst += (weight + 1) as f64 * sma.next(roc.next(input));
+        }
+
+        KnowSureThingOutput {
+            kst,
+            signal: self.signal.next(kst),
+        }
+    }
+}
+
+impl<T: Close> Next<&T> for KnowSureThing {
+    type Output = KnowSureThingOutput;
+
+    fn next(&mut self, input: &T) -> Self::Output {
+        self.next(input.close())
+    }
+}
+
+impl Ready for KnowSureThing {
+    fn bars_seen(&self) -> usize {
+        self.components[0].0.bars_seen()
+    }
+
+    fn is_ready(&self) -> bool {
+        self.components
+            .iter()
+            .all(|(roc, sma)| roc.is_ready() && sma.is_ready())
+            && self.signal.is_ready()
+    }
+}
+
+impl Reset for KnowSureThing {
+    fn reset(&mut self) {
+        for (roc, sma) in self.components.iter_mut() {
+            roc.reset();
+            sma.reset();
+        }
+        self.signal.reset();
+    }
+}
+
+impl Default for KnowSureThing {
+    fn default() -> Self {
+        Self::new([10, 15, 20, 30], [10, 10, 10, 15], 9).unwrap()
+    }
+}
+
+impl fmt::Display for KnowSureThing {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let [(roc1, sma1), (roc2, sma2), (roc3, sma3), (roc4, sma4)] = &self.components;
+        write!(
+            f,
+            "KST({}, {}, {}, {}, {}, {}, {}, {}, {})",
+            roc1.period(),
+            roc2.period(),
+            roc3.period(),
+            roc4.period(),
+            sma1.period(),
+            sma2.period(),
+            sma3.period(),
+            sma4.period(),
+            self.signal.period()
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helper::*;
+
+    test_indicator!(KnowSureThing);
+
+    fn round(nums: (f64, f64)) -> (f64, f64) {
+        (
+            crate::test_helper::round(nums.0),
+            crate::test_helper::round(nums.1),
+        )
+    }
+
+    #[test]
+    fn test_new() {
+        assert!(KnowSureThing::new([0, 15, 20, 30], [10, 10, 10, 15], 9).is_err());
+        assert!(KnowSureThing::new([10, 15, 20, 30], [10, 10, 0, 15], 9).is_err());
+        assert!(KnowSureThing::new([10, 15, 20, 30], [10, 10, 10, 15], 0).is_err());
+        assert!(KnowSureThing::new([10, 15, 20, 30], [10, 10, 10, 15], 9).is_ok());
+    }
+
+    #[test]
+    fn test_next() {
+        let mut kst = KnowSureThing::new([1, 2, 3, 4], [1, 1, 2, 2], 2).unwrap();
+
+        assert_eq!(round(kst.next(10.0).into()), (0.0, 0.0));
+        assert_eq!(round(kst.next(11.0).into()), (65.0, 32.5));
+        assert_eq!(round(kst.next(12.0).into()), (154.091, 109.545));
+        assert_eq!(round(kst.next(11.0).into()), (96.667, 125.379));
+        assert_eq!(round(kst.next(10.0).into()), (-21.061, 37.803));
+    }
+
+    #[test]
+    fn test_next_crossover() {
+        let mut kst = KnowSureThing::default();
+
+        // a cycle with its trough at bar 47 and its peak at bar 79
+        let histogram: Vec<f64> = (0..100)
+            .map(|i| {
+                let out = kst.next(100.0 + 10.0 * (i as f64 / 10.0).sin());
+                out.kst - out.signal
+            })
+            .collect();
+
+        // the KST crosses above its signal after the trough and below after the peak
+        assert!(histogram[51] < 0.0);
+        assert!(histogram[52..83].iter().all(|&value| value > 0.0));
+        assert!(histogram[83] < 0.0);
+    }
+
+    #[test]
+    fn test_reset() {
+        let mut kst = KnowSureThing::new([1, 2, 3, 4], [1, 1, 2, 2], 2).unwrap();
+
+        kst.next(10.0);
+        kst.next(20.0);
+        kst.reset();
+
+        assert_eq!(round(kst.next(10.0).into()), (0.0, 0.0));
+        assert_eq!(round(kst.next(11.0).into()), (65.0, 32.5));
+    }
+
+    #[test]
+    fn test_period() {
+        assert_eq!(KnowSureThing::default().period(), 30);
+    }
+
+    #[test]
+    fn test_ready() {
+        let mut kst = KnowSureThing::new([1, 2, 3, 4], [2, 2, 2, 2], 3).unwrap();
+        for _ in 0..4 {
+            kst.next(1.0);
+        }
+        assert_eq!((kst.bars_seen(), kst.is_ready()), (4, false));
+        // the longest rate of change is ready with the fifth input
+        kst.next(1.0);
+        assert_eq!((kst.bars_seen(), kst.is_ready()), (5, true));
+
+        kst.reset();
+        assert_eq!((kst.bars_seen(), kst.is_ready()), (0, false));
+    }
+
+    #[test]
+    fn test_default() {
+        KnowSureThing::default();
+    }
+
+    #[test]
+    fn test_display() {
+        let kst = KnowSureThing::default();
+        assert_eq!(format!("{}", kst), "KST(10, 15, 20, 30, 10, 10, 10, 15, 9)");
+    }
+}
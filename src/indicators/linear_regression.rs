@@ -0,0 +1,302 @@
+use std::fmt;
+
+use crate::errors::{Result, TaError};
+use crate::{Close, Next, Period, Ready, Reset};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Linear regression.
+///
+/// Fits a straight line through the last _period_ values with the least squares method. The
+/// values of the window are placed at _x = 0, 1, ..., n - 1_, the oldest value at 0 and the
+/// current value at _n - 1_.
+///
+/// # Formula
+///
+/// slope = (n * Σxy - Σx * Σy) / (n * Σx<sup>2</sup> - (Σx)<sup>2</sup>)
+///
+/// intercept = (Σy - slope * Σx) / n
+///
+/// forecast = intercept + slope * (n - 1)
+///
+/// Where _n_ is the number of values in the window. The _intercept_ is the value of the line
+/// at the oldest value of the window and the _forecast_ its value at the current one. With a
+/// single value the slope is 0. The sums are updated in constant time per input and rebuilt
+/// from the window once every _period_ inputs, so rounding errors do not accumulate.
+///
+/// # Parameters
+///
+/// * _period_ - number of periods (integer greater than 0). Default is 14.
+///
+/// # Example
+///
+/// ```
+/// use ta::indicators::LinearRegression;
+/// use ta::Next;
+///
+/// let mut linreg = LinearRegression::new(3).unwrap();
+/// linreg.next(1.0);
+/// linreg.next(3.0);
+///
+/// let out = linreg.next(5.0);
+/// assert_eq!(out.slope, 2.0);
+/// assert_eq!(out.intercept, 1.0);
+/// assert_eq!(out.forecast, 5.0);
+/// ```
+///
+/// # Links
+///
+/// * [Linear Regression, Wikipedia](https://en.wikipedia.org/wiki/Simple_linear_regression)
+///
+#[doc(alias = "LINREG")]
+#[doc(alias = "LSMA")]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone)]
+pub struct LinearRegression {
+    period: usize,
+    index: usize,
+    count: usize,
+    sum_y: f64,
+    sum_xy: f64,
+    deque: Box<[f64]>,
+    #[cfg_attr(feature = "serde", serde(default))]
+    bars_seen: usize,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct LinearRegressionOutput {
+    pub slope: f64,
+    pub intercept: f64,
+    pub forecast: f64,
+}
+
+impl From<LinearRegressionOutput> for (f64, f64, f64) {
+    fn from(lr: LinearRegressionOutput) -> Self {
+        (lr.slope, lr.intercept, lr.forecast)
+    }
+}
+
+impl LinearRegression {
+    pub fn new(period: usize) -> Result<Self> {
+        match period {
+            0 => Err(TaError::invalid_parameter("period", period)),
+            _ => Ok(Self {
+                period,
+                index: 0,
+                count: 0,
+                sum_y: 0.0,
+                sum_xy: 0.0,
+                deque: vec![0.0; period].into_boxed_slice(),
+                bars_seen: 0,
+            }),
+        }
+    }
+}
+
+impl Period for LinearRegression {
+    fn period(&self) -> usize {
+        self.period
+    }
+}
+
+impl Next<f64> for LinearRegression {
+    type Output = LinearRegressionOutput;
+
+    fn next(&mut self, input: f64) -> Self::Output {
+        self.bars_seen = self.bars_seen.saturating_add(1);
+        let old_val = self.deque[self.index];
+        self.deque[self.index] = input;
+
+        self.index = if self.index + 1 < self.period {
+            self.index + 1
+        } else {
+            0
+        };
+
+        if self.count < self.period {
+            self.sum_xy += self.count as f64 * input;
+            self.sum_y += input;
+            self.count += 1;
+        } else {
+            // every value but the oldest moves one step to the left
+            self.sum_xy += (self.period - 1) as f64 * input - (self.sum_y - old_val);
+            self.sum_y += input - old_val;
+        }
+
+        if self.index == 0 && self.count == self.period {
+            // the rolling updates accumulate rounding errors, the sum_xy update feeds the
+            // error of sum_y into it on every input, so both are rebuilt once per window. The
+            // window starts over at 0, so the deque is in order.
+            self.sum_y = self.deque.iter().sum();
+            self.sum_xy = self
+                .deque
+                .iter()
+                .enumerate()
+                .map(|(x, &y)| x as f64 * y)
+                .sum();
+        }
+
+        let n = self.count as f64;
+        let sum_x = n * (n - 1.0) / 2.0;
+        let sum_xx = (n - 1.0) * n * (2.0 * n - 1.0) / 6.0;
+        let denominator = n * sum_xx - sum_x * sum_x;
+
+        let slope = if denominator == 0.0 {
+            0.0
+        } else {
+            (n * self.sum_xy - sum_x * self.sum_y) / denominator
+        };
+        let intercept = (self.sum_y - slope * sum_x) / n;
+
+        LinearRegressionOutput {
+            slope,
+            intercept,
+            forecast: intercept + slope * (n - 1.0),
+        }
+    }
+}
+
+impl<T: Close> Next<&T> for LinearRegression {
+    type Output = LinearRegressionOutput;
+
+    fn next(&mut self, input: &T) -> Self::Output {
+        self.next(input.close())
+    }
+}
+
+impl Ready for LinearRegression {
+    fn bars_seen(&self) -> usize {
+        self.bars_seen
+    }
+
+    fn is_ready(&self) -> bool {
+        self.bars_seen >= self.period
+    }
+}
+
+impl Reset for LinearRegression {
+    fn reset(&mut self) {
+        self.bars_seen = 0;
+        self.index = 0;
+        self.count = 0;
+        self.sum_y = 0.0;
+        self.sum_xy = 0.0;
+        for i in 0..self.period {
+            self.deque[i] = 0.0;
+        }
+    }
+}
+
+impl Default for LinearRegression {
+    fn default() -> Self {
+        Self::new(14).unwrap()
+    }
+}
+
+impl fmt::Display for LinearRegression {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "LINREG({})", self.period)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helper::*;
+
+    test_indicator!(LinearRegression);
+
+    fn round(nums: (f64, f64, f64)) -> (f64, f64, f64) {
+        (
+            crate::test_helper::round(nums.0),
+            crate::test_helper::round(nums.1),
+            crate::test_helper::round(nums.2),
+        )
+    }
+
+    #[test]
+    fn test_new() {
+        assert!(LinearRegression::new(0).is_err());
+        assert!(LinearRegression::new(1).is_ok());
+    }
+
+    #[test]
+    fn test_next() {
+        let mut linreg = LinearRegression::new(3).unwrap();
+
+        assert_eq!(round(linreg.next(2.0).into()), (0.0, 2.0, 2.0));
+        assert_eq!(round(linreg.next(4.0).into()), (2.0, 2.0, 4.0));
+        assert_eq!(round(linreg.next(3.0).into()), (0.5, 2.5, 3.5));
+        assert_eq!(round(linreg.next(7.0).into()), (1.5, 3.167, 6.167));
+        assert_eq!(round(linreg.next(5.0).into()), (1.0, 4.0, 6.0));
+    }
+
+    #[test]
+    fn test_next_linear() {
+        let mut linreg = LinearRegression::new(14).unwrap();
+
+        for i in 0..1000 {
+            let price = 50.0 + 0.25 * i as f64;
+            let out = linreg.next(&Bar::new().close(price));
+
+            if i > 0 {
+                assert!((out.slope - 0.25).abs() < 1e-9);
+            }
+            // the line goes through every price
+            assert!((out.forecast - price).abs() < 1e-9);
+        }
+    }
+
+    #[test]
+    fn test_next_long_series() {
+        let mut linreg = LinearRegression::new(14).unwrap();
+
+        // an exact line at a high level over millions of inputs
+        let mut input = 0.0;
+        let mut out = linreg.next(50000.0);
+        for i in 1..2_000_000 {
+            input = 50000.0 + 0.001 * i as f64;
+            out = linreg.next(input);
+        }
+
+        assert!((out.slope - 0.001).abs() < 1e-9);
+        assert!((out.forecast - input).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_reset() {
+        let mut linreg = LinearRegression::new(3).unwrap();
+
+        linreg.next(10.0);
+        linreg.next(20.0);
+        linreg.reset();
+
+        assert_eq!(round(linreg.next(2.0).into()), (0.0, 2.0, 2.0));
+        assert_eq!(round(linreg.next(4.0).into()), (2.0, 2.0, 4.0));
+    }
+
+    #[test]
+    fn test_ready() {
+        let mut lr = LinearRegression::new(3).unwrap();
+        for _ in 0..2 {
+            lr.next(1.0);
+        }
+        assert_eq!((lr.bars_seen(), lr.is_ready()), (2, false));
+        lr.next(1.0);
+        assert_eq!((lr.bars_seen(), lr.is_ready()), (3, true));
+
+        lr.reset();
+        assert_eq!((lr.bars_seen(), lr.is_ready()), (0, false));
+    }
+
+    #[test]
+    fn test_default() {
+        LinearRegression::default();
+    }
+
+    #[test]
+    fn test_display() {
+        let linreg = LinearRegression::new(14).unwrap();
+        assert_eq!(format!("{}", linreg), "LINREG(14)");
+    }
+}
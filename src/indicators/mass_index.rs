@@ -0,0 +1,233 @@
+use std::fmt;
+
+use crate::errors::{Result, TaError};
+use crate::indicators::ExponentialMovingAverage;
+use crate::{High, Low, Next, Period, Ready, Reset};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Mass Index (MI).
+///
+/// Developed by Donald Dorsey, the mass index identifies trend reversals from the widening
+/// and narrowing of the high-low range. A "reversal bulge" occurs when the index rises above
+/// 27 and then falls below 26.5.
+///
+/// # Formula
+///
+/// Ratio = EMA(ema_period) of (high - low) / EMA(ema_period) of EMA(ema_period) of (high - low)
+///
+/// MI = sum of the ratios over the last _sum_period_ inputs
+///
+/// Where _EMA_ is the [exponential moving average](struct.ExponentialMovingAverage.html).
+/// While both EMAs are 0 the ratio is 1.
+///
+/// # Parameters
+///
+/// * _ema_period_ - period of the EMAs (integer greater than 0). Default is 9.
+/// * _sum_period_ - number of ratios to sum (integer greater than 0). Default is 25.
+///
+/// # Example
+///
+/// ```
+/// use ta::indicators::MassIndex;
+/// use ta::{DataItem, Next};
+///
+/// let mut mi = MassIndex::new(9, 25).unwrap();
+///
+/// let di = DataItem::builder()
+///     .open(10.0).high(11.0).low(9.0).close(10.0).volume(1000.0)
+///     .build(true).unwrap();
+///
+/// assert_eq!(mi.next(&di), 1.0);
+/// assert_eq!(mi.next(&di), 2.0);
+/// ```
+///
+/// # Links
+///
+/// * [Mass Index, StockCharts](https://school.stockcharts.com/doku.php?id=technical_indicators:mass_index)
+///
+#[doc(alias = "MI")]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone)]
+pub struct MassIndex {
+    ema: ExponentialMovingAverage,
+    double_ema: ExponentialMovingAverage,
+    sum_period: usize,
+    index: usize,
+    sum: f64,
+    deque: Box<[f64]>,
+}
+
+impl MassIndex {
+    pub fn new(ema_period: usize, sum_period: usize) -> Result<Self> {
+        match sum_period {
+            0 => Err(TaError::invalid_parameter("sum_period", sum_period)),
+            _ => Ok(Self {
+                ema: ExponentialMovingAverage::new(ema_period)?,
+                double_ema: ExponentialMovingAverage::new(ema_period)?,
+                sum_period,
+                index: 0,
+                sum: 0.0,
+                deque: vec![0.0; sum_period].into_boxed_slice(),
+            }),
+        }
+    }
+}
+
+impl Period for MassIndex {
+    fn period(&self) -> usize {
+        self.sum_period
+    }
+}
+
+impl<T: High + Low> Next<&T> for MassIndex {
+    type Output = f64;
+
+    fn next(&mut self, input: &T) -> Self::Output {
+        let ema = self.ema.next(input.high() - input.low());
+        let double_ema = self.double_ema.next(ema);
+        let ratio = if double_ema == 0.0 {
+            1.0
+        } else {
+            ema / double_ema
+        };
+
+        let old_val = self.deque[self.index];
+        self.deque[self.index] = ratio;
+        self.index = if self.index + 1 < self.sum_period {
+            self.index + 1
+        } else {
+            0
+        };
+
+        self.sum = self.sum - old_val + ratio;
+        self.sum
+    }
+}
+
+impl Ready for MassIndex {
+    fn bars_seen(&self) -> usize {
+        self.ema.bars_seen()
+    }
+
+    fn is_ready(&self) -> bool {
+        self.double_ema.is_ready() && self.ema.bars_seen() >= self.sum_period
+    }
+}
+
+impl Reset for MassIndex {
+    fn reset(&mut self) {
+        self.ema.reset();
+        self.double_ema.reset();
+        self.index = 0;
+        self.sum = 0.0;
+        for i in 0..self.sum_period {
+            self.deque[i] = 0.0;
+        }
+    }
+}
+
+impl Default for MassIndex {
+    fn default() -> Self {
+        Self::new(9, 25).unwrap()
+    }
+}
+
+impl fmt::Display for MassIndex {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "MI({}, {})", self.ema.period(), self.sum_period)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helper::*;
+
+    #[test]
+    fn test_new() {
+        assert!(MassIndex::new(0, 25).is_err());
+        assert!(MassIndex::new(9, 0).is_err());
+        assert!(MassIndex::new(9, 25).is_ok());
+    }
+
+    #[test]
+    fn test_next() {
+        let mut mi = MassIndex::new(2, 3).unwrap();
+
+        assert_eq!(round(mi.next(&Bar::new().high(12).low(10))), 1.0);
+        assert_eq!(round(mi.next(&Bar::new().high(12).low(10))), 2.0);
+        assert_eq!(round(mi.next(&Bar::new().high(14).low(10))), 3.154);
+        assert_eq!(round(mi.next(&Bar::new().high(11).low(10))), 2.981);
+    }
+
+    #[test]
+    fn test_next_reversal_bulge() {
+        let mut mi = MassIndex::default();
+
+        // a calm market, then the range widens for 8 bars and calms down again
+        let ranges = [1.0; 40]
+            .into_iter()
+            .chain((1..9).map(|i| 1.0 + 0.5 * i as f64))
+            .chain([1.0; 30]);
+        let values: Vec<f64> = ranges
+            .map(|range| mi.next(&Bar::new().high(10.0 + range).low(10)))
+            .collect();
+
+        assert_eq!(round(values[39]), 25.0);
+        // the bulge rises above 27 ...
+        assert!(values[..46].iter().all(|&value| value < 27.0));
+        assert!(values[46] > 27.0);
+        // ... and completes when it falls back below 26.5
+        assert!(values[46..59].iter().all(|&value| value > 26.5));
+        assert!(values[59] < 26.5);
+    }
+
+    #[test]
+    fn test_next_zero_range() {
+        let mut mi = MassIndex::new(9, 3).unwrap();
+
+        assert_eq!(mi.next(&Bar::new().high(10).low(10)), 1.0);
+        assert_eq!(mi.next(&Bar::new().high(10).low(10)), 2.0);
+    }
+
+    #[test]
+    fn test_reset() {
+        let mut mi = MassIndex::new(2, 3).unwrap();
+
+        mi.next(&Bar::new().high(12).low(10));
+        mi.next(&Bar::new().high(15).low(10));
+        mi.reset();
+
+        assert_eq!(round(mi.next(&Bar::new().high(12).low(10))), 1.0);
+        assert_eq!(round(mi.next(&Bar::new().high(12).low(10))), 2.0);
+        assert_eq!(round(mi.next(&Bar::new().high(14).low(10))), 3.154);
+    }
+
+    #[test]
+    fn test_ready() {
+        let mut mi = MassIndex::new(2, 3).unwrap();
+        let bar = Bar::new().high(10).low(8).close(9);
+        for _ in 0..2 {
+            mi.next(&bar);
+        }
+        assert_eq!((mi.bars_seen(), mi.is_ready()), (2, false));
+        // the third input fills the sum
+        mi.next(&bar);
+        assert_eq!((mi.bars_seen(), mi.is_ready()), (3, true));
+
+        mi.reset();
+        assert_eq!((mi.bars_seen(), mi.is_ready()), (0, false));
+    }
+
+    #[test]
+    fn test_default() {
+        MassIndex::default();
+    }
+
+    #[test]
+    fn test_display() {
+        let mi = MassIndex::new(9, 25).unwrap();
+        assert_eq!(format!("{}", mi), "MI(9, 25)");
+    }
+}
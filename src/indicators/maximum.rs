@@ -1,7 +1,7 @@
 use std::fmt;
 
 use crate::errors::{Result, TaError};
-use crate::{High, Next, Period, Reset};
+use crate::{Current, High, Next, Period, Ready, Reset};
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
@@ -31,17 +31,20 @@ pub struct Maximum {
     max_index: usize,
     cur_index: usize,
     deque: Box<[f64]>,
+    #[cfg_attr(feature = "serde", serde(default))]
+    bars_seen: usize,
 }
 
 impl Maximum {
     pub fn new(period: usize) -> Result<Self> {
         match period {
-            0 => Err(TaError::InvalidParameter),
+            0 => Err(TaError::invalid_parameter("period", period)),
             _ => Ok(Self {
                 period,
                 max_index: 0,
                 cur_index: 0,
                 deque: vec![f64::NEG_INFINITY; period].into_boxed_slice(),
+                bars_seen: 0,
             }),
         }
     }
@@ -71,6 +74,7 @@ impl Next<f64> for Maximum {
     type Output = f64;
 
     fn next(&mut self, input: f64) -> Self::Output {
+        self.bars_seen = self.bars_seen.saturating_add(1);
         self.deque[self.cur_index] = input;
 
         if input > self.deque[self.max_index] {
@@ -97,8 +101,30 @@ impl<T: High> Next<&T> for Maximum {
     }
 }
 
+impl Current for Maximum {
+    type Output = f64;
+
+    fn current(&self) -> Option<Self::Output> {
+        match self.bars_seen {
+            0 => None,
+            _ => Some(self.deque[self.max_index]),
+        }
+    }
+}
+
+impl Ready for Maximum {
+    fn bars_seen(&self) -> usize {
+        self.bars_seen
+    }
+
+    fn is_ready(&self) -> bool {
+        self.bars_seen >= self.period
+    }
+}
+
 impl Reset for Maximum {
     fn reset(&mut self) {
+        self.bars_seen = 0;
         for i in 0..self.period {
             self.deque[i] = f64::NEG_INFINITY;
         }
@@ -170,6 +196,36 @@ mod tests {
         assert_eq!(max.next(4.0), 4.0);
     }
 
+    #[test]
+    fn test_current() {
+        let mut max = Maximum::new(3).unwrap();
+        assert_eq!(max.current(), None);
+
+        for input in [4.0, 1.2, 5.0, 3.0, 2.0, 1.0] {
+            let value = max.next(input);
+            assert_eq!(max.current(), Some(value));
+        }
+
+        max.reset();
+        assert_eq!(max.current(), None);
+    }
+
+    #[test]
+    fn test_ready() {
+        let mut max = Maximum::new(3).unwrap();
+        assert_eq!((max.bars_seen(), max.is_ready()), (0, false));
+
+        max.next(1.0);
+        max.next(2.0);
+        assert_eq!((max.bars_seen(), max.is_ready()), (2, false));
+        // the third input fills the period
+        max.next(3.0);
+        assert_eq!((max.bars_seen(), max.is_ready()), (3, true));
+
+        max.reset();
+        assert_eq!((max.bars_seen(), max.is_ready()), (0, false));
+    }
+
     #[test]
     fn test_default() {
         Maximum::default();
@@ -4,7 +4,7 @@ use std::fmt;
 use serde::{Deserialize, Serialize};
 
 use crate::errors::{Result, TaError};
-use crate::{Close, Next, Period, Reset};
+use crate::{Close, Next, Period, Ready, Reset};
 
 /// Mean Absolute Deviation (MAD)
 ///
@@ -35,18 +35,21 @@ pub struct MeanAbsoluteDeviation {
     count: usize,
     sum: f64,
     deque: Box<[f64]>,
+    #[cfg_attr(feature = "serde", serde(default))]
+    bars_seen: usize,
 }
 
 impl MeanAbsoluteDeviation {
     pub fn new(period: usize) -> Result<Self> {
         match period {
-            0 => Err(TaError::InvalidParameter),
+            0 => Err(TaError::invalid_parameter("period", period)),
             _ => Ok(Self {
                 period,
                 index: 0,
                 count: 0,
                 sum: 0.0,
                 deque: vec![0.0; period].into_boxed_slice(),
+                bars_seen: 0,
             }),
         }
     }
@@ -62,8 +65,9 @@ impl Next<f64> for MeanAbsoluteDeviation {
     type Output = f64;
 
     fn next(&mut self, input: f64) -> Self::Output {
+        self.bars_seen = self.bars_seen.saturating_add(1);
         self.sum = if self.count < self.period {
-            self.count = self.count + 1;
+            self.count += 1;
             self.sum + input
         } else {
             self.sum + input - self.deque[self.index]
@@ -94,8 +98,19 @@ impl<T: Close> Next<&T> for MeanAbsoluteDeviation {
     }
 }
 
+impl Ready for MeanAbsoluteDeviation {
+    fn bars_seen(&self) -> usize {
+        self.bars_seen
+    }
+
+    fn is_ready(&self) -> bool {
+        self.bars_seen >= self.period
+    }
+}
+
 impl Reset for MeanAbsoluteDeviation {
     fn reset(&mut self) {
+        self.bars_seen = 0;
         self.index = 0;
         self.count = 0;
         self.sum = 0.0;
@@ -155,6 +170,22 @@ mod tests {
         assert_eq!(round(mad.next(4.0)), 1.25);
     }
 
+    #[test]
+    fn test_ready() {
+        let mut mad = MeanAbsoluteDeviation::new(3).unwrap();
+        assert_eq!((mad.bars_seen(), mad.is_ready()), (0, false));
+
+        mad.next(1.0);
+        mad.next(2.0);
+        assert_eq!((mad.bars_seen(), mad.is_ready()), (2, false));
+        // the third input fills the period
+        mad.next(3.0);
+        assert_eq!((mad.bars_seen(), mad.is_ready()), (3, true));
+
+        mad.reset();
+        assert_eq!((mad.bars_seen(), mad.is_ready()), (0, false));
+    }
+
     #[test]
     fn test_default() {
         MeanAbsoluteDeviation::default();
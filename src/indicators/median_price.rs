@@ -0,0 +1,98 @@
+use std::fmt;
+
+use crate::{Close, High, Low, Next, Open, PriceSource, Reset};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Median price.
+///
+/// The middle of a bar's range, also known as HL2. Like the other price transforms it keeps no
+/// state, its output is meant to be fed into another indicator.
+///
+/// # Formula
+///
+/// Median price = (high + low) / 2, [PriceSource::HL2]
+///
+/// # Example
+///
+/// ```
+/// use ta::indicators::{MedianPrice, SimpleMovingAverage};
+/// use ta::{DataItem, Next};
+///
+/// let mut mp = MedianPrice::new();
+/// let mut sma = SimpleMovingAverage::new(2).unwrap();
+///
+/// let di = DataItem::builder()
+///     .open(11.0).high(12.0).low(10.0).close(11.0).volume(1000.0)
+///     .build(true).unwrap();
+///
+/// assert_eq!(mp.next(&di), 11.0);
+/// assert_eq!(sma.next(mp.next(&di)), 11.0);
+/// ```
+///
+/// # Links
+///
+/// * [Median Price, TradingView](https://www.tradingview.com/support/solutions/43000502274-median-price/)
+///
+#[doc(alias = "HL2")]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone, Default)]
+pub struct MedianPrice;
+
+impl MedianPrice {
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl<T: Open + High + Low + Close> Next<&T> for MedianPrice {
+    type Output = f64;
+
+    fn next(&mut self, input: &T) -> Self::Output {
+        PriceSource::HL2.select(input)
+    }
+}
+
+/// Does nothing, the indicator has no state.
+impl Reset for MedianPrice {
+    fn reset(&mut self) {}
+}
+
+impl fmt::Display for MedianPrice {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "MEDPRICE")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helper::*;
+    use crate::DataItem;
+
+    #[test]
+    fn test_next() {
+        let mut mp = MedianPrice::new();
+        assert_eq!(mp.next(&Bar::new().high(12).low(10).close(11)), 11.0);
+        assert_eq!(mp.next(&Bar::new().high(15.5).low(14).close(15)), 14.75);
+    }
+
+    #[test]
+    fn test_next_data_item() {
+        let mut mp = MedianPrice::new();
+        let di = DataItem::builder()
+            .open(11.0)
+            .high(12.0)
+            .low(10.0)
+            .close(11.0)
+            .volume(1000.0)
+            .build(true)
+            .unwrap();
+        assert_eq!(mp.next(&di), 11.0);
+    }
+
+    #[test]
+    fn test_display() {
+        assert_eq!(format!("{}", MedianPrice::new()), "MEDPRICE");
+    }
+}
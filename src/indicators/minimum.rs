@@ -1,7 +1,7 @@
 use std::fmt;
 
 use crate::errors::{Result, TaError};
-use crate::{Low, Next, Period, Reset};
+use crate::{Current, Low, Next, Period, Ready, Reset};
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
@@ -30,17 +30,20 @@ pub struct Minimum {
     min_index: usize,
     cur_index: usize,
     deque: Box<[f64]>,
+    #[cfg_attr(feature = "serde", serde(default))]
+    bars_seen: usize,
 }
 
 impl Minimum {
     pub fn new(period: usize) -> Result<Self> {
         match period {
-            0 => Err(TaError::InvalidParameter),
+            0 => Err(TaError::invalid_parameter("period", period)),
             _ => Ok(Self {
                 period,
                 min_index: 0,
                 cur_index: 0,
                 deque: vec![f64::INFINITY; period].into_boxed_slice(),
+                bars_seen: 0,
             }),
         }
     }
@@ -70,6 +73,7 @@ impl Next<f64> for Minimum {
     type Output = f64;
 
     fn next(&mut self, input: f64) -> Self::Output {
+        self.bars_seen = self.bars_seen.saturating_add(1);
         self.deque[self.cur_index] = input;
 
         if input < self.deque[self.min_index] {
@@ -96,8 +100,30 @@ impl<T: Low> Next<&T> for Minimum {
     }
 }
 
+impl Current for Minimum {
+    type Output = f64;
+
+    fn current(&self) -> Option<Self::Output> {
+        match self.bars_seen {
+            0 => None,
+            _ => Some(self.deque[self.min_index]),
+        }
+    }
+}
+
+impl Ready for Minimum {
+    fn bars_seen(&self) -> usize {
+        self.bars_seen
+    }
+
+    fn is_ready(&self) -> bool {
+        self.bars_seen >= self.period
+    }
+}
+
 impl Reset for Minimum {
     fn reset(&mut self) {
+        self.bars_seen = 0;
         for i in 0..self.period {
             self.deque[i] = f64::INFINITY;
         }
@@ -170,6 +196,36 @@ mod tests {
         assert_eq!(min.next(8.0), 8.0);
     }
 
+    #[test]
+    fn test_current() {
+        let mut min = Minimum::new(3).unwrap();
+        assert_eq!(min.current(), None);
+
+        for input in [4.0, 1.2, 5.0, 3.0, 2.0, 1.0] {
+            let value = min.next(input);
+            assert_eq!(min.current(), Some(value));
+        }
+
+        min.reset();
+        assert_eq!(min.current(), None);
+    }
+
+    #[test]
+    fn test_ready() {
+        let mut min = Minimum::new(3).unwrap();
+        assert_eq!((min.bars_seen(), min.is_ready()), (0, false));
+
+        min.next(1.0);
+        min.next(2.0);
+        assert_eq!((min.bars_seen(), min.is_ready()), (2, false));
+        // the third input fills the period
+        min.next(3.0);
+        assert_eq!((min.bars_seen(), min.is_ready()), (3, true));
+
+        min.reset();
+        assert_eq!((min.bars_seen(), min.is_ready()), (0, false));
+    }
+
     #[test]
     fn test_default() {
         Minimum::default();
@@ -1,5 +1,5 @@
 mod exponential_moving_average;
-pub use self::exponential_moving_average::ExponentialMovingAverage;
+pub use self::exponential_moving_average::{EmaSeed, ExponentialMovingAverage};
 
 mod weighted_moving_average;
 pub use self::weighted_moving_average::WeightedMovingAverage;
@@ -14,7 +14,7 @@ mod mean_absolute_deviation;
 pub use self::mean_absolute_deviation::MeanAbsoluteDeviation;
 
 mod relative_strength_index;
-pub use self::relative_strength_index::RelativeStrengthIndex;
+pub use self::relative_strength_index::{RelativeStrengthIndex, RsiSmoothing};
 
 mod minimum;
 pub use self::minimum::Minimum;
@@ -69,5 +69,188 @@ mod on_balance_volume;
 pub use self::on_balance_volume::OnBalanceVolume;
 
 mod volume_weighted_average_price;
-pub use self::volume_weighted_average_price::VolumeWeightedAveragePrice;
-pub use self::volume_weighted_average_price::VolumeWeightedAveragePriceBands;
+#[allow(deprecated)]
+pub use self::volume_weighted_average_price::VolumeWeightedAveragePriceSource;
+pub use self::volume_weighted_average_price::{
+    VolumeWeightedAveragePrice, VolumeWeightedAveragePriceBands,
+};
+
+mod parabolic_sar;
+pub use self::parabolic_sar::{ParabolicSar, ParabolicSarOutput};
+
+mod average_directional_index;
+pub use self::average_directional_index::{AverageDirectionalIndex, AverageDirectionalIndexOutput};
+
+mod stochastic_oscillator;
+pub use self::stochastic_oscillator::{StochasticOscillator, StochasticOscillatorOutput};
+
+mod donchian_channel;
+pub use self::donchian_channel::{DonchianChannel, DonchianChannelOutput};
+
+mod williams_r;
+pub use self::williams_r::WilliamsR;
+
+mod chaikin_money_flow;
+pub use self::chaikin_money_flow::ChaikinMoneyFlow;
+
+mod accumulation_distribution;
+pub use self::accumulation_distribution::AccumulationDistribution;
+
+mod aroon;
+pub use self::aroon::{Aroon, AroonOutput};
+
+mod super_trend;
+pub use self::super_trend::{SuperTrend, SuperTrendOutput};
+
+mod vortex_indicator;
+pub use self::vortex_indicator::{VortexIndicator, VortexIndicatorOutput};
+
+mod hull_moving_average;
+pub use self::hull_moving_average::HullMovingAverage;
+
+mod kaufman_adaptive_moving_average;
+pub use self::kaufman_adaptive_moving_average::KaufmanAdaptiveMovingAverage;
+
+mod trix;
+pub use self::trix::Trix;
+
+mod ultimate_oscillator;
+pub use self::ultimate_oscillator::UltimateOscillator;
+
+mod awesome_oscillator;
+pub use self::awesome_oscillator::AwesomeOscillator;
+
+mod fisher_transform;
+pub use self::fisher_transform::{FisherTransform, FisherTransformOutput};
+
+mod cci_signal;
+pub use self::cci_signal::{CciSignal, CciSignalEvent};
+
+mod heikin_ashi;
+pub use self::heikin_ashi::HeikinAshi;
+
+mod elder_ray;
+pub use self::elder_ray::{ElderRay, ElderRayOutput};
+
+mod balance_of_power;
+pub use self::balance_of_power::BalanceOfPower;
+
+mod ease_of_movement;
+pub use self::ease_of_movement::EaseOfMovement;
+
+mod force_index;
+pub use self::force_index::ForceIndex;
+
+mod detrended_price_oscillator;
+pub use self::detrended_price_oscillator::DetrendedPriceOscillator;
+
+mod coppock_curve;
+pub use self::coppock_curve::CoppockCurve;
+
+mod know_sure_thing;
+pub use self::know_sure_thing::{KnowSureThing, KnowSureThingOutput};
+
+mod mass_index;
+pub use self::mass_index::MassIndex;
+
+mod relative_vigor_index;
+pub use self::relative_vigor_index::{RelativeVigorIndex, RelativeVigorIndexOutput};
+
+mod renko;
+pub use self::renko::{Renko, RenkoBrick, RenkoDirection};
+
+mod zig_zag;
+pub use self::zig_zag::{ZigZag, ZigZagPivot, ZigZagPivotKind};
+
+mod linear_regression;
+pub use self::linear_regression::{LinearRegression, LinearRegressionOutput};
+
+mod schaff_trend_cycle;
+pub use self::schaff_trend_cycle::SchaffTrendCycle;
+
+mod connors_rsi;
+pub use self::connors_rsi::ConnorsRsi;
+
+mod volume_profile;
+pub use self::volume_profile::{VolumeProfile, VolumeProfileBucket, VolumeProfileResult};
+
+mod alligator;
+pub use self::alligator::{Alligator, AlligatorOutput};
+
+mod chaikin_oscillator;
+pub use self::chaikin_oscillator::ChaikinOscillator;
+
+mod klinger_oscillator;
+pub use self::klinger_oscillator::{KlingerOscillator, KlingerOscillatorOutput};
+
+mod triangular_moving_average;
+pub use self::triangular_moving_average::TriangularMovingAverage;
+
+mod double_exponential_moving_average;
+pub use self::double_exponential_moving_average::DoubleExponentialMovingAverage;
+
+mod triple_exponential_moving_average;
+pub use self::triple_exponential_moving_average::TripleExponentialMovingAverage;
+
+mod median_price;
+pub use self::median_price::MedianPrice;
+
+mod typical_price;
+pub use self::typical_price::TypicalPrice;
+
+mod weighted_close;
+pub use self::weighted_close::WeightedClose;
+
+mod momentum;
+pub use self::momentum::Momentum;
+
+mod stochastic_rsi;
+pub use self::stochastic_rsi::{StochasticRsi, StochasticRsiOutput};
+
+mod ichimoku;
+pub use self::ichimoku::{Ichimoku, IchimokuOutput};
+
+mod volume_weighted_moving_average;
+pub use self::volume_weighted_moving_average::VolumeWeightedMovingAverage;
+
+mod standard_error_bands;
+pub use self::standard_error_bands::{StandardErrorBands, StandardErrorBandsOutput};
+
+mod gann_hilo;
+pub use self::gann_hilo::{GannHiLo, GannHiLoOutput};
+
+mod trailing_stop;
+pub use self::trailing_stop::{TrailingStop, TrailingStopMode, TrailingStopOutput};
+
+mod choppiness_index;
+pub use self::choppiness_index::ChoppinessIndex;
+
+mod quantitative_qualitative_estimation;
+pub use self::quantitative_qualitative_estimation::{Qqe, QqeOutput};
+
+mod fractals;
+pub use self::fractals::{Fractal, FractalKind, Fractals};
+
+mod average_true_range_percent;
+pub use self::average_true_range_percent::AverageTrueRangePercent;
+
+mod percent_rank;
+pub use self::percent_rank::PercentRank;
+
+mod disparity_index;
+pub use self::disparity_index::DisparityIndex;
+
+mod center_of_gravity;
+pub use self::center_of_gravity::CenterOfGravity;
+
+mod volume_oscillator;
+pub use self::volume_oscillator::VolumeOscillator;
+
+mod swing_points;
+pub use self::swing_points::{MarketStructure, Swing, SwingKind, SwingPoints};
+
+mod relative_volume;
+pub use self::relative_volume::RelativeVolume;
+
+mod historical_volatility;
+pub use self::historical_volatility::HistoricalVolatility;
@@ -0,0 +1,219 @@
+use std::fmt;
+
+use crate::errors::{Result, TaError};
+use crate::traits::{Close, Next, Period, Ready, Reset};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Momentum (MOM).
+///
+/// The difference between the current price and the price _n_ periods ago. Unlike the
+/// [rate of change](struct.RateOfChange.html) it is expressed in price units rather than in
+/// percent, which keeps it defined for prices of 0.
+///
+/// # Formula
+///
+/// MOM = Price<sub>t</sub> - Price<sub>t-n</sub>
+///
+/// Where:
+///
+/// * _Price<sub>t</sub>_ - price at the moment
+/// * _Price<sub>t-n</sub>_ - price _n_ periods ago
+///
+/// Until _n_ prices have been seen the oldest price is used instead.
+///
+/// # Parameters
+///
+/// * _period_ - number of periods (integer greater than 0). Default is 10.
+///
+/// # Example
+///
+/// ```
+/// use ta::indicators::Momentum;
+/// use ta::Next;
+///
+/// let mut mom = Momentum::new(2).unwrap();
+/// assert_eq!(mom.next(10.0), 0.0);   //  0
+/// assert_eq!(mom.next(9.5), -0.5);   //  9.5 - 10
+/// assert_eq!(mom.next(12.0), 2.0);   //  12 - 10
+/// assert_eq!(mom.next(13.0), 3.5);   //  13 - 9.5
+/// ```
+///
+/// # Links
+///
+/// * [Momentum, Wikipedia](https://en.wikipedia.org/wiki/Momentum_(technical_analysis))
+///
+#[doc(alias = "MOM")]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone)]
+pub struct Momentum {
+    period: usize,
+    index: usize,
+    count: usize,
+    deque: Box<[f64]>,
+    #[cfg_attr(feature = "serde", serde(default))]
+    bars_seen: usize,
+}
+
+impl Momentum {
+    pub fn new(period: usize) -> Result<Self> {
+        match period {
+            0 => Err(TaError::invalid_parameter("period", period)),
+            _ => Ok(Self {
+                period,
+                index: 0,
+                count: 0,
+                deque: vec![0.0; period].into_boxed_slice(),
+                bars_seen: 0,
+            }),
+        }
+    }
+}
+
+impl Period for Momentum {
+    fn period(&self) -> usize {
+        self.period
+    }
+}
+
+impl Next<f64> for Momentum {
+    type Output = f64;
+
+    fn next(&mut self, input: f64) -> f64 {
+        self.bars_seen = self.bars_seen.saturating_add(1);
+        let previous = if self.count < self.period {
+            self.count += 1;
+            if self.count == 1 {
+                input
+            } else {
+                self.deque[0]
+            }
+        } else {
+            self.deque[self.index]
+        };
+        self.deque[self.index] = input;
+
+        self.index = if self.index + 1 < self.period {
+            self.index + 1
+        } else {
+            0
+        };
+
+        input - previous
+    }
+}
+
+impl<T: Close> Next<&T> for Momentum {
+    type Output = f64;
+
+    fn next(&mut self, input: &T) -> f64 {
+        self.next(input.close())
+    }
+}
+
+impl Ready for Momentum {
+    fn bars_seen(&self) -> usize {
+        self.bars_seen
+    }
+
+    fn is_ready(&self) -> bool {
+        // only the inputs after the first _period_ have a price _period_ inputs before them
+        self.bars_seen > self.period
+    }
+}
+
+impl Reset for Momentum {
+    fn reset(&mut self) {
+        self.bars_seen = 0;
+        self.index = 0;
+        self.count = 0;
+        for i in 0..self.period {
+            self.deque[i] = 0.0;
+        }
+    }
+}
+
+impl Default for Momentum {
+    fn default() -> Self {
+        Self::new(10).unwrap()
+    }
+}
+
+impl fmt::Display for Momentum {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "MOM({})", self.period)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helper::*;
+
+    test_indicator!(Momentum);
+
+    #[test]
+    fn test_new() {
+        assert!(Momentum::new(0).is_err());
+        assert!(Momentum::new(1).is_ok());
+        assert!(Momentum::new(100_000).is_ok());
+    }
+
+    #[test]
+    fn test_next() {
+        let mut mom = Momentum::new(3).unwrap();
+
+        assert_eq!(round(mom.next(10.0)), 0.0);
+        assert_eq!(round(mom.next(10.4)), 0.4);
+        assert_eq!(round(mom.next(10.57)), 0.57);
+        assert_eq!(round(mom.next(10.8)), 0.8);
+        assert_eq!(round(mom.next(10.9)), 0.5);
+        assert_eq!(round(mom.next(10.0)), -0.57);
+
+        let mut mom = Momentum::new(1).unwrap();
+        assert_eq!(mom.next(&Bar::new().close(0)), 0.0);
+        assert_eq!(mom.next(&Bar::new().close(4)), 4.0);
+        assert_eq!(mom.next(&Bar::new().close(3)), -1.0);
+    }
+
+    #[test]
+    fn test_reset() {
+        let mut mom = Momentum::new(3).unwrap();
+
+        mom.next(12.3);
+        mom.next(15.0);
+
+        mom.reset();
+
+        assert_eq!(round(mom.next(10.0)), 0.0);
+        assert_eq!(round(mom.next(10.4)), 0.4);
+    }
+
+    #[test]
+    fn test_ready() {
+        let mut mom = Momentum::new(3).unwrap();
+        assert_eq!((mom.bars_seen(), mom.is_ready()), (0, false));
+
+        mom.next(1.0);
+        mom.next(2.0);
+        mom.next(3.0);
+        assert_eq!((mom.bars_seen(), mom.is_ready()), (3, false));
+        // the fourth input is the first compared with the value 3 inputs before
+        mom.next(4.0);
+        assert_eq!((mom.bars_seen(), mom.is_ready()), (4, true));
+
+        mom.reset();
+        assert_eq!((mom.bars_seen(), mom.is_ready()), (0, false));
+    }
+
+    #[test]
+    fn test_default() {
+        Momentum::default();
+    }
+
+    #[test]
+    fn test_display() {
+        let mom = Momentum::new(10).unwrap();
+        assert_eq!(format!("{}", mom), "MOM(10)");
+    }
+}
@@ -1,7 +1,7 @@
 use std::fmt;
 
 use crate::errors::{Result, TaError};
-use crate::{Close, High, Low, Next, Period, Reset, Volume};
+use crate::{Close, High, Low, Next, Period, Ready, Reset, Volume};
 
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
@@ -27,10 +27,12 @@ use serde::{Deserialize, Serialize};
 ///
 /// Money Flow Index(MFI) = PMF / (PMF + NMF) * 100
 ///
+/// When there is no negative money flow MFI is 100. When there is no money flow at all
+/// (e.g. the typical price did not change within the period) MFI is 50.
 ///
 /// # Parameters
 ///
-/// * _period_ - number of periods, integer greater than 0
+/// * _period_ - number of periods, integer greater than 0. Default is 14.
 ///
 /// # Example
 ///
@@ -45,14 +47,13 @@ use serde::{Deserialize, Serialize};
 ///             .close(2.0)
 ///             .open(1.5)
 ///             .volume(1000.0)
-///             .build().unwrap();
+///             .build(true).unwrap();
 /// mfi.next(&di);
 ///
 /// ```
 /// # Links
 /// * [Money Flow Index, Wikipedia](https://en.wikipedia.org/wiki/Money_flow_index)
 /// * [Money Flow Index, stockcharts](https://stockcharts.com/school/doku.php?id=chart_school:technical_indicators:money_flow_index_mfi)
-
 #[doc(alias = "MFI")]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[derive(Debug, Clone)]
@@ -64,12 +65,14 @@ pub struct MoneyFlowIndex {
     total_positive_money_flow: f64,
     total_negative_money_flow: f64,
     deque: Box<[f64]>,
+    #[cfg_attr(feature = "serde", serde(default))]
+    bars_seen: usize,
 }
 
 impl MoneyFlowIndex {
     pub fn new(period: usize) -> Result<Self> {
         match period {
-            0 => Err(TaError::InvalidParameter),
+            0 => Err(TaError::invalid_parameter("period", period)),
             _ => Ok(Self {
                 period,
                 index: 0,
@@ -78,6 +81,7 @@ impl MoneyFlowIndex {
                 total_positive_money_flow: 0.0,
                 total_negative_money_flow: 0.0,
                 deque: vec![0.0; period].into_boxed_slice(),
+                bars_seen: 0,
             }),
         }
     }
@@ -93,6 +97,7 @@ impl<T: High + Low + Close + Volume> Next<&T> for MoneyFlowIndex {
     type Output = f64;
 
     fn next(&mut self, input: &T) -> f64 {
+        self.bars_seen = self.bars_seen.saturating_add(1);
         let tp = (input.close() + input.high() + input.low()) / 3.0;
 
         self.index = if self.index + 1 < self.period {
@@ -102,7 +107,7 @@ impl<T: High + Low + Close + Volume> Next<&T> for MoneyFlowIndex {
         };
 
         if self.count < self.period {
-            self.count = self.count + 1;
+            self.count += 1;
             if self.count == 1 {
                 self.previous_typical_price = tp;
                 return 50.0;
@@ -129,6 +134,14 @@ impl<T: High + Low + Close + Volume> Next<&T> for MoneyFlowIndex {
         }
         self.previous_typical_price = tp;
 
+        if self.total_negative_money_flow == 0.0 {
+            return if self.total_positive_money_flow == 0.0 {
+                50.0
+            } else {
+                100.0
+            };
+        }
+
         self.total_positive_money_flow
             / (self.total_positive_money_flow + self.total_negative_money_flow)
             * 100.0
@@ -147,8 +160,20 @@ impl fmt::Display for MoneyFlowIndex {
     }
 }
 
+impl Ready for MoneyFlowIndex {
+    fn bars_seen(&self) -> usize {
+        self.bars_seen
+    }
+
+    fn is_ready(&self) -> bool {
+        // the first input only seeds the previous typical price
+        self.bars_seen > self.period
+    }
+}
+
 impl Reset for MoneyFlowIndex {
     fn reset(&mut self) {
+        self.bars_seen = 0;
         self.index = 0;
         self.count = 0;
         self.previous_typical_price = 0.0;
@@ -200,6 +225,36 @@ mod tests {
         assert_eq!(round(mfi.next(&bar8)), 60.87);
     }
 
+    #[test]
+    fn test_next_saturated() {
+        let mut mfi = MoneyFlowIndex::new(3).unwrap();
+        assert_eq!(
+            mfi.next(&Bar::new().high(2).low(1).close(1.5).volume(100.0)),
+            50.0
+        );
+
+        // only positive money flow
+        for i in 1..10 {
+            let price = 1.5 + i as f64;
+            let bar = Bar::new()
+                .high(price + 0.5)
+                .low(price - 0.5)
+                .close(price)
+                .volume(100.0);
+            assert_eq!(mfi.next(&bar), 100.0);
+        }
+    }
+
+    #[test]
+    fn test_next_flat() {
+        let mut mfi = MoneyFlowIndex::new(3).unwrap();
+        let bar = Bar::new().high(2).low(1).close(1.5).volume(100.0);
+
+        for _ in 0..5 {
+            assert_eq!(mfi.next(&bar), 50.0);
+        }
+    }
+
     #[test]
     fn test_reset() {
         let mut mfi = MoneyFlowIndex::new(3).unwrap();
@@ -216,6 +271,22 @@ mod tests {
         assert_eq!(round(mfi.next(&bar2)), 100.0);
     }
 
+    #[test]
+    fn test_ready() {
+        let mut mfi = MoneyFlowIndex::new(3).unwrap();
+        let bar = Bar::new().high(10).low(8).close(9).volume(100.0);
+        for _ in 0..3 {
+            mfi.next(&bar);
+        }
+        assert_eq!((mfi.bars_seen(), mfi.is_ready()), (3, false));
+        // the fourth input completes the third money flow
+        mfi.next(&bar);
+        assert_eq!((mfi.bars_seen(), mfi.is_ready()), (4, true));
+
+        mfi.reset();
+        assert_eq!((mfi.bars_seen(), mfi.is_ready()), (0, false));
+    }
+
     #[test]
     fn test_default() {
         MoneyFlowIndex::default();
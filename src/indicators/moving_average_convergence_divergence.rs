@@ -2,7 +2,7 @@ use std::fmt;
 
 use crate::errors::Result;
 use crate::indicators::ExponentialMovingAverage as Ema;
-use crate::{Close, Next, Period, Reset};
+use crate::{Close, Current, Next, Period, Ready, Reset};
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
@@ -109,6 +109,31 @@ impl<T: Close> Next<&T> for MovingAverageConvergenceDivergence {
     }
 }
 
+impl Current for MovingAverageConvergenceDivergence {
+    type Output = MovingAverageConvergenceDivergenceOutput;
+
+    fn current(&self) -> Option<Self::Output> {
+        let macd = self.fast_ema.current()? - self.slow_ema.current()?;
+        let signal = self.signal_ema.current()?;
+
+        Some(MovingAverageConvergenceDivergenceOutput {
+            macd,
+            signal,
+            histogram: macd - signal,
+        })
+    }
+}
+
+impl Ready for MovingAverageConvergenceDivergence {
+    fn bars_seen(&self) -> usize {
+        self.slow_ema.bars_seen()
+    }
+
+    fn is_ready(&self) -> bool {
+        self.fast_ema.is_ready() && self.slow_ema.is_ready() && self.signal_ema.is_ready()
+    }
+}
+
 impl Reset for MovingAverageConvergenceDivergence {
     fn reset(&mut self) {
         self.fast_ema.reset();
@@ -183,6 +208,35 @@ mod tests {
         assert_eq!(round(macd.next(3.0).into()), (0.21, 0.09, 0.13));
     }
 
+    #[test]
+    fn test_current() {
+        let mut macd = Macd::new(3, 6, 4).unwrap();
+        assert_eq!(macd.current(), None);
+
+        for input in [2.0, 3.0, 4.2, 7.0, 6.7] {
+            let value = macd.next(input);
+            assert_eq!(macd.current(), Some(value));
+        }
+
+        macd.reset();
+        assert_eq!(macd.current(), None);
+    }
+
+    #[test]
+    fn test_ready() {
+        let mut macd = MovingAverageConvergenceDivergence::new(2, 4, 3).unwrap();
+        for _ in 0..3 {
+            macd.next(2.0);
+        }
+        assert_eq!((macd.bars_seen(), macd.is_ready()), (3, false));
+        // the slow EMA is the last to fill its period
+        macd.next(2.0);
+        assert_eq!((macd.bars_seen(), macd.is_ready()), (4, true));
+
+        macd.reset();
+        assert_eq!((macd.bars_seen(), macd.is_ready()), (0, false));
+    }
+
     #[test]
     fn test_default() {
         Macd::default();
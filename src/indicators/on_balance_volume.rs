@@ -1,6 +1,6 @@
 use std::fmt;
 
-use crate::{Close, Next, Reset, Volume};
+use crate::{Close, Current, Next, Ready, Reset, Volume};
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
@@ -21,6 +21,8 @@ use serde::{Deserialize, Serialize};
 /// If the closing prices equals the prior close price then:
 /// Current OBV = Previous OBV
 ///
+/// The first bar only seeds the prior close price, so OBV starts at 0.
+///
 /// Where:
 ///
 /// obv - on the balance volume
@@ -39,7 +41,7 @@ use serde::{Deserialize, Serialize};
 ///             .close(2.0)
 ///             .open(1.5)
 ///             .volume(1000.0)
-///             .build().unwrap();
+///             .build(true).unwrap();
 ///
 /// let di2 = DataItem::builder()
 ///             .high(3.0)
@@ -47,30 +49,32 @@ use serde::{Deserialize, Serialize};
 ///             .close(1.5)
 ///             .open(1.5)
 ///             .volume(300.0)
-///             .build().unwrap();
+///             .build(true).unwrap();
 ///
-/// assert_eq!(obv.next(&di1), 1000.0);
-/// assert_eq!(obv.next(&di2), 700.0);
+/// assert_eq!(obv.next(&di1), 0.0);
+/// assert_eq!(obv.next(&di2), -300.0);
 /// ```
 ///
 /// # Links
 ///
 /// * [On Balance Volume, Wikipedia](https://en.wikipedia.org/wiki/On-balance_volume)
 /// * [On Balance Volume, stockcharts](https://stockcharts.com/school/doku.php?id=chart_school:technical_indicators:on_balance_volume_obv)
-
 #[doc(alias = "OBV")]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[derive(Debug, Clone)]
 pub struct OnBalanceVolume {
     obv: f64,
-    prev_close: f64,
+    prev_close: Option<f64>,
+    #[cfg_attr(feature = "serde", serde(default))]
+    bars_seen: usize,
 }
 
 impl OnBalanceVolume {
     pub fn new() -> Self {
         Self {
             obv: 0.0,
-            prev_close: 0.0,
+            prev_close: None,
+            bars_seen: 0,
         }
     }
 }
@@ -79,12 +83,16 @@ impl<T: Close + Volume> Next<&T> for OnBalanceVolume {
     type Output = f64;
 
     fn next(&mut self, input: &T) -> f64 {
-        if input.close() > self.prev_close {
-            self.obv = self.obv + input.volume();
-        } else if input.close() < self.prev_close {
-            self.obv = self.obv - input.volume();
+        self.bars_seen = self.bars_seen.saturating_add(1);
+        let close = input.close();
+        if let Some(prev_close) = self.prev_close {
+            if close > prev_close {
+                self.obv += input.volume();
+            } else if close < prev_close {
+                self.obv -= input.volume();
+            }
         }
-        self.prev_close = input.close();
+        self.prev_close = Some(close);
         self.obv
     }
 }
@@ -101,10 +109,29 @@ impl fmt::Display for OnBalanceVolume {
     }
 }
 
+impl Current for OnBalanceVolume {
+    type Output = f64;
+
+    fn current(&self) -> Option<Self::Output> {
+        self.prev_close.map(|_| self.obv)
+    }
+}
+
+impl Ready for OnBalanceVolume {
+    fn bars_seen(&self) -> usize {
+        self.bars_seen
+    }
+
+    fn is_ready(&self) -> bool {
+        self.bars_seen > 0
+    }
+}
+
 impl Reset for OnBalanceVolume {
     fn reset(&mut self) {
+        self.bars_seen = 0;
         self.obv = 0.0;
-        self.prev_close = 0.0;
+        self.prev_close = None;
     }
 }
 
@@ -121,17 +148,21 @@ mod tests {
         let bar2 = Bar::new().close(5).volume(5000.0);
         let bar3 = Bar::new().close(4).volume(9000.0);
         let bar4 = Bar::new().close(4).volume(4000.0);
+        let bar5 = Bar::new().close(4.5).volume(2500.0);
 
-        assert_eq!(obv.next(&bar1), 1000.0);
+        // first bar only seeds the previous close
+        assert_eq!(obv.next(&bar1), 0.0);
 
         //close > prev_close
-        assert_eq!(obv.next(&bar2), 6000.0);
+        assert_eq!(obv.next(&bar2), 5000.0);
 
         // close < prev_close
-        assert_eq!(obv.next(&bar3), -3000.0);
+        assert_eq!(obv.next(&bar3), -4000.0);
 
         // close == prev_close
-        assert_eq!(obv.next(&bar4), -3000.0);
+        assert_eq!(obv.next(&bar4), -4000.0);
+
+        assert_eq!(obv.next(&bar5), -1500.0);
     }
 
     #[test]
@@ -142,15 +173,48 @@ mod tests {
         let bar2 = Bar::new().close(4).volume(2000.0);
         let bar3 = Bar::new().close(8).volume(3000.0);
 
-        assert_eq!(obv.next(&bar1), 1000.0);
-        assert_eq!(obv.next(&bar2), 3000.0);
-        assert_eq!(obv.next(&bar3), 6000.0);
+        assert_eq!(obv.next(&bar1), 0.0);
+        assert_eq!(obv.next(&bar2), 2000.0);
+        assert_eq!(obv.next(&bar3), 5000.0);
 
         obv.reset();
 
-        assert_eq!(obv.next(&bar1), 1000.0);
-        assert_eq!(obv.next(&bar2), 3000.0);
-        assert_eq!(obv.next(&bar3), 6000.0);
+        assert_eq!(obv.next(&bar1), 0.0);
+        assert_eq!(obv.next(&bar2), 2000.0);
+        assert_eq!(obv.next(&bar3), 5000.0);
+    }
+
+    #[test]
+    fn test_current() {
+        let bars = [
+            Bar::new().close(1.5).volume(1000.0),
+            Bar::new().close(5).volume(5000.0),
+            Bar::new().close(4).volume(9000.0),
+        ];
+
+        let mut obv = OnBalanceVolume::new();
+        assert_eq!(obv.current(), None);
+
+        for bar in &bars {
+            let value = obv.next(bar);
+            assert_eq!(obv.current(), Some(value));
+        }
+
+        obv.reset();
+        assert_eq!(obv.current(), None);
+    }
+
+    #[test]
+    fn test_ready() {
+        let mut obv = OnBalanceVolume::new();
+        assert_eq!((obv.bars_seen(), obv.is_ready()), (0, false));
+
+        // a cumulative indicator is ready after the first bar
+        obv.next(&Bar::new().high(2).low(1).close(1.5).volume(100.0));
+        assert_eq!((obv.bars_seen(), obv.is_ready()), (1, true));
+
+        obv.reset();
+        assert_eq!((obv.bars_seen(), obv.is_ready()), (0, false));
     }
 
     #[test]
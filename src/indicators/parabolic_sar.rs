@@ -0,0 +1,266 @@
+use std::fmt;
+
+use crate::errors::{Result, TaError};
+use crate::{High, Low, Next, Reset};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Parabolic SAR (stop and reverse).
+///
+/// A trend following indicator developed by J. Welles Wilder. The SAR trails below the price
+/// in an uptrend and above the price in a downtrend, accelerating towards the price every time
+/// the trend makes a new extreme. When the price penetrates the SAR the trend is reversed and
+/// the SAR jumps to the extreme point of the previous trend.
+///
+/// # Formula
+///
+/// SAR<sub>t</sub> = SAR<sub>t-1</sub> + AF * (EP - SAR<sub>t-1</sub>)
+///
+/// Where:
+///
+/// * _EP_ - extreme point, the highest high of an uptrend or the lowest low of a downtrend
+/// * _AF_ - acceleration factor, starts at _step_ and grows by _step_ on every new extreme
+///   point, up to _max_acceleration_
+///
+/// In an uptrend the SAR is never above the lows of the two previous bars, in a downtrend
+/// it is never below the highs of the two previous bars.
+///
+/// The first bar starts an uptrend with the SAR at its low.
+///
+/// # Parameters
+///
+/// * _step_ - acceleration step (greater than 0). Default is 0.02.
+/// * _max_acceleration_ - maximum acceleration factor (not less than _step_). Default is 0.2.
+///
+/// # Example
+///
+/// ```
+/// use ta::indicators::ParabolicSar;
+/// use ta::{DataItem, Next};
+///
+/// let mut psar = ParabolicSar::new(0.02, 0.2).unwrap();
+///
+/// let di = DataItem::builder()
+///     .open(9.5).high(10.0).low(9.0).close(9.8).volume(1000.0)
+///     .build(true).unwrap();
+/// let out = psar.next(&di);
+///
+/// assert_eq!(out.sar, 9.0);
+/// assert!(out.is_uptrend);
+/// ```
+///
+/// # Links
+///
+/// * [Parabolic SAR, Wikipedia](https://en.wikipedia.org/wiki/Parabolic_SAR)
+/// * [Parabolic SAR, StockCharts](https://school.stockcharts.com/doku.php?id=technical_indicators:parabolic_sar)
+///
+#[doc(alias = "PSAR")]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone)]
+pub struct ParabolicSar {
+    step: f64,
+    max_acceleration: f64,
+    acceleration: f64,
+    extreme_point: f64,
+    sar: f64,
+    is_uptrend: bool,
+    is_new: bool,
+    // highs and lows of the previous two bars, the most recent one first
+    prev_highs: [f64; 2],
+    prev_lows: [f64; 2],
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ParabolicSarOutput {
+    pub sar: f64,
+    pub is_uptrend: bool,
+}
+
+impl From<ParabolicSarOutput> for (f64, bool) {
+    fn from(po: ParabolicSarOutput) -> Self {
+        (po.sar, po.is_uptrend)
+    }
+}
+
+impl ParabolicSar {
+    pub fn new(step: f64, max_acceleration: f64) -> Result<Self> {
+        if step.is_nan() || step <= 0.0 {
+            return Err(TaError::invalid_parameter("step", step));
+        }
+        let is_valid = max_acceleration >= step && max_acceleration.is_finite();
+        if !is_valid {
+            return Err(TaError::invalid_parameter(
+                "max_acceleration",
+                max_acceleration,
+            ));
+        }
+        Ok(Self {
+            step,
+            max_acceleration,
+            acceleration: step,
+            extreme_point: 0.0,
+            sar: 0.0,
+            is_uptrend: true,
+            is_new: true,
+            prev_highs: [f64::NEG_INFINITY; 2],
+            prev_lows: [f64::INFINITY; 2],
+        })
+    }
+
+    pub fn step(&self) -> f64 {
+        self.step
+    }
+
+    pub fn max_acceleration(&self) -> f64 {
+        self.max_acceleration
+    }
+}
+
+impl<T: High + Low> Next<&T> for ParabolicSar {
+    type Output = ParabolicSarOutput;
+
+    fn next(&mut self, input: &T) -> Self::Output {
+        let high = input.high();
+        let low = input.low();
+
+        if self.is_new {
+            self.is_new = false;
+            self.is_uptrend = true;
+            self.sar = low;
+            self.extreme_point = high;
+            self.acceleration = self.step;
+        } else {
+            let mut sar = self.sar + self.acceleration * (self.extreme_point - self.sar);
+
+            if self.is_uptrend {
+                sar = sar.min(self.prev_lows[0]).min(self.prev_lows[1]);
+
+                if low < sar {
+                    self.is_uptrend = false;
+                    sar = self.extreme_point;
+                    self.extreme_point = low;
+                    self.acceleration = self.step;
+                } else if high > self.extreme_point {
+                    self.extreme_point = high;
+                    self.acceleration = (self.acceleration + self.step).min(self.max_acceleration);
+                }
+            } else {
+                sar = sar.max(self.prev_highs[0]).max(self.prev_highs[1]);
+
+                if high > sar {
+                    self.is_uptrend = true;
+                    sar = self.extreme_point;
+                    self.extreme_point = high;
+                    self.acceleration = self.step;
+                } else if low < self.extreme_point {
+                    self.extreme_point = low;
+                    self.acceleration = (self.acceleration + self.step).min(self.max_acceleration);
+                }
+            }
+
+            self.sar = sar;
+        }
+
+        self.prev_highs = [high, self.prev_highs[0]];
+        self.prev_lows = [low, self.prev_lows[0]];
+
+        ParabolicSarOutput {
+            sar: self.sar,
+            is_uptrend: self.is_uptrend,
+        }
+    }
+}
+
+impl Reset for ParabolicSar {
+    fn reset(&mut self) {
+        self.acceleration = self.step;
+        self.extreme_point = 0.0;
+        self.sar = 0.0;
+        self.is_uptrend = true;
+        self.is_new = true;
+        self.prev_highs = [f64::NEG_INFINITY; 2];
+        self.prev_lows = [f64::INFINITY; 2];
+    }
+}
+
+impl Default for ParabolicSar {
+    fn default() -> Self {
+        Self::new(0.02, 0.2).unwrap()
+    }
+}
+
+impl fmt::Display for ParabolicSar {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "PSAR({}, {})", self.step, self.max_acceleration)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helper::*;
+
+    #[test]
+    fn test_new() {
+        assert!(ParabolicSar::new(0.0, 0.2).is_err());
+        assert!(ParabolicSar::new(-0.02, 0.2).is_err());
+        assert!(ParabolicSar::new(0.02, 0.01).is_err());
+        assert!(ParabolicSar::new(f64::NAN, 0.2).is_err());
+        assert!(ParabolicSar::new(0.02, f64::NAN).is_err());
+        assert!(ParabolicSar::new(0.02, 0.02).is_ok());
+        assert!(ParabolicSar::new(0.02, 0.2).is_ok());
+    }
+
+    #[test]
+    fn test_next() {
+        let mut psar = ParabolicSar::new(0.02, 0.2).unwrap();
+
+        let data = vec![
+            // high, low, sar, is_uptrend
+            (10.0, 9.0, 9.0, true),
+            (11.0, 9.5, 9.0, true),
+            (12.0, 10.5, 9.0, true),
+            (13.0, 11.5, 9.18, true),
+            (13.5, 12.0, 9.486, true),
+            (13.0, 12.0, 9.887, true),
+            (12.0, 10.8, 10.248, true),
+            (11.0, 10.0, 13.5, false), // low penetrates the SAR, reverse to the EP
+            (10.5, 9.5, 13.43, false),
+            (10.0, 9.0, 13.273, false),
+            (11.5, 9.8, 13.016, false),
+            (12.5, 11.0, 12.775, false),
+            (14.0, 12.5, 9.0, true), // high penetrates the SAR, reverse to the EP
+        ];
+
+        for (high, low, sar, is_uptrend) in data {
+            let out = psar.next(&Bar::new().high(high).low(low));
+            assert_eq!(round(out.sar), sar);
+            assert_eq!(out.is_uptrend, is_uptrend);
+        }
+    }
+
+    #[test]
+    fn test_reset() {
+        let mut psar = ParabolicSar::new(0.02, 0.2).unwrap();
+
+        psar.next(&Bar::new().high(10).low(9));
+        psar.next(&Bar::new().high(11).low(9.5));
+        psar.next(&Bar::new().high(8).low(7));
+
+        psar.reset();
+        let out = psar.next(&Bar::new().high(5).low(4));
+        assert_eq!(out.sar, 4.0);
+        assert!(out.is_uptrend);
+    }
+
+    #[test]
+    fn test_default() {
+        ParabolicSar::default();
+    }
+
+    #[test]
+    fn test_display() {
+        let psar = ParabolicSar::new(0.02, 0.2).unwrap();
+        assert_eq!(format!("{}", psar), "PSAR(0.02, 0.2)");
+    }
+}
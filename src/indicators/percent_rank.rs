@@ -0,0 +1,250 @@
+use std::fmt;
+
+use crate::errors::{Result, TaError};
+use crate::{Close, Next, Period, Ready, Reset};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Percent rank.
+///
+/// The percentage of the previous _period_ values which are lower than the current value. A
+/// value above all of them ranks 100, a value below all of them 0. Unlike a stochastic it does
+/// not depend on how far the value is above or below the others, only on how many.
+///
+/// # Formula
+///
+/// Percent rank = number of the previous _period_ values lower than the current value /
+/// number of previous values * 100
+///
+/// It is 0 until a previous value is available. The previous values are kept sorted, so a new
+/// value is ranked with a binary search instead of comparing it with the whole window.
+///
+/// # Parameters
+///
+/// * _period_ - number of previous values to rank against (integer greater than 0). Default is
+///   100.
+///
+/// # Example
+///
+/// ```
+/// use ta::indicators::PercentRank;
+/// use ta::Next;
+///
+/// let mut rank = PercentRank::new(4).unwrap();
+/// assert_eq!(rank.next(3.0), 0.0);
+/// assert_eq!(rank.next(5.0), 100.0);
+/// assert_eq!(rank.next(4.0), 50.0);
+/// assert_eq!(rank.next(1.0), 0.0);
+/// ```
+///
+/// # Links
+///
+/// * [Percentile rank, Wikipedia](https://en.wikipedia.org/wiki/Percentile_rank)
+///
+#[doc(alias = "PERCENTRANK")]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone)]
+pub struct PercentRank {
+    period: usize,
+    index: usize,
+    count: usize,
+    deque: Box<[f64]>,
+    // the values of the deque in ascending order
+    sorted: Vec<f64>,
+    #[cfg_attr(feature = "serde", serde(default))]
+    bars_seen: usize,
+}
+
+impl PercentRank {
+    pub fn new(period: usize) -> Result<Self> {
+        match period {
+            0 => Err(TaError::invalid_parameter("period", period)),
+            _ => Ok(Self {
+                period,
+                index: 0,
+                count: 0,
+                deque: vec![0.0; period].into_boxed_slice(),
+                sorted: Vec::with_capacity(period),
+                bars_seen: 0,
+            }),
+        }
+    }
+}
+
+impl Period for PercentRank {
+    fn period(&self) -> usize {
+        self.period
+    }
+}
+
+impl Next<f64> for PercentRank {
+    type Output = f64;
+
+    fn next(&mut self, input: f64) -> Self::Output {
+        self.bars_seen = self.bars_seen.saturating_add(1);
+        let lower = self.sorted.partition_point(|v| v.total_cmp(&input).is_lt());
+        let rank = if self.sorted.is_empty() {
+            0.0
+        } else {
+            lower as f64 / self.sorted.len() as f64 * 100.0
+        };
+
+        if self.count < self.period {
+            self.count += 1;
+        } else {
+            let old_val = self.deque[self.index];
+            if let Ok(position) = self.sorted.binary_search_by(|v| v.total_cmp(&old_val)) {
+                self.sorted.remove(position);
+            }
+        }
+        let position = self.sorted.partition_point(|v| v.total_cmp(&input).is_lt());
+        self.sorted.insert(position, input);
+
+        self.deque[self.index] = input;
+        self.index = if self.index + 1 < self.period {
+            self.index + 1
+        } else {
+            0
+        };
+
+        rank
+    }
+}
+
+impl<T: Close> Next<&T> for PercentRank {
+    type Output = f64;
+
+    fn next(&mut self, input: &T) -> Self::Output {
+        self.next(input.close())
+    }
+}
+
+impl Ready for PercentRank {
+    fn bars_seen(&self) -> usize {
+        self.bars_seen
+    }
+
+    fn is_ready(&self) -> bool {
+        // only the inputs after the first _period_ are ranked against _period_ previous values
+        self.bars_seen > self.period
+    }
+}
+
+impl Reset for PercentRank {
+    fn reset(&mut self) {
+        self.bars_seen = 0;
+        self.index = 0;
+        self.count = 0;
+        for i in 0..self.period {
+            self.deque[i] = 0.0;
+        }
+        self.sorted.clear();
+    }
+}
+
+impl Default for PercentRank {
+    fn default() -> Self {
+        Self::new(100).unwrap()
+    }
+}
+
+impl fmt::Display for PercentRank {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "PERCENTRANK({})", self.period)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helper::*;
+    use rand::rngs::StdRng;
+    use rand::{Rng, SeedableRng};
+
+    test_indicator!(PercentRank);
+
+    #[test]
+    fn test_new() {
+        assert!(PercentRank::new(0).is_err());
+        assert!(PercentRank::new(1).is_ok());
+    }
+
+    #[test]
+    fn test_next() {
+        let mut rank = PercentRank::new(4).unwrap();
+
+        assert_eq!(rank.next(3.0), 0.0);
+        assert_eq!(rank.next(5.0), 100.0);
+        assert_eq!(round(rank.next(4.0)), 50.0);
+        // equal values are not lower
+        assert_eq!(round(rank.next(4.0)), 33.333);
+        // the maximum of the window
+        assert_eq!(rank.next(6.0), 100.0);
+        // the 3 left the window, a 3 is the minimum now
+        assert_eq!(rank.next(3.0), 0.0);
+
+        let mut rank = PercentRank::new(4).unwrap();
+        assert_eq!(rank.next(&Bar::new().close(3)), 0.0);
+        assert_eq!(rank.next(&Bar::new().close(5)), 100.0);
+    }
+
+    #[test]
+    fn test_next_random() {
+        let mut rng = StdRng::seed_from_u64(342);
+        // few distinct values to have ties
+        let values: Vec<f64> = (0..500).map(|_| rng.gen_range(0, 20) as f64).collect();
+
+        for period in [1, 3, 50] {
+            let mut rank = PercentRank::new(period).unwrap();
+            for (i, &value) in values.iter().enumerate() {
+                let previous = &values[i.saturating_sub(period)..i];
+                let expected = if previous.is_empty() {
+                    0.0
+                } else {
+                    let lower = previous.iter().filter(|&&v| v < value).count();
+                    lower as f64 / previous.len() as f64 * 100.0
+                };
+                assert_eq!(rank.next(value), expected);
+            }
+        }
+    }
+
+    #[test]
+    fn test_reset() {
+        let mut rank = PercentRank::new(4).unwrap();
+
+        rank.next(3.0);
+        rank.next(5.0);
+        rank.reset();
+
+        assert_eq!(rank.next(4.0), 0.0);
+        assert_eq!(rank.next(3.0), 0.0);
+        assert_eq!(rank.next(5.0), 100.0);
+    }
+
+    #[test]
+    fn test_ready() {
+        let mut rank = PercentRank::new(2).unwrap();
+        for _ in 0..2 {
+            rank.next(1.0);
+        }
+        assert_eq!((rank.bars_seen(), rank.is_ready()), (2, false));
+        // the third input is ranked against two previous values
+        rank.next(1.0);
+        assert_eq!((rank.bars_seen(), rank.is_ready()), (3, true));
+
+        rank.reset();
+        assert_eq!((rank.bars_seen(), rank.is_ready()), (0, false));
+    }
+
+    #[test]
+    fn test_default() {
+        PercentRank::default();
+    }
+
+    #[test]
+    fn test_display() {
+        let rank = PercentRank::new(100).unwrap();
+        assert_eq!(format!("{}", rank), "PERCENTRANK(100)");
+    }
+}
@@ -2,7 +2,7 @@ use std::fmt;
 
 use crate::errors::Result;
 use crate::indicators::ExponentialMovingAverage as Ema;
-use crate::{Close, Next, Period, Reset};
+use crate::{Close, Current, Next, Period, Ready, Reset};
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
@@ -22,12 +22,20 @@ use serde::{Deserialize, Serialize};
 ///
 /// # Formula
 ///
+/// PPO = 100 * (EMA(fast_period) - EMA(slow_period)) / EMA(slow_period)
+///
+/// Signal = EMA(signal_period) of PPO
+///
+/// Histogram = PPO - Signal
+///
 /// # Parameters
 ///
 /// * _fast_period_ - period for the fast EMA. Default is 12.
 /// * _slow_period_ - period for the slow EMA. Default is 26.
 /// * _signal_period_ - period for the signal EMA. Default is 9.
 ///
+/// The [`Period`] of the oscillator is the larger of _fast_period_ and _slow_period_.
+///
 /// # Example
 ///
 /// ```
@@ -82,6 +90,12 @@ impl From<PercentagePriceOscillatorOutput> for (f64, f64, f64) {
     }
 }
 
+impl Period for PercentagePriceOscillator {
+    fn period(&self) -> usize {
+        self.fast_ema.period().max(self.slow_ema.period())
+    }
+}
+
 impl Next<f64> for PercentagePriceOscillator {
     type Output = PercentagePriceOscillatorOutput;
 
@@ -109,6 +123,32 @@ impl<T: Close> Next<&T> for PercentagePriceOscillator {
     }
 }
 
+impl Current for PercentagePriceOscillator {
+    type Output = PercentagePriceOscillatorOutput;
+
+    fn current(&self) -> Option<Self::Output> {
+        let slow_val = self.slow_ema.current()?;
+        let ppo = (self.fast_ema.current()? - slow_val) / slow_val * 100.0;
+        let signal = self.signal_ema.current()?;
+
+        Some(PercentagePriceOscillatorOutput {
+            ppo,
+            signal,
+            histogram: ppo - signal,
+        })
+    }
+}
+
+impl Ready for PercentagePriceOscillator {
+    fn bars_seen(&self) -> usize {
+        self.slow_ema.bars_seen()
+    }
+
+    fn is_ready(&self) -> bool {
+        self.fast_ema.is_ready() && self.slow_ema.is_ready() && self.signal_ema.is_ready()
+    }
+}
+
 impl Reset for PercentagePriceOscillator {
     fn reset(&mut self) {
         self.fast_ema.reset();
@@ -170,6 +210,22 @@ mod tests {
         assert_eq!(round(ppo.next(6.5).into()), (17.84, 19.08, -1.24));
     }
 
+    #[test]
+    fn test_next_histogram() {
+        let mut ppo = Ppo::new(3, 6, 4).unwrap();
+
+        for input in [2.0, 3.0, 4.2, 7.0, 6.7, 6.5, 5.1, 5.9, 8.3, 7.7] {
+            let out = ppo.next(input);
+            assert_eq!(out.histogram, out.ppo - out.signal);
+        }
+    }
+
+    #[test]
+    fn test_period() {
+        assert_eq!(Ppo::new(12, 26, 9).unwrap().period(), 26);
+        assert_eq!(Ppo::new(30, 10, 9).unwrap().period(), 30);
+    }
+
     #[test]
     fn test_reset() {
         let mut ppo = Ppo::new(3, 6, 4).unwrap();
@@ -183,6 +239,35 @@ mod tests {
         assert_eq!(round(ppo.next(3.0).into()), (9.38, 3.75, 5.63));
     }
 
+    #[test]
+    fn test_current() {
+        let mut ppo = PercentagePriceOscillator::new(3, 6, 4).unwrap();
+        assert_eq!(ppo.current(), None);
+
+        for input in [2.0, 3.0, 4.2, 7.0, 6.7, 6.5] {
+            let value = ppo.next(input);
+            assert_eq!(ppo.current(), Some(value));
+        }
+
+        ppo.reset();
+        assert_eq!(ppo.current(), None);
+    }
+
+    #[test]
+    fn test_ready() {
+        let mut ppo = PercentagePriceOscillator::new(2, 4, 3).unwrap();
+        for _ in 0..3 {
+            ppo.next(2.0);
+        }
+        assert_eq!((ppo.bars_seen(), ppo.is_ready()), (3, false));
+        // the slow EMA is the last to fill its period
+        ppo.next(2.0);
+        assert_eq!((ppo.bars_seen(), ppo.is_ready()), (4, true));
+
+        ppo.reset();
+        assert_eq!((ppo.bars_seen(), ppo.is_ready()), (0, false));
+    }
+
     #[test]
     fn test_default() {
         Ppo::default();
@@ -0,0 +1,383 @@
+use std::fmt;
+
+use crate::errors::{Result, TaError};
+use crate::indicators::{ExponentialMovingAverage as Ema, RelativeStrengthIndex as Rsi};
+use crate::{Close, Next, Period, Ready, Reset};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Quantitative Qualitative Estimation (QQE).
+///
+/// A smoothed [RSI](struct.RelativeStrengthIndex.html) accompanied by trailing lines which
+/// follow it at a distance derived from the volatility of the RSI itself, an ATR of the RSI.
+/// Like a trailing stop a line stays below the smoothed RSI while it rises and above it while
+/// it falls, a cross of the smoothed RSI and a line signals a change of the momentum. The fast
+/// line keeps a smaller distance than the slow line and is crossed more often.
+///
+/// # Formula
+///
+/// RSI<sub>s</sub> = EMA(smoothing) of RSI(rsi_period)
+///
+/// ATR<sub>RSI</sub> = EMA(2 * rsi_period - 1) of |RSI<sub>s</sub> - previous RSI<sub>s</sub>|
+///
+/// DAR = EMA(2 * rsi_period - 1) of ATR<sub>RSI</sub>
+///
+/// The slow line trails RSI<sub>s</sub> at a distance of _DAR * factor_, the fast line at
+/// _DAR * factor * 0.618_. Below RSI<sub>s</sub> a line is at _max(previous line,
+/// RSI<sub>s</sub> - distance)_ while the current and the previous RSI<sub>s</sub> are above
+/// it, above RSI<sub>s</sub> at _min(previous line, RSI<sub>s</sub> + distance)_ while both are
+/// below it. A line jumps to the other side when RSI<sub>s</sub> crosses it. The lines start
+/// below RSI<sub>s</sub>.
+///
+/// # Parameters
+///
+/// * _rsi_period_ - period of the RSI (integer greater than 0). Default is 14.
+/// * _smoothing_ - period of the EMA smoothing the RSI (integer greater than 0). Default is 5.
+/// * _factor_ - multiple of the DAR the slow line trails at (greater than 0). Default is 4.236.
+///
+/// The [period](Period::period) is the longest of the smoothing and the _2 * rsi_period - 1_ of
+/// the EMAs smoothing the RSI changes.
+///
+/// # Example
+///
+/// ```
+/// use ta::indicators::Qqe;
+/// use ta::Next;
+///
+/// let mut qqe = Qqe::new(3, 2, 4.236).unwrap();
+///
+/// let out = qqe.next(10.0);
+/// assert_eq!((out.rsi_smoothed, out.fast, out.slow), (50.0, 50.0, 50.0));
+///
+/// // the lines trail below the rising RSI
+/// let out = qqe.next(11.0);
+/// assert!(out.slow < out.fast && out.fast < out.rsi_smoothed);
+/// ```
+///
+/// # Links
+///
+/// * [QQE, TradingView](https://www.tradingview.com/script/tJ6vtBBe-QQE/)
+///
+#[doc(alias = "QQE")]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone)]
+pub struct Qqe {
+    factor: f64,
+    rsi: Rsi,
+    rsi_ema: Ema,
+    atr_rsi: Ema,
+    dar: Ema,
+    prev_rsi: Option<f64>,
+    fast: TrailingLine,
+    slow: TrailingLine,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct QqeOutput {
+    pub rsi_smoothed: f64,
+    pub fast: f64,
+    pub slow: f64,
+}
+
+impl From<QqeOutput> for (f64, f64, f64) {
+    fn from(qo: QqeOutput) -> Self {
+        (qo.rsi_smoothed, qo.fast, qo.slow)
+    }
+}
+
+impl Qqe {
+    pub fn new(rsi_period: usize, smoothing: usize, factor: f64) -> Result<Self> {
+        let is_valid = factor > 0.0 && factor.is_finite();
+        if !is_valid {
+            return Err(TaError::invalid_parameter("factor", factor));
+        }
+        let rsi = Rsi::new(rsi_period)?;
+        let wilders_period = 2 * rsi_period - 1;
+        Ok(Self {
+            factor,
+            rsi,
+            rsi_ema: Ema::new(smoothing)?,
+            atr_rsi: Ema::new(wilders_period)?,
+            dar: Ema::new(wilders_period)?,
+            prev_rsi: None,
+            fast: TrailingLine::new(factor * 0.618),
+            slow: TrailingLine::new(factor),
+        })
+    }
+
+    pub fn factor(&self) -> f64 {
+        self.factor
+    }
+
+    pub fn rsi_period(&self) -> usize {
+        self.rsi.period()
+    }
+}
+
+impl Period for Qqe {
+    fn period(&self) -> usize {
+        self.dar.period().max(self.rsi_ema.period())
+    }
+}
+
+impl Next<f64> for Qqe {
+    type Output = QqeOutput;
+
+    fn next(&mut self, input: f64) -> Self::Output {
+        let rsi = self.rsi_ema.next(self.rsi.next(input));
+        let prev_rsi = self.prev_rsi.unwrap_or(rsi);
+        self.prev_rsi = Some(rsi);
+
+        let dar = self.dar.next(self.atr_rsi.next((rsi - prev_rsi).abs()));
+
+        QqeOutput {
+            rsi_smoothed: rsi,
+            fast: self.fast.next(rsi, prev_rsi, dar),
+            slow: self.slow.next(rsi, prev_rsi, dar),
+        }
+    }
+}
+
+impl<T: Close> Next<&T> for Qqe {
+    type Output = QqeOutput;
+
+    fn next(&mut self, input: &T) -> Self::Output {
+        self.next(input.close())
+    }
+}
+
+impl Ready for Qqe {
+    fn bars_seen(&self) -> usize {
+        self.rsi.bars_seen()
+    }
+
+    fn is_ready(&self) -> bool {
+        self.rsi.is_ready()
+            && self.rsi_ema.is_ready()
+            && self.atr_rsi.is_ready()
+            && self.dar.is_ready()
+    }
+}
+
+impl Reset for Qqe {
+    fn reset(&mut self) {
+        self.rsi.reset();
+        self.rsi_ema.reset();
+        self.atr_rsi.reset();
+        self.dar.reset();
+        self.prev_rsi = None;
+        self.fast.reset();
+        self.slow.reset();
+    }
+}
+
+impl Default for Qqe {
+    fn default() -> Self {
+        Self::new(14, 5, 4.236).unwrap()
+    }
+}
+
+impl fmt::Display for Qqe {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "QQE({}, {}, {})",
+            self.rsi_period(),
+            self.rsi_ema.period(),
+            self.factor
+        )
+    }
+}
+
+/// A line trailing the smoothed RSI at `multiplier` times the DAR.
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone)]
+struct TrailingLine {
+    multiplier: f64,
+    // the bands below and above the smoothed RSI
+    bands: Option<(f64, f64)>,
+    is_below: bool,
+}
+
+impl TrailingLine {
+    fn new(multiplier: f64) -> Self {
+        Self {
+            multiplier,
+            bands: None,
+            is_below: true,
+        }
+    }
+
+    fn next(&mut self, rsi: f64, prev_rsi: f64, dar: f64) -> f64 {
+        let distance = dar * self.multiplier;
+        let (new_lower, new_upper) = (rsi - distance, rsi + distance);
+
+        let (lower, upper) = match self.bands {
+            None => (new_lower, new_upper),
+            Some((lower, upper)) => {
+                if self.is_below && rsi < lower {
+                    self.is_below = false;
+                } else if !self.is_below && rsi > upper {
+                    self.is_below = true;
+                }
+                // a band only ratchets towards the RSI while the RSI stays on its side
+                (
+                    if prev_rsi > lower && rsi > lower {
+                        lower.max(new_lower)
+                    } else {
+                        new_lower
+                    },
+                    if prev_rsi < upper && rsi < upper {
+                        upper.min(new_upper)
+                    } else {
+                        new_upper
+                    },
+                )
+            }
+        };
+        self.bands = Some((lower, upper));
+
+        if self.is_below {
+            lower
+        } else {
+            upper
+        }
+    }
+
+    fn reset(&mut self) {
+        self.bands = None;
+        self.is_below = true;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helper::*;
+
+    fn round(out: QqeOutput) -> (f64, f64, f64) {
+        (
+            crate::test_helper::round(out.rsi_smoothed),
+            crate::test_helper::round(out.fast),
+            crate::test_helper::round(out.slow),
+        )
+    }
+
+    test_indicator!(Qqe);
+
+    #[test]
+    fn test_new() {
+        assert!(Qqe::new(0, 5, 4.236).is_err());
+        assert!(Qqe::new(14, 0, 4.236).is_err());
+        assert!(Qqe::new(14, 5, 0.0).is_err());
+        assert!(Qqe::new(14, 5, f64::NAN).is_err());
+        assert!(Qqe::new(1, 1, 1.0).is_ok());
+        assert!(Qqe::new(14, 5, 4.236).is_ok());
+    }
+
+    #[test]
+    fn test_next() {
+        let mut qqe = Qqe::new(3, 2, 4.236).unwrap();
+
+        let inputs = [10.0, 11.0, 12.0, 11.0, 10.0, 11.5, 13.0, 12.5, 12.0, 14.0];
+        let expected = [
+            (50.0, 50.0, 50.0),
+            (83.333, 73.638, 67.644),
+            (94.444, 78.285, 68.296),
+            // the smoothed rsi crosses the fast line, it jumps above
+            (75.926, 98.549, 68.296),
+            // and then the slow line
+            (54.938, 84.025, 99.022),
+            // both lines stay above while the rsi is below them
+            (63.815, 84.025, 99.022),
+            (75.056, 84.025, 99.022),
+            (70.002, 84.025, 99.022),
+            (59.452, 84.025, 99.022),
+            (72.487, 84.025, 99.022),
+        ];
+
+        for (&input, expected) in inputs.iter().zip(expected) {
+            assert_eq!(round(qqe.next(input)), expected);
+        }
+
+        let mut qqe = Qqe::new(3, 2, 4.236).unwrap();
+        assert_eq!(round(qqe.next(&Bar::new().close(10))), (50.0, 50.0, 50.0));
+        assert_eq!(
+            round(qqe.next(&Bar::new().close(11))),
+            (83.333, 73.638, 67.644)
+        );
+    }
+
+    #[test]
+    fn test_next_monotonic() {
+        let mut qqe = Qqe::default();
+
+        // on a rising input the lines stay below and never move down
+        let mut prev = qqe.next(100.0);
+        for i in 1..100 {
+            let out = qqe.next(100.0 + i as f64);
+            assert!(out.fast <= out.rsi_smoothed && out.slow <= out.fast);
+            assert!(out.fast >= prev.fast && out.slow >= prev.slow);
+            prev = out;
+        }
+
+        // on a falling input they cross over once and then never move up
+        for i in 1..100 {
+            let out = qqe.next(200.0 - i as f64);
+            if i > 20 {
+                assert!(out.fast >= out.rsi_smoothed && out.slow >= out.fast);
+                assert!(out.fast <= prev.fast && out.slow <= prev.slow);
+            }
+            prev = out;
+        }
+    }
+
+    #[test]
+    fn test_reset() {
+        let mut qqe = Qqe::new(3, 2, 4.236).unwrap();
+
+        qqe.next(10.0);
+        qqe.next(11.0);
+        qqe.next(12.0);
+        qqe.reset();
+
+        assert_eq!(round(qqe.next(10.0)), (50.0, 50.0, 50.0));
+        assert_eq!(round(qqe.next(11.0)), (83.333, 73.638, 67.644));
+    }
+
+    #[test]
+    fn test_ready() {
+        let mut qqe = Qqe::new(3, 2, 4.236).unwrap();
+        for _ in 0..4 {
+            qqe.next(1.0);
+        }
+        assert_eq!((qqe.bars_seen(), qqe.is_ready()), (4, false));
+        // the EMAs of the RSI changes span five inputs
+        qqe.next(1.0);
+        assert_eq!((qqe.bars_seen(), qqe.is_ready()), (5, true));
+
+        qqe.reset();
+        assert_eq!((qqe.bars_seen(), qqe.is_ready()), (0, false));
+    }
+
+    #[test]
+    fn test_period() {
+        let qqe = Qqe::new(14, 5, 4.236).unwrap();
+        assert_eq!((qqe.period(), qqe.rsi_period()), (27, 14));
+
+        // a smoothing longer than the EMAs of the RSI changes
+        let qqe = Qqe::new(2, 5, 4.236).unwrap();
+        assert_eq!(qqe.period(), 5);
+    }
+
+    #[test]
+    fn test_default() {
+        Qqe::default();
+    }
+
+    #[test]
+    fn test_display() {
+        let qqe = Qqe::new(14, 5, 4.236).unwrap();
+        assert_eq!(format!("{}", qqe), "QQE(14, 5, 4.236)");
+    }
+}
@@ -1,7 +1,7 @@
 use std::fmt;
 
 use crate::errors::{Result, TaError};
-use crate::traits::{Close, Next, Period, Reset};
+use crate::traits::{Close, Next, Period, Ready, Reset};
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
@@ -17,9 +17,12 @@ use serde::{Deserialize, Serialize};
 /// * P<sub>t</sub> - price at the moment
 /// * P<sub>t-n</sub> - price _n_ periods ago
 ///
+/// Until _n_ prices have been seen the oldest price is used instead. The rate of change from a
+/// price of 0 is undefined, it is reported as 0.
+///
 /// # Parameters
 ///
-/// * _period_ - number of periods integer greater than 0
+/// * _period_ - number of periods (integer greater than 0). Default is 9.
 ///
 /// # Example
 ///
@@ -46,17 +49,20 @@ pub struct RateOfChange {
     index: usize,
     count: usize,
     deque: Box<[f64]>,
+    #[cfg_attr(feature = "serde", serde(default))]
+    bars_seen: usize,
 }
 
 impl RateOfChange {
     pub fn new(period: usize) -> Result<Self> {
         match period {
-            0 => Err(TaError::InvalidParameter),
+            0 => Err(TaError::invalid_parameter("period", period)),
             _ => Ok(Self {
                 period,
                 index: 0,
                 count: 0,
                 deque: vec![0.0; period].into_boxed_slice(),
+                bars_seen: 0,
             }),
         }
     }
@@ -72,6 +78,7 @@ impl Next<f64> for RateOfChange {
     type Output = f64;
 
     fn next(&mut self, input: f64) -> f64 {
+        self.bars_seen = self.bars_seen.saturating_add(1);
         let previous = if self.count > self.period {
             self.deque[self.index]
         } else {
@@ -90,6 +97,9 @@ impl Next<f64> for RateOfChange {
             0
         };
 
+        if previous == 0.0 {
+            return 0.0;
+        }
         (input - previous) / previous * 100.0
     }
 }
@@ -114,8 +124,20 @@ impl fmt::Display for RateOfChange {
     }
 }
 
+impl Ready for RateOfChange {
+    fn bars_seen(&self) -> usize {
+        self.bars_seen
+    }
+
+    fn is_ready(&self) -> bool {
+        // only the inputs after the first _period_ have a price _period_ inputs before them
+        self.bars_seen > self.period
+    }
+}
+
 impl Reset for RateOfChange {
     fn reset(&mut self) {
+        self.bars_seen = 0;
         self.index = 0;
         self.count = 0;
         for i in 0..self.period {
@@ -150,6 +172,16 @@ mod tests {
         assert_eq!(round(roc.next(10.0)), -5.393);
     }
 
+    #[test]
+    fn test_next_zero() {
+        let mut roc = RateOfChange::new(2).unwrap();
+
+        assert_eq!(roc.next(0.0), 0.0);
+        assert_eq!(roc.next(5.0), 0.0);
+        assert_eq!(roc.next(10.0), 0.0);
+        assert_eq!(roc.next(10.0), 100.0);
+    }
+
     #[test]
     fn test_next_bar() {
         fn bar(close: f64) -> Bar {
@@ -176,4 +208,32 @@ mod tests {
         assert_eq!(round(roc.next(10.4)), 4.0);
         assert_eq!(round(roc.next(10.57)), 5.7);
     }
+
+    #[test]
+    fn test_ready() {
+        let mut roc = RateOfChange::new(3).unwrap();
+        assert_eq!((roc.bars_seen(), roc.is_ready()), (0, false));
+
+        roc.next(1.0);
+        roc.next(2.0);
+        roc.next(3.0);
+        assert_eq!((roc.bars_seen(), roc.is_ready()), (3, false));
+        // the fourth input is the first compared with the value 3 inputs before
+        roc.next(4.0);
+        assert_eq!((roc.bars_seen(), roc.is_ready()), (4, true));
+
+        roc.reset();
+        assert_eq!((roc.bars_seen(), roc.is_ready()), (0, false));
+    }
+
+    #[test]
+    fn test_default() {
+        RateOfChange::default();
+    }
+
+    #[test]
+    fn test_display() {
+        let roc = RateOfChange::new(9).unwrap();
+        assert_eq!(format!("{}", roc), "ROC(9)");
+    }
 }
@@ -1,8 +1,9 @@
 use std::fmt;
 
-use crate::errors::Result;
-use crate::indicators::ExponentialMovingAverage as Ema;
-use crate::{Close, Next, Period, Reset};
+use crate::errors::{Result, TaError};
+use crate::helpers::WilderSmoothing;
+use crate::indicators::{ExponentialMovingAverage as Ema, SimpleMovingAverage as Sma};
+use crate::{Close, Current, Next, Period, Ready, Reset};
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
@@ -20,13 +21,13 @@ use serde::{Deserialize, Serialize};
 ///
 /// # Formula
 ///
-/// RSI<sub>t</sub> = EMA<sub>Ut</sub> * 100 / (EMA<sub>Ut</sub> + EMA<sub>Dt</sub>)
+/// RSI<sub>t</sub> = AVG<sub>Ut</sub> * 100 / (AVG<sub>Ut</sub> + AVG<sub>Dt</sub>)
 ///
 /// Where:
 ///
 /// * RSI<sub>t</sub> - value of RSI indicator in a moment of time _t_
-/// * EMA<sub>Ut</sub> - value of [EMA](struct.ExponentialMovingAverage.html) of up periods in a moment of time _t_
-/// * EMA<sub>Dt</sub> - value of [EMA](struct.ExponentialMovingAverage.html) of down periods in a moment of time _t_
+/// * AVG<sub>Ut</sub> - smoothed value of up periods in a moment of time _t_
+/// * AVG<sub>Dt</sub> - smoothed value of down periods in a moment of time _t_
 ///
 /// If current period has value higher than previous period, than:
 ///
@@ -47,6 +48,15 @@ use serde::{Deserialize, Serialize};
 /// * p<sub>t</sub> - input value in a moment of time _t_
 /// * p<sub>t-1</sub> - input value in a moment of time _t-1_
 ///
+/// By default the gains and losses are smoothed with Wilder's smoothing, equal to an EMA of
+/// _2 * period - 1_ seeded with the average of the first _period_ changes, as in Wilder's
+/// definition. [with_smoothing](Self::with_smoothing) selects an
+/// [EMA](struct.ExponentialMovingAverage.html) of _period_ instead, the smoothing of earlier
+/// versions, or an SMA of _period_, known as Cutler's RSI, see [RsiSmoothing]. The first input
+/// only seeds the previous value and yields 50, as does a period without any change. With the
+/// EMA both averages are seeded with 0.1 by the first input instead. Either way the RSI is
+/// [ready](Ready::is_ready) once it saw _period_ changes, after _period + 1_ inputs.
+///
 /// # Parameters
 ///
 /// * _period_ - number of periods (integer greater than 0). Default value is 14.
@@ -54,14 +64,24 @@ use serde::{Deserialize, Serialize};
 /// # Example
 ///
 /// ```
-/// use ta::indicators::RelativeStrengthIndex;
+/// use ta::indicators::{RelativeStrengthIndex, RsiSmoothing};
 /// use ta::Next;
 ///
 /// let mut rsi = RelativeStrengthIndex::new(3).unwrap();
 /// assert_eq!(rsi.next(10.0), 50.0);
-/// assert_eq!(rsi.next(10.5).round(), 86.0);
-/// assert_eq!(rsi.next(10.0).round(), 35.0);
-/// assert_eq!(rsi.next(9.5).round(), 16.0);
+/// assert_eq!(rsi.next(10.5), 100.0);
+/// assert_eq!(rsi.next(10.0), 50.0);
+/// assert_eq!(rsi.next(9.5).round(), 33.0);
+///
+/// let mut ema = RelativeStrengthIndex::new(3).unwrap().with_smoothing(RsiSmoothing::Ema);
+/// assert_eq!(ema.next(10.0), 50.0);
+/// assert_eq!(ema.next(10.5).round(), 86.0);
+/// assert_eq!(ema.next(10.0).round(), 35.0);
+///
+/// let mut cutler = RelativeStrengthIndex::new(3).unwrap().with_smoothing(RsiSmoothing::Sma);
+/// assert_eq!(cutler.next(10.0), 50.0);
+/// assert_eq!(cutler.next(10.5), 100.0);
+/// assert_eq!(cutler.next(10.0), 50.0);
 /// ```
 ///
 /// # Links
@@ -73,22 +93,108 @@ use serde::{Deserialize, Serialize};
 #[derive(Debug, Clone)]
 pub struct RelativeStrengthIndex {
     period: usize,
-    up_ema_indicator: Ema,
-    down_ema_indicator: Ema,
+    smoothing: RsiSmoothing,
+    up: Smoother,
+    down: Smoother,
     prev_val: f64,
     is_new: bool,
+    #[cfg_attr(feature = "serde", serde(default))]
+    bars_seen: usize,
+}
+
+/// How a [RelativeStrengthIndex] smooths the gains and losses.
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum RsiSmoothing {
+    /// Wilder's smoothing, seeded with the average of the first _period_ changes.
+    #[default]
+    Wilder,
+    /// A simple moving average of the last _period_ changes, Cutler's RSI.
+    Sma,
+    /// An exponential moving average of _period_.
+    Ema,
+}
+
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone)]
+enum Smoother {
+    Wilder(WilderSmoothing),
+    Sma(Sma),
+    Ema(Ema),
+}
+
+impl Smoother {
+    fn new(smoothing: RsiSmoothing, period: usize) -> Result<Self> {
+        Ok(match smoothing {
+            RsiSmoothing::Wilder => Self::Wilder(WilderSmoothing::new(period)),
+            RsiSmoothing::Sma => Self::Sma(Sma::new(period)?),
+            RsiSmoothing::Ema => Self::Ema(Ema::new(period)?),
+        })
+    }
+
+    fn next(&mut self, input: f64) -> f64 {
+        match self {
+            Self::Wilder(wilder) => wilder.next(input),
+            // the rolling sum can drift below 0 once only zeros are left in the window
+            Self::Sma(sma) => sma.next(input).max(0.0),
+            Self::Ema(ema) => ema.next(input),
+        }
+    }
+
+    fn current(&self) -> f64 {
+        match self {
+            Self::Wilder(wilder) => wilder.current(),
+            Self::Sma(sma) => sma.current().unwrap_or(0.0).max(0.0),
+            Self::Ema(ema) => ema.current().unwrap_or(0.0),
+        }
+    }
+
+    fn reset(&mut self) {
+        match self {
+            Self::Wilder(wilder) => wilder.reset(),
+            Self::Sma(sma) => sma.reset(),
+            Self::Ema(ema) => ema.reset(),
+        }
+    }
 }
 
 impl RelativeStrengthIndex {
     pub fn new(period: usize) -> Result<Self> {
+        if period == 0 {
+            return Err(TaError::invalid_parameter("period", period));
+        }
+        let smoothing = RsiSmoothing::default();
         Ok(Self {
             period,
-            up_ema_indicator: Ema::new(period)?,
-            down_ema_indicator: Ema::new(period)?,
+            smoothing,
+            up: Smoother::new(smoothing, period)?,
+            down: Smoother::new(smoothing, period)?,
             prev_val: 0.0,
             is_new: true,
+            bars_seen: 0,
         })
     }
+
+    pub fn with_smoothing(mut self, smoothing: RsiSmoothing) -> Self {
+        // the period was validated by new
+        self.smoothing = smoothing;
+        self.up = Smoother::new(smoothing, self.period).unwrap();
+        self.down = Smoother::new(smoothing, self.period).unwrap();
+        self
+    }
+
+    pub fn smoothing(&self) -> RsiSmoothing {
+        self.smoothing
+    }
+
+    fn rsi(up_avg: f64, down_avg: f64) -> f64 {
+        if up_avg + down_avg == 0.0 {
+            50.0
+        } else {
+            // the ratio first, so that it cannot round above 100 without any loss
+            100.0 * (up_avg / (up_avg + down_avg))
+        }
+    }
 }
 
 impl Period for RelativeStrengthIndex {
@@ -101,26 +207,29 @@ impl Next<f64> for RelativeStrengthIndex {
     type Output = f64;
 
     fn next(&mut self, input: f64) -> Self::Output {
+        self.bars_seen = self.bars_seen.saturating_add(1);
         let mut up = 0.0;
         let mut down = 0.0;
 
         if self.is_new {
             self.is_new = false;
+            self.prev_val = input;
+            if self.smoothing != RsiSmoothing::Ema {
+                return 50.0;
+            }
             // Initialize with some small seed numbers to avoid division by zero
             up = 0.1;
             down = 0.1;
+        } else if input > self.prev_val {
+            up = input - self.prev_val;
         } else {
-            if input > self.prev_val {
-                up = input - self.prev_val;
-            } else {
-                down = self.prev_val - input;
-            }
+            down = self.prev_val - input;
         }
 
         self.prev_val = input;
-        let up_ema = self.up_ema_indicator.next(up);
-        let down_ema = self.down_ema_indicator.next(down);
-        100.0 * up_ema / (up_ema + down_ema)
+        let up_avg = self.up.next(up);
+        let down_avg = self.down.next(down);
+        Self::rsi(up_avg, down_avg)
     }
 }
 
@@ -132,12 +241,36 @@ impl<T: Close> Next<&T> for RelativeStrengthIndex {
     }
 }
 
+impl Current for RelativeStrengthIndex {
+    type Output = f64;
+
+    fn current(&self) -> Option<Self::Output> {
+        if self.is_new {
+            None
+        } else {
+            // before the first change the averages are still 0, which is an RSI of 50
+            Some(Self::rsi(self.up.current(), self.down.current()))
+        }
+    }
+}
+
+impl Ready for RelativeStrengthIndex {
+    fn bars_seen(&self) -> usize {
+        self.bars_seen
+    }
+
+    fn is_ready(&self) -> bool {
+        self.bars_seen > self.period
+    }
+}
+
 impl Reset for RelativeStrengthIndex {
     fn reset(&mut self) {
+        self.bars_seen = 0;
         self.is_new = true;
         self.prev_val = 0.0;
-        self.up_ema_indicator.reset();
-        self.down_ema_indicator.reset();
+        self.up.reset();
+        self.down.reset();
     }
 }
 
@@ -149,7 +282,11 @@ impl Default for RelativeStrengthIndex {
 
 impl fmt::Display for RelativeStrengthIndex {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "RSI({})", self.period)
+        match self.smoothing {
+            RsiSmoothing::Wilder => write!(f, "RSI({})", self.period),
+            RsiSmoothing::Sma => write!(f, "RSI({}, sma)", self.period),
+            RsiSmoothing::Ema => write!(f, "RSI({}, ema)", self.period),
+        }
     }
 }
 
@@ -157,6 +294,8 @@ impl fmt::Display for RelativeStrengthIndex {
 mod tests {
     use super::*;
     use crate::test_helper::*;
+    use rand::rngs::StdRng;
+    use rand::{Rng, SeedableRng};
 
     test_indicator!(RelativeStrengthIndex);
 
@@ -169,21 +308,125 @@ mod tests {
     #[test]
     fn test_next() {
         let mut rsi = RelativeStrengthIndex::new(3).unwrap();
+        assert_eq!(rsi.smoothing(), RsiSmoothing::Wilder);
+        assert_eq!(rsi.next(10.0), 50.0);
+        assert_eq!(rsi.next(10.5), 100.0);
+        assert_eq!(rsi.next(10.0), 50.0);
+        assert_eq!(round(rsi.next(9.5)), 33.333);
+
+        let mut rsi = RelativeStrengthIndex::new(3)
+            .unwrap()
+            .with_smoothing(RsiSmoothing::Ema);
         assert_eq!(rsi.next(10.0), 50.0);
         assert_eq!(rsi.next(10.5).round(), 86.0);
         assert_eq!(rsi.next(10.0).round(), 35.0);
         assert_eq!(rsi.next(9.5).round(), 16.0);
     }
 
+    #[test]
+    fn test_next_smoothing() {
+        let inputs = [10.0, 10.5, 10.0, 9.5, 9.8, 10.4, 10.2, 10.9, 11.3, 10.8];
+        let mut wilder = RelativeStrengthIndex::new(3)
+            .unwrap()
+            .with_smoothing(RsiSmoothing::Wilder);
+        let mut cutler = RelativeStrengthIndex::new(3)
+            .unwrap()
+            .with_smoothing(RsiSmoothing::Sma);
+        assert_eq!(wilder.smoothing(), RsiSmoothing::Wilder);
+        assert_eq!(cutler.smoothing(), RsiSmoothing::Sma);
+
+        let wilder: Vec<f64> = inputs.iter().map(|&i| round(wilder.next(i))).collect();
+        let cutler: Vec<f64> = inputs.iter().map(|&i| round(cutler.next(i))).collect();
+
+        assert_eq!(
+            wilder,
+            [50.0, 100.0, 50.0, 33.333, 48.718, 69.697, 57.862, 77.722, 84.133, 54.649]
+        );
+        // the same until the first period of changes is complete, Cutler's RSI then forgets
+        // the older changes entirely
+        assert_eq!(
+            cutler,
+            [50.0, 100.0, 50.0, 33.333, 23.077, 64.286, 81.818, 86.667, 84.615, 68.75]
+        );
+    }
+
+    #[test]
+    fn test_next_smoothing_bounds() {
+        let mut rng = StdRng::seed_from_u64(347);
+
+        for smoothing in [RsiSmoothing::Wilder, RsiSmoothing::Sma, RsiSmoothing::Ema] {
+            let mut rsi = RelativeStrengthIndex::new(5)
+                .unwrap()
+                .with_smoothing(smoothing);
+            for _ in 0..500 {
+                let out = rsi.next(rng.gen_range(1.0, 100.0));
+                assert!((0.0..=100.0).contains(&out));
+            }
+        }
+
+        // no change keeps the RSI at 50
+        let mut rsi = RelativeStrengthIndex::new(3)
+            .unwrap()
+            .with_smoothing(RsiSmoothing::Sma);
+        for _ in 0..5 {
+            assert_eq!(rsi.next(10.0), 50.0);
+        }
+    }
+
     #[test]
     fn test_reset() {
-        let mut rsi = RelativeStrengthIndex::new(3).unwrap();
+        let mut rsi = RelativeStrengthIndex::new(3)
+            .unwrap()
+            .with_smoothing(RsiSmoothing::Ema);
         assert_eq!(rsi.next(10.0), 50.0);
         assert_eq!(rsi.next(10.5).round(), 86.0);
 
         rsi.reset();
         assert_eq!(rsi.next(10.0).round(), 50.0);
         assert_eq!(rsi.next(10.5).round(), 86.0);
+
+        let mut rsi = RelativeStrengthIndex::new(3)
+            .unwrap()
+            .with_smoothing(RsiSmoothing::Sma);
+        rsi.next(10.0);
+        rsi.next(9.0);
+        rsi.reset();
+        assert_eq!(rsi.next(10.0), 50.0);
+        assert_eq!(rsi.next(10.5), 100.0);
+    }
+
+    #[test]
+    fn test_current() {
+        for smoothing in [RsiSmoothing::Wilder, RsiSmoothing::Sma, RsiSmoothing::Ema] {
+            let mut rsi = RelativeStrengthIndex::new(3)
+                .unwrap()
+                .with_smoothing(smoothing);
+            assert_eq!(rsi.current(), None);
+
+            for input in [10.0, 10.5, 10.0, 9.5, 9.5, 9.5, 9.5, 11.0] {
+                let value = rsi.next(input);
+                assert_eq!(rsi.current(), Some(value));
+            }
+
+            rsi.reset();
+            assert_eq!(rsi.current(), None);
+        }
+    }
+
+    #[test]
+    fn test_ready() {
+        let mut rsi = RelativeStrengthIndex::new(2).unwrap();
+        assert_eq!((rsi.bars_seen(), rsi.is_ready()), (0, false));
+
+        rsi.next(1.0);
+        rsi.next(2.0);
+        assert_eq!((rsi.bars_seen(), rsi.is_ready()), (2, false));
+        // the third input completes the second change
+        rsi.next(3.0);
+        assert_eq!((rsi.bars_seen(), rsi.is_ready()), (3, true));
+
+        rsi.reset();
+        assert_eq!((rsi.bars_seen(), rsi.is_ready()), (0, false));
     }
 
     #[test]
@@ -195,5 +438,11 @@ mod tests {
     fn test_display() {
         let rsi = RelativeStrengthIndex::new(16).unwrap();
         assert_eq!(format!("{}", rsi), "RSI(16)");
+
+        let rsi = rsi.with_smoothing(RsiSmoothing::Sma);
+        assert_eq!(format!("{}", rsi), "RSI(16, sma)");
+
+        let rsi = rsi.with_smoothing(RsiSmoothing::Ema);
+        assert_eq!(format!("{}", rsi), "RSI(16, ema)");
     }
 }
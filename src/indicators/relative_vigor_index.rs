@@ -0,0 +1,304 @@
+use std::fmt;
+
+use crate::errors::Result;
+use crate::indicators::SimpleMovingAverage;
+use crate::{Close, High, Low, Next, Open, Period, Ready, Reset};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Relative Vigor Index (RVI).
+///
+/// Developed by John Ehlers, the relative vigor index measures the strength of a trend by
+/// comparing the close to the open relative to the range of the bar. In an uptrend prices
+/// tend to close above their open, in a downtrend below.
+///
+/// # Formula
+///
+/// Numerator = SWMA of (close - open)
+///
+/// Denominator = SWMA of (high - low)
+///
+/// RVI = SMA(period) of Numerator / SMA(period) of Denominator
+///
+/// Signal = SWMA of RVI
+///
+/// Where:
+///
+/// * _SWMA_ - symmetric weighted moving average of the last 4 values, with weights 1, 2, 2, 1.
+///   Until 4 values are available, the first value stands in for the missing ones.
+/// * _SMA_ - [simple moving average](struct.SimpleMovingAverage.html)
+///
+/// A zero denominator yields an RVI of 0.
+///
+/// # Parameters
+///
+/// * _period_ - period of the SMAs (integer greater than 0). Default is 10.
+///
+/// # Example
+///
+/// ```
+/// use ta::indicators::RelativeVigorIndex;
+/// use ta::{DataItem, Next};
+///
+/// let mut rvi = RelativeVigorIndex::new(10).unwrap();
+///
+/// let di = DataItem::builder()
+///     .open(10.0).high(12.0).low(8.0).close(11.0).volume(1000.0)
+///     .build(true).unwrap();
+///
+/// let out = rvi.next(&di);
+/// assert_eq!(out.rvi, 0.25);
+/// assert_eq!(out.signal, 0.25);
+/// ```
+///
+/// # Links
+///
+/// * [Relative Vigor Index, Investopedia](https://www.investopedia.com/terms/r/relative_vigor_index.asp)
+///
+#[doc(alias = "RVI")]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone)]
+pub struct RelativeVigorIndex {
+    numerator: SymmetricWeighted,
+    denominator: SymmetricWeighted,
+    numerator_sma: SimpleMovingAverage,
+    denominator_sma: SimpleMovingAverage,
+    signal: SymmetricWeighted,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct RelativeVigorIndexOutput {
+    pub rvi: f64,
+    pub signal: f64,
+}
+
+impl From<RelativeVigorIndexOutput> for (f64, f64) {
+    fn from(rvi: RelativeVigorIndexOutput) -> Self {
+        (rvi.rvi, rvi.signal)
+    }
+}
+
+impl RelativeVigorIndex {
+    pub fn new(period: usize) -> Result<Self> {
+        Ok(Self {
+            numerator: SymmetricWeighted::new(),
+            denominator: SymmetricWeighted::new(),
+            numerator_sma: SimpleMovingAverage::new(period)?,
+            denominator_sma: SimpleMovingAverage::new(period)?,
+            signal: SymmetricWeighted::new(),
+        })
+    }
+}
+
+impl Period for RelativeVigorIndex {
+    fn period(&self) -> usize {
+        self.numerator_sma.period()
+    }
+}
+
+impl<T: Open + High + Low + Close> Next<&T> for RelativeVigorIndex {
+    type Output = RelativeVigorIndexOutput;
+
+    fn next(&mut self, input: &T) -> Self::Output {
+        let numerator = self
+            .numerator_sma
+            .next(self.numerator.next(input.close() - input.open()));
+        let denominator = self
+            .denominator_sma
+            .next(self.denominator.next(input.high() - input.low()));
+
+        let rvi = if denominator == 0.0 {
+            0.0
+        } else {
+            numerator / denominator
+        };
+
+        RelativeVigorIndexOutput {
+            rvi,
+            signal: self.signal.next(rvi),
+        }
+    }
+}
+
+impl Ready for RelativeVigorIndex {
+    fn bars_seen(&self) -> usize {
+        self.numerator_sma.bars_seen()
+    }
+
+    fn is_ready(&self) -> bool {
+        // the symmetric weighting spans 4 bars
+        self.numerator_sma.is_ready() && self.numerator_sma.bars_seen() >= 4
+    }
+}
+
+impl Reset for RelativeVigorIndex {
+    fn reset(&mut self) {
+        self.numerator.reset();
+        self.denominator.reset();
+        self.numerator_sma.reset();
+        self.denominator_sma.reset();
+        self.signal.reset();
+    }
+}
+
+impl Default for RelativeVigorIndex {
+    fn default() -> Self {
+        Self::new(10).unwrap()
+    }
+}
+
+impl fmt::Display for RelativeVigorIndex {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "RVI({})", self.numerator_sma.period())
+    }
+}
+
+/// Weighted average of the last 4 values with the weights 1, 2, 2, 1.
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone)]
+struct SymmetricWeighted {
+    index: usize,
+    filled: bool,
+    deque: [f64; 4],
+}
+
+impl SymmetricWeighted {
+    fn new() -> Self {
+        Self {
+            index: 0,
+            filled: false,
+            deque: [0.0; 4],
+        }
+    }
+
+    fn next(&mut self, input: f64) -> f64 {
+        if !self.filled {
+            self.deque = [input; 4];
+            self.filled = true;
+        }
+        self.deque[self.index] = input;
+        self.index = (self.index + 1) % 4;
+
+        // the oldest value is at the index for the next input
+        let oldest = self.deque[self.index];
+        let sum: f64 = self.deque.iter().sum();
+        (2.0 * sum - oldest - input) / 6.0
+    }
+
+    fn reset(&mut self) {
+        self.index = 0;
+        self.filled = false;
+        self.deque = [0.0; 4];
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helper::*;
+
+    fn round(nums: (f64, f64)) -> (f64, f64) {
+        (
+            crate::test_helper::round(nums.0),
+            crate::test_helper::round(nums.1),
+        )
+    }
+
+    #[test]
+    fn test_new() {
+        assert!(RelativeVigorIndex::new(0).is_err());
+        assert!(RelativeVigorIndex::new(1).is_ok());
+    }
+
+    #[test]
+    fn test_next() {
+        let mut rvi = RelativeVigorIndex::new(2).unwrap();
+
+        let next = |rvi: &mut RelativeVigorIndex, o, h, l, c| {
+            round(rvi.next(&Bar::new().open(o).high(h).low(l).close(c)).into())
+        };
+        assert_eq!(next(&mut rvi, 10.0, 12.0, 9.0, 11.0), (0.333, 0.333));
+        assert_eq!(next(&mut rvi, 11.0, 13.0, 10.0, 12.0), (0.333, 0.333));
+        assert_eq!(next(&mut rvi, 12.0, 12.0, 10.0, 10.0), (0.257, 0.321));
+        assert_eq!(next(&mut rvi, 10.0, 11.0, 8.0, 9.0), (0.03, 0.257));
+    }
+
+    #[test]
+    fn test_next_crossover() {
+        let mut rvi = RelativeVigorIndex::default();
+
+        // every bar opens at the previous close of a cycle, which peaks around bar 39
+        let price = |i: usize| 100.0 + 10.0 * (i as f64 / 5.0).sin();
+        let histogram: Vec<f64> = (1..100)
+            .map(|i| {
+                let (open, close) = (price(i - 1), price(i));
+                let out = rvi.next(
+                    &Bar::new()
+                        .open(open)
+                        .high(open.max(close) + 1.0)
+                        .low(open.min(close) - 1.0)
+                        .close(close),
+                );
+                out.rvi - out.signal
+            })
+            .collect();
+
+        // the RVI crosses below its signal line when the rise loses its vigor
+        assert!(histogram[23..38].iter().all(|&value| value > 0.0));
+        assert!(histogram[38..54].iter().all(|&value| value < 0.0));
+    }
+
+    #[test]
+    fn test_next_zero_range() {
+        let mut rvi = RelativeVigorIndex::new(2).unwrap();
+        assert_eq!(
+            rvi.next(&Bar::new().open(10).high(10).low(10).close(10))
+                .rvi,
+            0.0
+        );
+    }
+
+    #[test]
+    fn test_reset() {
+        let mut rvi = RelativeVigorIndex::new(2).unwrap();
+
+        rvi.next(&Bar::new().open(10).high(12).low(9).close(11));
+        rvi.next(&Bar::new().open(12).high(12).low(10).close(10));
+        rvi.reset();
+
+        assert_eq!(
+            round(
+                rvi.next(&Bar::new().open(10).high(12).low(9).close(11))
+                    .into()
+            ),
+            (0.333, 0.333)
+        );
+    }
+
+    #[test]
+    fn test_ready() {
+        let mut rvi = RelativeVigorIndex::new(2).unwrap();
+        let bar = Bar::new().open(9).high(10).low(8).close(9.5);
+        for _ in 0..3 {
+            rvi.next(&bar);
+        }
+        assert_eq!((rvi.bars_seen(), rvi.is_ready()), (3, false));
+        // the symmetric weighting spans four bars
+        rvi.next(&bar);
+        assert_eq!((rvi.bars_seen(), rvi.is_ready()), (4, true));
+
+        rvi.reset();
+        assert_eq!((rvi.bars_seen(), rvi.is_ready()), (0, false));
+    }
+
+    #[test]
+    fn test_default() {
+        RelativeVigorIndex::default();
+    }
+
+    #[test]
+    fn test_display() {
+        let rvi = RelativeVigorIndex::new(10).unwrap();
+        assert_eq!(format!("{}", rvi), "RVI(10)");
+    }
+}
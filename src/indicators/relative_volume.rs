@@ -0,0 +1,237 @@
+use std::fmt;
+
+use crate::errors::{Result, TaError};
+use crate::{Next, Period, Ready, Reset, Volume};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Relative volume (RVOL).
+///
+/// The volume of the bar compared with the average volume of the bars before it. A value of 2
+/// means twice the usual volume, a breakout backed by a high relative volume draws more
+/// conviction than one on a quiet bar.
+///
+/// # Formula
+///
+/// RVOL = volume / average volume of the previous _period_ bars
+///
+/// The current bar is not part of the average. As long as fewer than _period_ bars came before
+/// the average of the available ones is used, until a previous bar is available 1 is returned.
+/// When the previous bars had no volume 0 is returned.
+///
+/// # Parameters
+///
+/// * _period_ - number of previous bars (integer greater than 0). Default is 20.
+///
+/// # Example
+///
+/// ```
+/// use ta::indicators::RelativeVolume;
+/// use ta::{DataItem, Next};
+///
+/// let mut rvol = RelativeVolume::new(2).unwrap();
+///
+/// let mut next = |volume: f64| {
+///     let di = DataItem::builder()
+///         .open(10.0).high(10.0).low(10.0).close(10.0).volume(volume)
+///         .build(true).unwrap();
+///     rvol.next(&di)
+/// };
+///
+/// assert_eq!(next(100.0), 1.0);
+/// assert_eq!(next(300.0), 3.0);
+/// assert_eq!(next(100.0), 0.5);
+/// ```
+///
+/// # Links
+///
+/// * [Relative Volume, TradingView](https://www.tradingview.com/support/solutions/43000705489-relative-volume-rvol/)
+///
+#[doc(alias = "RVOL")]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone)]
+pub struct RelativeVolume {
+    period: usize,
+    index: usize,
+    count: usize,
+    sum: f64,
+    deque: Box<[f64]>,
+    #[cfg_attr(feature = "serde", serde(default))]
+    bars_seen: usize,
+}
+
+impl RelativeVolume {
+    pub fn new(period: usize) -> Result<Self> {
+        match period {
+            0 => Err(TaError::invalid_parameter("period", period)),
+            _ => Ok(Self {
+                period,
+                index: 0,
+                count: 0,
+                sum: 0.0,
+                deque: vec![0.0; period].into_boxed_slice(),
+                bars_seen: 0,
+            }),
+        }
+    }
+}
+
+impl Period for RelativeVolume {
+    fn period(&self) -> usize {
+        self.period
+    }
+}
+
+impl<T: Volume> Next<&T> for RelativeVolume {
+    type Output = f64;
+
+    fn next(&mut self, input: &T) -> Self::Output {
+        self.bars_seen = self.bars_seen.saturating_add(1);
+        let volume = input.volume();
+        let rvol = if self.count == 0 {
+            1.0
+        } else if self.sum > 0.0 {
+            volume / (self.sum / self.count as f64)
+        } else {
+            0.0
+        };
+
+        let old_val = self.deque[self.index];
+        self.deque[self.index] = volume;
+
+        self.index = if self.index + 1 < self.period {
+            self.index + 1
+        } else {
+            0
+        };
+
+        if self.count < self.period {
+            self.count += 1;
+        }
+
+        self.sum += volume - old_val;
+        rvol
+    }
+}
+
+impl Ready for RelativeVolume {
+    fn bars_seen(&self) -> usize {
+        self.bars_seen
+    }
+
+    fn is_ready(&self) -> bool {
+        // the volume is compared with the average of the previous _period_ volumes
+        self.bars_seen > self.period
+    }
+}
+
+impl Reset for RelativeVolume {
+    fn reset(&mut self) {
+        self.bars_seen = 0;
+        self.index = 0;
+        self.count = 0;
+        self.sum = 0.0;
+        for i in 0..self.period {
+            self.deque[i] = 0.0;
+        }
+    }
+}
+
+impl Default for RelativeVolume {
+    fn default() -> Self {
+        Self::new(20).unwrap()
+    }
+}
+
+impl fmt::Display for RelativeVolume {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "RVOL({})", self.period)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helper::*;
+
+    #[test]
+    fn test_new() {
+        assert!(RelativeVolume::new(0).is_err());
+        assert!(RelativeVolume::new(1).is_ok());
+    }
+
+    #[test]
+    fn test_next() {
+        let mut rvol = RelativeVolume::new(3).unwrap();
+
+        assert_eq!(rvol.next(&Bar::new().volume(100.0)), 1.0);
+        // the average of the bars so far during the warm up
+        assert_eq!(rvol.next(&Bar::new().volume(200.0)), 2.0);
+        assert_eq!(rvol.next(&Bar::new().volume(300.0)), 2.0);
+        assert_eq!(rvol.next(&Bar::new().volume(400.0)), 2.0);
+        // the 100 left the window
+        assert_eq!(rvol.next(&Bar::new().volume(300.0)), 1.0);
+    }
+
+    #[test]
+    fn test_next_spike() {
+        let mut rvol = RelativeVolume::default();
+
+        for _ in 0..20 {
+            rvol.next(&Bar::new().volume(1000.0));
+        }
+
+        // a spike bar
+        assert_eq!(rvol.next(&Bar::new().volume(5000.0)), 5.0);
+        // the spike raised the average, a quiet bar
+        assert_eq!(round(rvol.next(&Bar::new().volume(400.0))), 0.333);
+    }
+
+    #[test]
+    fn test_next_zero_average() {
+        let mut rvol = RelativeVolume::new(2).unwrap();
+
+        rvol.next(&Bar::new().volume(0.0));
+        assert_eq!(rvol.next(&Bar::new().volume(100.0)), 0.0);
+        assert_eq!(rvol.next(&Bar::new().volume(100.0)), 2.0);
+    }
+
+    #[test]
+    fn test_reset() {
+        let mut rvol = RelativeVolume::new(3).unwrap();
+
+        rvol.next(&Bar::new().volume(100.0));
+        rvol.next(&Bar::new().volume(500.0));
+        rvol.reset();
+
+        assert_eq!(rvol.next(&Bar::new().volume(100.0)), 1.0);
+        assert_eq!(rvol.next(&Bar::new().volume(200.0)), 2.0);
+    }
+
+    #[test]
+    fn test_ready() {
+        let mut rvol = RelativeVolume::new(3).unwrap();
+        let bar = Bar::new().high(10).low(8).close(9).volume(100.0);
+        for _ in 0..3 {
+            rvol.next(&bar);
+        }
+        assert_eq!((rvol.bars_seen(), rvol.is_ready()), (3, false));
+        // the fourth volume is compared with three previous ones
+        rvol.next(&bar);
+        assert_eq!((rvol.bars_seen(), rvol.is_ready()), (4, true));
+
+        rvol.reset();
+        assert_eq!((rvol.bars_seen(), rvol.is_ready()), (0, false));
+    }
+
+    #[test]
+    fn test_default() {
+        RelativeVolume::default();
+    }
+
+    #[test]
+    fn test_display() {
+        let rvol = RelativeVolume::new(20).unwrap();
+        assert_eq!(format!("{}", rvol), "RVOL(20)");
+    }
+}
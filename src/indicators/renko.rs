@@ -0,0 +1,329 @@
+use std::fmt;
+
+use crate::errors::{Result, TaError};
+use crate::{Close, Next, Reset};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Direction of a [RenkoBrick].
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RenkoDirection {
+    Up,
+    Down,
+}
+
+/// A completed brick of a [Renko] chart.
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone, PartialEq)]
+pub struct RenkoBrick {
+    pub open: f64,
+    pub close: f64,
+    pub direction: RenkoDirection,
+}
+
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone)]
+enum BrickSize {
+    Fixed(f64),
+    Percent(f64),
+}
+
+/// Renko bricks.
+///
+/// Transforms closing prices into bricks of a fixed height, which filters out the noise of
+/// small movements. A brick completes when the price moves one brick beyond the close of the
+/// last brick in its direction. A reversal needs the price to move one brick beyond the open
+/// of the last brick. Movements smaller than a brick carry over to the next inputs, so one
+/// input completes zero, one or many bricks.
+///
+/// Every brick the price moved through is returned, one input emits about _|price - last
+/// close| / brick size_ bricks. There is no cap: a bad tick far away from the price, e.g. a
+/// price of 1e12 with a brick size of 1, allocates that many bricks, so filter such inputs
+/// before. Non-finite inputs are ignored, they emit no brick and do not set the base price.
+///
+/// The first input sets the base price the bricks are built on. [Renko::new] builds bricks
+/// of a fixed size, [Renko::percent] bricks whose size is a percentage of their open.
+///
+/// # Parameters
+///
+/// * _brick_size_ - height of a brick (number greater than 0).
+/// * _percent_ - height of a brick in percent of its open (number greater than 0).
+///
+/// # Example
+///
+/// ```
+/// use ta::indicators::{Renko, RenkoDirection};
+/// use ta::Next;
+///
+/// let mut renko = Renko::new(1.0).unwrap();
+/// assert!(renko.next(10.0).is_empty());
+/// assert!(renko.next(10.5).is_empty());
+///
+/// let bricks = renko.next(12.2);
+/// assert_eq!(bricks.len(), 2);
+/// assert_eq!((bricks[1].open, bricks[1].close), (11.0, 12.0));
+/// assert_eq!(bricks[1].direction, RenkoDirection::Up);
+/// ```
+///
+/// # Links
+///
+/// * [Renko Charts, StockCharts](https://school.stockcharts.com/doku.php?id=chart_analysis:renko)
+///
+#[doc(alias = "Bricks")]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone)]
+pub struct Renko {
+    size: BrickSize,
+    base: Option<f64>,
+    last: Option<RenkoBrick>,
+}
+
+impl Renko {
+    pub fn new(brick_size: f64) -> Result<Self> {
+        Self::with_size(BrickSize::Fixed(brick_size), brick_size)
+    }
+
+    pub fn percent(percent: f64) -> Result<Self> {
+        Self::with_size(BrickSize::Percent(percent), percent)
+    }
+
+    fn with_size(size: BrickSize, value: f64) -> Result<Self> {
+        let is_valid = value.is_finite() && value > 0.0;
+        if !is_valid {
+            let name = match size {
+                BrickSize::Fixed(_) => "brick_size",
+                BrickSize::Percent(_) => "percent",
+            };
+            return Err(TaError::invalid_parameter(name, value));
+        }
+
+        Ok(Self {
+            size,
+            base: None,
+            last: None,
+        })
+    }
+
+    /// The last completed brick.
+    pub fn last_brick(&self) -> Option<&RenkoBrick> {
+        self.last.as_ref()
+    }
+
+    fn brick_size(&self, open: f64) -> f64 {
+        match self.size {
+            BrickSize::Fixed(size) => size,
+            BrickSize::Percent(percent) => open.abs() * percent / 100.0,
+        }
+    }
+
+    fn brick(&self, open: f64, direction: RenkoDirection) -> RenkoBrick {
+        let size = self.brick_size(open);
+        let close = match direction {
+            RenkoDirection::Up => open + size,
+            RenkoDirection::Down => open - size,
+        };
+
+        RenkoBrick {
+            open,
+            close,
+            direction,
+        }
+    }
+
+    fn next_brick(&self, base: f64, price: f64) -> Option<RenkoBrick> {
+        // the brick continuing the trend and the one reversing it
+        let (up, down) = match &self.last {
+            None => (
+                self.brick(base, RenkoDirection::Up),
+                self.brick(base, RenkoDirection::Down),
+            ),
+            Some(last) => match last.direction {
+                RenkoDirection::Up => (
+                    self.brick(last.close, RenkoDirection::Up),
+                    self.brick(last.open, RenkoDirection::Down),
+                ),
+                RenkoDirection::Down => (
+                    self.brick(last.open, RenkoDirection::Up),
+                    self.brick(last.close, RenkoDirection::Down),
+                ),
+            },
+        };
+
+        if price >= up.close && up.close > up.open {
+            Some(up)
+        } else if price <= down.close && down.close < down.open {
+            Some(down)
+        } else {
+            None
+        }
+    }
+}
+
+impl Next<f64> for Renko {
+    type Output = Vec<RenkoBrick>;
+
+    fn next(&mut self, input: f64) -> Self::Output {
+        // an infinite price would complete bricks forever
+        if !input.is_finite() {
+            return Vec::new();
+        }
+        let base = *self.base.get_or_insert(input);
+
+        let mut bricks = Vec::new();
+        while let Some(brick) = self.next_brick(base, input) {
+            self.last = Some(brick.clone());
+            bricks.push(brick);
+        }
+        bricks
+    }
+}
+
+impl<T: Close> Next<&T> for Renko {
+    type Output = Vec<RenkoBrick>;
+
+    fn next(&mut self, input: &T) -> Self::Output {
+        self.next(input.close())
+    }
+}
+
+impl Reset for Renko {
+    fn reset(&mut self) {
+        self.base = None;
+        self.last = None;
+    }
+}
+
+impl fmt::Display for Renko {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self.size {
+            BrickSize::Fixed(size) => write!(f, "RENKO({})", size),
+            BrickSize::Percent(percent) => write!(f, "RENKO({}%)", percent),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helper::*;
+
+    use RenkoDirection::{Down, Up};
+
+    fn bricks(bricks: Vec<RenkoBrick>) -> Vec<(f64, f64, RenkoDirection)> {
+        bricks
+            .into_iter()
+            .map(|brick| (round(brick.open), round(brick.close), brick.direction))
+            .collect()
+    }
+
+    #[test]
+    fn test_new() {
+        assert!(Renko::new(0.0).is_err());
+        assert!(Renko::new(-1.0).is_err());
+        assert!(Renko::new(f64::NAN).is_err());
+        assert!(Renko::new(1.0).is_ok());
+        assert!(Renko::percent(0.0).is_err());
+        assert!(Renko::percent(1.0).is_ok());
+    }
+
+    #[test]
+    fn test_next_jump() {
+        let mut renko = Renko::new(1.0).unwrap();
+
+        assert!(renko.next(100.0).is_empty());
+        // a single jump completes many bricks, the rest of the move carries over
+        assert_eq!(
+            bricks(renko.next(105.5)),
+            [
+                (100.0, 101.0, Up),
+                (101.0, 102.0, Up),
+                (102.0, 103.0, Up),
+                (103.0, 104.0, Up),
+                (104.0, 105.0, Up),
+            ]
+        );
+        assert_eq!(
+            bricks(renko.next(&Bar::new().close(106.0))),
+            [(105.0, 106.0, Up)]
+        );
+    }
+
+    #[test]
+    fn test_next_reversal() {
+        let mut renko = Renko::new(1.0).unwrap();
+
+        renko.next(100.0);
+        assert_eq!(
+            bricks(renko.next(102.3)),
+            [(100.0, 101.0, Up), (101.0, 102.0, Up)]
+        );
+        // less than a brick below the open of the last brick
+        assert!(renko.next(101.5).is_empty());
+        assert!(renko.next(100.2).is_empty());
+        assert_eq!(bricks(renko.next(99.9)), [(101.0, 100.0, Down)]);
+        assert_eq!(
+            bricks(renko.next(97.5)),
+            [(100.0, 99.0, Down), (99.0, 98.0, Down)]
+        );
+        // the next reversal starts from the open of the last down brick
+        assert!(renko.next(99.5).is_empty());
+        assert_eq!(bricks(renko.next(100.0)), [(99.0, 100.0, Up)]);
+    }
+
+    #[test]
+    fn test_next_percent() {
+        let mut renko = Renko::percent(10.0).unwrap();
+
+        renko.next(100.0);
+        assert_eq!(
+            bricks(renko.next(125.0)),
+            [(100.0, 110.0, Up), (110.0, 121.0, Up)]
+        );
+        assert_eq!(bricks(renko.next(99.0)), [(110.0, 99.0, Down)]);
+    }
+
+    #[test]
+    fn test_last_brick() {
+        let mut renko = Renko::new(1.0).unwrap();
+        assert_eq!(renko.last_brick(), None);
+
+        renko.next(10.0);
+        renko.next(8.0);
+        assert_eq!(renko.last_brick().map(|brick| brick.close), Some(8.0));
+    }
+
+    #[test]
+    fn test_next_non_finite() {
+        let mut renko = Renko::new(1.0).unwrap();
+
+        // no base price is set
+        assert!(renko.next(f64::NAN).is_empty());
+        assert!(renko.next(10.0).is_empty());
+
+        assert!(renko.next(f64::INFINITY).is_empty());
+        assert!(renko.next(f64::NEG_INFINITY).is_empty());
+        assert_eq!(bricks(renko.next(11.0)), [(10.0, 11.0, Up)]);
+    }
+
+    #[test]
+    fn test_reset() {
+        let mut renko = Renko::new(1.0).unwrap();
+
+        renko.next(100.0);
+        renko.next(105.0);
+        renko.reset();
+
+        assert!(renko.next(50.0).is_empty());
+        assert_eq!(
+            bricks(renko.next(48.0)),
+            [(50.0, 49.0, Down), (49.0, 48.0, Down)]
+        );
+    }
+
+    #[test]
+    fn test_display() {
+        assert_eq!(format!("{}", Renko::new(2.5).unwrap()), "RENKO(2.5)");
+        assert_eq!(format!("{}", Renko::percent(1.0).unwrap()), "RENKO(1%)");
+    }
+}
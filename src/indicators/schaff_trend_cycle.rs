@@ -0,0 +1,310 @@
+use std::fmt;
+
+use crate::errors::Result;
+use crate::indicators::{ExponentialMovingAverage as Ema, Maximum, Minimum};
+use crate::{Close, Next, Period, Ready, Reset};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Schaff Trend Cycle (STC).
+///
+/// Developed by Doug Schaff, the trend cycle applies a stochastic twice to the MACD line. The
+/// result is an oscillator between 0 and 100 which turns earlier than the MACD crossing its
+/// signal line.
+///
+/// # Formula
+///
+/// MACD = EMA(macd_fast) - EMA(macd_slow)
+///
+/// %K<sub>1</sub> = (MACD - lowest MACD) / (highest MACD - lowest MACD) * 100
+///
+/// %D<sub>1</sub> = %D<sub>1, t-1</sub> + 0.5 * (%K<sub>1</sub> - %D<sub>1, t-1</sub>)
+///
+/// %K<sub>2</sub> = (%D<sub>1</sub> - lowest %D<sub>1</sub>) / (highest %D<sub>1</sub> - lowest %D<sub>1</sub>) * 100
+///
+/// STC = STC<sub>t-1</sub> + 0.5 * (%K<sub>2</sub> - STC<sub>t-1</sub>)
+///
+/// Where:
+///
+/// * _EMA_ - [exponential moving average](struct.ExponentialMovingAverage.html)
+/// * the lowest and highest values are taken over the last _cycle_period_ values.
+///
+/// The first %D and STC equal their %K. While the highest value equals the lowest one, the
+/// %K is the previous smoothed value, or 0 on the first input.
+///
+/// # Parameters
+///
+/// * _macd_fast_ - period of the fast EMA (integer greater than 0). Default is 23.
+/// * _macd_slow_ - period of the slow EMA (integer greater than 0). Default is 50.
+/// * _cycle_period_ - period of the stochastics (integer greater than 0). Default is 10.
+///
+/// The [period](Period::period) is the longest of the three.
+///
+/// # Example
+///
+/// ```
+/// use ta::indicators::SchaffTrendCycle;
+/// use ta::Next;
+///
+/// let mut stc = SchaffTrendCycle::new(3, 6, 3).unwrap();
+/// assert_eq!(stc.next(10.0), 0.0);
+/// assert_eq!(stc.next(11.0), 50.0);
+/// assert_eq!(stc.next(12.0), 75.0);
+/// ```
+///
+/// # Links
+///
+/// * [Schaff Trend Cycle, Investopedia](https://www.investopedia.com/articles/forex/10/schaff-trend-cycle-indicator.asp)
+///
+#[doc(alias = "STC")]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone)]
+pub struct SchaffTrendCycle {
+    fast_ema: Ema,
+    slow_ema: Ema,
+    macd_stochastic: SmoothedStochastic,
+    stc_stochastic: SmoothedStochastic,
+}
+
+impl SchaffTrendCycle {
+    pub fn new(macd_fast: usize, macd_slow: usize, cycle_period: usize) -> Result<Self> {
+        Ok(Self {
+            fast_ema: Ema::new(macd_fast)?,
+            slow_ema: Ema::new(macd_slow)?,
+            macd_stochastic: SmoothedStochastic::new(cycle_period)?,
+            stc_stochastic: SmoothedStochastic::new(cycle_period)?,
+        })
+    }
+
+    pub fn cycle_period(&self) -> usize {
+        self.macd_stochastic.min.period()
+    }
+}
+
+impl Period for SchaffTrendCycle {
+    fn period(&self) -> usize {
+        self.fast_ema
+            .period()
+            .max(self.slow_ema.period())
+            .max(self.cycle_period())
+    }
+}
+
+impl Next<f64> for SchaffTrendCycle {
+    type Output = f64;
+
+    fn next(&mut self, input: f64) -> Self::Output {
+        let macd = self.fast_ema.next(input) - self.slow_ema.next(input);
+        let d = self.macd_stochastic.next(macd);
+        self.stc_stochastic.next(d)
+    }
+}
+
+impl<T: Close> Next<&T> for SchaffTrendCycle {
+    type Output = f64;
+
+    fn next(&mut self, input: &T) -> Self::Output {
+        self.next(input.close())
+    }
+}
+
+impl Ready for SchaffTrendCycle {
+    fn bars_seen(&self) -> usize {
+        self.slow_ema.bars_seen()
+    }
+
+    fn is_ready(&self) -> bool {
+        self.fast_ema.is_ready()
+            && self.slow_ema.is_ready()
+            && self.macd_stochastic.max.is_ready()
+            && self.stc_stochastic.max.is_ready()
+    }
+}
+
+impl Reset for SchaffTrendCycle {
+    fn reset(&mut self) {
+        self.fast_ema.reset();
+        self.slow_ema.reset();
+        self.macd_stochastic.reset();
+        self.stc_stochastic.reset();
+    }
+}
+
+impl Default for SchaffTrendCycle {
+    fn default() -> Self {
+        Self::new(23, 50, 10).unwrap()
+    }
+}
+
+impl fmt::Display for SchaffTrendCycle {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "STC({}, {}, {})",
+            self.fast_ema.period(),
+            self.slow_ema.period(),
+            self.cycle_period()
+        )
+    }
+}
+
+/// Stochastic of the input, smoothed by half of every change.
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone)]
+struct SmoothedStochastic {
+    min: Minimum,
+    max: Maximum,
+    current: Option<f64>,
+}
+
+impl SmoothedStochastic {
+    fn new(period: usize) -> Result<Self> {
+        Ok(Self {
+            min: Minimum::new(period)?,
+            max: Maximum::new(period)?,
+            current: None,
+        })
+    }
+
+    fn next(&mut self, input: f64) -> f64 {
+        let min = self.min.next(input);
+        let max = self.max.next(input);
+
+        let k = if max > min {
+            (input - min) / (max - min) * 100.0
+        } else {
+            self.current.unwrap_or(0.0)
+        };
+
+        let current = match self.current {
+            Some(current) => current + 0.5 * (k - current),
+            None => k,
+        };
+        self.current = Some(current);
+        current
+    }
+
+    fn reset(&mut self) {
+        self.min.reset();
+        self.max.reset();
+        self.current = None;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::indicators::MovingAverageConvergenceDivergence as Macd;
+    use crate::test_helper::*;
+
+    test_indicator!(SchaffTrendCycle);
+
+    #[test]
+    fn test_new() {
+        assert!(SchaffTrendCycle::new(0, 50, 10).is_err());
+        assert!(SchaffTrendCycle::new(23, 0, 10).is_err());
+        assert!(SchaffTrendCycle::new(23, 50, 0).is_err());
+        assert!(SchaffTrendCycle::new(23, 50, 10).is_ok());
+    }
+
+    #[test]
+    fn test_next() {
+        let mut stc = SchaffTrendCycle::new(3, 6, 3).unwrap();
+
+        assert_eq!(round(stc.next(10.0)), 0.0);
+        assert_eq!(round(stc.next(11.0)), 50.0);
+        assert_eq!(round(stc.next(12.0)), 75.0);
+        assert_eq!(round(stc.next(11.0)), 39.811);
+        assert_eq!(round(stc.next(10.0)), 19.905);
+        assert_eq!(round(stc.next(11.0)), 16.582);
+        assert_eq!(round(stc.next(13.0)), 58.291);
+    }
+
+    #[test]
+    fn test_next_leads_macd() {
+        let mut stc = SchaffTrendCycle::default();
+        let mut macd = Macd::new(23, 50, 9).unwrap();
+
+        // a cycle with its troughs around bars 71 and 165
+        let mut stc_cross = None;
+        let mut macd_cross = None;
+        let mut prev = None;
+        for i in 0..200 {
+            let price = 100.0 + 10.0 * (i as f64 / 15.0).sin();
+            let value = stc.next(price);
+            let histogram = macd.next(price).histogram;
+            assert!((0.0..=100.0).contains(&value));
+
+            if let Some((prev_value, prev_histogram)) = prev {
+                if i > 50 && stc_cross.is_none() && prev_value < 25.0 && value >= 25.0 {
+                    stc_cross = Some(i);
+                }
+                if i > 50 && macd_cross.is_none() && prev_histogram <= 0.0 && histogram > 0.0 {
+                    macd_cross = Some(i);
+                }
+            }
+            prev = Some((value, histogram));
+        }
+
+        // the STC leaves the oversold zone before the MACD crosses its signal line
+        assert_eq!(stc_cross, Some(72));
+        assert_eq!(macd_cross, Some(76));
+    }
+
+    #[test]
+    fn test_next_flat() {
+        let mut stc = SchaffTrendCycle::new(3, 6, 3).unwrap();
+
+        for _ in 0..10 {
+            assert_eq!(stc.next(10.0), 0.0);
+        }
+    }
+
+    #[test]
+    fn test_reset() {
+        let mut stc = SchaffTrendCycle::new(3, 6, 3).unwrap();
+
+        stc.next(10.0);
+        stc.next(11.0);
+        stc.reset();
+
+        assert_eq!(round(stc.next(10.0)), 0.0);
+        assert_eq!(round(stc.next(11.0)), 50.0);
+    }
+
+    #[test]
+    fn test_ready() {
+        let mut stc = SchaffTrendCycle::new(2, 4, 3).unwrap();
+        for _ in 0..3 {
+            stc.next(1.0);
+        }
+        assert_eq!((stc.bars_seen(), stc.is_ready()), (3, false));
+        // the slow EMA is the last to fill its period
+        stc.next(1.0);
+        assert_eq!((stc.bars_seen(), stc.is_ready()), (4, true));
+
+        stc.reset();
+        assert_eq!((stc.bars_seen(), stc.is_ready()), (0, false));
+    }
+
+    #[test]
+    fn test_period() {
+        let stc = SchaffTrendCycle::new(3, 6, 4).unwrap();
+        assert_eq!((stc.period(), stc.cycle_period()), (6, 4));
+
+        // a cycle longer than the slow EMA
+        let stc = SchaffTrendCycle::new(3, 6, 10).unwrap();
+        assert_eq!(stc.period(), 10);
+    }
+
+    #[test]
+    fn test_default() {
+        SchaffTrendCycle::default();
+    }
+
+    #[test]
+    fn test_display() {
+        let stc = SchaffTrendCycle::new(23, 50, 10).unwrap();
+        assert_eq!(format!("{}", stc), "STC(23, 50, 10)");
+    }
+}
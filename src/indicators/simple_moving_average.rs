@@ -1,7 +1,8 @@
 use std::fmt;
 
+use crate::adapters::Sourced;
 use crate::errors::{Result, TaError};
-use crate::{Close, Next, Period, Reset};
+use crate::{Close, Current, Float, Next, Period, PriceSource, Ready, Reset};
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
@@ -21,6 +22,12 @@ use serde::{Deserialize, Serialize};
 ///
 /// * _period_ - number of periods (integer greater than 0)
 ///
+/// Fed with bars it consumes the close, [with_source](Self::with_source) wraps it in a
+/// [Sourced] to select another [PriceSource].
+///
+/// The average computes with `f64` by default, [new_with_precision](Self::new_with_precision)
+/// and [with_precision](Self::with_precision) select `f32`, see [Float].
+///
 /// # Example
 ///
 /// ```
@@ -41,39 +48,74 @@ use serde::{Deserialize, Serialize};
 #[doc(alias = "SMA")]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[derive(Debug, Clone)]
-pub struct SimpleMovingAverage {
+pub struct SimpleMovingAverage<F = f64> {
     period: usize,
     index: usize,
     count: usize,
-    sum: f64,
-    deque: Box<[f64]>,
+    sum: F,
+    deque: Box<[F]>,
+    #[cfg_attr(feature = "serde", serde(default))]
+    bars_seen: usize,
 }
 
 impl SimpleMovingAverage {
     pub fn new(period: usize) -> Result<Self> {
+        Self::new_with_precision(period)
+    }
+}
+
+impl<F: Float> SimpleMovingAverage<F> {
+    /// Like [new](SimpleMovingAverage::new), computing with the float type of the indicator,
+    /// e.g. `SimpleMovingAverage::<f32>::new_with_precision(9)`.
+    pub fn new_with_precision(period: usize) -> Result<Self> {
         match period {
-            0 => Err(TaError::InvalidParameter),
+            0 => Err(TaError::invalid_parameter("period", period)),
             _ => Ok(Self {
                 period,
                 index: 0,
                 count: 0,
-                sum: 0.0,
-                deque: vec![0.0; period].into_boxed_slice(),
+                sum: F::zero(),
+                deque: vec![F::zero(); period].into_boxed_slice(),
+                bars_seen: 0,
             }),
         }
     }
+
+    /// Converts the average, including its current state, to compute with another floating
+    /// point type.
+    pub fn with_precision<G: Float>(self) -> SimpleMovingAverage<G> {
+        SimpleMovingAverage {
+            period: self.period,
+            index: self.index,
+            count: self.count,
+            sum: G::from_f64(self.sum.to_f64()),
+            deque: self
+                .deque
+                .iter()
+                .map(|value| G::from_f64(value.to_f64()))
+                .collect(),
+            bars_seen: self.bars_seen,
+        }
+    }
+
+    /// Feeds the moving average with the given price of every bar.
+    pub fn with_source(self, source: PriceSource) -> Sourced<Self> {
+        Sourced::new(self, source)
+    }
 }
 
-impl Period for SimpleMovingAverage {
+impl<F> Period for SimpleMovingAverage<F> {
     fn period(&self) -> usize {
         self.period
     }
 }
 
-impl Next<f64> for SimpleMovingAverage {
-    type Output = f64;
+impl<F: Float> Next<f64> for SimpleMovingAverage<F> {
+    type Output = F;
 
     fn next(&mut self, input: f64) -> Self::Output {
+        let input = F::from_f64(input);
+        self.bars_seen = self.bars_seen.saturating_add(1);
         let old_val = self.deque[self.index];
         self.deque[self.index] = input;
 
@@ -88,25 +130,47 @@ impl Next<f64> for SimpleMovingAverage {
         }
 
         self.sum = self.sum - old_val + input;
-        self.sum / (self.count as f64)
+        self.sum / F::from_f64(self.count as f64)
     }
 }
 
-impl<T: Close> Next<&T> for SimpleMovingAverage {
-    type Output = f64;
+impl<F: Float, T: Close> Next<&T> for SimpleMovingAverage<F> {
+    type Output = F;
 
     fn next(&mut self, input: &T) -> Self::Output {
         self.next(input.close())
     }
 }
 
-impl Reset for SimpleMovingAverage {
+impl<F: Float> Current for SimpleMovingAverage<F> {
+    type Output = F;
+
+    fn current(&self) -> Option<Self::Output> {
+        match self.count {
+            0 => None,
+            _ => Some(self.sum / F::from_f64(self.count as f64)),
+        }
+    }
+}
+
+impl<F> Ready for SimpleMovingAverage<F> {
+    fn bars_seen(&self) -> usize {
+        self.bars_seen
+    }
+
+    fn is_ready(&self) -> bool {
+        self.bars_seen >= self.period
+    }
+}
+
+impl<F: Float> Reset for SimpleMovingAverage<F> {
     fn reset(&mut self) {
+        self.bars_seen = 0;
         self.index = 0;
         self.count = 0;
-        self.sum = 0.0;
+        self.sum = F::zero();
         for i in 0..self.period {
-            self.deque[i] = 0.0;
+            self.deque[i] = F::zero();
         }
     }
 }
@@ -117,7 +181,7 @@ impl Default for SimpleMovingAverage {
     }
 }
 
-impl fmt::Display for SimpleMovingAverage {
+impl<F> fmt::Display for SimpleMovingAverage<F> {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         write!(f, "SMA({})", self.period)
     }
@@ -127,6 +191,7 @@ impl fmt::Display for SimpleMovingAverage {
 mod tests {
     use super::*;
     use crate::test_helper::*;
+    use assert_approx_eq::assert_approx_eq;
 
     test_indicator!(SimpleMovingAverage);
 
@@ -161,6 +226,20 @@ mod tests {
         assert_eq!(sma.next(&bar(1.0)), 4.0);
     }
 
+    #[test]
+    fn test_next_with_source() {
+        let mut sma = SimpleMovingAverage::new(3)
+            .unwrap()
+            .with_source(PriceSource::High);
+        assert_eq!(sma.source(), PriceSource::High);
+
+        assert_eq!(sma.next(&Bar::new().high(12).low(8).close(11)), 12.0);
+        assert_eq!(
+            round(sma.next(&Bar::new().high(14).low(10).close(13))),
+            13.0
+        );
+    }
+
     #[test]
     fn test_reset() {
         let mut sma = SimpleMovingAverage::new(4).unwrap();
@@ -172,6 +251,53 @@ mod tests {
         assert_eq!(sma.next(99.0), 99.0);
     }
 
+    #[test]
+    fn test_current() {
+        let mut sma = SimpleMovingAverage::new(3).unwrap();
+        assert_eq!(sma.current(), None);
+
+        for input in [4.0, 5.0, 6.0, 6.0] {
+            let value = sma.next(input);
+            assert_eq!(sma.current(), Some(value));
+        }
+
+        sma.reset();
+        assert_eq!(sma.current(), None);
+    }
+
+    #[test]
+    fn test_ready() {
+        let mut sma = SimpleMovingAverage::new(3).unwrap();
+        assert_eq!((sma.bars_seen(), sma.is_ready()), (0, false));
+
+        sma.next(1.0);
+        sma.next(2.0);
+        assert_eq!((sma.bars_seen(), sma.is_ready()), (2, false));
+        // the third input fills the period
+        sma.next(3.0);
+        assert_eq!((sma.bars_seen(), sma.is_ready()), (3, true));
+
+        sma.reset();
+        assert_eq!((sma.bars_seen(), sma.is_ready()), (0, false));
+    }
+
+    #[test]
+    fn test_next_f32() {
+        let mut sma = SimpleMovingAverage::new(3).unwrap();
+        let mut sma_f32 = SimpleMovingAverage::<f32>::new_with_precision(3).unwrap();
+
+        for input in [4.0, 5.0, 6.0, 6.0, 2.0, 7.5] {
+            let expected = sma.next(input);
+            let actual: f32 = sma_f32.next(input);
+            assert_approx_eq!(actual as f64, expected, 1e-5);
+        }
+
+        // the state is converted along with the average
+        let mut converted = sma.clone().with_precision::<f32>();
+        assert_eq!(converted.current(), Some(sma.current().unwrap() as f32));
+        assert_approx_eq!(converted.next(3.0) as f64, sma.next(3.0), 1e-5);
+    }
+
     #[test]
     fn test_default() {
         SimpleMovingAverage::default();
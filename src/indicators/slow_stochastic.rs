@@ -2,7 +2,7 @@ use std::fmt;
 
 use crate::errors::Result;
 use crate::indicators::{ExponentialMovingAverage, FastStochastic};
-use crate::{Close, High, Low, Next, Period, Reset};
+use crate::{Close, High, Low, Next, Period, Ready, Reset};
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
@@ -60,6 +60,16 @@ impl<T: High + Low + Close> Next<&T> for SlowStochastic {
     }
 }
 
+impl Ready for SlowStochastic {
+    fn bars_seen(&self) -> usize {
+        self.fast_stochastic.bars_seen()
+    }
+
+    fn is_ready(&self) -> bool {
+        self.fast_stochastic.is_ready() && self.ema.is_ready()
+    }
+}
+
 impl Reset for SlowStochastic {
     fn reset(&mut self) {
         self.fast_stochastic.reset();
@@ -139,6 +149,21 @@ mod tests {
         assert_eq!(stoch.next(10.0), 50.0);
     }
 
+    #[test]
+    fn test_ready() {
+        let mut stoch = SlowStochastic::new(3, 2).unwrap();
+        let bar = Bar::new().high(10).low(8).close(9);
+        for _ in 0..2 {
+            stoch.next(&bar);
+        }
+        assert_eq!((stoch.bars_seen(), stoch.is_ready()), (2, false));
+        stoch.next(&bar);
+        assert_eq!((stoch.bars_seen(), stoch.is_ready()), (3, true));
+
+        stoch.reset();
+        assert_eq!((stoch.bars_seen(), stoch.is_ready()), (0, false));
+    }
+
     #[test]
     fn test_default() {
         SlowStochastic::default();
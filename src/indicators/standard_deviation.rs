@@ -1,13 +1,15 @@
 use std::fmt;
 
 use crate::errors::{Result, TaError};
-use crate::{Close, Next, Period, Reset};
+use crate::{Close, Current, Next, Period, Ready, Reset};
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
 /// Standard deviation (SD).
 ///
-/// Returns the standard deviation of the last n values.
+/// Returns the standard deviation of the last n values. [new](Self::new) computes the
+/// population standard deviation, [sample](Self::sample) the sample standard deviation which
+/// divides by _N - 1_ instead of _N_.
 ///
 /// # Formula
 ///
@@ -19,9 +21,12 @@ use serde::{Deserialize, Serialize};
 /// * _N_ - number of probes in observation.
 /// * _x<sub>i</sub>_ - i-th observed value from N elements observation.
 ///
+/// The mean and the sum of squared differences are updated in constant time per input.
+/// The sample standard deviation of a single value is 0.
+///
 /// # Parameters
 ///
-/// * _period_ - number of periods (integer greater than 0)
+/// * _period_ - number of periods (integer greater than 0). Default is 9.
 ///
 /// # Example
 ///
@@ -32,6 +37,10 @@ use serde::{Deserialize, Serialize};
 /// let mut sd = StandardDeviation::new(3).unwrap();
 /// assert_eq!(sd.next(10.0), 0.0);
 /// assert_eq!(sd.next(20.0), 5.0);
+///
+/// let mut sd = StandardDeviation::sample(3).unwrap();
+/// assert_eq!(sd.next(10.0), 0.0);
+/// assert_eq!(sd.next(20.0).round(), 7.0);
 /// ```
 ///
 /// # Links
@@ -47,27 +56,58 @@ pub struct StandardDeviation {
     count: usize,
     m: f64,
     m2: f64,
+    #[cfg_attr(feature = "serde", serde(default))]
+    sample: bool,
     deque: Box<[f64]>,
+    #[cfg_attr(feature = "serde", serde(default))]
+    bars_seen: usize,
 }
 
 impl StandardDeviation {
     pub fn new(period: usize) -> Result<Self> {
+        Self::with_sample(period, false)
+    }
+
+    pub fn sample(period: usize) -> Result<Self> {
+        Self::with_sample(period, true)
+    }
+
+    fn with_sample(period: usize, sample: bool) -> Result<Self> {
         match period {
-            0 => Err(TaError::InvalidParameter),
+            0 => Err(TaError::invalid_parameter("period", period)),
             _ => Ok(Self {
                 period,
                 index: 0,
                 count: 0,
                 m: 0.0,
                 m2: 0.0,
+                sample,
                 deque: vec![0.0; period].into_boxed_slice(),
+                bars_seen: 0,
             }),
         }
     }
 
+    /// Whether the sample standard deviation is computed.
+    pub fn is_sample(&self) -> bool {
+        self.sample
+    }
+
     pub(super) fn mean(&self) -> f64 {
         self.m
     }
+
+    fn value(&self) -> f64 {
+        let n = if self.sample {
+            self.count - 1
+        } else {
+            self.count
+        };
+        match n {
+            0 => 0.0,
+            _ => (self.m2 / n as f64).sqrt(),
+        }
+    }
 }
 
 impl Period for StandardDeviation {
@@ -80,6 +120,7 @@ impl Next<f64> for StandardDeviation {
     type Output = f64;
 
     fn next(&mut self, input: f64) -> Self::Output {
+        self.bars_seen = self.bars_seen.saturating_add(1);
         let old_val = self.deque[self.index];
         self.deque[self.index] = input;
 
@@ -106,7 +147,7 @@ impl Next<f64> for StandardDeviation {
             self.m2 = 0.0;
         }
 
-        (self.m2 / self.count as f64).sqrt()
+        self.value()
     }
 }
 
@@ -118,8 +159,30 @@ impl<T: Close> Next<&T> for StandardDeviation {
     }
 }
 
+impl Current for StandardDeviation {
+    type Output = f64;
+
+    fn current(&self) -> Option<Self::Output> {
+        match self.count {
+            0 => None,
+            _ => Some(self.value()),
+        }
+    }
+}
+
+impl Ready for StandardDeviation {
+    fn bars_seen(&self) -> usize {
+        self.bars_seen
+    }
+
+    fn is_ready(&self) -> bool {
+        self.bars_seen >= self.period
+    }
+}
+
 impl Reset for StandardDeviation {
     fn reset(&mut self) {
+        self.bars_seen = 0;
         self.index = 0;
         self.count = 0;
         self.m = 0.0;
@@ -138,7 +201,11 @@ impl Default for StandardDeviation {
 
 impl fmt::Display for StandardDeviation {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "SD({})", self.period)
+        if self.sample {
+            write!(f, "SD({}, sample)", self.period)
+        } else {
+            write!(f, "SD({})", self.period)
+        }
     }
 }
 
@@ -202,6 +269,46 @@ mod tests {
         assert_eq!(sd.next(4.2), 0.0);
     }
 
+    #[test]
+    fn test_next_sample() {
+        let mut sd = StandardDeviation::sample(5).unwrap();
+
+        // numpy.std(window, ddof=1) of the trailing windows
+        let data = [
+            (3.2, 0.0),
+            (4.7, 1.061),
+            (4.1, 0.755),
+            (3.9, 0.618),
+            (5.5, 0.867),
+            (6.1, 0.932),
+            (2.8, 1.32),
+        ];
+
+        for (input, expected) in data {
+            assert_eq!(round(sd.next(input)), expected);
+        }
+        assert!(sd.is_sample());
+    }
+
+    #[test]
+    fn test_next_constant_then_jump() {
+        let mut sd = StandardDeviation::sample(20).unwrap();
+
+        for _ in 0..100_000 {
+            assert_eq!(sd.next(1_000_000.0), 0.0);
+        }
+
+        // one jumped value in the window of 20
+        let value = sd.next(1_000_001.0);
+        assert_eq!(round(value), round((1.0f64 / 20.0).sqrt()));
+
+        for _ in 0..19 {
+            sd.next(1_000_001.0);
+        }
+        // the window only holds the jumped values again
+        assert!(sd.next(1_000_001.0).abs() < 1e-6);
+    }
+
     #[test]
     fn test_reset() {
         let mut sd = StandardDeviation::new(4).unwrap();
@@ -213,6 +320,36 @@ mod tests {
         assert_eq!(sd.next(20.0), 0.0);
     }
 
+    #[test]
+    fn test_current() {
+        let mut sd = StandardDeviation::sample(3).unwrap();
+        assert_eq!(sd.current(), None);
+
+        for input in [4.0, 5.0, 6.0, 2.0] {
+            let value = sd.next(input);
+            assert_eq!(sd.current(), Some(value));
+        }
+
+        sd.reset();
+        assert_eq!(sd.current(), None);
+    }
+
+    #[test]
+    fn test_ready() {
+        let mut sd = StandardDeviation::new(3).unwrap();
+        assert_eq!((sd.bars_seen(), sd.is_ready()), (0, false));
+
+        sd.next(1.0);
+        sd.next(2.0);
+        assert_eq!((sd.bars_seen(), sd.is_ready()), (2, false));
+        // the third input fills the period
+        sd.next(3.0);
+        assert_eq!((sd.bars_seen(), sd.is_ready()), (3, true));
+
+        sd.reset();
+        assert_eq!((sd.bars_seen(), sd.is_ready()), (0, false));
+    }
+
     #[test]
     fn test_default() {
         StandardDeviation::default();
@@ -222,5 +359,8 @@ mod tests {
     fn test_display() {
         let sd = StandardDeviation::new(5).unwrap();
         assert_eq!(format!("{}", sd), "SD(5)");
+
+        let sd = StandardDeviation::sample(5).unwrap();
+        assert_eq!(format!("{}", sd), "SD(5, sample)");
     }
 }
@@ -0,0 +1,346 @@
+use std::fmt;
+
+use crate::errors::Result;
+use crate::indicators::LinearRegression;
+use crate::{Close, Next, Period, Ready, Reset};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Standard Error Bands (SEB).
+///
+/// Developed by Jon Andersen, the bands are placed around the
+/// [linear regression](struct.LinearRegression.html) line of the last _period_ values, at a
+/// multiple of the standard error of the estimate. The standard error measures how far the
+/// values scatter around the line: while the price follows a straight trend the bands contract
+/// onto the line, on erratic prices they widen.
+///
+/// # Formula
+///
+/// Middle = forecast of the linear regression over _period_
+///
+/// SE = sqrt(Σ(y - ŷ)<sup>2</sup> / (n - 2))
+///
+/// Upper = Middle + SE * multiplier
+///
+/// Lower = Middle - SE * multiplier
+///
+/// Where _ŷ_ is the value of the regression line at _y_ and _n_ the number of values in the
+/// window. With less than 3 values the standard error is 0. The sums are updated in constant
+/// time per input and rebuilt around the mean of the window once every _period_ inputs.
+///
+/// # Parameters
+///
+/// * _period_ - number of periods (integer greater than 0). Default is 21.
+/// * _multiplier_ - number of standard errors the bands are away from the line. Default is 2.
+///
+/// # Example
+///
+/// ```
+/// use ta::indicators::StandardErrorBands;
+/// use ta::Next;
+///
+/// let mut seb = StandardErrorBands::new(3, 2.0).unwrap();
+/// seb.next(2.0);
+/// seb.next(4.0);
+///
+/// // a straight line has no error
+/// let out = seb.next(6.0);
+/// assert_eq!((out.upper, out.middle, out.lower), (6.0, 6.0, 6.0));
+///
+/// let out = seb.next(5.0);
+/// assert_eq!(out.middle, 5.5);
+/// assert!(out.upper > out.middle && out.lower < out.middle);
+/// ```
+///
+/// # Links
+///
+/// * [Standard Error Bands, Investopedia](https://www.investopedia.com/terms/s/standard-error-bands.asp)
+///
+#[doc(alias = "SEB")]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone)]
+pub struct StandardErrorBands {
+    multiplier: f64,
+    linreg: LinearRegression,
+    index: usize,
+    count: usize,
+    // the sums are taken of the values minus the shift, a recent mean of the window, which
+    // keeps them small and avoids the cancellation of large squares
+    shift: f64,
+    sum_y: f64,
+    sum_yy: f64,
+    deque: Box<[f64]>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct StandardErrorBandsOutput {
+    pub upper: f64,
+    pub middle: f64,
+    pub lower: f64,
+}
+
+impl From<StandardErrorBandsOutput> for (f64, f64, f64) {
+    fn from(seb: StandardErrorBandsOutput) -> Self {
+        (seb.upper, seb.middle, seb.lower)
+    }
+}
+
+impl StandardErrorBands {
+    pub fn new(period: usize, multiplier: f64) -> Result<Self> {
+        Ok(Self {
+            multiplier,
+            linreg: LinearRegression::new(period)?,
+            index: 0,
+            count: 0,
+            shift: 0.0,
+            sum_y: 0.0,
+            sum_yy: 0.0,
+            deque: vec![0.0; period].into_boxed_slice(),
+        })
+    }
+
+    pub fn multiplier(&self) -> f64 {
+        self.multiplier
+    }
+}
+
+impl Period for StandardErrorBands {
+    fn period(&self) -> usize {
+        self.linreg.period()
+    }
+}
+
+impl Next<f64> for StandardErrorBands {
+    type Output = StandardErrorBandsOutput;
+
+    fn next(&mut self, input: f64) -> Self::Output {
+        let line = self.linreg.next(input);
+
+        let old_val = self.deque[self.index];
+        self.deque[self.index] = input;
+
+        self.index = if self.index + 1 < self.deque.len() {
+            self.index + 1
+        } else {
+            0
+        };
+
+        if self.count == 0 {
+            self.shift = input;
+        }
+        let y = input - self.shift;
+        if self.count < self.deque.len() {
+            self.count += 1;
+            self.sum_y += y;
+            self.sum_yy += y * y;
+        } else {
+            let old_y = old_val - self.shift;
+            self.sum_y += y - old_y;
+            self.sum_yy += y * y - old_y * old_y;
+        }
+
+        if self.index == 0 && self.count == self.deque.len() {
+            // rebuild the sums once per window around its mean, so neither rounding errors
+            // accumulate nor the values drift away from the shift
+            self.shift = self.deque.iter().sum::<f64>() / self.count as f64;
+            self.sum_y = self.deque.iter().map(|&v| v - self.shift).sum();
+            self.sum_yy = self.deque.iter().map(|&v| (v - self.shift).powi(2)).sum();
+        }
+
+        let n = self.count as f64;
+        let std_err = if self.count > 2 {
+            // the squared residuals are the variance of y not explained by the slope
+            let s_yy = self.sum_yy - self.sum_y * self.sum_y / n;
+            let s_xx = n * (n * n - 1.0) / 12.0;
+            let sse = s_yy - line.slope * line.slope * s_xx;
+            // on a straight line the difference can round to slightly below 0
+            (sse.max(0.0) / (n - 2.0)).sqrt()
+        } else {
+            0.0
+        };
+
+        Self::Output {
+            upper: line.forecast + std_err * self.multiplier,
+            middle: line.forecast,
+            lower: line.forecast - std_err * self.multiplier,
+        }
+    }
+}
+
+impl<T: Close> Next<&T> for StandardErrorBands {
+    type Output = StandardErrorBandsOutput;
+
+    fn next(&mut self, input: &T) -> Self::Output {
+        self.next(input.close())
+    }
+}
+
+impl Ready for StandardErrorBands {
+    fn bars_seen(&self) -> usize {
+        self.linreg.bars_seen()
+    }
+
+    fn is_ready(&self) -> bool {
+        self.linreg.is_ready()
+    }
+}
+
+impl Reset for StandardErrorBands {
+    fn reset(&mut self) {
+        self.linreg.reset();
+        self.index = 0;
+        self.count = 0;
+        self.shift = 0.0;
+        self.sum_y = 0.0;
+        self.sum_yy = 0.0;
+        for i in 0..self.deque.len() {
+            self.deque[i] = 0.0;
+        }
+    }
+}
+
+impl Default for StandardErrorBands {
+    fn default() -> Self {
+        Self::new(21, 2.0).unwrap()
+    }
+}
+
+impl fmt::Display for StandardErrorBands {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "SEB({}, {})", self.period(), self.multiplier)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helper::*;
+
+    fn round(out: StandardErrorBandsOutput) -> (f64, f64, f64) {
+        (
+            crate::test_helper::round(out.upper),
+            crate::test_helper::round(out.middle),
+            crate::test_helper::round(out.lower),
+        )
+    }
+
+    test_indicator!(StandardErrorBands);
+
+    #[test]
+    fn test_new() {
+        assert!(StandardErrorBands::new(0, 2.0).is_err());
+        assert!(StandardErrorBands::new(1, 2.0).is_ok());
+        assert!(StandardErrorBands::new(21, 2.0).is_ok());
+    }
+
+    #[test]
+    fn test_next() {
+        let mut seb = StandardErrorBands::new(4, 2.0).unwrap();
+
+        let inputs = [10.0, 12.0, 11.0, 14.0, 13.0, 16.0, 15.0];
+        let expected = [
+            (10.0, 10.0, 10.0),
+            (12.0, 12.0, 12.0),
+            (13.949, 11.5, 9.051),
+            (15.724, 13.4, 11.076),
+            (15.93, 13.4, 10.87),
+            (18.13, 15.6, 13.07),
+            (17.93, 15.4, 12.87),
+        ];
+
+        for (&input, expected) in inputs.iter().zip(expected) {
+            assert_eq!(round(seb.next(input)), expected);
+        }
+
+        let mut seb = StandardErrorBands::new(4, 2.0).unwrap();
+        assert_eq!(round(seb.next(&Bar::new().close(10))), (10.0, 10.0, 10.0));
+        assert_eq!(round(seb.next(&Bar::new().close(12))), (12.0, 12.0, 12.0));
+    }
+
+    #[test]
+    fn test_next_width() {
+        let mut linear = StandardErrorBands::new(10, 2.0).unwrap();
+        let mut noisy = StandardErrorBands::new(10, 2.0).unwrap();
+
+        let (mut linear_width, mut noisy_width) = (0.0, 0.0);
+        for i in 0..30 {
+            let price = 100.0 + i as f64;
+            let out = linear.next(price);
+            linear_width = out.upper - out.lower;
+
+            let noise = if i % 2 == 0 { 3.0 } else { -3.0 };
+            let out = noisy.next(price + noise);
+            noisy_width = out.upper - out.lower;
+        }
+
+        // the bands collapse onto a straight line and widen around the noise
+        assert!(linear_width < 1e-6);
+        assert!(noisy_width > 10.0);
+    }
+
+    #[test]
+    fn test_next_long_series() {
+        let mut seb = StandardErrorBands::new(21, 2.0).unwrap();
+
+        // a line at a high level with a little noise over millions of inputs
+        let input =
+            |i: usize| 50000.0 + 0.001 * i as f64 + if i.is_multiple_of(2) { 0.01 } else { -0.01 };
+        let count = 2_000_000;
+        let mut out = seb.next(input(0));
+        for i in 1..count {
+            out = seb.next(input(i));
+        }
+
+        // the same window without the long history
+        let mut fresh = StandardErrorBands::new(21, 2.0).unwrap();
+        let mut expected = fresh.next(input(count - 42));
+        for i in count - 41..count {
+            expected = fresh.next(input(i));
+        }
+
+        assert!((out.middle - expected.middle).abs() < 1e-6);
+        assert!((out.upper - expected.upper).abs() < 1e-6);
+        assert!((out.lower - expected.lower).abs() < 1e-6);
+        // about 0.01 away from the line
+        assert!(out.upper - out.middle > 0.015 && out.upper - out.middle < 0.025);
+    }
+
+    #[test]
+    fn test_reset() {
+        let mut seb = StandardErrorBands::new(4, 2.0).unwrap();
+
+        seb.next(10.0);
+        seb.next(12.0);
+        seb.next(11.0);
+        seb.reset();
+
+        assert_eq!(round(seb.next(10.0)), (10.0, 10.0, 10.0));
+        assert_eq!(round(seb.next(12.0)), (12.0, 12.0, 12.0));
+        assert_eq!(round(seb.next(11.0)), (13.949, 11.5, 9.051));
+    }
+
+    #[test]
+    fn test_ready() {
+        let mut seb = StandardErrorBands::new(3, 2.0).unwrap();
+        for _ in 0..2 {
+            seb.next(1.0);
+        }
+        assert_eq!((seb.bars_seen(), seb.is_ready()), (2, false));
+        seb.next(1.0);
+        assert_eq!((seb.bars_seen(), seb.is_ready()), (3, true));
+
+        seb.reset();
+        assert_eq!((seb.bars_seen(), seb.is_ready()), (0, false));
+    }
+
+    #[test]
+    fn test_default() {
+        StandardErrorBands::default();
+    }
+
+    #[test]
+    fn test_display() {
+        let seb = StandardErrorBands::new(21, 2.0).unwrap();
+        assert_eq!(format!("{}", seb), "SEB(21, 2)");
+    }
+}
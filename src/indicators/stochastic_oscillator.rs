@@ -0,0 +1,253 @@
+use std::fmt;
+
+use crate::errors::Result;
+use crate::indicators::{FastStochastic, SimpleMovingAverage};
+use crate::{Close, High, Low, Next, Period, Ready, Reset};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Stochastic oscillator (%K / %D).
+///
+/// The full stochastic oscillator: the raw %K of a [fast stochastic](struct.FastStochastic.html)
+/// is smoothed with a simple moving average to get the slow %K, and %D is a simple moving
+/// average of the slow %K.
+///
+/// # Formula
+///
+/// %K<sub>raw</sub> = (close - lowest low) / (highest high - lowest low) * 100
+///
+/// %K = SMA(smoothing) of %K<sub>raw</sub>
+///
+/// %D = SMA(d_period) of %K
+///
+/// Where the highest high and the lowest low are taken over the last _k_period_ bars.
+/// When they are equal %K<sub>raw</sub> is 50.
+///
+/// # Parameters
+///
+/// * _k_period_ - number of bars to look for the highest high and the lowest low
+///   (integer greater than 0). Default is 14.
+/// * _d_period_ - period of the %D moving average (integer greater than 0). Default is 3.
+/// * _smoothing_ - period of the %K moving average (integer greater than 0). Default is 3.
+///
+/// # Example
+///
+/// ```
+/// use ta::indicators::StochasticOscillator;
+/// use ta::{DataItem, Next};
+///
+/// let mut stoch = StochasticOscillator::new(5, 3, 3).unwrap();
+///
+/// let di = DataItem::builder()
+///     .open(9.5).high(10.0).low(9.0).close(9.5).volume(1000.0)
+///     .build(true).unwrap();
+/// let out = stoch.next(&di);
+///
+/// assert_eq!(out.k, 50.0);
+/// assert_eq!(out.d, 50.0);
+/// ```
+///
+/// # Links
+///
+/// * [Stochastic oscillator, Wikipedia](https://en.wikipedia.org/wiki/Stochastic_oscillator)
+/// * [Stochastic oscillator, StockCharts](https://school.stockcharts.com/doku.php?id=technical_indicators:stochastic_oscillator_fast_slow_and_full)
+///
+#[doc(alias = "STOCH")]
+#[doc(alias = "KD")]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone)]
+pub struct StochasticOscillator {
+    fast_stochastic: FastStochastic,
+    k_sma: SimpleMovingAverage,
+    d_sma: SimpleMovingAverage,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct StochasticOscillatorOutput {
+    pub k: f64,
+    pub d: f64,
+}
+
+impl From<StochasticOscillatorOutput> for (f64, f64) {
+    fn from(so: StochasticOscillatorOutput) -> Self {
+        (so.k, so.d)
+    }
+}
+
+impl StochasticOscillator {
+    pub fn new(k_period: usize, d_period: usize, smoothing: usize) -> Result<Self> {
+        Ok(Self {
+            fast_stochastic: FastStochastic::new(k_period)?,
+            k_sma: SimpleMovingAverage::new(smoothing)?,
+            d_sma: SimpleMovingAverage::new(d_period)?,
+        })
+    }
+
+    fn smooth(&mut self, raw_k: f64) -> StochasticOscillatorOutput {
+        let k = self.k_sma.next(raw_k);
+        let d = self.d_sma.next(k);
+        StochasticOscillatorOutput { k, d }
+    }
+}
+
+impl Period for StochasticOscillator {
+    fn period(&self) -> usize {
+        self.fast_stochastic.period()
+    }
+}
+
+impl Next<f64> for StochasticOscillator {
+    type Output = StochasticOscillatorOutput;
+
+    fn next(&mut self, input: f64) -> Self::Output {
+        let raw_k = self.fast_stochastic.next(input);
+        self.smooth(raw_k)
+    }
+}
+
+impl<T: High + Low + Close> Next<&T> for StochasticOscillator {
+    type Output = StochasticOscillatorOutput;
+
+    fn next(&mut self, input: &T) -> Self::Output {
+        let raw_k = self.fast_stochastic.next(input);
+        self.smooth(raw_k)
+    }
+}
+
+impl Ready for StochasticOscillator {
+    fn bars_seen(&self) -> usize {
+        self.fast_stochastic.bars_seen()
+    }
+
+    fn is_ready(&self) -> bool {
+        self.fast_stochastic.is_ready() && self.k_sma.is_ready() && self.d_sma.is_ready()
+    }
+}
+
+impl Reset for StochasticOscillator {
+    fn reset(&mut self) {
+        self.fast_stochastic.reset();
+        self.k_sma.reset();
+        self.d_sma.reset();
+    }
+}
+
+impl Default for StochasticOscillator {
+    fn default() -> Self {
+        Self::new(14, 3, 3).unwrap()
+    }
+}
+
+impl fmt::Display for StochasticOscillator {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "STOCH({}, {}, {})",
+            self.fast_stochastic.period(),
+            self.d_sma.period(),
+            self.k_sma.period()
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helper::*;
+
+    test_indicator!(StochasticOscillator);
+
+    #[test]
+    fn test_new() {
+        assert!(StochasticOscillator::new(0, 3, 3).is_err());
+        assert!(StochasticOscillator::new(14, 0, 3).is_err());
+        assert!(StochasticOscillator::new(14, 3, 0).is_err());
+        assert!(StochasticOscillator::new(1, 1, 1).is_ok());
+        assert!(StochasticOscillator::new(14, 3, 3).is_ok());
+    }
+
+    #[test]
+    fn test_next_bar() {
+        let mut stoch = StochasticOscillator::new(5, 3, 3).unwrap();
+
+        let bars = [
+            // high, low, close, k, d
+            (10.0, 9.0, 9.5, 50.0, 50.0),
+            (11.0, 9.5, 10.5, 62.5, 56.25),
+            (12.0, 10.0, 11.5, 69.444, 60.648),
+            (11.5, 10.5, 11.0, 75.0, 68.981),
+            (11.0, 9.5, 10.0, 61.111, 68.519),
+            (10.5, 9.0, 9.2, 35.556, 57.222),
+            (10.0, 8.5, 9.8, 25.714, 40.794),
+            (11.0, 9.5, 10.8, 40.159, 33.81),
+            (12.5, 10.5, 12.2, 68.77, 44.881),
+            (13.0, 11.5, 12.0, 82.315, 63.748),
+        ];
+
+        for (high, low, close, k, d) in bars {
+            let out = stoch.next(&Bar::new().high(high).low(low).close(close));
+            assert_eq!(round(out.k), k);
+            assert_eq!(round(out.d), d);
+        }
+    }
+
+    #[test]
+    fn test_next_bounded() {
+        let mut stoch = StochasticOscillator::new(4, 3, 2).unwrap();
+
+        for i in 0..50 {
+            let x = i as f64;
+            let close = 100.0 + 10.0 * (x * 0.7).sin() + x * 0.3;
+            let bar = Bar::new().high(close + 1.5).low(close - 2.0).close(close);
+            let out = stoch.next(&bar);
+            assert!((0.0..=100.0).contains(&out.k), "k out of range: {}", out.k);
+            assert!((0.0..=100.0).contains(&out.d), "d out of range: {}", out.d);
+        }
+    }
+
+    #[test]
+    fn test_reset() {
+        let mut stoch = StochasticOscillator::new(5, 3, 3).unwrap();
+
+        stoch.next(&Bar::new().high(10).low(9).close(9.5));
+        stoch.next(&Bar::new().high(11).low(9.5).close(11));
+        stoch.reset();
+
+        let out = stoch.next(&Bar::new().high(10).low(9).close(9.5));
+        assert_eq!(out.k, 50.0);
+        assert_eq!(out.d, 50.0);
+    }
+
+    #[test]
+    fn test_period() {
+        let stoch = StochasticOscillator::new(9, 3, 3).unwrap();
+        assert_eq!(stoch.period(), 9);
+    }
+
+    #[test]
+    fn test_ready() {
+        let mut stoch = StochasticOscillator::new(3, 4, 2).unwrap();
+        let bar = Bar::new().high(10).low(8).close(9);
+        for _ in 0..3 {
+            stoch.next(&bar);
+        }
+        assert_eq!((stoch.bars_seen(), stoch.is_ready()), (3, false));
+        // the %D average is the last to fill its period
+        stoch.next(&bar);
+        assert_eq!((stoch.bars_seen(), stoch.is_ready()), (4, true));
+
+        stoch.reset();
+        assert_eq!((stoch.bars_seen(), stoch.is_ready()), (0, false));
+    }
+
+    #[test]
+    fn test_default() {
+        StochasticOscillator::default();
+    }
+
+    #[test]
+    fn test_display() {
+        let stoch = StochasticOscillator::new(14, 3, 5).unwrap();
+        assert_eq!(format!("{}", stoch), "STOCH(14, 3, 5)");
+    }
+}
@@ -0,0 +1,330 @@
+use std::fmt;
+
+use crate::errors::Result;
+use crate::indicators::{RelativeStrengthIndex as Rsi, RsiSmoothing, SimpleMovingAverage as Sma};
+use crate::window::MonotonicWindow;
+use crate::{Close, Next, Period, Ready, Reset};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Stochastic RSI (StochRSI).
+///
+/// Developed by Tushar Chande and Stanley Kroll, the indicator applies the stochastic
+/// oscillator to the [relative strength index](struct.RelativeStrengthIndex.html) instead of
+/// the price. It shows where the RSI is within its own recent range, which makes it more
+/// sensitive than the RSI itself: it reaches its extremes of 0 and 100 far more often.
+///
+/// # Formula
+///
+/// StochRSI = (RSI - lowest RSI) / (highest RSI - lowest RSI) * 100
+///
+/// %K = SMA(k_smooth) of StochRSI
+///
+/// %D = SMA(d_smooth) of %K
+///
+/// Where the lowest and highest RSI are taken over the last _stoch_period_ RSI values and
+/// _SMA_ is the [simple moving average](struct.SimpleMovingAverage.html). While the RSI has
+/// no range, e.g. on the first input, StochRSI is 50.
+///
+/// # Parameters
+///
+/// * _rsi_period_ - period of the RSI (integer greater than 0). Default is 14.
+/// * _stoch_period_ - number of RSI values the range is taken over (integer greater than 0).
+///   Default is 14.
+/// * _k_smooth_ - period of the %K smoothing (integer greater than 0). Default is 3.
+/// * _d_smooth_ - period of the %D smoothing (integer greater than 0). Default is 3.
+///
+/// The RSI uses Wilder's smoothing, [with_smoothing](Self::with_smoothing) selects another
+/// [RsiSmoothing].
+///
+/// # Example
+///
+/// ```
+/// use ta::indicators::StochasticRsi;
+/// use ta::Next;
+///
+/// let mut stoch_rsi = StochasticRsi::new(3, 3, 2, 2).unwrap();
+///
+/// assert_eq!(stoch_rsi.next(10.0).k, 50.0);
+/// assert_eq!(stoch_rsi.next(11.0).k, 75.0);
+/// let out = stoch_rsi.next(12.0);
+/// assert_eq!((out.k, out.d), (100.0, 87.5));
+/// ```
+///
+/// # Links
+///
+/// * [Stochastic RSI, StockCharts](https://school.stockcharts.com/doku.php?id=technical_indicators:stochrsi)
+///
+#[doc(alias = "StochRSI")]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone)]
+pub struct StochasticRsi {
+    stoch_period: usize,
+    rsi: Rsi,
+    window: MonotonicWindow,
+    k: Sma,
+    d: Sma,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct StochasticRsiOutput {
+    pub k: f64,
+    pub d: f64,
+}
+
+impl From<StochasticRsiOutput> for (f64, f64) {
+    fn from(sro: StochasticRsiOutput) -> Self {
+        (sro.k, sro.d)
+    }
+}
+
+impl StochasticRsi {
+    pub fn new(
+        rsi_period: usize,
+        stoch_period: usize,
+        k_smooth: usize,
+        d_smooth: usize,
+    ) -> Result<Self> {
+        Ok(Self {
+            stoch_period,
+            rsi: Rsi::new(rsi_period)?,
+            window: MonotonicWindow::new(stoch_period)?,
+            k: Sma::new(k_smooth)?,
+            d: Sma::new(d_smooth)?,
+        })
+    }
+
+    pub fn with_smoothing(mut self, smoothing: RsiSmoothing) -> Self {
+        self.rsi = self.rsi.with_smoothing(smoothing);
+        self
+    }
+
+    pub fn smoothing(&self) -> RsiSmoothing {
+        self.rsi.smoothing()
+    }
+}
+
+/// The number of RSI values the range is taken over.
+impl Period for StochasticRsi {
+    fn period(&self) -> usize {
+        self.stoch_period
+    }
+}
+
+impl Next<f64> for StochasticRsi {
+    type Output = StochasticRsiOutput;
+
+    fn next(&mut self, input: f64) -> Self::Output {
+        let rsi = self.rsi.next(input);
+        let (lowest, highest) = self.window.push(rsi);
+
+        let stoch_rsi = if highest == lowest {
+            50.0
+        } else {
+            (rsi - lowest) / (highest - lowest) * 100.0
+        };
+
+        // the running sums of the SMAs can drift out of range by a rounding error
+        let k = self.k.next(stoch_rsi).clamp(0.0, 100.0);
+        StochasticRsiOutput {
+            k,
+            d: self.d.next(k).clamp(0.0, 100.0),
+        }
+    }
+}
+
+impl<T: Close> Next<&T> for StochasticRsi {
+    type Output = StochasticRsiOutput;
+
+    fn next(&mut self, input: &T) -> Self::Output {
+        self.next(input.close())
+    }
+}
+
+impl Ready for StochasticRsi {
+    fn bars_seen(&self) -> usize {
+        self.rsi.bars_seen()
+    }
+
+    fn is_ready(&self) -> bool {
+        self.rsi.is_ready()
+            && self.rsi.bars_seen() >= self.stoch_period
+            && self.k.is_ready()
+            && self.d.is_ready()
+    }
+}
+
+impl Reset for StochasticRsi {
+    fn reset(&mut self) {
+        self.rsi.reset();
+        self.window.reset();
+        self.k.reset();
+        self.d.reset();
+    }
+}
+
+impl Default for StochasticRsi {
+    fn default() -> Self {
+        Self::new(14, 14, 3, 3).unwrap()
+    }
+}
+
+impl fmt::Display for StochasticRsi {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "STOCHRSI({}, {}, {}, {}",
+            self.rsi.period(),
+            self.stoch_period,
+            self.k.period(),
+            self.d.period()
+        )?;
+        match self.smoothing() {
+            RsiSmoothing::Wilder => write!(f, ")"),
+            RsiSmoothing::Sma => write!(f, ", sma)"),
+            RsiSmoothing::Ema => write!(f, ", ema)"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helper::*;
+
+    fn round(out: StochasticRsiOutput) -> (f64, f64) {
+        (
+            crate::test_helper::round(out.k),
+            crate::test_helper::round(out.d),
+        )
+    }
+
+    test_indicator!(StochasticRsi);
+
+    #[test]
+    fn test_new() {
+        assert!(StochasticRsi::new(0, 14, 3, 3).is_err());
+        assert!(StochasticRsi::new(14, 0, 3, 3).is_err());
+        assert!(StochasticRsi::new(14, 14, 0, 3).is_err());
+        assert!(StochasticRsi::new(14, 14, 3, 0).is_err());
+        assert!(StochasticRsi::new(14, 14, 3, 3).is_ok());
+    }
+
+    #[test]
+    fn test_next() {
+        let mut stoch_rsi = StochasticRsi::new(3, 3, 2, 2).unwrap();
+
+        // rsi: 50, 100, 100, 66.667, 44.444, 68.254, 80.676, 67.475
+        let inputs = [10.0, 11.0, 12.0, 11.0, 10.0, 11.5, 13.0, 12.5];
+        let expected = [
+            // the first rsi has no range yet
+            (50.0, 50.0),
+            (75.0, 62.5),
+            (100.0, 87.5),
+            (50.0, 75.0),
+            (0.0, 25.0),
+            (50.0, 25.0),
+            (100.0, 75.0),
+            (50.0, 75.0),
+        ];
+
+        for (&input, expected) in inputs.iter().zip(expected) {
+            assert_eq!(round(stoch_rsi.next(input)), expected);
+        }
+
+        let mut stoch_rsi = StochasticRsi::new(3, 3, 2, 2).unwrap();
+        assert_eq!(round(stoch_rsi.next(&Bar::new().close(10))), (50.0, 50.0));
+        assert_eq!(round(stoch_rsi.next(&Bar::new().close(11))), (75.0, 62.5));
+    }
+
+    #[test]
+    fn test_next_smoothing() {
+        let inputs = [
+            10.0, 11.0, 12.0, 11.0, 10.0, 11.5, 13.0, 12.5, 12.0, 12.5, 12.25, 11.0, 11.5,
+        ];
+
+        let mut wilder = StochasticRsi::new(3, 3, 2, 2).unwrap();
+        let mut ema = StochasticRsi::new(3, 3, 2, 2)
+            .unwrap()
+            .with_smoothing(RsiSmoothing::Ema);
+        assert_eq!(ema.smoothing(), RsiSmoothing::Ema);
+
+        let wilder: Vec<_> = inputs.iter().map(|&x| round(wilder.next(x))).collect();
+        let ema: Vec<_> = inputs.iter().map(|&x| round(ema.next(x))).collect();
+
+        // the RSIs differ from the second input, but they rank the same until the tenth
+        assert_eq!(wilder[..9], ema[..9]);
+        assert_eq!(
+            wilder[9..],
+            [
+                (39.312, 19.656),
+                (44.148, 41.73),
+                (4.836, 24.492),
+                (30.483, 17.659)
+            ]
+        );
+        assert_eq!(
+            ema[9..],
+            [
+                (50.0, 25.0),
+                (60.815, 55.408),
+                (10.815, 35.815),
+                (47.806, 29.311)
+            ]
+        );
+    }
+
+    #[test]
+    fn test_next_bounded() {
+        let mut stoch_rsi = StochasticRsi::default();
+
+        for i in 0..200 {
+            let input = 100.0 + (i as f64 * 0.7).sin() * 10.0 + (i % 7) as f64;
+            let out = stoch_rsi.next(input);
+            assert!((0.0..=100.0).contains(&out.k));
+            assert!((0.0..=100.0).contains(&out.d));
+        }
+    }
+
+    #[test]
+    fn test_reset() {
+        let mut stoch_rsi = StochasticRsi::new(3, 3, 2, 2).unwrap();
+
+        stoch_rsi.next(10.0);
+        stoch_rsi.next(11.0);
+        stoch_rsi.next(12.0);
+        stoch_rsi.reset();
+
+        assert_eq!(round(stoch_rsi.next(10.0)), (50.0, 50.0));
+        assert_eq!(round(stoch_rsi.next(11.0)), (75.0, 62.5));
+    }
+
+    #[test]
+    fn test_ready() {
+        let mut stoch = StochasticRsi::new(2, 4, 2, 3).unwrap();
+        for _ in 0..3 {
+            stoch.next(1.0);
+        }
+        assert_eq!((stoch.bars_seen(), stoch.is_ready()), (3, false));
+        // the range of four RSI values is the last to fill
+        stoch.next(1.0);
+        assert_eq!((stoch.bars_seen(), stoch.is_ready()), (4, true));
+
+        stoch.reset();
+        assert_eq!((stoch.bars_seen(), stoch.is_ready()), (0, false));
+    }
+
+    #[test]
+    fn test_default() {
+        StochasticRsi::default();
+    }
+
+    #[test]
+    fn test_display() {
+        let stoch_rsi = StochasticRsi::new(14, 14, 3, 3).unwrap();
+        assert_eq!(format!("{}", stoch_rsi), "STOCHRSI(14, 14, 3, 3)");
+
+        let stoch_rsi = stoch_rsi.with_smoothing(RsiSmoothing::Ema);
+        assert_eq!(format!("{}", stoch_rsi), "STOCHRSI(14, 14, 3, 3, ema)");
+    }
+}
@@ -0,0 +1,264 @@
+use std::fmt;
+
+use crate::errors::{Result, TaError};
+use crate::indicators::AverageTrueRange;
+use crate::{Close, High, Low, Next, Period, Ready, Reset};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// SuperTrend.
+///
+/// A trend following indicator that trails the price with a band placed _multiplier_ times
+/// the [average true range](struct.AverageTrueRange.html) away from the middle of the bar.
+/// In an uptrend the value is the lower band, in a downtrend the upper band. The trend flips
+/// when the close crosses the active band.
+///
+/// # Formula
+///
+/// Basic upper band = (high + low) / 2 + multiplier * ATR
+///
+/// Basic lower band = (high + low) / 2 - multiplier * ATR
+///
+/// The final upper band only moves down, unless the previous close was above it.
+/// The final lower band only moves up, unless the previous close was below it.
+///
+/// An uptrend turns into a downtrend when the close is below the final lower band, a downtrend
+/// turns into an uptrend when the close is above the final upper band. The first bar starts
+/// an uptrend.
+///
+/// # Parameters
+///
+/// * _atr_period_ - period of the ATR (integer greater than 0). Default is 10.
+/// * _multiplier_ - ATR multiplier (greater than 0). Default is 3.0.
+///
+/// # Example
+///
+/// ```
+/// use ta::indicators::SuperTrend;
+/// use ta::{DataItem, Next};
+///
+/// let mut st = SuperTrend::new(3, 1.5).unwrap();
+///
+/// let di = DataItem::builder()
+///     .open(10.0).high(10.5).low(9.5).close(10.0).volume(1000.0)
+///     .build(true).unwrap();
+/// let out = st.next(&di);
+///
+/// assert_eq!(out.value, 8.5);
+/// assert!(out.is_uptrend);
+/// ```
+///
+/// # Links
+///
+/// * [SuperTrend, TradingView](https://www.tradingview.com/support/solutions/43000634738-supertrend/)
+///
+#[doc(alias = "ST")]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone)]
+pub struct SuperTrend {
+    multiplier: f64,
+    atr: AverageTrueRange,
+    upper_band: f64,
+    lower_band: f64,
+    prev_close: Option<f64>,
+    is_uptrend: bool,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct SuperTrendOutput {
+    pub value: f64,
+    pub is_uptrend: bool,
+}
+
+impl From<SuperTrendOutput> for (f64, bool) {
+    fn from(so: SuperTrendOutput) -> Self {
+        (so.value, so.is_uptrend)
+    }
+}
+
+impl SuperTrend {
+    pub fn new(atr_period: usize, multiplier: f64) -> Result<Self> {
+        let is_valid = multiplier > 0.0 && multiplier.is_finite();
+        if !is_valid {
+            return Err(TaError::invalid_parameter("multiplier", multiplier));
+        }
+        Ok(Self {
+            multiplier,
+            atr: AverageTrueRange::new(atr_period)?,
+            upper_band: 0.0,
+            lower_band: 0.0,
+            prev_close: None,
+            is_uptrend: true,
+        })
+    }
+
+    pub fn multiplier(&self) -> f64 {
+        self.multiplier
+    }
+}
+
+impl Period for SuperTrend {
+    fn period(&self) -> usize {
+        self.atr.period()
+    }
+}
+
+impl<T: High + Low + Close> Next<&T> for SuperTrend {
+    type Output = SuperTrendOutput;
+
+    fn next(&mut self, input: &T) -> Self::Output {
+        let close = input.close();
+        let atr = self.atr.next(input);
+        let mid = (input.high() + input.low()) / 2.0;
+        let basic_upper = mid + self.multiplier * atr;
+        let basic_lower = mid - self.multiplier * atr;
+
+        match self.prev_close {
+            None => {
+                self.upper_band = basic_upper;
+                self.lower_band = basic_lower;
+                self.is_uptrend = true;
+            }
+            Some(prev_close) => {
+                if basic_upper < self.upper_band || prev_close > self.upper_band {
+                    self.upper_band = basic_upper;
+                }
+                if basic_lower > self.lower_band || prev_close < self.lower_band {
+                    self.lower_band = basic_lower;
+                }
+
+                if self.is_uptrend && close < self.lower_band {
+                    self.is_uptrend = false;
+                } else if !self.is_uptrend && close > self.upper_band {
+                    self.is_uptrend = true;
+                }
+            }
+        }
+        self.prev_close = Some(close);
+
+        SuperTrendOutput {
+            value: if self.is_uptrend {
+                self.lower_band
+            } else {
+                self.upper_band
+            },
+            is_uptrend: self.is_uptrend,
+        }
+    }
+}
+
+impl Ready for SuperTrend {
+    fn bars_seen(&self) -> usize {
+        self.atr.bars_seen()
+    }
+
+    fn is_ready(&self) -> bool {
+        self.atr.is_ready()
+    }
+}
+
+impl Reset for SuperTrend {
+    fn reset(&mut self) {
+        self.atr.reset();
+        self.upper_band = 0.0;
+        self.lower_band = 0.0;
+        self.prev_close = None;
+        self.is_uptrend = true;
+    }
+}
+
+impl Default for SuperTrend {
+    fn default() -> Self {
+        Self::new(10, 3.0).unwrap()
+    }
+}
+
+impl fmt::Display for SuperTrend {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "SUPERTREND({}, {})", self.atr.period(), self.multiplier)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helper::*;
+
+    #[test]
+    fn test_new() {
+        assert!(SuperTrend::new(0, 3.0).is_err());
+        assert!(SuperTrend::new(10, 0.0).is_err());
+        assert!(SuperTrend::new(10, -1.0).is_err());
+        assert!(SuperTrend::new(10, f64::NAN).is_err());
+        assert!(SuperTrend::new(1, 0.5).is_ok());
+        assert!(SuperTrend::new(10, 3.0).is_ok());
+    }
+
+    #[test]
+    fn test_next() {
+        let mut st = SuperTrend::new(3, 1.5).unwrap();
+
+        let data = [
+            // high, low, close, value, is_uptrend
+            (10.5, 9.5, 10.0, 8.5, true),
+            (11.0, 10.0, 10.8, 9.0, true),
+            (11.5, 10.5, 11.2, 9.5, true),
+            (12.0, 11.0, 11.8, 10.0, true),
+            (12.2, 11.4, 11.6, 10.45, true),
+            (11.8, 10.6, 10.8, 10.45, true), // the lower band does not move down
+            (11.0, 9.8, 10.0, 12.088, false), // close below the lower band, flip
+            (10.2, 9.0, 9.2, 11.344, false),
+            (9.6, 8.6, 8.8, 10.722, false),
+            (9.4, 8.4, 9.2, 10.461, false),
+            (10.2, 9.0, 10.0, 10.461, false), // the upper band does not move up
+            (11.0, 9.8, 10.8, 8.66, true),    // close above the upper band, flip
+            (11.6, 10.6, 11.4, 9.48, true),
+            (12.4, 11.2, 12.2, 10.09, true),
+        ];
+
+        for (high, low, close, value, is_uptrend) in data {
+            let out = st.next(&Bar::new().high(high).low(low).close(close));
+            assert_eq!(round(out.value), value);
+            assert_eq!(out.is_uptrend, is_uptrend);
+        }
+    }
+
+    #[test]
+    fn test_reset() {
+        let mut st = SuperTrend::new(3, 1.5).unwrap();
+
+        st.next(&Bar::new().high(10.5).low(9.5).close(10));
+        st.next(&Bar::new().high(8).low(6).close(6));
+        st.reset();
+
+        let out = st.next(&Bar::new().high(10.5).low(9.5).close(10));
+        assert_eq!(out.value, 8.5);
+        assert!(out.is_uptrend);
+    }
+
+    #[test]
+    fn test_ready() {
+        let mut st = SuperTrend::new(3, 3.0).unwrap();
+        let bar = Bar::new().high(10).low(8).close(9);
+        for _ in 0..2 {
+            st.next(&bar);
+        }
+        assert_eq!((st.bars_seen(), st.is_ready()), (2, false));
+        st.next(&bar);
+        assert_eq!((st.bars_seen(), st.is_ready()), (3, true));
+
+        st.reset();
+        assert_eq!((st.bars_seen(), st.is_ready()), (0, false));
+    }
+
+    #[test]
+    fn test_default() {
+        SuperTrend::default();
+    }
+
+    #[test]
+    fn test_display() {
+        let st = SuperTrend::new(10, 3.0).unwrap();
+        assert_eq!(format!("{}", st), "SUPERTREND(10, 3)");
+    }
+}
@@ -0,0 +1,366 @@
+use std::fmt;
+
+use crate::errors::{Result, TaError};
+use crate::indicators::{FractalKind, Fractals};
+use crate::{High, Low, Next, Period, Ready, Reset};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Swing points.
+///
+/// Finds confirmed swing highs and lows, the turning points the market structure is read from.
+/// Every swing is compared with the previous swing of its kind: an uptrend makes higher highs
+/// and higher lows, a downtrend lower highs and lower lows. The swings are the
+/// [fractals](struct.Fractals.html) of a window of _2 * strength + 1_ bars.
+///
+/// # Formula
+///
+/// Swing high = a bar with a high greater than the highs of the _strength_ bars before and the
+/// _strength_ bars after it
+///
+/// Swing low = a bar with a low less than the lows of the _strength_ bars before and the
+/// _strength_ bars after it
+///
+/// A swing high is a higher high when it is above the previous swing high, and a lower high
+/// otherwise, so an equal high counts as a lower high. A swing low is a lower low when it is
+/// below the previous swing low, and a higher low otherwise.
+///
+/// # Outside bars
+///
+/// A bar which is both, an outside bar engulfing the bars around it, is reported as both: the
+/// swing high first and the swing low by the next call, with the same _bar_index_. The next
+/// swing low is compared with its low.
+///
+/// # Lag
+///
+/// A swing is only confirmed once _strength_ bars followed it. It is returned by the call
+/// consuming the last of them, one call later for the swing low of an outside bar.
+/// _bar_index_ tells which bar it is.
+///
+/// # Parameters
+///
+/// * _strength_ - number of bars on each side of a swing (integer greater than 0). Default is
+///   2.
+///
+/// # Example
+///
+/// ```
+/// use ta::indicators::{MarketStructure, SwingKind, SwingPoints};
+/// use ta::{DataItem, Next};
+///
+/// let mut swings = SwingPoints::new(1).unwrap();
+///
+/// let mut next = |high: f64| {
+///     let di = DataItem::builder()
+///         .open(high).high(high).low(high - 1.0).close(high).volume(1000.0)
+///         .build(true).unwrap();
+///     swings.next(&di)
+/// };
+///
+/// assert_eq!(next(10.0), None);
+/// assert_eq!(next(12.0), None);
+/// // the bar after the 12 confirms it
+/// let swing = next(11.0).unwrap();
+/// assert_eq!((swing.kind, swing.price, swing.bar_index), (SwingKind::High, 12.0, 1));
+/// assert_eq!(swing.structure, None);
+///
+/// next(9.0);
+/// next(13.0);
+/// let swing = next(12.0).unwrap();
+/// assert_eq!(swing.structure, Some(MarketStructure::HigherHigh));
+/// ```
+///
+/// # Links
+///
+/// * [Swing High, Investopedia](https://www.investopedia.com/terms/s/swinghigh.asp)
+///
+#[doc(alias = "Gann Swing")]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone)]
+pub struct SwingPoints {
+    strength: usize,
+    fractals: Fractals,
+    bars_seen: usize,
+    prev_high: Option<f64>,
+    prev_low: Option<f64>,
+}
+
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SwingKind {
+    High,
+    Low,
+}
+
+/// A swing compared with the previous swing of its kind.
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MarketStructure {
+    HigherHigh,
+    LowerHigh,
+    HigherLow,
+    LowerLow,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Swing {
+    pub kind: SwingKind,
+    /// The high of a swing high, the low of a swing low.
+    pub price: f64,
+    /// The index of the swing bar, counting the bars consumed since the creation or the last
+    /// reset from 0.
+    pub bar_index: usize,
+    /// None for the first swing of its kind.
+    pub structure: Option<MarketStructure>,
+}
+
+impl SwingPoints {
+    pub fn new(strength: usize) -> Result<Self> {
+        match strength {
+            0 => Err(TaError::invalid_parameter("strength", strength)),
+            _ => Ok(Self {
+                strength,
+                fractals: Fractals::with_window(2 * strength + 1)?,
+                bars_seen: 0,
+                prev_high: None,
+                prev_low: None,
+            }),
+        }
+    }
+}
+
+/// The strength.
+impl Period for SwingPoints {
+    fn period(&self) -> usize {
+        self.strength
+    }
+}
+
+impl<T: High + Low> Next<&T> for SwingPoints {
+    type Output = Option<Swing>;
+
+    fn next(&mut self, input: &T) -> Self::Output {
+        self.bars_seen += 1;
+        let fractal = self.fractals.next(input)?;
+        let price = fractal.price;
+
+        let (kind, structure) = match fractal.kind {
+            FractalKind::Up => {
+                let structure = self.prev_high.map(|prev| {
+                    if price > prev {
+                        MarketStructure::HigherHigh
+                    } else {
+                        MarketStructure::LowerHigh
+                    }
+                });
+                self.prev_high = Some(price);
+                (SwingKind::High, structure)
+            }
+            FractalKind::Down => {
+                let structure = self.prev_low.map(|prev| {
+                    if price < prev {
+                        MarketStructure::LowerLow
+                    } else {
+                        MarketStructure::HigherLow
+                    }
+                });
+                self.prev_low = Some(price);
+                (SwingKind::Low, structure)
+            }
+        };
+
+        Some(Swing {
+            kind,
+            price,
+            bar_index: self.bars_seen - 1 - fractal.bars_ago,
+            structure,
+        })
+    }
+}
+
+impl Ready for SwingPoints {
+    fn bars_seen(&self) -> usize {
+        self.bars_seen
+    }
+
+    fn is_ready(&self) -> bool {
+        self.fractals.is_ready()
+    }
+}
+
+impl Reset for SwingPoints {
+    fn reset(&mut self) {
+        self.fractals.reset();
+        self.bars_seen = 0;
+        self.prev_high = None;
+        self.prev_low = None;
+    }
+}
+
+impl Default for SwingPoints {
+    fn default() -> Self {
+        Self::new(2).unwrap()
+    }
+}
+
+impl fmt::Display for SwingPoints {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "SWING({})", self.strength)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helper::*;
+    use MarketStructure::*;
+
+    #[test]
+    fn test_new() {
+        assert!(SwingPoints::new(0).is_err());
+        assert!(SwingPoints::new(1).is_ok());
+    }
+
+    #[test]
+    fn test_next_wave() {
+        let mut swings = SwingPoints::new(1).unwrap();
+
+        let prices = [
+            10.0, 12.0, 14.0, 12.0, 10.0, 12.0, 16.0, 12.0, 8.0, 11.0, 13.0, 11.0, 9.0, 10.0,
+        ];
+        let found: Vec<_> = prices
+            .iter()
+            .filter_map(|&price| swings.next(&Bar::new().high(price + 1.0).low(price - 1.0)))
+            .map(|swing| (swing.kind, swing.price, swing.bar_index, swing.structure))
+            .collect();
+
+        assert_eq!(
+            found,
+            [
+                (SwingKind::High, 15.0, 2, None),
+                (SwingKind::Low, 9.0, 4, None),
+                (SwingKind::High, 17.0, 6, Some(HigherHigh)),
+                (SwingKind::Low, 7.0, 8, Some(LowerLow)),
+                (SwingKind::High, 14.0, 10, Some(LowerHigh)),
+                (SwingKind::Low, 8.0, 12, Some(HigherLow)),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_next_strength() {
+        let mut swings = SwingPoints::new(2).unwrap();
+
+        // needs 2 lower highs on each side
+        assert_eq!(swings.next(&Bar::new().high(13).low(11)), None);
+        assert_eq!(swings.next(&Bar::new().high(12).low(10)), None);
+        assert_eq!(swings.next(&Bar::new().high(14).low(12)), None);
+        assert_eq!(swings.next(&Bar::new().high(13).low(11)), None);
+        // confirmed 2 bars later
+        let swing = swings.next(&Bar::new().high(12).low(10)).unwrap();
+        assert_eq!((swing.kind, swing.price), (SwingKind::High, 14.0));
+        assert_eq!(swing.bar_index, 2);
+    }
+
+    #[test]
+    fn test_next_ties() {
+        let mut swings = SwingPoints::new(1).unwrap();
+
+        swings.next(&Bar::new().high(11).low(9));
+        swings.next(&Bar::new().high(13).low(11));
+        // an equal high is no swing
+        assert_eq!(swings.next(&Bar::new().high(13).low(11)), None);
+        assert_eq!(swings.next(&Bar::new().high(12).low(10)), None);
+
+        swings.next(&Bar::new().high(11).low(9));
+        swings.next(&Bar::new().high(15).low(13));
+        let swing = swings.next(&Bar::new().high(11).low(9)).unwrap();
+        assert_eq!(swing.kind, SwingKind::High);
+        assert_eq!(swing.structure, None);
+
+        // a double top is a lower high
+        swings.next(&Bar::new().high(10).low(8));
+        swings.next(&Bar::new().high(15).low(13));
+        let swing = swings.next(&Bar::new().high(11).low(9)).unwrap();
+        assert_eq!(swing.structure, Some(LowerHigh));
+    }
+
+    #[test]
+    fn test_next_outside_bar() {
+        let mut swings = SwingPoints::new(1).unwrap();
+
+        let bars = [
+            Bar::new().high(10).low(9),
+            // engulfs both neighbours
+            Bar::new().high(12).low(7),
+            Bar::new().high(11).low(8),
+            Bar::new().high(10).low(6),
+            Bar::new().high(11).low(7),
+            Bar::new().high(13).low(9),
+            Bar::new().high(12).low(8),
+        ];
+        let found: Vec<_> = bars
+            .iter()
+            .map(|bar| {
+                swings
+                    .next(bar)
+                    .map(|swing| (swing.kind, swing.price, swing.bar_index, swing.structure))
+            })
+            .collect();
+
+        assert_eq!(
+            found,
+            [
+                None,
+                None,
+                Some((SwingKind::High, 12.0, 1, None)),
+                // the low of the outside bar follows its high
+                Some((SwingKind::Low, 7.0, 1, None)),
+                Some((SwingKind::Low, 6.0, 3, Some(LowerLow))),
+                None,
+                Some((SwingKind::High, 13.0, 5, Some(HigherHigh))),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_reset() {
+        let mut swings = SwingPoints::new(1).unwrap();
+
+        swings.next(&Bar::new().high(11).low(9));
+        swings.next(&Bar::new().high(13).low(11));
+        swings.next(&Bar::new().high(12).low(10));
+        swings.reset();
+
+        swings.next(&Bar::new().high(11).low(9));
+        swings.next(&Bar::new().high(12).low(10));
+        let swing = swings.next(&Bar::new().high(11).low(9)).unwrap();
+        assert_eq!((swing.price, swing.bar_index), (12.0, 1));
+        assert_eq!(swing.structure, None);
+    }
+
+    #[test]
+    fn test_ready() {
+        let mut swings = SwingPoints::new(1).unwrap();
+        let bar = Bar::new().high(10).low(8).close(9);
+        for _ in 0..2 {
+            swings.next(&bar);
+        }
+        assert_eq!((swings.bars_seen(), swings.is_ready()), (2, false));
+        swings.next(&bar);
+        assert_eq!((swings.bars_seen(), swings.is_ready()), (3, true));
+
+        swings.reset();
+        assert_eq!((swings.bars_seen(), swings.is_ready()), (0, false));
+    }
+
+    #[test]
+    fn test_default() {
+        SwingPoints::default();
+    }
+
+    #[test]
+    fn test_display() {
+        assert_eq!(format!("{}", SwingPoints::default()), "SWING(2)");
+    }
+}
@@ -0,0 +1,334 @@
+use std::fmt;
+
+use crate::errors::{Result, TaError};
+use crate::indicators::AverageTrueRange;
+use crate::{Close, High, Low, Next, Period, Ready, Reset};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Distance of a [TrailingStop] from the close.
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum TrailingStopMode {
+    /// A percentage of the close, e.g. `5.0` for 5%.
+    Percent(f64),
+    /// A multiple of the [average true range](struct.AverageTrueRange.html) over _period_ bars.
+    Atr { period: usize, multiplier: f64 },
+}
+
+/// Trailing stop.
+///
+/// A stop level which follows the close at a distance, either a percentage of the close or a
+/// multiple of the average true range. In an uptrend the stop is below the close and only moves
+/// up, in a downtrend it is above the close and only moves down. When the close breaches the
+/// stop the trend flips and the stop starts over on the other side of the close.
+///
+/// # Formula
+///
+/// Distance = close * percent / 100, or ATR(period) * multiplier
+///
+/// Long stop = max(previous stop, close - distance)
+///
+/// Short stop = min(previous stop, close + distance)
+///
+/// An uptrend turns into a downtrend when the close is below the stop, a downtrend turns into
+/// an uptrend when the close is above the stop. The first bar starts an uptrend.
+///
+/// # Parameters
+///
+/// * _mode_ - distance of the stop, see [TrailingStopMode]. The percent and the multiplier
+///   must be greater than 0, the percent less than 100. Default is an ATR of 14 bars times 3.
+///
+/// # Example
+///
+/// ```
+/// use ta::indicators::{TrailingStop, TrailingStopMode};
+/// use ta::{DataItem, Next};
+///
+/// let mut stop = TrailingStop::new(TrailingStopMode::Percent(10.0)).unwrap();
+///
+/// let di = DataItem::builder()
+///     .open(100.0).high(100.0).low(100.0).close(100.0).volume(1000.0)
+///     .build(true).unwrap();
+/// let out = stop.next(&di);
+/// assert_eq!((out.stop, out.is_long), (90.0, true));
+///
+/// let di = DataItem::builder()
+///     .open(100.0).high(110.0).low(100.0).close(110.0).volume(1000.0)
+///     .build(true).unwrap();
+/// let out = stop.next(&di);
+/// assert_eq!((out.stop, out.is_long), (99.0, true));
+/// ```
+///
+/// # Links
+///
+/// * [Trailing Stop, Investopedia](https://www.investopedia.com/terms/t/trailingstop.asp)
+///
+#[doc(alias = "TSTOP")]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone)]
+pub struct TrailingStop {
+    mode: TrailingStopMode,
+    atr: Option<AverageTrueRange>,
+    stop: f64,
+    is_long: bool,
+    is_new: bool,
+    #[cfg_attr(feature = "serde", serde(default))]
+    bars_seen: usize,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct TrailingStopOutput {
+    pub stop: f64,
+    pub is_long: bool,
+}
+
+impl From<TrailingStopOutput> for (f64, bool) {
+    fn from(tso: TrailingStopOutput) -> Self {
+        (tso.stop, tso.is_long)
+    }
+}
+
+impl TrailingStop {
+    pub fn new(mode: TrailingStopMode) -> Result<Self> {
+        let atr = match mode {
+            TrailingStopMode::Percent(percent) => {
+                let is_valid = percent > 0.0 && percent < 100.0;
+                if !is_valid {
+                    return Err(TaError::invalid_parameter("percent", percent));
+                }
+                None
+            }
+            TrailingStopMode::Atr { period, multiplier } => {
+                let is_valid = multiplier > 0.0 && multiplier.is_finite();
+                if !is_valid {
+                    return Err(TaError::invalid_parameter("multiplier", multiplier));
+                }
+                Some(AverageTrueRange::new(period)?)
+            }
+        };
+        Ok(Self {
+            mode,
+            atr,
+            stop: 0.0,
+            is_long: true,
+            is_new: true,
+            bars_seen: 0,
+        })
+    }
+
+    pub fn mode(&self) -> TrailingStopMode {
+        self.mode
+    }
+}
+
+/// The period of the ATR, 1 for a percentage.
+impl Period for TrailingStop {
+    fn period(&self) -> usize {
+        match self.mode {
+            TrailingStopMode::Percent(_) => 1,
+            TrailingStopMode::Atr { period, .. } => period,
+        }
+    }
+}
+
+impl<T: High + Low + Close> Next<&T> for TrailingStop {
+    type Output = TrailingStopOutput;
+
+    fn next(&mut self, input: &T) -> Self::Output {
+        self.bars_seen = self.bars_seen.saturating_add(1);
+        let close = input.close();
+        let distance = match self.mode {
+            TrailingStopMode::Percent(percent) => close * percent / 100.0,
+            // the ATR is created together with the mode
+            TrailingStopMode::Atr { multiplier, .. } => {
+                self.atr.as_mut().unwrap().next(input) * multiplier
+            }
+        };
+
+        if self.is_new {
+            self.is_new = false;
+            self.is_long = true;
+            self.stop = close - distance;
+        } else if self.is_long {
+            if close < self.stop {
+                self.is_long = false;
+                self.stop = close + distance;
+            } else {
+                self.stop = self.stop.max(close - distance);
+            }
+        } else if close > self.stop {
+            self.is_long = true;
+            self.stop = close - distance;
+        } else {
+            self.stop = self.stop.min(close + distance);
+        }
+
+        TrailingStopOutput {
+            stop: self.stop,
+            is_long: self.is_long,
+        }
+    }
+}
+
+impl Ready for TrailingStop {
+    fn bars_seen(&self) -> usize {
+        self.bars_seen
+    }
+
+    fn is_ready(&self) -> bool {
+        self.bars_seen >= self.period()
+    }
+}
+
+impl Reset for TrailingStop {
+    fn reset(&mut self) {
+        self.bars_seen = 0;
+        if let Some(atr) = self.atr.as_mut() {
+            atr.reset();
+        }
+        self.stop = 0.0;
+        self.is_long = true;
+        self.is_new = true;
+    }
+}
+
+impl Default for TrailingStop {
+    fn default() -> Self {
+        Self::new(TrailingStopMode::Atr {
+            period: 14,
+            multiplier: 3.0,
+        })
+        .unwrap()
+    }
+}
+
+impl fmt::Display for TrailingStop {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self.mode {
+            TrailingStopMode::Percent(percent) => write!(f, "TSTOP({}%)", percent),
+            TrailingStopMode::Atr { period, multiplier } => {
+                write!(f, "TSTOP(ATR({}), {})", period, multiplier)
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helper::*;
+
+    fn atr(period: usize, multiplier: f64) -> TrailingStopMode {
+        TrailingStopMode::Atr { period, multiplier }
+    }
+
+    #[test]
+    fn test_new() {
+        assert!(TrailingStop::new(TrailingStopMode::Percent(0.0)).is_err());
+        assert!(TrailingStop::new(TrailingStopMode::Percent(-5.0)).is_err());
+        assert!(TrailingStop::new(TrailingStopMode::Percent(100.0)).is_err());
+        assert!(TrailingStop::new(TrailingStopMode::Percent(f64::NAN)).is_err());
+        assert!(TrailingStop::new(TrailingStopMode::Percent(5.0)).is_ok());
+
+        assert!(TrailingStop::new(atr(0, 3.0)).is_err());
+        assert!(TrailingStop::new(atr(14, 0.0)).is_err());
+        assert!(TrailingStop::new(atr(14, f64::INFINITY)).is_err());
+        assert!(TrailingStop::new(atr(14, 3.0)).is_ok());
+    }
+
+    #[test]
+    fn test_next_percent() {
+        let mut stop = TrailingStop::new(TrailingStopMode::Percent(10.0)).unwrap();
+
+        let data = [
+            // close, stop, is_long
+            (100.0, 90.0, true),
+            (110.0, 99.0, true),
+            (105.0, 99.0, true),  // the stop does not move down
+            (98.0, 107.8, false), // close below the stop, flip
+            (95.0, 104.5, false),
+            (100.0, 104.5, false), // the stop does not move up
+            (106.0, 95.4, true),   // close above the stop, flip
+        ];
+
+        for (close, expected, is_long) in data {
+            let bar = Bar::new().high(close).low(close).close(close);
+            let out = stop.next(&bar);
+            assert_eq!((round(out.stop), out.is_long), (expected, is_long));
+        }
+    }
+
+    #[test]
+    fn test_next_atr() {
+        let mut stop = TrailingStop::new(atr(3, 2.0)).unwrap();
+
+        let data = [
+            // high, low, close, stop, is_long
+            (11.0, 9.0, 10.0, 6.0, true),
+            (13.0, 11.0, 12.0, 7.0, true),
+            (14.0, 12.0, 13.0, 8.5, true),
+            (10.0, 6.0, 7.0, 16.25, false), // close below the stop, flip
+            (9.0, 7.0, 8.0, 14.625, false),
+            (16.0, 14.0, 15.5, 4.1875, true), // close above the stop, flip
+        ];
+
+        for (high, low, close, expected, is_long) in data {
+            let bar = Bar::new().high(high).low(low).close(close);
+            let out = stop.next(&bar);
+            assert_eq!((out.stop, out.is_long), (expected, is_long));
+        }
+    }
+
+    #[test]
+    fn test_reset() {
+        let mut stop = TrailingStop::new(atr(3, 2.0)).unwrap();
+
+        stop.next(&Bar::new().high(11.0).low(9.0).close(10.0));
+        stop.next(&Bar::new().high(10.0).low(4.0).close(5.0));
+        stop.reset();
+
+        let out = stop.next(&Bar::new().high(11.0).low(9.0).close(10.0));
+        assert_eq!((out.stop, out.is_long), (6.0, true));
+    }
+
+    #[test]
+    fn test_period() {
+        let stop = TrailingStop::new(TrailingStopMode::Percent(5.0)).unwrap();
+        assert_eq!(stop.period(), 1);
+        assert_eq!(TrailingStop::default().period(), 14);
+    }
+
+    #[test]
+    fn test_ready() {
+        let mut stop = TrailingStop::new(TrailingStopMode::Atr {
+            period: 3,
+            multiplier: 2.0,
+        })
+        .unwrap();
+        let bar = Bar::new().high(10).low(8).close(9);
+        for _ in 0..2 {
+            stop.next(&bar);
+        }
+        assert_eq!((stop.bars_seen(), stop.is_ready()), (2, false));
+        stop.next(&bar);
+        assert_eq!((stop.bars_seen(), stop.is_ready()), (3, true));
+
+        stop.reset();
+        assert_eq!((stop.bars_seen(), stop.is_ready()), (0, false));
+    }
+
+    #[test]
+    fn test_default() {
+        TrailingStop::default();
+    }
+
+    #[test]
+    fn test_display() {
+        let stop = TrailingStop::new(TrailingStopMode::Percent(5.0)).unwrap();
+        assert_eq!(format!("{}", stop), "TSTOP(5%)");
+
+        let stop = TrailingStop::new(atr(14, 3.0)).unwrap();
+        assert_eq!(format!("{}", stop), "TSTOP(ATR(14), 3)");
+    }
+}
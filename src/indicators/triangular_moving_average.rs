@@ -0,0 +1,245 @@
+use std::fmt;
+
+use crate::errors::{Result, TaError};
+use crate::indicators::SimpleMovingAverage as Sma;
+use crate::{Close, Current, Next, Period, Ready, Reset};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Triangular moving average (TRIMA).
+///
+/// A simple moving average of a simple moving average. The double smoothing weights the inputs
+/// triangularly: the inputs in the middle of the window weigh the most, the oldest and the
+/// latest ones the least. The result is smoother than a plain SMA of the same period, but it
+/// reacts slower to the latest inputs.
+///
+/// # Formula
+///
+/// TRIMA = SMA(m) of SMA(k)
+///
+/// Where _SMA_ is the [simple moving average](struct.SimpleMovingAverage.html) and for the
+/// period _n_:
+///
+/// * odd _n_: _k = m = (n + 1) / 2_
+/// * even _n_: _k = n / 2_ and _m = n / 2 + 1_
+///
+/// # Parameters
+///
+/// * _period_ - number of periods (integer greater than 0). Default is 20.
+///
+/// # Example
+///
+/// ```
+/// use ta::indicators::TriangularMovingAverage;
+/// use ta::Next;
+///
+/// let mut trima = TriangularMovingAverage::new(3).unwrap();
+/// assert_eq!(trima.next(10.0), 10.0);
+/// assert_eq!(trima.next(12.0), 10.5);
+/// assert_eq!(trima.next(14.0), 12.0);
+/// assert_eq!(trima.next(16.0), 14.0);
+/// ```
+///
+/// # Links
+///
+/// * [Triangular Moving Average, Fidelity](https://www.fidelity.com/learning-center/trading-investing/technical-analysis/technical-indicator-guide/tma)
+///
+#[doc(alias = "TRIMA")]
+#[doc(alias = "TMA")]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone)]
+pub struct TriangularMovingAverage {
+    period: usize,
+    inner: Sma,
+    outer: Sma,
+}
+
+impl TriangularMovingAverage {
+    pub fn new(period: usize) -> Result<Self> {
+        if period == 0 {
+            return Err(TaError::invalid_parameter("period", period));
+        }
+
+        // (n + 1) / 2 twice for an odd n, n / 2 and n / 2 + 1 for an even one
+        Ok(Self {
+            period,
+            inner: Sma::new(period.div_ceil(2))?,
+            outer: Sma::new(period / 2 + 1)?,
+        })
+    }
+}
+
+impl Period for TriangularMovingAverage {
+    fn period(&self) -> usize {
+        self.period
+    }
+}
+
+impl Next<f64> for TriangularMovingAverage {
+    type Output = f64;
+
+    fn next(&mut self, input: f64) -> Self::Output {
+        let inner = self.inner.next(input);
+        self.outer.next(inner)
+    }
+}
+
+impl<T: Close> Next<&T> for TriangularMovingAverage {
+    type Output = f64;
+
+    fn next(&mut self, input: &T) -> Self::Output {
+        self.next(input.close())
+    }
+}
+
+impl Current for TriangularMovingAverage {
+    type Output = f64;
+
+    fn current(&self) -> Option<Self::Output> {
+        self.outer.current()
+    }
+}
+
+impl Ready for TriangularMovingAverage {
+    fn bars_seen(&self) -> usize {
+        self.inner.bars_seen()
+    }
+
+    fn is_ready(&self) -> bool {
+        // the outer average is complete once the whole period is
+        self.inner.bars_seen() >= self.period
+    }
+}
+
+impl Reset for TriangularMovingAverage {
+    fn reset(&mut self) {
+        self.inner.reset();
+        self.outer.reset();
+    }
+}
+
+impl Default for TriangularMovingAverage {
+    fn default() -> Self {
+        Self::new(20).unwrap()
+    }
+}
+
+impl fmt::Display for TriangularMovingAverage {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "TRIMA({})", self.period)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helper::*;
+
+    test_indicator!(TriangularMovingAverage);
+
+    #[test]
+    fn test_new() {
+        assert!(TriangularMovingAverage::new(0).is_err());
+        assert!(TriangularMovingAverage::new(1).is_ok());
+        assert!(TriangularMovingAverage::new(2).is_ok());
+    }
+
+    #[test]
+    fn test_next() {
+        let inputs = [10.0, 11.0, 12.0, 13.0, 12.0, 15.0, 20.0, 18.0];
+
+        // even period, SMA(3) of SMA(2)
+        let mut trima = TriangularMovingAverage::new(4).unwrap();
+        let values: Vec<f64> = inputs.iter().map(|&x| round(trima.next(x))).collect();
+        assert_eq!(
+            values,
+            [10.0, 10.25, 10.667, 11.5, 12.167, 12.833, 14.5, 16.667]
+        );
+
+        // odd period, SMA(3) of SMA(3)
+        let mut trima = TriangularMovingAverage::new(5).unwrap();
+        let values: Vec<f64> = inputs.iter().map(|&x| round(trima.next(x))).collect();
+        assert_eq!(
+            values,
+            [10.0, 10.25, 10.5, 11.167, 11.778, 12.556, 13.778, 15.556]
+        );
+
+        let mut trima = TriangularMovingAverage::new(1).unwrap();
+        assert_eq!(trima.next(&Bar::new().close(4)), 4.0);
+        assert_eq!(trima.next(&Bar::new().close(6)), 6.0);
+    }
+
+    #[test]
+    fn test_next_lag() {
+        let mut trima = TriangularMovingAverage::new(10).unwrap();
+        let mut sma = Sma::new(10).unwrap();
+
+        for _ in 0..20 {
+            trima.next(0.0);
+            sma.next(0.0);
+        }
+
+        // after a step both need the full period to reach the new level, but the
+        // triangular weighting moves slower during the first half of it
+        for i in 0..10 {
+            let (trima, sma) = (trima.next(10.0), sma.next(10.0));
+            if i < 4 {
+                assert!(trima < sma);
+            }
+            if i == 9 {
+                assert_eq!((round(trima), sma), (10.0, 10.0));
+            }
+        }
+    }
+
+    #[test]
+    fn test_reset() {
+        let mut trima = TriangularMovingAverage::new(4).unwrap();
+
+        trima.next(10.0);
+        trima.next(20.0);
+        trima.reset();
+
+        assert_eq!(trima.next(10.0), 10.0);
+        assert_eq!(trima.next(11.0), 10.25);
+    }
+
+    #[test]
+    fn test_current() {
+        let mut trima = TriangularMovingAverage::new(4).unwrap();
+        assert_eq!(trima.current(), None);
+
+        for input in [2.0, 3.0, 4.2, 7.0, 6.7, 6.5] {
+            let value = trima.next(input);
+            assert_eq!(trima.current(), Some(value));
+        }
+
+        trima.reset();
+        assert_eq!(trima.current(), None);
+    }
+
+    #[test]
+    fn test_ready() {
+        let mut trima = TriangularMovingAverage::new(5).unwrap();
+        for _ in 0..4 {
+            trima.next(1.0);
+        }
+        assert_eq!((trima.bars_seen(), trima.is_ready()), (4, false));
+        trima.next(1.0);
+        assert_eq!((trima.bars_seen(), trima.is_ready()), (5, true));
+
+        trima.reset();
+        assert_eq!((trima.bars_seen(), trima.is_ready()), (0, false));
+    }
+
+    #[test]
+    fn test_default() {
+        TriangularMovingAverage::default();
+    }
+
+    #[test]
+    fn test_display() {
+        let trima = TriangularMovingAverage::new(20).unwrap();
+        assert_eq!(format!("{}", trima), "TRIMA(20)");
+    }
+}
@@ -0,0 +1,238 @@
+use std::fmt;
+
+use crate::errors::Result;
+use crate::indicators::ExponentialMovingAverage as Ema;
+use crate::{Close, Current, Next, Period, Ready, Reset};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Triple exponential moving average (TEMA).
+///
+/// Developed by Patrick Mulloy, the TEMA reduces the lag of an
+/// [exponential moving average](struct.ExponentialMovingAverage.html) even more than the
+/// [DEMA](struct.DoubleExponentialMovingAverage.html) by combining three smoothings. Despite
+/// the name, it is not an EMA of an EMA of an EMA. That one is the base of
+/// [TRIX](struct.Trix.html).
+///
+/// # Formula
+///
+/// TEMA = 3 * EMA1 - 3 * EMA2 + EMA3
+///
+/// Where:
+///
+/// * _EMA1_ = EMA(period) of the input
+/// * _EMA2_ = EMA(period) of EMA1
+/// * _EMA3_ = EMA(period) of EMA2
+///
+/// # Parameters
+///
+/// * _period_ - smoothing period of the EMAs (integer greater than 0). Default is 9.
+///
+/// # Example
+///
+/// ```
+/// use ta::indicators::TripleExponentialMovingAverage;
+/// use ta::Next;
+///
+/// let mut tema = TripleExponentialMovingAverage::new(3).unwrap();
+/// assert_eq!(tema.next(10.0), 10.0);
+/// assert_eq!(tema.next(11.0), 10.875);
+/// assert_eq!(tema.next(12.0), 11.9375);
+/// ```
+///
+/// # Links
+///
+/// * [Triple exponential moving average, Wikipedia](https://en.wikipedia.org/wiki/Triple_exponential_moving_average)
+///
+#[doc(alias = "TEMA")]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone)]
+pub struct TripleExponentialMovingAverage {
+    ema1: Ema,
+    ema2: Ema,
+    ema3: Ema,
+}
+
+impl TripleExponentialMovingAverage {
+    pub fn new(period: usize) -> Result<Self> {
+        Ok(Self {
+            ema1: Ema::new(period)?,
+            ema2: Ema::new(period)?,
+            ema3: Ema::new(period)?,
+        })
+    }
+}
+
+impl Period for TripleExponentialMovingAverage {
+    fn period(&self) -> usize {
+        self.ema1.period()
+    }
+}
+
+impl Next<f64> for TripleExponentialMovingAverage {
+    type Output = f64;
+
+    fn next(&mut self, input: f64) -> Self::Output {
+        let ema1 = self.ema1.next(input);
+        let ema2 = self.ema2.next(ema1);
+        let ema3 = self.ema3.next(ema2);
+        3.0 * ema1 - 3.0 * ema2 + ema3
+    }
+}
+
+impl<T: Close> Next<&T> for TripleExponentialMovingAverage {
+    type Output = f64;
+
+    fn next(&mut self, input: &T) -> Self::Output {
+        self.next(input.close())
+    }
+}
+
+impl Current for TripleExponentialMovingAverage {
+    type Output = f64;
+
+    fn current(&self) -> Option<Self::Output> {
+        Some(3.0 * self.ema1.current()? - 3.0 * self.ema2.current()? + self.ema3.current()?)
+    }
+}
+
+impl Ready for TripleExponentialMovingAverage {
+    fn bars_seen(&self) -> usize {
+        self.ema1.bars_seen()
+    }
+
+    fn is_ready(&self) -> bool {
+        self.ema1.is_ready() && self.ema2.is_ready() && self.ema3.is_ready()
+    }
+}
+
+impl Reset for TripleExponentialMovingAverage {
+    fn reset(&mut self) {
+        self.ema1.reset();
+        self.ema2.reset();
+        self.ema3.reset();
+    }
+}
+
+impl Default for TripleExponentialMovingAverage {
+    fn default() -> Self {
+        Self::new(9).unwrap()
+    }
+}
+
+impl fmt::Display for TripleExponentialMovingAverage {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "TEMA({})", self.ema1.period())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::indicators::DoubleExponentialMovingAverage;
+    use crate::test_helper::*;
+
+    test_indicator!(TripleExponentialMovingAverage);
+
+    #[test]
+    fn test_new() {
+        assert!(TripleExponentialMovingAverage::new(0).is_err());
+        assert!(TripleExponentialMovingAverage::new(1).is_ok());
+    }
+
+    #[test]
+    fn test_next() {
+        let mut tema = TripleExponentialMovingAverage::new(3).unwrap();
+
+        let inputs = [10.0, 11.0, 12.0, 13.0, 12.0, 15.0, 20.0, 18.0];
+        let values: Vec<f64> = inputs.iter().map(|&x| round(tema.next(x))).collect();
+        assert_eq!(
+            values,
+            [10.0, 10.875, 11.938, 13.0, 12.281, 14.664, 19.535, 18.715]
+        );
+
+        let mut tema = TripleExponentialMovingAverage::new(3).unwrap();
+        assert_eq!(tema.next(&Bar::new().close(4)), 4.0);
+        assert_eq!(tema.next(&Bar::new().close(6)), 5.75);
+    }
+
+    #[test]
+    fn test_next_ramp() {
+        let mut tema = TripleExponentialMovingAverage::new(10).unwrap();
+        let mut dema = DoubleExponentialMovingAverage::new(10).unwrap();
+        let mut ema = Ema::new(10).unwrap();
+
+        // on a ramp the TEMA leads the DEMA, which leads the EMA
+        for i in 0..40 {
+            let input = i as f64;
+            let (tema, dema, ema) = (tema.next(input), dema.next(input), ema.next(input));
+            if i > 0 {
+                assert!(ema < dema && dema < tema);
+            }
+            // once warmed up, the EMA lags by almost half of its period
+            if i >= 20 {
+                assert!((tema - input).abs() < 0.3);
+                assert!(input - ema > 4.0);
+            }
+        }
+
+        // and both converge on a constant input
+        for _ in 0..100 {
+            tema.next(50.0);
+            ema.next(50.0);
+        }
+        assert_eq!(round(tema.next(50.0)), 50.0);
+        assert_eq!(round(ema.next(50.0)), 50.0);
+    }
+
+    #[test]
+    fn test_reset() {
+        let mut tema = TripleExponentialMovingAverage::new(3).unwrap();
+
+        tema.next(10.0);
+        tema.next(20.0);
+        tema.reset();
+
+        assert_eq!(tema.next(10.0), 10.0);
+        assert_eq!(tema.next(11.0), 10.875);
+    }
+
+    #[test]
+    fn test_current() {
+        let mut tema = TripleExponentialMovingAverage::new(3).unwrap();
+        assert_eq!(tema.current(), None);
+
+        for input in [2.0, 3.0, 4.2, 7.0, 6.7, 6.5] {
+            let value = tema.next(input);
+            assert_eq!(tema.current(), Some(value));
+        }
+
+        tema.reset();
+        assert_eq!(tema.current(), None);
+    }
+
+    #[test]
+    fn test_ready() {
+        let mut tema = TripleExponentialMovingAverage::new(3).unwrap();
+        for _ in 0..2 {
+            tema.next(1.0);
+        }
+        assert_eq!((tema.bars_seen(), tema.is_ready()), (2, false));
+        tema.next(1.0);
+        assert_eq!((tema.bars_seen(), tema.is_ready()), (3, true));
+
+        tema.reset();
+        assert_eq!((tema.bars_seen(), tema.is_ready()), (0, false));
+    }
+
+    #[test]
+    fn test_default() {
+        TripleExponentialMovingAverage::default();
+    }
+
+    #[test]
+    fn test_display() {
+        let tema = TripleExponentialMovingAverage::new(9).unwrap();
+        assert_eq!(format!("{}", tema), "TEMA(9)");
+    }
+}
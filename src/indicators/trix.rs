@@ -0,0 +1,205 @@
+use std::fmt;
+
+use crate::errors::Result;
+use crate::indicators::ExponentialMovingAverage;
+use crate::{Close, Next, Period, Ready, Reset};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// TRIX, the rate of change of a triple exponential moving average.
+///
+/// Developed by Jack Hutson, TRIX filters out price movements that are insignificant for the
+/// trend by smoothing the price three times.
+///
+/// # Formula
+///
+/// EMA3<sub>t</sub> = EMA(period) of EMA(period) of EMA(period) of price<sub>t</sub>
+///
+/// TRIX<sub>t</sub> = 100 * (EMA3<sub>t</sub> - EMA3<sub>t-1</sub>) / EMA3<sub>t-1</sub>
+///
+/// Where _EMA_ is the [exponential moving average](struct.ExponentialMovingAverage.html).
+/// TRIX is 0 on the first input and whenever the previous triple smoothed value is 0.
+///
+/// # Parameters
+///
+/// * _period_ - smoothing period of the EMAs (integer greater than 0). Default is 15.
+///
+/// # Example
+///
+/// ```
+/// use ta::indicators::Trix;
+/// use ta::Next;
+///
+/// let mut trix = Trix::new(3).unwrap();
+/// assert_eq!(trix.next(10.0), 0.0);
+/// assert_eq!(trix.next(11.0), 1.25);
+/// ```
+///
+/// # Links
+///
+/// * [Trix, Wikipedia](https://en.wikipedia.org/wiki/Trix_(technical_analysis))
+/// * [TRIX, StockCharts](https://school.stockcharts.com/doku.php?id=technical_indicators:trix)
+///
+#[doc(alias = "TRIX")]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone)]
+pub struct Trix {
+    ema1: ExponentialMovingAverage,
+    ema2: ExponentialMovingAverage,
+    ema3: ExponentialMovingAverage,
+    prev: Option<f64>,
+}
+
+impl Trix {
+    pub fn new(period: usize) -> Result<Self> {
+        Ok(Self {
+            ema1: ExponentialMovingAverage::new(period)?,
+            ema2: ExponentialMovingAverage::new(period)?,
+            ema3: ExponentialMovingAverage::new(period)?,
+            prev: None,
+        })
+    }
+}
+
+impl Period for Trix {
+    fn period(&self) -> usize {
+        self.ema1.period()
+    }
+}
+
+impl Next<f64> for Trix {
+    type Output = f64;
+
+    fn next(&mut self, input: f64) -> Self::Output {
+        let smoothed = self.ema3.next(self.ema2.next(self.ema1.next(input)));
+
+        match self.prev.replace(smoothed) {
+            Some(prev) if prev != 0.0 => 100.0 * (smoothed - prev) / prev,
+            _ => 0.0,
+        }
+    }
+}
+
+impl<T: Close> Next<&T> for Trix {
+    type Output = f64;
+
+    fn next(&mut self, input: &T) -> Self::Output {
+        self.next(input.close())
+    }
+}
+
+impl Ready for Trix {
+    fn bars_seen(&self) -> usize {
+        self.ema1.bars_seen()
+    }
+
+    fn is_ready(&self) -> bool {
+        // the first input has no previous average to change from
+        self.ema1.bars_seen() > self.ema1.period()
+    }
+}
+
+impl Reset for Trix {
+    fn reset(&mut self) {
+        self.ema1.reset();
+        self.ema2.reset();
+        self.ema3.reset();
+        self.prev = None;
+    }
+}
+
+impl Default for Trix {
+    fn default() -> Self {
+        Self::new(15).unwrap()
+    }
+}
+
+impl fmt::Display for Trix {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "TRIX({})", self.ema1.period())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helper::*;
+
+    test_indicator!(Trix);
+
+    #[test]
+    fn test_new() {
+        assert!(Trix::new(0).is_err());
+        assert!(Trix::new(1).is_ok());
+    }
+
+    #[test]
+    fn test_next() {
+        let mut trix = Trix::new(3).unwrap();
+
+        let data = [
+            (10.0, 0.0),
+            (11.0, 1.25),
+            (12.0, 3.086),
+            (11.0, 2.395),
+            (13.0, 3.801),
+            (14.0, 5.282),
+            (13.0, 4.114),
+            (15.0, 4.786),
+            (16.0, 5.579),
+            (15.0, 4.199),
+        ];
+
+        for (input, expected) in data {
+            assert_eq!(round(trix.next(input)), expected);
+        }
+    }
+
+    #[test]
+    fn test_next_from_zero() {
+        let mut trix = Trix::new(3).unwrap();
+
+        // the previous smoothed value is 0, no spurious huge percentage
+        assert_eq!(trix.next(0.0), 0.0);
+        assert_eq!(trix.next(5.0), 0.0);
+        assert!(trix.next(6.0).is_finite());
+    }
+
+    #[test]
+    fn test_reset() {
+        let mut trix = Trix::new(3).unwrap();
+
+        trix.next(10.0);
+        trix.next(20.0);
+        trix.reset();
+
+        assert_eq!(trix.next(10.0), 0.0);
+        assert_eq!(trix.next(11.0), 1.25);
+    }
+
+    #[test]
+    fn test_ready() {
+        let mut trix = Trix::new(3).unwrap();
+        for _ in 0..3 {
+            trix.next(1.0);
+        }
+        assert_eq!((trix.bars_seen(), trix.is_ready()), (3, false));
+        // the fourth input completes the third change
+        trix.next(1.0);
+        assert_eq!((trix.bars_seen(), trix.is_ready()), (4, true));
+
+        trix.reset();
+        assert_eq!((trix.bars_seen(), trix.is_ready()), (0, false));
+    }
+
+    #[test]
+    fn test_default() {
+        Trix::default();
+    }
+
+    #[test]
+    fn test_display() {
+        let trix = Trix::new(15).unwrap();
+        assert_eq!(format!("{}", trix), "TRIX(15)");
+    }
+}
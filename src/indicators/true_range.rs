@@ -44,7 +44,7 @@ use serde::{Deserialize, Serialize};
 ///             .close(close)
 ///             .open(open)
 ///             .volume(1000.0)
-///             .build().unwrap();
+///             .build(true).unwrap();
 ///         assert_approx_eq!(indicator.next(&di), tr);
 ///     }
 /// }
@@ -0,0 +1,99 @@
+use std::fmt;
+
+use crate::{Close, High, Low, Next, Open, PriceSource, Reset};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Typical price.
+///
+/// The average of a bar's high, low and close, also known as HLC3. It is the price the
+/// [commodity channel index](struct.CommodityChannelIndex.html) and the
+/// [money flow index](struct.MoneyFlowIndex.html) are built on.
+///
+/// # Formula
+///
+/// Typical price = (high + low + close) / 3, [PriceSource::HLC3]
+///
+/// # Example
+///
+/// ```
+/// use ta::indicators::{TypicalPrice, SimpleMovingAverage};
+/// use ta::{DataItem, Next};
+///
+/// let mut tp = TypicalPrice::new();
+/// let mut sma = SimpleMovingAverage::new(2).unwrap();
+///
+/// let di = DataItem::builder()
+///     .open(12.0).high(12.0).low(9.0).close(12.0).volume(1000.0)
+///     .build(true).unwrap();
+///
+/// assert_eq!(tp.next(&di), 11.0);
+/// assert_eq!(sma.next(tp.next(&di)), 11.0);
+/// ```
+///
+/// # Links
+///
+/// * [Typical price, Wikipedia](https://en.wikipedia.org/wiki/Typical_price)
+///
+#[doc(alias = "HLC3")]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone, Default)]
+pub struct TypicalPrice;
+
+impl TypicalPrice {
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl<T: Open + High + Low + Close> Next<&T> for TypicalPrice {
+    type Output = f64;
+
+    fn next(&mut self, input: &T) -> Self::Output {
+        PriceSource::HLC3.select(input)
+    }
+}
+
+/// Does nothing, the indicator has no state.
+impl Reset for TypicalPrice {
+    fn reset(&mut self) {}
+}
+
+impl fmt::Display for TypicalPrice {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "TYPPRICE")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helper::*;
+    use crate::DataItem;
+
+    #[test]
+    fn test_next() {
+        let mut tp = TypicalPrice::new();
+        assert_eq!(tp.next(&Bar::new().high(12).low(9).close(12)), 11.0);
+        assert_eq!(tp.next(&Bar::new().high(15).low(14).close(14.5)), 14.5);
+    }
+
+    #[test]
+    fn test_next_data_item() {
+        let mut tp = TypicalPrice::new();
+        let di = DataItem::builder()
+            .open(12.0)
+            .high(12.0)
+            .low(9.0)
+            .close(12.0)
+            .volume(1000.0)
+            .build(true)
+            .unwrap();
+        assert_eq!(tp.next(&di), 11.0);
+    }
+
+    #[test]
+    fn test_display() {
+        assert_eq!(format!("{}", TypicalPrice::new()), "TYPPRICE");
+    }
+}
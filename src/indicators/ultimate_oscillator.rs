@@ -0,0 +1,298 @@
+use std::fmt;
+
+use crate::errors::{Result, TaError};
+use crate::{Close, High, Low, Next, Period, Ready, Reset};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Ultimate Oscillator (UO).
+///
+/// Developed by Larry Williams, the ultimate oscillator combines the buying pressure of three
+/// different time frames into one value between 0 and 100.
+///
+/// # Formula
+///
+/// BP<sub>t</sub> = close<sub>t</sub> - min(low<sub>t</sub>, close<sub>t-1</sub>)
+///
+/// TR<sub>t</sub> = max(high<sub>t</sub>, close<sub>t-1</sub>) - min(low<sub>t</sub>, close<sub>t-1</sub>)
+///
+/// Average<sub>n</sub> = sum of BP over _n_ bars / sum of TR over _n_ bars
+///
+/// UO = 100 * (4 * Average<sub>short</sub> + 2 * Average<sub>medium</sub> + Average<sub>long</sub>) / 7
+///
+/// The first bar uses its own high and low in place of the previous close. An average over
+/// bars without any range is 0.5.
+///
+/// # Parameters
+///
+/// * _short_ - short period (integer greater than 0). Default is 7.
+/// * _medium_ - medium period (integer not less than _short_). Default is 14.
+/// * _long_ - long period (integer not less than _medium_). Default is 28.
+///
+/// # Example
+///
+/// ```
+/// use ta::indicators::UltimateOscillator;
+/// use ta::{DataItem, Next};
+///
+/// let mut uo = UltimateOscillator::new(7, 14, 28).unwrap();
+///
+/// let di = DataItem::builder()
+///     .open(9.0).high(10.0).low(8.0).close(9.5).volume(1000.0)
+///     .build(true).unwrap();
+///
+/// assert_eq!(uo.next(&di), 75.0);
+/// ```
+///
+/// # Links
+///
+/// * [Ultimate oscillator, Wikipedia](https://en.wikipedia.org/wiki/Ultimate_oscillator)
+/// * [Ultimate Oscillator, StockCharts](https://school.stockcharts.com/doku.php?id=technical_indicators:ultimate_oscillator)
+///
+#[doc(alias = "UO")]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone)]
+pub struct UltimateOscillator {
+    short: usize,
+    medium: usize,
+    long: usize,
+    index: usize,
+    prev_close: Option<f64>,
+    // sums of buying pressure and true range for the short, medium and long windows
+    sums: [(f64, f64); 3],
+    // buying pressure and true range of the last _long_ bars
+    deque: Box<[(f64, f64)]>,
+    #[cfg_attr(feature = "serde", serde(default))]
+    bars_seen: usize,
+}
+
+impl UltimateOscillator {
+    pub fn new(short: usize, medium: usize, long: usize) -> Result<Self> {
+        if short == 0 {
+            return Err(TaError::invalid_parameter("short", short));
+        }
+        if short > medium {
+            return Err(TaError::invalid_parameter("medium", medium));
+        }
+        if medium > long {
+            return Err(TaError::invalid_parameter("long", long));
+        }
+        Ok(Self {
+            short,
+            medium,
+            long,
+            index: 0,
+            prev_close: None,
+            sums: [(0.0, 0.0); 3],
+            deque: vec![(0.0, 0.0); long].into_boxed_slice(),
+            bars_seen: 0,
+        })
+    }
+}
+
+impl Period for UltimateOscillator {
+    fn period(&self) -> usize {
+        self.long
+    }
+}
+
+impl<T: High + Low + Close> Next<&T> for UltimateOscillator {
+    type Output = f64;
+
+    fn next(&mut self, input: &T) -> Self::Output {
+        self.bars_seen = self.bars_seen.saturating_add(1);
+        let close = input.close();
+        let (low, high) = match self.prev_close {
+            Some(prev_close) => (input.low().min(prev_close), input.high().max(prev_close)),
+            None => (input.low(), input.high()),
+        };
+        let buying_pressure = close - low;
+        let true_range = high - low;
+        self.prev_close = Some(close);
+
+        let periods = [self.short, self.medium, self.long];
+        let mut averages = [0.0; 3];
+        for (i, &period) in periods.iter().enumerate() {
+            // the value that leaves the window of this period, zero while it is not full yet
+            let (old_buying_pressure, old_true_range) =
+                self.deque[(self.index + self.long - period) % self.long];
+            let (bp_sum, tr_sum) = &mut self.sums[i];
+            *bp_sum += buying_pressure - old_buying_pressure;
+            *tr_sum += true_range - old_true_range;
+            averages[i] = if *tr_sum > 0.0 {
+                *bp_sum / *tr_sum
+            } else {
+                0.5
+            };
+        }
+
+        self.deque[self.index] = (buying_pressure, true_range);
+        self.index = if self.index + 1 < self.long {
+            self.index + 1
+        } else {
+            0
+        };
+
+        100.0 * (4.0 * averages[0] + 2.0 * averages[1] + averages[2]) / 7.0
+    }
+}
+
+impl Ready for UltimateOscillator {
+    fn bars_seen(&self) -> usize {
+        self.bars_seen
+    }
+
+    fn is_ready(&self) -> bool {
+        self.bars_seen >= self.long
+    }
+}
+
+impl Reset for UltimateOscillator {
+    fn reset(&mut self) {
+        self.bars_seen = 0;
+        self.index = 0;
+        self.prev_close = None;
+        self.sums = [(0.0, 0.0); 3];
+        for i in 0..self.long {
+            self.deque[i] = (0.0, 0.0);
+        }
+    }
+}
+
+impl Default for UltimateOscillator {
+    fn default() -> Self {
+        Self::new(7, 14, 28).unwrap()
+    }
+}
+
+impl fmt::Display for UltimateOscillator {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "UO({}, {}, {})", self.short, self.medium, self.long)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helper::*;
+
+    #[test]
+    fn test_new() {
+        assert!(UltimateOscillator::new(0, 14, 28).is_err());
+        assert!(UltimateOscillator::new(14, 7, 28).is_err());
+        assert!(UltimateOscillator::new(7, 28, 14).is_err());
+        assert!(UltimateOscillator::new(1, 1, 1).is_ok());
+        assert!(UltimateOscillator::new(7, 14, 28).is_ok());
+    }
+
+    #[test]
+    fn test_next() {
+        let mut uo = UltimateOscillator::new(3, 6, 12).unwrap();
+
+        #[rustfmt::skip]
+        let data = [
+            // high, low, close, uo
+            (30.20, 29.41, 29.87, 58.228),
+            (30.28, 29.32, 30.24, 78.857),
+            (30.45, 29.96, 30.10, 67.857),
+            (29.35, 28.74, 28.90, 44.809),
+            (29.35, 28.56, 28.92, 34.201),
+            (29.29, 28.41, 28.48, 28.286),
+            (28.83, 28.08, 28.56, 39.27),
+            (28.73, 27.43, 27.56, 25.444),
+            (28.67, 27.66, 28.47, 43.377),
+            (28.85, 27.83, 28.28, 42.712),
+            (28.64, 27.40, 27.49, 39.819),
+            (27.68, 27.09, 27.23, 29.551),
+            (27.21, 26.18, 26.35, 21.471),
+            (26.87, 26.13, 26.33, 26.149),
+            (27.41, 26.63, 27.03, 34.675),
+            (26.94, 26.13, 26.22, 32.619),
+            (26.52, 25.43, 26.01, 40.231),
+            (26.52, 25.35, 25.46, 27.676),
+            (27.09, 25.88, 27.03, 52.93),
+            (27.69, 26.96, 27.45, 56.814),
+            (28.45, 27.14, 28.36, 74.668),
+            (28.53, 28.01, 28.43, 73.843),
+            (28.67, 27.88, 27.95, 62.415),
+            (29.01, 27.99, 29.01, 68.126),
+            (29.87, 28.76, 29.38, 62.264),
+            (29.80, 29.14, 29.36, 65.766),
+            (29.75, 28.71, 28.91, 43.345),
+            (30.65, 28.93, 30.61, 61.676),
+            (30.60, 30.03, 30.05, 59.199),
+            (30.76, 29.39, 30.19, 64.231),
+        ];
+
+        for (high, low, close, expected) in data {
+            let out = uo.next(&Bar::new().high(high).low(low).close(close));
+            assert!((0.0..=100.0).contains(&out));
+            assert_eq!(round(out), expected);
+        }
+    }
+
+    #[test]
+    fn test_next_bounded() {
+        let mut uo = UltimateOscillator::new(2, 3, 5).unwrap();
+
+        for i in 0..40 {
+            let x = i as f64;
+            let close = 50.0 + 8.0 * (x * 0.9).sin();
+            let bar = Bar::new().high(close + 1.0).low(close - 1.5).close(close);
+            let out = uo.next(&bar);
+            assert!((0.0..=100.0).contains(&out), "out of range: {}", out);
+        }
+    }
+
+    #[test]
+    fn test_next_flat() {
+        let mut uo = UltimateOscillator::new(2, 3, 5).unwrap();
+        let bar = Bar::new().high(5).low(5).close(5);
+        assert_eq!(uo.next(&bar), 50.0);
+        assert_eq!(uo.next(&bar), 50.0);
+    }
+
+    #[test]
+    fn test_reset() {
+        let mut uo = UltimateOscillator::new(3, 6, 12).unwrap();
+
+        uo.next(&Bar::new().high(10).low(8).close(8));
+        uo.next(&Bar::new().high(9).low(7).close(7.5));
+        uo.reset();
+
+        assert_eq!(uo.next(&Bar::new().high(10).low(8).close(9.5)), 75.0);
+    }
+
+    #[test]
+    fn test_period() {
+        let uo = UltimateOscillator::new(7, 14, 28).unwrap();
+        assert_eq!(uo.period(), 28);
+    }
+
+    #[test]
+    fn test_ready() {
+        let mut uo = UltimateOscillator::new(1, 2, 3).unwrap();
+        let bar = Bar::new().high(10).low(8).close(9);
+        for _ in 0..2 {
+            uo.next(&bar);
+        }
+        assert_eq!((uo.bars_seen(), uo.is_ready()), (2, false));
+        // the third input fills the long window
+        uo.next(&bar);
+        assert_eq!((uo.bars_seen(), uo.is_ready()), (3, true));
+
+        uo.reset();
+        assert_eq!((uo.bars_seen(), uo.is_ready()), (0, false));
+    }
+
+    #[test]
+    fn test_default() {
+        UltimateOscillator::default();
+    }
+
+    #[test]
+    fn test_display() {
+        let uo = UltimateOscillator::new(7, 14, 28).unwrap();
+        assert_eq!(format!("{}", uo), "UO(7, 14, 28)");
+    }
+}
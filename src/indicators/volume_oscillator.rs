@@ -0,0 +1,215 @@
+use std::fmt;
+
+use crate::errors::{Result, TaError};
+use crate::indicators::ExponentialMovingAverage as Ema;
+use crate::{Next, Period, Ready, Reset, Volume};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Volume Oscillator.
+///
+/// The difference of a fast and a slow exponential moving average of the volume, in percent of
+/// the slow one. It is positive while the volume runs above its longer term average, as on a
+/// breakout drawing in participants, and negative while trading dries up.
+///
+/// # Formula
+///
+/// Volume Oscillator = (EMA(fast) of volume - EMA(slow) of volume) / EMA(slow) of volume * 100
+///
+/// Where _EMA_ is the [exponential moving average](struct.ExponentialMovingAverage.html). A slow
+/// EMA of 0 yields 0.
+///
+/// # Parameters
+///
+/// * _fast_ - period of the fast EMA (integer greater than 0). Default is 5.
+/// * _slow_ - period of the slow EMA (integer greater than or equal to _fast_). Default is 10.
+///
+/// # Example
+///
+/// ```
+/// use ta::indicators::VolumeOscillator;
+/// use ta::{DataItem, Next};
+///
+/// let mut vo = VolumeOscillator::new(2, 3).unwrap();
+///
+/// let mut next = |volume: f64| {
+///     let di = DataItem::builder()
+///         .open(10.0).high(10.0).low(10.0).close(10.0).volume(volume)
+///         .build(true).unwrap();
+///     vo.next(&di)
+/// };
+///
+/// assert_eq!(next(100.0), 0.0);
+/// // the fast EMA reacts to the higher volume first
+/// assert!(next(200.0) > 0.0);
+/// ```
+///
+/// # Links
+///
+/// * [Volume Oscillator, TradingView](https://www.tradingview.com/support/solutions/43000591350-volume-oscillator/)
+///
+#[doc(alias = "VO")]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone)]
+pub struct VolumeOscillator {
+    fast_ema: Ema,
+    slow_ema: Ema,
+}
+
+impl VolumeOscillator {
+    pub fn new(fast: usize, slow: usize) -> Result<Self> {
+        if fast > slow {
+            return Err(TaError::invalid_parameter("slow", slow));
+        }
+        Ok(Self {
+            fast_ema: Ema::new(fast)?,
+            slow_ema: Ema::new(slow)?,
+        })
+    }
+}
+
+impl Period for VolumeOscillator {
+    fn period(&self) -> usize {
+        self.slow_ema.period()
+    }
+}
+
+impl<T: Volume> Next<&T> for VolumeOscillator {
+    type Output = f64;
+
+    fn next(&mut self, input: &T) -> Self::Output {
+        let fast = self.fast_ema.next(input.volume());
+        let slow = self.slow_ema.next(input.volume());
+        if slow == 0.0 {
+            0.0
+        } else {
+            (fast - slow) / slow * 100.0
+        }
+    }
+}
+
+impl Ready for VolumeOscillator {
+    fn bars_seen(&self) -> usize {
+        self.slow_ema.bars_seen()
+    }
+
+    fn is_ready(&self) -> bool {
+        self.fast_ema.is_ready() && self.slow_ema.is_ready()
+    }
+}
+
+impl Reset for VolumeOscillator {
+    fn reset(&mut self) {
+        self.fast_ema.reset();
+        self.slow_ema.reset();
+    }
+}
+
+impl Default for VolumeOscillator {
+    fn default() -> Self {
+        Self::new(5, 10).unwrap()
+    }
+}
+
+impl fmt::Display for VolumeOscillator {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "VO({}, {})",
+            self.fast_ema.period(),
+            self.slow_ema.period()
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helper::*;
+
+    #[test]
+    fn test_new() {
+        assert!(VolumeOscillator::new(0, 10).is_err());
+        assert!(VolumeOscillator::new(10, 5).is_err());
+        assert!(VolumeOscillator::new(5, 5).is_ok());
+        assert!(VolumeOscillator::new(5, 10).is_ok());
+    }
+
+    #[test]
+    fn test_next() {
+        let mut vo = VolumeOscillator::new(2, 3).unwrap();
+
+        assert_eq!(vo.next(&Bar::new().volume(100.0)), 0.0);
+        assert_eq!(round(vo.next(&Bar::new().volume(200.0))), 11.111);
+        assert_eq!(round(vo.next(&Bar::new().volume(50.0))), -11.111);
+        assert_eq!(round(vo.next(&Bar::new().volume(0.0))), -40.741);
+    }
+
+    #[test]
+    fn test_next_surge() {
+        let mut vo = VolumeOscillator::default();
+
+        for _ in 0..3 {
+            assert_eq!(vo.next(&Bar::new().volume(1000.0)), 0.0);
+        }
+
+        // the volume jumps and stays high
+        assert_eq!(round(vo.next(&Bar::new().volume(5000.0))), 35.088);
+        let mut prev = vo.next(&Bar::new().volume(5000.0));
+        assert_eq!(round(prev), 38.75);
+
+        // the slow EMA catches up and the reading decays towards zero
+        for _ in 0..28 {
+            let out = vo.next(&Bar::new().volume(5000.0));
+            assert!(out > 0.0 && out < prev);
+            prev = out;
+        }
+        assert_eq!(round(prev), 0.194);
+    }
+
+    #[test]
+    fn test_next_zero_volume() {
+        let mut vo = VolumeOscillator::new(2, 3).unwrap();
+
+        assert_eq!(vo.next(&Bar::new().volume(0.0)), 0.0);
+        assert_eq!(vo.next(&Bar::new().volume(0.0)), 0.0);
+    }
+
+    #[test]
+    fn test_reset() {
+        let mut vo = VolumeOscillator::new(2, 3).unwrap();
+
+        vo.next(&Bar::new().volume(100.0));
+        vo.next(&Bar::new().volume(400.0));
+        vo.reset();
+
+        assert_eq!(vo.next(&Bar::new().volume(100.0)), 0.0);
+        assert_eq!(round(vo.next(&Bar::new().volume(200.0))), 11.111);
+    }
+
+    #[test]
+    fn test_ready() {
+        let mut vo = VolumeOscillator::new(2, 3).unwrap();
+        let bar = Bar::new().high(10).low(8).close(9).volume(100.0);
+        for _ in 0..2 {
+            vo.next(&bar);
+        }
+        assert_eq!((vo.bars_seen(), vo.is_ready()), (2, false));
+        vo.next(&bar);
+        assert_eq!((vo.bars_seen(), vo.is_ready()), (3, true));
+
+        vo.reset();
+        assert_eq!((vo.bars_seen(), vo.is_ready()), (0, false));
+    }
+
+    #[test]
+    fn test_default() {
+        VolumeOscillator::default();
+    }
+
+    #[test]
+    fn test_display() {
+        let vo = VolumeOscillator::new(5, 10).unwrap();
+        assert_eq!(format!("{}", vo), "VO(5, 10)");
+    }
+}
@@ -0,0 +1,271 @@
+use std::fmt;
+
+use crate::errors::{Result, TaError};
+use crate::{Close, High, Low, Volume};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// A price bucket of a [VolumeProfile].
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone, PartialEq)]
+pub struct VolumeProfileBucket {
+    pub low: f64,
+    pub high: f64,
+    pub volume: f64,
+}
+
+/// The profile computed by [VolumeProfile::compute].
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone, PartialEq)]
+pub struct VolumeProfileResult {
+    /// Middle price of the bucket with the most volume.
+    pub point_of_control: f64,
+    /// Upper bound of the value area.
+    pub value_area_high: f64,
+    /// Lower bound of the value area.
+    pub value_area_low: f64,
+    /// The buckets, from the lowest price to the highest.
+    pub histogram: Vec<VolumeProfileBucket>,
+}
+
+/// Volume Profile (VP).
+///
+/// Shows how much volume was traded at which price over a window of bars. Unlike the
+/// indicators it is not computed input by input, [compute](Self::compute) builds the profile
+/// of a whole slice of bars.
+///
+/// The range from the lowest low to the highest high is divided into _bucket_count_ buckets
+/// of equal height. The volume of every bar is distributed evenly over its range, a bar with
+/// high equal to low puts all of its volume into the bucket of its close.
+///
+/// * The _point of control_ is the middle of the bucket with the most volume, the lowest one
+///   if several buckets have the most volume.
+/// * The _value area_ holds 70% of the volume. Starting from the point of control, it grows
+///   by the bigger of the adjacent buckets until it holds enough volume.
+///
+/// # Parameters
+///
+/// * _bucket_count_ - number of price buckets (integer greater than 0). Default is 24.
+///
+/// # Example
+///
+/// ```
+/// use ta::indicators::VolumeProfile;
+/// use ta::DataItem;
+///
+/// let bars = [
+///     DataItem::builder().open(10.0).high(12.0).low(10.0).close(11.0).volume(100.0)
+///         .build(true).unwrap(),
+///     DataItem::builder().open(11.0).high(12.0).low(11.0).close(12.0).volume(300.0)
+///         .build(true).unwrap(),
+/// ];
+///
+/// let profile = VolumeProfile::new(2).unwrap().compute(&bars).unwrap();
+/// assert_eq!(profile.point_of_control, 11.5);
+/// assert_eq!(profile.histogram[0].volume, 50.0);
+/// assert_eq!(profile.histogram[1].volume, 350.0);
+/// ```
+///
+/// # Links
+///
+/// * [Volume Profile, TradingView](https://www.tradingview.com/support/solutions/43000502040-volume-profile/)
+///
+#[doc(alias = "VP")]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone)]
+pub struct VolumeProfile {
+    bucket_count: usize,
+}
+
+const VALUE_AREA: f64 = 0.7;
+
+impl VolumeProfile {
+    pub fn new(bucket_count: usize) -> Result<Self> {
+        match bucket_count {
+            0 => Err(TaError::invalid_parameter("bucket_count", bucket_count)),
+            _ => Ok(Self { bucket_count }),
+        }
+    }
+
+    pub fn bucket_count(&self) -> usize {
+        self.bucket_count
+    }
+
+    /// Builds the profile of the bars, `None` if there are no bars or no volume.
+    pub fn compute<T: High + Low + Close + Volume>(
+        &self,
+        bars: &[T],
+    ) -> Option<VolumeProfileResult> {
+        let low = bars.iter().map(|bar| bar.low()).reduce(f64::min)?;
+        let high = bars.iter().map(|bar| bar.high()).reduce(f64::max)?;
+        let height = (high - low) / self.bucket_count as f64;
+
+        let mut histogram: Vec<VolumeProfileBucket> = (0..self.bucket_count)
+            .map(|i| VolumeProfileBucket {
+                low: low + height * i as f64,
+                high: low + height * (i + 1) as f64,
+                volume: 0.0,
+            })
+            .collect();
+
+        for bar in bars {
+            let range = bar.high() - bar.low();
+            if range > 0.0 {
+                for bucket in histogram.iter_mut() {
+                    let overlap = bucket.high.min(bar.high()) - bucket.low.max(bar.low());
+                    if overlap > 0.0 {
+                        bucket.volume += bar.volume() * overlap / range;
+                    }
+                }
+            } else {
+                let index = if height > 0.0 {
+                    ((bar.close() - low) / height) as usize
+                } else {
+                    0
+                };
+                histogram[index.min(self.bucket_count - 1)].volume += bar.volume();
+            }
+        }
+
+        let total: f64 = histogram.iter().map(|bucket| bucket.volume).sum();
+        if total <= 0.0 {
+            return None;
+        }
+
+        let mut poc = 0;
+        for (i, bucket) in histogram.iter().enumerate() {
+            if bucket.volume > histogram[poc].volume {
+                poc = i;
+            }
+        }
+
+        // grow the value area by the bigger neighbour until it holds enough volume
+        let (mut bottom, mut top) = (poc, poc);
+        let mut volume = histogram[poc].volume;
+        while volume < total * VALUE_AREA {
+            let below = bottom.checked_sub(1).map(|i| histogram[i].volume);
+            let above = histogram.get(top + 1).map(|bucket| bucket.volume);
+            match (below, above) {
+                (Some(below), Some(above)) if above > below => {
+                    top += 1;
+                    volume += above;
+                }
+                (Some(below), _) => {
+                    bottom -= 1;
+                    volume += below;
+                }
+                (None, Some(above)) => {
+                    top += 1;
+                    volume += above;
+                }
+                (None, None) => break,
+            }
+        }
+
+        Some(VolumeProfileResult {
+            point_of_control: (histogram[poc].low + histogram[poc].high) / 2.0,
+            value_area_high: histogram[top].high,
+            value_area_low: histogram[bottom].low,
+            histogram,
+        })
+    }
+}
+
+impl Default for VolumeProfile {
+    fn default() -> Self {
+        Self::new(24).unwrap()
+    }
+}
+
+impl fmt::Display for VolumeProfile {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "VP({})", self.bucket_count)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helper::*;
+
+    #[test]
+    fn test_new() {
+        assert!(VolumeProfile::new(0).is_err());
+        assert!(VolumeProfile::new(1).is_ok());
+    }
+
+    #[test]
+    fn test_compute() {
+        let bars = [
+            // 100 in every bucket
+            Bar::new().high(15).low(10).close(10).volume(500.0),
+            Bar::new().high(13).low(12).close(12).volume(400.0),
+            Bar::new().high(12).low(11).close(11).volume(200.0),
+            // no range, all of the volume goes into the bucket of the close
+            Bar::new().high(11).low(11).close(11).volume(50.0),
+        ];
+
+        let profile = VolumeProfile::new(5).unwrap().compute(&bars).unwrap();
+
+        let volumes: Vec<f64> = profile.histogram.iter().map(|b| b.volume).collect();
+        assert_eq!(volumes, [100.0, 350.0, 500.0, 100.0, 100.0]);
+        assert_eq!(profile.histogram[2].low, 12.0);
+        assert_eq!(profile.histogram[2].high, 13.0);
+
+        assert_eq!(profile.point_of_control, 12.5);
+        // 500 + 350 of 1150 is more than 70%
+        assert_eq!(profile.value_area_low, 11.0);
+        assert_eq!(profile.value_area_high, 13.0);
+    }
+
+    #[test]
+    fn test_compute_value_area_above() {
+        let bars = [
+            Bar::new().high(11).low(10).close(10).volume(600.0),
+            Bar::new().high(12).low(11).close(11).volume(200.0),
+            Bar::new().high(13).low(12).close(12).volume(150.0),
+            Bar::new().high(14).low(13).close(13).volume(50.0),
+        ];
+
+        // the point of control is the lowest bucket, the value area can only grow upwards
+        let profile = VolumeProfile::new(4).unwrap().compute(&bars).unwrap();
+        assert_eq!(profile.point_of_control, 10.5);
+        assert_eq!(profile.value_area_low, 10.0);
+        assert_eq!(profile.value_area_high, 12.0);
+    }
+
+    #[test]
+    fn test_compute_single_price() {
+        let bars = [
+            Bar::new().high(10).low(10).close(10).volume(100.0),
+            Bar::new().high(10).low(10).close(10).volume(50.0),
+        ];
+
+        let profile = VolumeProfile::new(3).unwrap().compute(&bars).unwrap();
+        assert_eq!(profile.point_of_control, 10.0);
+        assert_eq!(profile.value_area_low, 10.0);
+        assert_eq!(profile.value_area_high, 10.0);
+        assert_eq!(profile.histogram[0].volume, 150.0);
+    }
+
+    #[test]
+    fn test_compute_empty() {
+        let vp = VolumeProfile::new(3).unwrap();
+        assert_eq!(vp.compute::<Bar>(&[]), None);
+        assert_eq!(
+            vp.compute(&[Bar::new().high(11).low(10).close(10).volume(0.0)]),
+            None
+        );
+    }
+
+    #[test]
+    fn test_default() {
+        assert_eq!(VolumeProfile::default().bucket_count(), 24);
+    }
+
+    #[test]
+    fn test_display() {
+        let vp = VolumeProfile::new(24).unwrap();
+        assert_eq!(format!("{}", vp), "VP(24)");
+    }
+}
@@ -1,7 +1,56 @@
 use std::fmt;
 
-use crate::{High, Low, Next, Reset, Volume, Close};
+use crate::errors::{Result, TaError};
+use crate::{
+    Close, Current, Float, High, Low, NanPolicy, Next, Open, Period, PriceSource, Ready, Reset,
+    Volume,
+};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
 
+/// Direction of a [VolumeWeightedAveragePrice] band.
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum VolumeWeightedAveragePriceBands {
+    Up,
+    Down,
+}
+
+/// Price of a bar used by [VolumeWeightedAveragePrice].
+///
+/// The variants are those of [PriceSource], which has them all. Note that the default of
+/// [PriceSource] is the close, while the VWAP defaults to [PriceSource::HLC3].
+#[deprecated(note = "use PriceSource")]
+pub type VolumeWeightedAveragePriceSource = PriceSource;
+
+/// Volume weighted average price (VWAP).
+///
+/// The average of the bars' prices weighted by volume. By default the typical price
+/// _(high + low + close) / 3_ is used, [with_source](Self::with_source) selects another
+/// [PriceSource].
+/// [new](Self::new) creates a session VWAP that accumulates every bar until it is reset,
+/// [rolling](Self::rolling) only takes the last _period_ bars into account.
+///
+/// The indicator computes with `f64` by default. For large datasets it can compute with
+/// `f32`, created with [new_with_precision](VolumeWeightedAveragePrice::new_with_precision) or
+/// converted with [with_precision](VolumeWeightedAveragePrice::with_precision), which halves
+/// the memory of a rolling window. Note that the standard deviation is derived from the
+/// difference of two large sums, so with `f32` the bands are only rough when the prices barely
+/// vary compared to their level.
+///
+/// # Formula
+///
+/// VWAP = Σ(price * volume) / Σ(volume)
+///
+/// # Parameters
+///
+/// * _period_ - size of the rolling window (integer greater than 0). Only for `rolling`.
+/// * _source_ - price used for every bar, set with `with_source`. Default is HLC3.
+/// * _nan_policy_ - treatment of a bar with a NaN price or volume, set with `with_nan_policy`.
+///   Default is [NanPolicy::Propagate], a single NaN bar turns a session VWAP into NaN until it
+///   is reset. With [NanPolicy::UseLast] the price and the volume are taken separately from the
+///   last valid bar, a NaN bar before any valid one is skipped.
+///
 /// # Example
 ///
 /// ```
@@ -42,12 +91,12 @@ use crate::{High, Low, Next, Reset, Volume, Close};
 ///             .close(close)
 ///             .open(open)
 ///             .volume(volume)
-///             .build().unwrap();
+///             .build(true).unwrap();
 ///         assert_approx_eq!(indicator.next(&di), vwap, 0.01);
 ///     }
 /// }
 /// ```
-/// 
+///
 /// # Example StdDev
 ///
 /// ```
@@ -88,7 +137,7 @@ use crate::{High, Low, Next, Reset, Volume, Close};
 ///             .close(close)
 ///             .open(open)
 ///             .volume(volume)
-///             .build().unwrap();
+///             .build(true).unwrap();
 ///
 ///         assert_approx_eq!(indicator.next(&di), vwap, 0.01);
 ///         assert_approx_eq!(indicator.std_dev(2.0, VolumeWeightedAveragePriceBands::Up), vwap_std_2_up, 0.01);
@@ -99,70 +148,349 @@ use crate::{High, Low, Next, Reset, Volume, Close};
 ///     
 /// }
 /// ```
-
-#[derive(Debug)]
-pub enum VolumeWeightedAveragePriceBands {
-    Up,
-    Down,
-}
-
 #[doc(alias = "VWAP")]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[derive(Debug, Clone)]
-pub struct VolumeWeightedAveragePrice {
-    cumulative_total: f64,
-    cumulative_volume: f64,
-    cumulative_v2: f64,
-    vwap: f64,
-    std_dev: f64
+pub struct VolumeWeightedAveragePrice<F = f64> {
+    period: Option<usize>,
+    source: PriceSource,
+    index: usize,
+    count: usize,
+    cumulative_total: F,
+    cumulative_volume: F,
+    cumulative_v2: F,
+    vwap: F,
+    std_dev: F,
+    is_new: bool,
+    #[cfg_attr(feature = "serde", serde(default))]
+    bars_seen: usize,
+    nan_policy: NanPolicy,
+    // (price, volume) of the last bar without NaNs
+    last: Option<(f64, f64)>,
+    // (typical price * volume, volume, typical price^2 * volume) of the bars in the window
+    deque: Box<[(F, F, F)]>,
 }
 
 impl VolumeWeightedAveragePrice {
     pub fn new() -> Self {
+        Self::new_with_precision()
+    }
+
+    pub fn rolling(period: usize) -> Result<Self> {
+        Self::rolling_with_precision(period)
+    }
+}
+
+impl<F: Float> VolumeWeightedAveragePrice<F> {
+    /// Like [new](VolumeWeightedAveragePrice::new), computing with the float type of the
+    /// indicator, e.g. `VolumeWeightedAveragePrice::<f32>::new_with_precision()`.
+    pub fn new_with_precision() -> Self {
         Self {
-            cumulative_total: 0.0,
-            cumulative_volume: 0.0,
-            cumulative_v2: 0.0,
-            vwap: 0.0,
-            std_dev: 0.0
+            period: None,
+            source: PriceSource::HLC3,
+            index: 0,
+            count: 0,
+            cumulative_total: F::zero(),
+            cumulative_volume: F::zero(),
+            cumulative_v2: F::zero(),
+            vwap: F::zero(),
+            std_dev: F::zero(),
+            is_new: true,
+            bars_seen: 0,
+            nan_policy: NanPolicy::default(),
+            last: None,
+            deque: Box::new([]),
+        }
+    }
+
+    /// Like [rolling](VolumeWeightedAveragePrice::rolling), computing with the float type of
+    /// the indicator.
+    pub fn rolling_with_precision(period: usize) -> Result<Self> {
+        match period {
+            0 => Err(TaError::invalid_parameter("period", period)),
+            _ => Ok(Self {
+                period: Some(period),
+                deque: vec![(F::zero(), F::zero(), F::zero()); period].into_boxed_slice(),
+                ..Self::new_with_precision()
+            }),
+        }
+    }
+
+    /// Converts the indicator, including its current state, to compute with another
+    /// floating point type. See [Float](crate::Float).
+    pub fn with_precision<G: Float>(self) -> VolumeWeightedAveragePrice<G> {
+        let convert = |value: F| G::from_f64(value.to_f64());
+        VolumeWeightedAveragePrice {
+            period: self.period,
+            source: self.source,
+            index: self.index,
+            count: self.count,
+            cumulative_total: convert(self.cumulative_total),
+            cumulative_volume: convert(self.cumulative_volume),
+            cumulative_v2: convert(self.cumulative_v2),
+            vwap: convert(self.vwap),
+            std_dev: convert(self.std_dev),
+            is_new: self.is_new,
+            bars_seen: self.bars_seen,
+            nan_policy: self.nan_policy,
+            last: self.last,
+            deque: self
+                .deque
+                .iter()
+                .map(|&(total, volume, v2)| (convert(total), convert(volume), convert(v2)))
+                .collect(),
         }
     }
 
-    pub fn std_dev(&self, offset: f64, band_direction: VolumeWeightedAveragePriceBands) -> f64 {
+    pub fn with_source(mut self, source: PriceSource) -> Self {
+        self.source = source;
+        self
+    }
+
+    pub fn source(&self) -> PriceSource {
+        self.source
+    }
+
+    pub fn with_nan_policy(mut self, nan_policy: NanPolicy) -> Self {
+        self.nan_policy = nan_policy;
+        self
+    }
+
+    pub fn nan_policy(&self) -> NanPolicy {
+        self.nan_policy
+    }
+
+    /// The VWAP computed by the last call to `next`.
+    pub fn vwap(&self) -> F {
+        self.vwap
+    }
+
+    /// The volume weighted standard deviation computed by the last call to `next`.
+    pub fn raw_std_dev(&self) -> F {
+        self.std_dev
+    }
+
+    pub fn std_dev(&self, offset: F, band_direction: VolumeWeightedAveragePriceBands) -> F {
         match band_direction {
             VolumeWeightedAveragePriceBands::Up => self.vwap + offset * self.std_dev,
             VolumeWeightedAveragePriceBands::Down => self.vwap - offset * self.std_dev,
         }
     }
+
+    /// Upper and lower bands for every offset, in standard deviations, at once.
+    ///
+    /// Equal to calling [std_dev](Self::std_dev) with both directions for every offset.
+    pub fn bands(&self, offsets: &[F]) -> Vec<(F, F)> {
+        offsets
+            .iter()
+            .map(|&offset| {
+                (
+                    self.std_dev(offset, VolumeWeightedAveragePriceBands::Up),
+                    self.std_dev(offset, VolumeWeightedAveragePriceBands::Down),
+                )
+            })
+            .collect()
+    }
+
+    /// Consumes the next bar and returns the VWAP together with its [bands](Self::bands).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use ta::indicators::VolumeWeightedAveragePrice;
+    /// use ta::DataItem;
+    ///
+    /// let mut vwap = VolumeWeightedAveragePrice::new();
+    ///
+    /// let di = DataItem::builder()
+    ///     .open(10.0).high(12.0).low(8.0).close(10.0).volume(100.0)
+    ///     .build(true).unwrap();
+    /// vwap.next_with_bands(&di, &[1.0]);
+    ///
+    /// let di = DataItem::builder()
+    ///     .open(12.0).high(15.0).low(12.0).close(15.0).volume(100.0)
+    ///     .build(true).unwrap();
+    /// let (value, bands) = vwap.next_with_bands(&di, &[1.0, 2.0]);
+    ///
+    /// assert_eq!(value, 12.0);
+    /// assert_eq!(bands, vec![(14.0, 10.0), (16.0, 8.0)]);
+    /// ```
+    pub fn next_with_bands<T: Open + High + Low + Close + Volume>(
+        &mut self,
+        d: &T,
+        offsets: &[F],
+    ) -> (F, Vec<(F, F)>) {
+        let vwap = self.next(d);
+        (vwap, self.bands(offsets))
+    }
+
+    /// Consumes the next bar, [anchoring](Self::anchor) the VWAP at it first when it opens a
+    /// new session.
+    ///
+    /// Lets the caller pass the session boundary along with the bar, e.g. from a change of the
+    /// trading day, instead of resetting the indicator between the sessions.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use ta::indicators::VolumeWeightedAveragePrice;
+    /// use ta::DataItem;
+    ///
+    /// let mut vwap = VolumeWeightedAveragePrice::new();
+    ///
+    /// let di = DataItem::builder()
+    ///     .open(10.0).high(10.0).low(10.0).close(10.0).volume(100.0)
+    ///     .build(true).unwrap();
+    /// assert_eq!(vwap.next_with_session(&di, true), 10.0);
+    ///
+    /// let di = DataItem::builder()
+    ///     .open(12.0).high(12.0).low(12.0).close(12.0).volume(100.0)
+    ///     .build(true).unwrap();
+    /// assert_eq!(vwap.next_with_session(&di, false), 11.0);
+    /// // the next session starts from its first bar
+    /// assert_eq!(vwap.next_with_session(&di, true), 12.0);
+    /// ```
+    pub fn next_with_session<T: Open + High + Low + Close + Volume>(
+        &mut self,
+        d: &T,
+        new_session: bool,
+    ) -> F {
+        if new_session {
+            self.anchor();
+        }
+        self.next(d)
+    }
+
+    /// Anchors the VWAP at the next bar.
+    ///
+    /// Clears the accumulated bars, so the VWAP restarts from the next bar passed to `next`,
+    /// e.g. an earnings or a swing high bar. Unlike [reset](Reset::reset) the last computed
+    /// [vwap](Self::vwap) and bands stay available until then. A rolling VWAP keeps its
+    /// period, its window fills up again from the anchor bar, and so does
+    /// [bars_seen](Ready::bars_seen).
+    pub fn anchor(&mut self) {
+        self.index = 0;
+        self.count = 0;
+        self.bars_seen = 0;
+        self.cumulative_total = F::zero();
+        self.cumulative_volume = F::zero();
+        self.cumulative_v2 = F::zero();
+        for item in self.deque.iter_mut() {
+            *item = (F::zero(), F::zero(), F::zero());
+        }
+    }
+}
+
+/// The size of the rolling window. A session VWAP has no window, so its period is 1.
+impl<F> Period for VolumeWeightedAveragePrice<F> {
+    fn period(&self) -> usize {
+        self.period.unwrap_or(1)
+    }
 }
 
-impl<T: High + Low + Close + Volume> Next<&T> for VolumeWeightedAveragePrice {
-    type Output = f64;
+impl<F: Float, T: Open + High + Low + Close + Volume> Next<&T> for VolumeWeightedAveragePrice<F> {
+    type Output = F;
 
     fn next(&mut self, d: &T) -> Self::Output {
-        let typical_price = (d.high() + d.low() + d.close()) / 3.0;
+        let (mut price, mut volume) = (self.source.select(d), d.volume());
+        if price.is_nan() || volume.is_nan() {
+            match (self.nan_policy, self.last) {
+                (NanPolicy::Propagate, _) => {}
+                (NanPolicy::UseLast, Some((last_price, last_volume))) => {
+                    if price.is_nan() {
+                        price = last_price;
+                    }
+                    if volume.is_nan() {
+                        volume = last_volume;
+                    }
+                }
+                (NanPolicy::SkipBar, _) | (NanPolicy::UseLast, None) => {
+                    return match self.is_new {
+                        true => F::from_f64(f64::NAN),
+                        false => self.vwap,
+                    };
+                }
+            }
+        } else {
+            self.last = Some((price, volume));
+        }
 
-        self.cumulative_volume = d.volume() + self.cumulative_volume;
+        self.bars_seen = self.bars_seen.saturating_add(1);
 
-        self.cumulative_total = (typical_price * d.volume()) + self.cumulative_total;
-        self.vwap = self.cumulative_total / self.cumulative_volume;
+        let typical_price = F::from_f64(price);
+        let volume = F::from_f64(volume);
+        let total = typical_price * volume;
+        let v2 = volume * typical_price * typical_price;
+
+        if let Some(period) = self.period {
+            let (old_total, old_volume, old_v2) = self.deque[self.index];
+            self.deque[self.index] = (total, volume, v2);
 
-        self.cumulative_v2 = (d.volume() * typical_price * typical_price) + self.cumulative_v2;
+            self.index = if self.index + 1 < period {
+                self.index + 1
+            } else {
+                0
+            };
+
+            if self.count < period {
+                self.count += 1;
+            } else {
+                self.cumulative_total -= old_total;
+                self.cumulative_volume -= old_volume;
+                self.cumulative_v2 -= old_v2;
+            }
+        }
+
+        self.cumulative_volume += volume;
+        self.cumulative_total += total;
+        self.cumulative_v2 += v2;
+        self.is_new = false;
+
+        if self.cumulative_volume <= F::zero() {
+            // No volume traded yet, there is nothing to weight the price with.
+            self.vwap = typical_price;
+            self.std_dev = F::zero();
+            return self.vwap;
+        }
+
+        self.vwap = self.cumulative_total / self.cumulative_volume;
 
         let val = (self.cumulative_v2 / self.cumulative_volume) - self.vwap * self.vwap;
-        self.std_dev = val.max(0.0).sqrt();
+        self.std_dev = val.max(F::zero()).sqrt();
 
         self.vwap
     }
 }
 
-impl Reset for VolumeWeightedAveragePrice {
+impl<F: Float> Reset for VolumeWeightedAveragePrice<F> {
     fn reset(&mut self) {
-        self.cumulative_total = 0.0;
-        self.cumulative_volume = 0.0;
-        self.cumulative_v2 = 0.0;
-        self.vwap = 0.0;
-        self.std_dev = 0.0;
+        self.anchor();
+        self.vwap = F::zero();
+        self.std_dev = F::zero();
+        self.is_new = true;
+        self.last = None;
+    }
+}
+
+impl<F: Float> Current for VolumeWeightedAveragePrice<F> {
+    type Output = F;
+
+    fn current(&self) -> Option<Self::Output> {
+        match self.is_new {
+            true => None,
+            false => Some(self.vwap),
+        }
+    }
+}
+
+/// A session VWAP is ready after the first bar, a rolling one once its window is full.
+impl<F> Ready for VolumeWeightedAveragePrice<F> {
+    fn bars_seen(&self) -> usize {
+        self.bars_seen
+    }
+
+    fn is_ready(&self) -> bool {
+        self.bars_seen >= self.period()
     }
 }
 
@@ -172,18 +500,21 @@ impl Default for VolumeWeightedAveragePrice {
     }
 }
 
-impl fmt::Display for VolumeWeightedAveragePrice {
+impl<F> fmt::Display for VolumeWeightedAveragePrice<F> {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "VWAP()")
+        match self.period {
+            Some(period) => write!(f, "VWAP({})", period),
+            None => write!(f, "VWAP()"),
+        }
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::DataItem;
     use assert_approx_eq::assert_approx_eq;
     use VolumeWeightedAveragePriceBands::*;
-    use crate::DataItem;
 
     fn generate_bar(record: (f64, f64, f64, f64, f64)) -> DataItem {
         let (open, high, low, close, volume): (f64, f64, f64, f64, f64) = record;
@@ -194,7 +525,7 @@ mod tests {
             .low(low)
             .close(close)
             .volume(volume)
-            .build()
+            .build(true)
             .unwrap()
     }
 
@@ -202,23 +533,23 @@ mod tests {
     fn test_next() {
         let data = vec![
             // open, high, low, close, volume, vwap
-            (150.39, 150.39, 150.22, 150.31, 380.0,  150.31),
+            (150.39, 150.39, 150.22, 150.31, 380.0, 150.31),
             (150.47, 150.47, 150.38, 150.41, 5270.0, 150.41),
-            (150.49, 150.49, 150.33, 150.46, 990.0,  150.41),
+            (150.49, 150.49, 150.33, 150.46, 990.0, 150.41),
             (150.63, 150.63, 150.44, 150.61, 1031.0, 150.43),
             (151.10, 151.10, 150.67, 151.01, 2675.0, 150.56),
             (151.30, 151.30, 150.77, 150.80, 3334.0, 150.66),
-            (150.95, 150.95, 150.78, 150.93, 430.0,  150.66),
-            (151.12, 151.12, 150.80, 151.10, 220.0,  150.67),
-            (151.27, 151.27, 151.01, 151.25, 900.0,  150.70),
+            (150.95, 150.95, 150.78, 150.93, 430.0, 150.66),
+            (151.12, 151.12, 150.80, 151.10, 220.0, 150.67),
+            (151.27, 151.27, 151.01, 151.25, 900.0, 150.70),
             (151.35, 151.35, 151.26, 151.33, 4088.0, 150.83),
-            (151.52, 151.52, 151.32, 151.51, 650.0,  150.85),
+            (151.52, 151.52, 151.32, 151.51, 650.0, 150.85),
             (151.69, 151.69, 151.49, 151.67, 1582.0, 150.91),
             (152.03, 152.03, 151.66, 151.80, 1892.0, 150.98),
             (151.90, 151.90, 151.75, 151.88, 2200.0, 151.05),
             (152.15, 152.15, 151.86, 152.10, 3043.0, 151.16),
-            (152.43, 152.43, 152.03, 152.33, 675.0,  151.18),
-            (152.57, 152.57, 152.25, 152.50, 1243.0, 151.24)
+            (152.43, 152.43, 152.03, 152.33, 675.0, 151.18),
+            (152.57, 152.57, 152.25, 152.50, 1243.0, 151.24),
         ];
         let mut indicator = VolumeWeightedAveragePrice::new();
         for (open, high, low, close, volume, vwap) in data {
@@ -228,6 +559,7 @@ mod tests {
     }
 
     #[test]
+    #[rustfmt::skip]
     fn test_next_std_dev() {
         let mut indicator = VolumeWeightedAveragePrice::new();
 
@@ -262,6 +594,423 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_next_f32() {
+        let data = [
+            // open, high, low, close, volume
+            (150.39, 150.39, 150.22, 150.31, 380.0),
+            (150.47, 150.47, 150.38, 150.41, 5270.0),
+            (150.49, 150.49, 150.33, 150.46, 990.0),
+            (150.63, 150.63, 150.44, 150.61, 1031.0),
+            (151.10, 151.10, 150.67, 151.01, 2675.0),
+            (151.30, 151.30, 150.77, 150.80, 3334.0),
+        ];
+
+        let mut vwap_f64 = VolumeWeightedAveragePrice::new();
+        let mut vwap_f32 = VolumeWeightedAveragePrice::new().with_precision::<f32>();
+        let mut rolling_f64 = VolumeWeightedAveragePrice::rolling(3).unwrap();
+        let mut rolling_f32 = VolumeWeightedAveragePrice::rolling(3)
+            .unwrap()
+            .with_precision::<f32>();
+        let mut created_f32 = VolumeWeightedAveragePrice::<f32>::new_with_precision();
+        let mut created_rolling_f32 =
+            VolumeWeightedAveragePrice::<f32>::rolling_with_precision(3).unwrap();
+
+        for record in data {
+            let di = generate_bar(record);
+            let expected = vwap_f64.next(&di);
+            let actual: f32 = vwap_f32.next(&di);
+            assert_approx_eq!(actual as f64, expected, 1e-3);
+            assert_eq!(created_f32.next(&di), actual);
+
+            let expected = rolling_f64.next(&di);
+            let actual = rolling_f32.next(&di);
+            assert_approx_eq!(actual as f64, expected, 1e-3);
+            assert_eq!(created_rolling_f32.next(&di), actual);
+        }
+    }
+
+    #[test]
+    fn test_with_precision_keeps_state() {
+        let mut vwap = VolumeWeightedAveragePrice::rolling(2).unwrap();
+        vwap.next(&generate_bar((10.0, 12.0, 8.0, 11.0, 100.0)));
+
+        let mut converted = vwap.clone().with_precision::<f32>();
+        assert_eq!(converted.vwap(), vwap.vwap() as f32);
+        assert_eq!(converted.period(), 2);
+        assert_eq!(format!("{}", converted), "VWAP(2)");
+
+        let bar = generate_bar((11.0, 14.0, 10.0, 13.0, 300.0));
+        assert_approx_eq!(converted.next(&bar) as f64, vwap.next(&bar), 1e-4);
+    }
+
+    #[test]
+    fn test_anchor() {
+        let mut vwap = VolumeWeightedAveragePrice::new();
+        vwap.next(&generate_bar((150.39, 150.39, 150.22, 150.31, 380.0)));
+        vwap.next(&generate_bar((150.47, 150.47, 150.38, 150.41, 5270.0)));
+        let before = vwap.next(&generate_bar((150.49, 150.49, 150.33, 150.46, 990.0)));
+
+        vwap.anchor();
+        // the last value is kept until the anchor bar arrives
+        assert_eq!(vwap.vwap(), before);
+
+        // the anchor bar's typical price
+        let anchor_bar = generate_bar((10.0, 12.0, 8.0, 11.0, 100.0));
+        assert_approx_eq!(vwap.next(&anchor_bar), 31.0 / 3.0);
+        assert_eq!(vwap.raw_std_dev(), 0.0);
+
+        let bar = generate_bar((11.0, 14.0, 10.0, 13.0, 300.0));
+        let expected = (31.0 / 3.0 * 100.0 + 37.0 / 3.0 * 300.0) / 400.0;
+        assert_approx_eq!(vwap.next(&bar), expected);
+    }
+
+    #[test]
+    fn test_next_with_session() {
+        let mut vwap = VolumeWeightedAveragePrice::new();
+
+        let sessions = [
+            [
+                generate_bar((10.0, 12.0, 8.0, 11.0, 100.0)),
+                generate_bar((11.0, 14.0, 10.0, 13.0, 300.0)),
+                generate_bar((12.0, 15.0, 11.0, 14.0, 200.0)),
+            ],
+            [
+                generate_bar((20.0, 21.0, 19.0, 20.0, 100.0)),
+                generate_bar((20.0, 23.0, 20.0, 22.0, 100.0)),
+                generate_bar((22.0, 22.0, 20.0, 21.0, 200.0)),
+            ],
+        ];
+
+        for session in sessions.iter() {
+            // each session matches a fresh VWAP
+            let mut fresh = VolumeWeightedAveragePrice::new();
+            for (i, bar) in session.iter().enumerate() {
+                let value = vwap.next_with_session(bar, i == 0);
+                assert_approx_eq!(value, fresh.next(bar));
+            }
+        }
+
+        // the first bar of the second session is its typical price
+        vwap.next_with_session(&sessions[0][0], true);
+        assert_eq!(vwap.next_with_session(&sessions[1][0], true), 20.0);
+        assert_eq!(vwap.bars_seen(), 1);
+    }
+
+    #[test]
+    fn test_anchor_rolling() {
+        let mut vwap = VolumeWeightedAveragePrice::rolling(2).unwrap();
+        vwap.next(&generate_bar((150.39, 150.39, 150.22, 150.31, 380.0)));
+        vwap.next(&generate_bar((150.47, 150.47, 150.38, 150.41, 5270.0)));
+
+        vwap.anchor();
+        assert_eq!(vwap.period(), 2);
+
+        let bars = [
+            generate_bar((10.0, 12.0, 8.0, 11.0, 100.0)),
+            generate_bar((11.0, 14.0, 10.0, 13.0, 300.0)),
+            generate_bar((12.0, 15.0, 11.0, 14.0, 200.0)),
+        ];
+        let mut fresh = VolumeWeightedAveragePrice::rolling(2).unwrap();
+        for bar in bars.iter() {
+            assert_approx_eq!(vwap.next(bar), fresh.next(bar));
+        }
+    }
+
+    #[test]
+    fn test_ready() {
+        let bar = generate_bar((10.0, 12.0, 8.0, 11.0, 100.0));
+
+        // a session VWAP is ready after the first bar
+        let mut vwap = VolumeWeightedAveragePrice::new();
+        assert!(!vwap.is_ready());
+        vwap.next(&bar);
+        assert_eq!((vwap.bars_seen(), vwap.is_ready()), (1, true));
+        vwap.anchor();
+        assert_eq!((vwap.bars_seen(), vwap.is_ready()), (0, false));
+
+        // a rolling one once its window is full
+        let mut vwap = VolumeWeightedAveragePrice::rolling(3).unwrap();
+        vwap.next(&bar);
+        vwap.next(&bar);
+        assert_eq!((vwap.bars_seen(), vwap.is_ready()), (2, false));
+        vwap.next(&bar);
+        assert_eq!((vwap.bars_seen(), vwap.is_ready()), (3, true));
+        vwap.reset();
+        assert_eq!((vwap.bars_seen(), vwap.is_ready()), (0, false));
+
+        // skipped bars are not counted
+        let mut vwap = VolumeWeightedAveragePrice::new().with_nan_policy(NanPolicy::SkipBar);
+        let [_, gap, _] = nan_bars();
+        vwap.next(&gap);
+        assert_eq!((vwap.bars_seen(), vwap.is_ready()), (0, false));
+    }
+
+    #[test]
+    fn test_current() {
+        let mut vwap = VolumeWeightedAveragePrice::new();
+        assert_eq!(vwap.current(), None);
+
+        let bars = [
+            generate_bar((150.39, 150.39, 150.22, 150.31, 380.0)),
+            generate_bar((150.47, 150.47, 150.38, 150.41, 5270.0)),
+            // no volume, the typical price
+            generate_bar((150.49, 150.49, 150.33, 150.46, 0.0)),
+        ];
+        for bar in bars.iter() {
+            let value = vwap.next(bar);
+            assert_eq!(vwap.current(), Some(value));
+        }
+
+        // the last value stays available after anchoring
+        vwap.anchor();
+        assert_eq!(vwap.current(), Some(vwap.vwap()));
+
+        vwap.reset();
+        assert_eq!(vwap.current(), None);
+
+        let mut vwap = VolumeWeightedAveragePrice::new();
+        let value = vwap.next(&generate_bar((10.0, 10.0, 10.0, 10.0, 0.0)));
+        assert_eq!(vwap.current(), Some(value));
+    }
+
+    #[test]
+    #[rustfmt::skip]
+    fn test_next_with_bands() {
+        let mut indicator = VolumeWeightedAveragePrice::new();
+
+        let data = [
+            //open  ,high   ,low     ,close  ,volume,vwap              ,(upper, lower)                         ,(upper, lower)
+            (76.529, 76.529, 76.529, 76.529, 1.0    ,76.529            ,(76.529, 76.529)                       ,(76.529, 76.529)),
+            (76.073, 76.073, 76.043, 76.073, 121.0  ,76.06681967213113 ,(76.15085245902783, 75.98278688523443) ,(76.19286885247618, 75.94077049178608)),
+            (76.323, 76.323, 76.053, 76.193, 181.0  ,76.14020352035202 ,(76.27197010203601, 76.00843693866803) ,(76.33785339287802, 75.94255364782602)),
+            (76.208, 76.208, 75.918, 75.988, 146.0  ,76.1069703043801  ,(76.25148729829992, 75.96245331046028) ,(76.32374579525984, 75.89019481350036)),
+            (76.088, 76.088, 75.883, 76.058, 149.0  ,76.08272575250835 ,(76.23361138086482, 75.93184012415189) ,(76.30905419504305, 75.85639730997366)),
+        ];
+
+        for (open, high, low, close, volume, vwap, band_2, band_3) in data {
+            let di = generate_bar((open, high, low, close, volume));
+
+            let (value, bands) = indicator.next_with_bands(&di, &[2.0, 3.0]);
+            assert_approx_eq!(value, vwap, 0.01);
+            assert_eq!(bands.len(), 2);
+            assert_approx_eq!(bands[0].0, band_2.0, 0.01);
+            assert_approx_eq!(bands[0].1, band_2.1, 0.01);
+            assert_approx_eq!(bands[1].0, band_3.0, 0.01);
+            assert_approx_eq!(bands[1].1, band_3.1, 0.01);
+
+            // the same values as the single band getter
+            assert_eq!(bands, indicator.bands(&[2.0, 3.0]));
+            assert_eq!(bands[1].0, indicator.std_dev(3.0, Up));
+            assert_eq!(bands[1].1, indicator.std_dev(3.0, Down));
+        }
+
+        assert!(indicator.bands(&[]).is_empty());
+    }
+
+    #[test]
+    fn test_rolling_new() {
+        assert!(VolumeWeightedAveragePrice::rolling(0).is_err());
+        assert!(VolumeWeightedAveragePrice::rolling(1).is_ok());
+    }
+
+    #[test]
+    fn test_next_rolling() {
+        let data = [
+            // open, high, low, close, volume
+            (150.39, 150.39, 150.22, 150.31, 380.0),
+            (150.47, 150.47, 150.38, 150.41, 5270.0),
+            (150.49, 150.49, 150.33, 150.46, 990.0),
+            (150.63, 150.63, 150.44, 150.61, 1031.0),
+            (151.10, 151.10, 150.67, 151.01, 2675.0),
+            (151.30, 151.30, 150.77, 150.80, 3334.0),
+        ];
+        let mut rolling = VolumeWeightedAveragePrice::rolling(3).unwrap();
+        for record in data.iter() {
+            rolling.next(&generate_bar(*record));
+        }
+
+        // only the last 3 bars are in the window
+        let mut session = VolumeWeightedAveragePrice::new();
+        for record in data[3..].iter() {
+            session.next(&generate_bar(*record));
+        }
+
+        assert_approx_eq!(rolling.vwap(), session.vwap());
+        assert_approx_eq!(rolling.std_dev(2.0, Up), session.std_dev(2.0, Up));
+        assert_approx_eq!(rolling.std_dev(2.0, Down), session.std_dev(2.0, Down));
+    }
+
+    #[test]
+    fn test_rolling_reset() {
+        let mut vwap = VolumeWeightedAveragePrice::rolling(2).unwrap();
+
+        vwap.next(&generate_bar((150.39, 150.39, 150.22, 150.31, 380.0)));
+        vwap.next(&generate_bar((150.47, 150.47, 150.38, 150.41, 5270.0)));
+        vwap.next(&generate_bar((150.49, 150.49, 150.33, 150.46, 990.0)));
+
+        vwap.reset();
+        assert_approx_eq!(
+            vwap.next(&generate_bar((150.39, 150.39, 150.22, 150.31, 380.0))),
+            150.31,
+            0.01
+        );
+    }
+
+    #[test]
+    fn test_next_with_source() {
+        use PriceSource::*;
+
+        let bar1 = generate_bar((10.0, 12.0, 8.0, 11.0, 100.0));
+        let bar2 = generate_bar((11.0, 14.0, 10.0, 13.0, 300.0));
+
+        // (source, vwap after bar1, vwap after bar2)
+        let cases = [
+            (
+                HLC3,
+                31.0 / 3.0,
+                (31.0 / 3.0 * 100.0 + 37.0 / 3.0 * 300.0) / 400.0,
+            ),
+            (OHLC4, 10.25, (10.25 * 100.0 + 12.0 * 300.0) / 400.0),
+            (HL2, 10.0, (10.0 * 100.0 + 12.0 * 300.0) / 400.0),
+            (Close, 11.0, (11.0 * 100.0 + 13.0 * 300.0) / 400.0),
+            (Open, 10.0, (10.0 * 100.0 + 11.0 * 300.0) / 400.0),
+            (HLCC4, 10.5, (10.5 * 100.0 + 12.5 * 300.0) / 400.0),
+        ];
+
+        for (source, vwap1, vwap2) in cases {
+            let mut vwap = VolumeWeightedAveragePrice::new().with_source(source);
+            assert_eq!(vwap.source(), source);
+            assert_approx_eq!(vwap.next(&bar1), vwap1);
+            assert_approx_eq!(vwap.next(&bar2), vwap2);
+        }
+
+        assert_eq!(VolumeWeightedAveragePrice::new().source(), HLC3);
+    }
+
+    #[test]
+    #[allow(deprecated)]
+    fn test_next_with_deprecated_source() {
+        let bar = generate_bar((10.0, 12.0, 8.0, 11.0, 100.0));
+        let mut vwap =
+            VolumeWeightedAveragePrice::new().with_source(VolumeWeightedAveragePriceSource::HL2);
+        assert_eq!(vwap.source(), PriceSource::HL2);
+        assert_eq!(vwap.next(&bar), 10.0);
+    }
+
+    #[test]
+    fn test_next_zero_volume() {
+        let mut vwap = VolumeWeightedAveragePrice::new();
+
+        let first = vwap.next(&generate_bar((150.39, 150.39, 150.22, 150.31, 0.0)));
+        assert!(first.is_finite());
+        assert_approx_eq!(first, 150.31, 0.01);
+        assert_eq!(vwap.std_dev(2.0, Up), first);
+
+        let second = vwap.next(&generate_bar((150.47, 150.47, 150.38, 150.41, 5270.0)));
+        assert!(second.is_finite());
+        assert_approx_eq!(second, 150.42, 0.01);
+        assert!(vwap.std_dev(2.0, Up).is_finite());
+    }
+
+    fn nan_bars() -> [DataItem; 3] {
+        let gap = DataItem::builder()
+            .open(f64::NAN)
+            .high(f64::NAN)
+            .low(f64::NAN)
+            .close(f64::NAN)
+            .volume(300.0)
+            .build(false)
+            .unwrap();
+        [
+            generate_bar((10.0, 10.0, 10.0, 10.0, 100.0)),
+            gap,
+            generate_bar((13.0, 13.0, 13.0, 13.0, 100.0)),
+        ]
+    }
+
+    #[test]
+    fn test_next_nan_propagate() {
+        let mut vwap = VolumeWeightedAveragePrice::new();
+        assert_eq!(vwap.nan_policy(), NanPolicy::Propagate);
+
+        let [first, gap, last] = nan_bars();
+        assert_eq!(vwap.next(&first), 10.0);
+        assert!(vwap.next(&gap).is_nan());
+        // the NaN stays in the session sums
+        assert!(vwap.next(&last).is_nan());
+
+        vwap.reset();
+        assert_eq!(vwap.next(&first), 10.0);
+    }
+
+    #[test]
+    fn test_next_nan_skip_bar() {
+        let mut vwap = VolumeWeightedAveragePrice::new().with_nan_policy(NanPolicy::SkipBar);
+
+        let [first, gap, last] = nan_bars();
+        assert!(vwap.next(&gap).is_nan());
+        assert_eq!(vwap.current(), None);
+
+        assert_eq!(vwap.next(&first), 10.0);
+        assert_eq!(vwap.next(&gap), 10.0);
+        assert_eq!(vwap.next(&last), 11.5);
+    }
+
+    #[test]
+    fn test_next_nan_use_last() {
+        let mut vwap = VolumeWeightedAveragePrice::new().with_nan_policy(NanPolicy::UseLast);
+
+        let [first, gap, last] = nan_bars();
+        // nothing to carry forward yet, the bar is skipped
+        assert!(vwap.next(&gap).is_nan());
+
+        assert_eq!(vwap.next(&first), 10.0);
+        // the price of the first bar with the volume of the gap bar
+        assert_eq!(vwap.next(&gap), 10.0);
+        assert_eq!(vwap.next(&last), 10.6);
+
+        // a NaN volume carries the last volume forward instead
+        let mut vwap = VolumeWeightedAveragePrice::rolling(2)
+            .unwrap()
+            .with_nan_policy(NanPolicy::UseLast);
+        let gap = DataItem::builder()
+            .open(16.0)
+            .high(16.0)
+            .low(16.0)
+            .close(16.0)
+            .volume(f64::NAN)
+            .build(false)
+            .unwrap();
+        assert_eq!(vwap.next(&first), 10.0);
+        assert_eq!(vwap.next(&gap), 13.0);
+        assert_eq!(vwap.next(&last), 14.5);
+    }
+
+    #[test]
+    fn test_getters() {
+        let mut vwap = VolumeWeightedAveragePrice::new();
+        assert_eq!(vwap.vwap(), 0.0);
+        assert_eq!(vwap.raw_std_dev(), 0.0);
+
+        vwap.next(&generate_bar((76.529, 76.529, 76.529, 76.529, 1.0)));
+        let last = vwap.next(&generate_bar((76.073, 76.073, 76.043, 76.073, 121.0)));
+
+        assert_eq!(vwap.vwap(), last);
+        assert_eq!(vwap.raw_std_dev(), vwap.std_dev);
+        assert_approx_eq!(vwap.std_dev(2.0, Up), last + 2.0 * vwap.raw_std_dev());
+        assert_approx_eq!(vwap.std_dev(3.0, Down), last - 3.0 * vwap.raw_std_dev());
+    }
+
+    #[test]
+    fn test_period() {
+        assert_eq!(VolumeWeightedAveragePrice::new().period(), 1);
+        assert_eq!(
+            VolumeWeightedAveragePrice::rolling(14).unwrap().period(),
+            14
+        );
+    }
+
     #[test]
     fn test_reset() {
         let mut vwap = VolumeWeightedAveragePrice::new();
@@ -294,5 +1043,8 @@ mod tests {
     fn test_display() {
         let vwap = VolumeWeightedAveragePrice::new();
         assert_eq!(format!("{}", vwap), "VWAP()");
+
+        let vwap = VolumeWeightedAveragePrice::rolling(14).unwrap();
+        assert_eq!(format!("{}", vwap), "VWAP(14)");
     }
 }
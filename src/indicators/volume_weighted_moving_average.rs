@@ -0,0 +1,229 @@
+use std::fmt;
+
+use crate::errors::{Result, TaError};
+use crate::{Close, Next, Period, Ready, Reset, Volume};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Volume weighted moving average (VWMA).
+///
+/// A moving average of the close in which every bar is weighted by its volume, so bars with
+/// heavy trading pull the average towards their price more than quiet ones. Unlike the
+/// [VWAP](struct.VolumeWeightedAveragePrice.html) it is taken over the last _period_ bars and
+/// uses the close instead of the typical price.
+///
+/// # Formula
+///
+/// VWMA = sum of (close * volume) over _period_ / sum of volume over _period_
+///
+/// When there is no volume in the period the close is returned.
+///
+/// # Parameters
+///
+/// * _period_ - number of bars (integer greater than 0). Default is 20.
+///
+/// # Example
+///
+/// ```
+/// use ta::indicators::VolumeWeightedMovingAverage;
+/// use ta::{DataItem, Next};
+///
+/// let mut vwma = VolumeWeightedMovingAverage::new(3).unwrap();
+///
+/// let di = DataItem::builder()
+///     .open(10.0).high(10.0).low(10.0).close(10.0).volume(100.0)
+///     .build(true).unwrap();
+/// assert_eq!(vwma.next(&di), 10.0);
+///
+/// let di = DataItem::builder()
+///     .open(12.0).high(12.0).low(12.0).close(12.0).volume(300.0)
+///     .build(true).unwrap();
+/// assert_eq!(vwma.next(&di), 11.5);
+/// ```
+///
+/// # Links
+///
+/// * [Volume Weighted Moving Average, TradingView](https://www.tradingview.com/support/solutions/43000592293-volume-weighted-moving-average-vwma/)
+///
+#[doc(alias = "VWMA")]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone)]
+pub struct VolumeWeightedMovingAverage {
+    period: usize,
+    index: usize,
+    price_volume_sum: f64,
+    volume_sum: f64,
+    // price * volume and volume of the bars in the window
+    deque: Box<[(f64, f64)]>,
+    #[cfg_attr(feature = "serde", serde(default))]
+    bars_seen: usize,
+}
+
+impl VolumeWeightedMovingAverage {
+    pub fn new(period: usize) -> Result<Self> {
+        match period {
+            0 => Err(TaError::invalid_parameter("period", period)),
+            _ => Ok(Self {
+                period,
+                index: 0,
+                price_volume_sum: 0.0,
+                volume_sum: 0.0,
+                deque: vec![(0.0, 0.0); period].into_boxed_slice(),
+                bars_seen: 0,
+            }),
+        }
+    }
+}
+
+impl Period for VolumeWeightedMovingAverage {
+    fn period(&self) -> usize {
+        self.period
+    }
+}
+
+impl<T: Close + Volume> Next<&T> for VolumeWeightedMovingAverage {
+    type Output = f64;
+
+    fn next(&mut self, input: &T) -> Self::Output {
+        self.bars_seen = self.bars_seen.saturating_add(1);
+        let volume = input.volume();
+        let price_volume = input.close() * volume;
+
+        let (old_price_volume, old_volume) = self.deque[self.index];
+        self.deque[self.index] = (price_volume, volume);
+
+        self.index = if self.index + 1 < self.period {
+            self.index + 1
+        } else {
+            0
+        };
+
+        self.price_volume_sum += price_volume - old_price_volume;
+        self.volume_sum += volume - old_volume;
+
+        if self.volume_sum > 0.0 {
+            self.price_volume_sum / self.volume_sum
+        } else {
+            input.close()
+        }
+    }
+}
+
+impl Ready for VolumeWeightedMovingAverage {
+    fn bars_seen(&self) -> usize {
+        self.bars_seen
+    }
+
+    fn is_ready(&self) -> bool {
+        self.bars_seen >= self.period
+    }
+}
+
+impl Reset for VolumeWeightedMovingAverage {
+    fn reset(&mut self) {
+        self.bars_seen = 0;
+        self.index = 0;
+        self.price_volume_sum = 0.0;
+        self.volume_sum = 0.0;
+        for i in 0..self.period {
+            self.deque[i] = (0.0, 0.0);
+        }
+    }
+}
+
+impl Default for VolumeWeightedMovingAverage {
+    fn default() -> Self {
+        Self::new(20).unwrap()
+    }
+}
+
+impl fmt::Display for VolumeWeightedMovingAverage {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "VWMA({})", self.period)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helper::*;
+
+    #[test]
+    fn test_new() {
+        assert!(VolumeWeightedMovingAverage::new(0).is_err());
+        assert!(VolumeWeightedMovingAverage::new(1).is_ok());
+    }
+
+    #[test]
+    fn test_next() {
+        let mut vwma = VolumeWeightedMovingAverage::new(3).unwrap();
+
+        assert_eq!(vwma.next(&Bar::new().close(10).volume(100.0)), 10.0);
+        assert_eq!(vwma.next(&Bar::new().close(12).volume(300.0)), 11.5);
+        assert_eq!(vwma.next(&Bar::new().close(11).volume(100.0)), 11.4);
+        // the first bar leaves the window
+        assert_eq!(vwma.next(&Bar::new().close(14).volume(500.0)), 13.0);
+    }
+
+    #[test]
+    fn test_next_volume_weight() {
+        let mut heavy = VolumeWeightedMovingAverage::new(2).unwrap();
+        let mut light = VolumeWeightedMovingAverage::new(2).unwrap();
+
+        heavy.next(&Bar::new().close(10).volume(100.0));
+        light.next(&Bar::new().close(10).volume(100.0));
+
+        let heavy = heavy.next(&Bar::new().close(20).volume(900.0));
+        let light = light.next(&Bar::new().close(20).volume(25.0));
+        assert_eq!(heavy, 19.0);
+        assert_eq!(light, 12.0);
+    }
+
+    #[test]
+    fn test_next_zero_volume() {
+        let mut vwma = VolumeWeightedMovingAverage::new(2).unwrap();
+
+        assert_eq!(vwma.next(&Bar::new().close(10).volume(0.0)), 10.0);
+        assert_eq!(vwma.next(&Bar::new().close(12).volume(0.0)), 12.0);
+        assert_eq!(vwma.next(&Bar::new().close(14).volume(100.0)), 14.0);
+        assert_eq!(vwma.next(&Bar::new().close(16).volume(100.0)), 15.0);
+    }
+
+    #[test]
+    fn test_reset() {
+        let mut vwma = VolumeWeightedMovingAverage::new(3).unwrap();
+
+        vwma.next(&Bar::new().close(10).volume(100.0));
+        vwma.next(&Bar::new().close(12).volume(300.0));
+        vwma.reset();
+
+        assert_eq!(vwma.next(&Bar::new().close(14).volume(100.0)), 14.0);
+        assert_eq!(vwma.next(&Bar::new().close(12).volume(100.0)), 13.0);
+    }
+
+    #[test]
+    fn test_ready() {
+        let mut vwma = VolumeWeightedMovingAverage::new(3).unwrap();
+        let bar = Bar::new().high(10).low(8).close(9).volume(100.0);
+        for _ in 0..2 {
+            vwma.next(&bar);
+        }
+        assert_eq!((vwma.bars_seen(), vwma.is_ready()), (2, false));
+        vwma.next(&bar);
+        assert_eq!((vwma.bars_seen(), vwma.is_ready()), (3, true));
+
+        vwma.reset();
+        assert_eq!((vwma.bars_seen(), vwma.is_ready()), (0, false));
+    }
+
+    #[test]
+    fn test_default() {
+        VolumeWeightedMovingAverage::default();
+    }
+
+    #[test]
+    fn test_display() {
+        let vwma = VolumeWeightedMovingAverage::new(20).unwrap();
+        assert_eq!(format!("{}", vwma), "VWMA(20)");
+    }
+}
@@ -0,0 +1,289 @@
+use std::fmt;
+
+use crate::errors::{Result, TaError};
+use crate::indicators::TrueRange;
+use crate::{Close, High, Low, Next, Period, Ready, Reset};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Vortex Indicator (VI+ / VI-).
+///
+/// Developed by Etienne Botes and Douglas Siepman, the vortex indicator consists of two lines
+/// capturing positive and negative trend movement. VI+ crossing above VI- signals the start of
+/// an uptrend, VI- crossing above VI+ signals the start of a downtrend.
+///
+/// # Formula
+///
+/// VM+<sub>t</sub> = |high<sub>t</sub> - low<sub>t-1</sub>|
+///
+/// VM-<sub>t</sub> = |low<sub>t</sub> - high<sub>t-1</sub>|
+///
+/// VI+ = sum of VM+ over _period_ / sum of TR over _period_
+///
+/// VI- = sum of VM- over _period_ / sum of TR over _period_
+///
+/// Where _TR_ is the [true range](struct.TrueRange.html). The first bar has no previous bar,
+/// so its vortex movements are 0.
+///
+/// # Parameters
+///
+/// * _period_ - number of bars (integer greater than 0). Default is 14.
+///
+/// # Example
+///
+/// ```
+/// use ta::indicators::VortexIndicator;
+/// use ta::{DataItem, Next};
+///
+/// let mut vi = VortexIndicator::new(2).unwrap();
+///
+/// let bar = |high, low, close| {
+///     DataItem::builder()
+///         .open(close).high(high).low(low).close(close).volume(1000.0)
+///         .build(true).unwrap()
+/// };
+///
+/// vi.next(&bar(10.0, 9.0, 9.5));
+/// let out = vi.next(&bar(11.0, 10.0, 10.5));
+///
+/// // VM+ = |11 - 9| = 2, VM- = |10 - 10| = 0, TR = 1 + 1.5
+/// assert_eq!(out.vi_plus, 2.0 / 2.5);
+/// assert_eq!(out.vi_minus, 0.0);
+/// ```
+///
+/// # Links
+///
+/// * [Vortex indicator, Wikipedia](https://en.wikipedia.org/wiki/Vortex_indicator)
+/// * [Vortex Indicator, StockCharts](https://school.stockcharts.com/doku.php?id=technical_indicators:vortex_indicator)
+///
+#[doc(alias = "VI")]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone)]
+pub struct VortexIndicator {
+    period: usize,
+    index: usize,
+    true_range: TrueRange,
+    prev: Option<(f64, f64)>,
+    plus_sum: f64,
+    minus_sum: f64,
+    true_range_sum: f64,
+    // VM+, VM- and TR of the bars in the window
+    deque: Box<[(f64, f64, f64)]>,
+    #[cfg_attr(feature = "serde", serde(default))]
+    bars_seen: usize,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct VortexIndicatorOutput {
+    pub vi_plus: f64,
+    pub vi_minus: f64,
+}
+
+impl From<VortexIndicatorOutput> for (f64, f64) {
+    fn from(vo: VortexIndicatorOutput) -> Self {
+        (vo.vi_plus, vo.vi_minus)
+    }
+}
+
+impl VortexIndicator {
+    pub fn new(period: usize) -> Result<Self> {
+        match period {
+            0 => Err(TaError::invalid_parameter("period", period)),
+            _ => Ok(Self {
+                period,
+                index: 0,
+                true_range: TrueRange::new(),
+                prev: None,
+                plus_sum: 0.0,
+                minus_sum: 0.0,
+                true_range_sum: 0.0,
+                deque: vec![(0.0, 0.0, 0.0); period].into_boxed_slice(),
+                bars_seen: 0,
+            }),
+        }
+    }
+}
+
+impl Period for VortexIndicator {
+    fn period(&self) -> usize {
+        self.period
+    }
+}
+
+impl<T: High + Low + Close> Next<&T> for VortexIndicator {
+    type Output = VortexIndicatorOutput;
+
+    fn next(&mut self, input: &T) -> Self::Output {
+        self.bars_seen = self.bars_seen.saturating_add(1);
+        let high = input.high();
+        let low = input.low();
+        let true_range = self.true_range.next(input);
+
+        let (plus, minus) = match self.prev.replace((high, low)) {
+            Some((prev_high, prev_low)) => ((high - prev_low).abs(), (low - prev_high).abs()),
+            None => (0.0, 0.0),
+        };
+
+        let (old_plus, old_minus, old_true_range) = self.deque[self.index];
+        self.deque[self.index] = (plus, minus, true_range);
+
+        self.index = if self.index + 1 < self.period {
+            self.index + 1
+        } else {
+            0
+        };
+
+        self.plus_sum += plus - old_plus;
+        self.minus_sum += minus - old_minus;
+        self.true_range_sum += true_range - old_true_range;
+
+        if self.true_range_sum > 0.0 {
+            VortexIndicatorOutput {
+                vi_plus: self.plus_sum / self.true_range_sum,
+                vi_minus: self.minus_sum / self.true_range_sum,
+            }
+        } else {
+            VortexIndicatorOutput {
+                vi_plus: 0.0,
+                vi_minus: 0.0,
+            }
+        }
+    }
+}
+
+impl Ready for VortexIndicator {
+    fn bars_seen(&self) -> usize {
+        self.bars_seen
+    }
+
+    fn is_ready(&self) -> bool {
+        // the first input has no previous bar to move from
+        self.bars_seen > self.period
+    }
+}
+
+impl Reset for VortexIndicator {
+    fn reset(&mut self) {
+        self.bars_seen = 0;
+        self.index = 0;
+        self.true_range.reset();
+        self.prev = None;
+        self.plus_sum = 0.0;
+        self.minus_sum = 0.0;
+        self.true_range_sum = 0.0;
+        for i in 0..self.period {
+            self.deque[i] = (0.0, 0.0, 0.0);
+        }
+    }
+}
+
+impl Default for VortexIndicator {
+    fn default() -> Self {
+        Self::new(14).unwrap()
+    }
+}
+
+impl fmt::Display for VortexIndicator {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "VI({})", self.period)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helper::*;
+
+    #[test]
+    fn test_new() {
+        assert!(VortexIndicator::new(0).is_err());
+        assert!(VortexIndicator::new(1).is_ok());
+    }
+
+    #[test]
+    fn test_next() {
+        let mut vi = VortexIndicator::new(3).unwrap();
+
+        #[rustfmt::skip]
+        let data = [
+            // high, low, close, vi+, vi-
+            (10.0, 9.0, 9.5, 0.0, 0.0),     // tr = 1
+            (11.0, 10.0, 10.5, 0.8, 0.0),   // vm+ = 2, vm- = 0, tr = 1.5
+            (10.5, 9.5, 9.8, 0.714, 0.429), // vm+ = 0.5, vm- = 1.5, tr = 1
+            (10.0, 8.5, 8.8, 0.75, 0.875),  // vm+ = 0.5, vm- = 2, tr = 1.5, first bar leaves
+        ];
+
+        for (high, low, close, vi_plus, vi_minus) in data {
+            let out = vi.next(&Bar::new().high(high).low(low).close(close));
+            assert_eq!(round(out.vi_plus), vi_plus);
+            assert_eq!(round(out.vi_minus), vi_minus);
+        }
+    }
+
+    #[test]
+    fn test_next_crossover() {
+        let mut vi = VortexIndicator::new(5).unwrap();
+
+        // a downtrend followed by an uptrend
+        let closes = [
+            20.0, 19.5, 19.0, 18.2, 17.8, 17.0, 16.5, 16.8, 17.5, 18.3, 19.0, 19.8, 20.5, 21.4,
+        ];
+
+        let mut was_below = false;
+        let mut crossed_over = false;
+        for close in closes {
+            let out = vi.next(&Bar::new().high(close + 0.4).low(close - 0.4).close(close));
+            if out.vi_plus < out.vi_minus {
+                was_below = true;
+            } else if was_below && out.vi_plus > out.vi_minus {
+                crossed_over = true;
+            }
+        }
+
+        assert!(was_below);
+        assert!(crossed_over);
+
+        let out = vi.next(&Bar::new().high(22.4).low(21.6).close(22.0));
+        assert!(out.vi_plus > out.vi_minus);
+    }
+
+    #[test]
+    fn test_reset() {
+        let mut vi = VortexIndicator::new(3).unwrap();
+
+        vi.next(&Bar::new().high(10).low(9).close(9.5));
+        vi.next(&Bar::new().high(11).low(10).close(10.5));
+        vi.reset();
+
+        let out = vi.next(&Bar::new().high(11).low(10).close(10.5));
+        assert_eq!(out.vi_plus, 0.0);
+        assert_eq!(out.vi_minus, 0.0);
+    }
+
+    #[test]
+    fn test_ready() {
+        let mut vi = VortexIndicator::new(3).unwrap();
+        let bar = Bar::new().high(10).low(8).close(9);
+        for _ in 0..3 {
+            vi.next(&bar);
+        }
+        assert_eq!((vi.bars_seen(), vi.is_ready()), (3, false));
+        // the fourth input completes the third movement
+        vi.next(&bar);
+        assert_eq!((vi.bars_seen(), vi.is_ready()), (4, true));
+
+        vi.reset();
+        assert_eq!((vi.bars_seen(), vi.is_ready()), (0, false));
+    }
+
+    #[test]
+    fn test_default() {
+        VortexIndicator::default();
+    }
+
+    #[test]
+    fn test_display() {
+        let vi = VortexIndicator::new(14).unwrap();
+        assert_eq!(format!("{}", vi), "VI(14)");
+    }
+}
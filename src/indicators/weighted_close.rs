@@ -0,0 +1,98 @@
+use std::fmt;
+
+use crate::{Close, High, Low, Next, Open, PriceSource, Reset};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Weighted close.
+///
+/// The average of a bar's high, low and close, with the close counting twice. It favours the
+/// close over the [typical price](struct.TypicalPrice.html).
+///
+/// # Formula
+///
+/// Weighted close = (high + low + 2 * close) / 4, [PriceSource::HLCC4]
+///
+/// # Example
+///
+/// ```
+/// use ta::indicators::{WeightedClose, SimpleMovingAverage};
+/// use ta::{DataItem, Next};
+///
+/// let mut wc = WeightedClose::new();
+/// let mut sma = SimpleMovingAverage::new(2).unwrap();
+///
+/// let di = DataItem::builder()
+///     .open(12.0).high(12.0).low(10.0).close(12.0).volume(1000.0)
+///     .build(true).unwrap();
+///
+/// assert_eq!(wc.next(&di), 11.5);
+/// assert_eq!(sma.next(wc.next(&di)), 11.5);
+/// ```
+///
+/// # Links
+///
+/// * [Weighted Close, StockCharts](https://school.stockcharts.com/doku.php?id=technical_indicators:weighted_close)
+///
+#[doc(alias = "WCL")]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone, Default)]
+pub struct WeightedClose;
+
+impl WeightedClose {
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl<T: Open + High + Low + Close> Next<&T> for WeightedClose {
+    type Output = f64;
+
+    fn next(&mut self, input: &T) -> Self::Output {
+        PriceSource::HLCC4.select(input)
+    }
+}
+
+/// Does nothing, the indicator has no state.
+impl Reset for WeightedClose {
+    fn reset(&mut self) {}
+}
+
+impl fmt::Display for WeightedClose {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "WCLPRICE")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helper::*;
+    use crate::DataItem;
+
+    #[test]
+    fn test_next() {
+        let mut wc = WeightedClose::new();
+        assert_eq!(wc.next(&Bar::new().high(12).low(10).close(12)), 11.5);
+        assert_eq!(wc.next(&Bar::new().high(15).low(13).close(13.5)), 13.75);
+    }
+
+    #[test]
+    fn test_next_data_item() {
+        let mut wc = WeightedClose::new();
+        let di = DataItem::builder()
+            .open(12.0)
+            .high(12.0)
+            .low(10.0)
+            .close(12.0)
+            .volume(1000.0)
+            .build(true)
+            .unwrap();
+        assert_eq!(wc.next(&di), 11.5);
+    }
+
+    #[test]
+    fn test_display() {
+        assert_eq!(format!("{}", WeightedClose::new()), "WCLPRICE");
+    }
+}
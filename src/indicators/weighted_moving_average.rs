@@ -1,7 +1,8 @@
 use std::fmt;
 
+use crate::adapters::Sourced;
 use crate::errors::{Result, TaError};
-use crate::{Close, Next, Period, Reset};
+use crate::{Close, Current, Next, Period, PriceSource, Ready, Reset};
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
@@ -20,6 +21,15 @@ use serde::{Deserialize, Serialize};
 /// * _n_ - is the period.
 /// * _p<sub>M</sub>_ - is the input value at a time period t.
 ///
+/// The sums are updated incrementally, every input takes O(1) time regardless of the period.
+///
+/// # Parameters
+///
+/// * _period_ - number of periods (integer greater than 0). Default is 9.
+///
+/// Fed with bars it consumes the close, [with_source](Self::with_source) wraps it in a
+/// [Sourced] to select another [PriceSource].
+///
 /// # Example
 ///
 /// ```
@@ -37,7 +47,6 @@ use serde::{Deserialize, Serialize};
 ///
 /// * [Weighted moving average, Wikipedia](https://en.wikipedia.org/wiki/Moving_average#Weighted_moving_average)
 ///
-
 #[doc(alias = "WMA")]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[derive(Debug, Clone)]
@@ -49,12 +58,14 @@ pub struct WeightedMovingAverage {
     sum: f64,
     sum_flat: f64,
     deque: Box<[f64]>,
+    #[cfg_attr(feature = "serde", serde(default))]
+    bars_seen: usize,
 }
 
 impl WeightedMovingAverage {
     pub fn new(period: usize) -> Result<Self> {
         match period {
-            0 => Err(TaError::InvalidParameter),
+            0 => Err(TaError::invalid_parameter("period", period)),
             _ => Ok(Self {
                 period,
                 index: 0,
@@ -63,9 +74,15 @@ impl WeightedMovingAverage {
                 sum: 0.0,
                 sum_flat: 0.0,
                 deque: vec![0.0; period].into_boxed_slice(),
+                bars_seen: 0,
             }),
         }
     }
+
+    /// Feeds the moving average with the given price of every bar.
+    pub fn with_source(self, source: PriceSource) -> Sourced<Self> {
+        Sourced::new(self, source)
+    }
 }
 
 impl Period for WeightedMovingAverage {
@@ -78,6 +95,7 @@ impl Next<f64> for WeightedMovingAverage {
     type Output = f64;
 
     fn next(&mut self, input: f64) -> Self::Output {
+        self.bars_seen = self.bars_seen.saturating_add(1);
         let old_val: f64 = self.deque[self.index];
         self.deque[self.index] = input;
 
@@ -107,8 +125,30 @@ impl<T: Close> Next<&T> for WeightedMovingAverage {
     }
 }
 
+impl Current for WeightedMovingAverage {
+    type Output = f64;
+
+    fn current(&self) -> Option<Self::Output> {
+        match self.count {
+            0 => None,
+            _ => Some(self.sum / (self.weight * (self.weight + 1.0) / 2.0)),
+        }
+    }
+}
+
+impl Ready for WeightedMovingAverage {
+    fn bars_seen(&self) -> usize {
+        self.bars_seen
+    }
+
+    fn is_ready(&self) -> bool {
+        self.bars_seen >= self.period
+    }
+}
+
 impl Reset for WeightedMovingAverage {
     fn reset(&mut self) {
+        self.bars_seen = 0;
         self.index = 0;
         self.count = 0;
         self.weight = 0.0;
@@ -135,6 +175,7 @@ impl fmt::Display for WeightedMovingAverage {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::indicators::SimpleMovingAverage;
     use crate::test_helper::*;
 
     test_indicator!(WeightedMovingAverage);
@@ -161,6 +202,36 @@ mod tests {
         assert_eq!(wma.next(&bar2), 4.0);
     }
 
+    #[test]
+    fn test_next_lag() {
+        let mut wma = WeightedMovingAverage::new(5).unwrap();
+        let mut sma = SimpleMovingAverage::new(5).unwrap();
+
+        for i in 0..20 {
+            let price = 10.0 + i as f64;
+            let (wma, sma) = (wma.next(price), sma.next(price));
+            if i >= 4 {
+                // on a trend a full WMA lags by (n - 1) / 3, an SMA by (n - 1) / 2
+                assert_eq!(round(price - wma), 1.333);
+                assert_eq!(round(price - sma), 2.0);
+            }
+        }
+    }
+
+    #[test]
+    fn test_next_with_source() {
+        let mut wma = WeightedMovingAverage::new(3)
+            .unwrap()
+            .with_source(PriceSource::High);
+        assert_eq!(wma.source(), PriceSource::High);
+
+        assert_eq!(wma.next(&Bar::new().high(12).low(8).close(11)), 12.0);
+        assert_eq!(
+            round(wma.next(&Bar::new().high(14).low(10).close(13))),
+            13.333
+        );
+    }
+
     #[test]
     fn test_reset() {
         let mut wma = WeightedMovingAverage::new(5).unwrap();
@@ -175,6 +246,36 @@ mod tests {
         assert_eq!(wma.next(4.0), 4.0);
     }
 
+    #[test]
+    fn test_current() {
+        let mut wma = WeightedMovingAverage::new(3).unwrap();
+        assert_eq!(wma.current(), None);
+
+        for input in [12.0, 3.0, 4.0, 61.0, 5.0] {
+            let value = wma.next(input);
+            assert_eq!(wma.current(), Some(value));
+        }
+
+        wma.reset();
+        assert_eq!(wma.current(), None);
+    }
+
+    #[test]
+    fn test_ready() {
+        let mut wma = WeightedMovingAverage::new(3).unwrap();
+        assert_eq!((wma.bars_seen(), wma.is_ready()), (0, false));
+
+        wma.next(1.0);
+        wma.next(2.0);
+        assert_eq!((wma.bars_seen(), wma.is_ready()), (2, false));
+        // the third input fills the period
+        wma.next(3.0);
+        assert_eq!((wma.bars_seen(), wma.is_ready()), (3, true));
+
+        wma.reset();
+        assert_eq!((wma.bars_seen(), wma.is_ready()), (0, false));
+    }
+
     #[test]
     fn test_default() {
         WeightedMovingAverage::default();
@@ -0,0 +1,217 @@
+use std::fmt;
+
+use crate::errors::Result;
+use crate::window::MonotonicWindow;
+use crate::{Close, High, Low, Next, Period, Ready, Reset};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Williams %R.
+///
+/// A momentum oscillator developed by Larry Williams showing where the close is relative
+/// to the highest high of the last _period_ bars. It moves between -100 (close at the lowest
+/// low) and 0 (close at the highest high).
+///
+/// # Formula
+///
+/// %R = (highest high - close) / (highest high - lowest low) * -100
+///
+/// Where the highest high and the lowest low are taken over the last _period_ bars.
+/// When they are equal %R is -50.
+///
+/// # Parameters
+///
+/// * _period_ - number of bars (integer greater than 0). Default is 14.
+///
+/// # Example
+///
+/// ```
+/// use ta::indicators::WilliamsR;
+/// use ta::Next;
+///
+/// let mut wr = WilliamsR::new(3).unwrap();
+/// assert_eq!(wr.next(10.0), -50.0);
+/// assert_eq!(wr.next(20.0), 0.0);
+/// assert_eq!(wr.next(15.0), -50.0);
+/// assert_eq!(wr.next(10.0), -100.0);
+/// ```
+///
+/// # Links
+///
+/// * [Williams %R, Wikipedia](https://en.wikipedia.org/wiki/Williams_%25R)
+/// * [Williams %R, StockCharts](https://school.stockcharts.com/doku.php?id=technical_indicators:williams_r)
+///
+#[doc(alias = "%R")]
+#[doc(alias = "WillR")]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone)]
+pub struct WilliamsR {
+    period: usize,
+    window: MonotonicWindow,
+    #[cfg_attr(feature = "serde", serde(default))]
+    bars_seen: usize,
+}
+
+impl WilliamsR {
+    pub fn new(period: usize) -> Result<Self> {
+        Ok(Self {
+            period,
+            window: MonotonicWindow::new(period)?,
+            bars_seen: 0,
+        })
+    }
+
+    fn calc(highest: f64, lowest: f64, close: f64) -> f64 {
+        if highest == lowest {
+            // To avoid division by zero, return the middle of the range
+            -50.0
+        } else {
+            (highest - close) / (highest - lowest) * -100.0
+        }
+    }
+}
+
+impl Period for WilliamsR {
+    fn period(&self) -> usize {
+        self.period
+    }
+}
+
+impl Next<f64> for WilliamsR {
+    type Output = f64;
+
+    fn next(&mut self, input: f64) -> Self::Output {
+        self.bars_seen = self.bars_seen.saturating_add(1);
+        let (lowest, highest) = self.window.push(input);
+        Self::calc(highest, lowest, input)
+    }
+}
+
+impl<T: High + Low + Close> Next<&T> for WilliamsR {
+    type Output = f64;
+
+    fn next(&mut self, input: &T) -> Self::Output {
+        self.bars_seen = self.bars_seen.saturating_add(1);
+        let (lowest, highest) = self.window.push_range(input.low(), input.high());
+        Self::calc(highest, lowest, input.close())
+    }
+}
+
+impl Ready for WilliamsR {
+    fn bars_seen(&self) -> usize {
+        self.bars_seen
+    }
+
+    fn is_ready(&self) -> bool {
+        self.bars_seen >= self.period
+    }
+}
+
+impl Reset for WilliamsR {
+    fn reset(&mut self) {
+        self.bars_seen = 0;
+        self.window.reset();
+    }
+}
+
+impl Default for WilliamsR {
+    fn default() -> Self {
+        Self::new(14).unwrap()
+    }
+}
+
+impl fmt::Display for WilliamsR {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "WILLR({})", self.period)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helper::*;
+
+    test_indicator!(WilliamsR);
+
+    #[test]
+    fn test_new() {
+        assert!(WilliamsR::new(0).is_err());
+        assert!(WilliamsR::new(1).is_ok());
+    }
+
+    #[test]
+    fn test_next_bar() {
+        let mut wr = WilliamsR::new(3).unwrap();
+
+        let bar1 = Bar::new().high(10).low(8).close(9);
+        assert_eq!(wr.next(&bar1), -50.0);
+
+        let bar2 = Bar::new().high(12).low(9).close(11.5);
+        assert_eq!(wr.next(&bar2), -12.5);
+
+        let bar3 = Bar::new().high(11).low(7).close(8);
+        assert_eq!(wr.next(&bar3), -80.0);
+
+        // bar1 leaves the window
+        let bar4 = Bar::new().high(10).low(9).close(9.5);
+        assert_eq!(wr.next(&bar4), -50.0);
+    }
+
+    #[test]
+    fn test_next_boundaries() {
+        let mut wr = WilliamsR::new(3).unwrap();
+
+        wr.next(&Bar::new().high(10).low(8).close(9));
+
+        // close equals the highest high of the window
+        assert_eq!(wr.next(&Bar::new().high(12).low(9).close(12)), 0.0);
+
+        // close equals the lowest low of the window
+        assert_eq!(wr.next(&Bar::new().high(11).low(7).close(7)), -100.0);
+    }
+
+    #[test]
+    fn test_next_flat() {
+        let mut wr = WilliamsR::new(3).unwrap();
+        let bar = Bar::new().high(5).low(5).close(5);
+        assert_eq!(wr.next(&bar), -50.0);
+        assert_eq!(wr.next(&bar), -50.0);
+    }
+
+    #[test]
+    fn test_reset() {
+        let mut wr = WilliamsR::new(3).unwrap();
+
+        wr.next(&Bar::new().high(10).low(8).close(9));
+        wr.next(&Bar::new().high(20).low(1).close(2));
+        wr.reset();
+
+        assert_eq!(wr.next(&Bar::new().high(10).low(8).close(10)), 0.0);
+    }
+
+    #[test]
+    fn test_ready() {
+        let mut wr = WilliamsR::new(3).unwrap();
+        let bar = Bar::new().high(10).low(8).close(9);
+        for _ in 0..2 {
+            wr.next(&bar);
+        }
+        assert_eq!((wr.bars_seen(), wr.is_ready()), (2, false));
+        wr.next(&bar);
+        assert_eq!((wr.bars_seen(), wr.is_ready()), (3, true));
+
+        wr.reset();
+        assert_eq!((wr.bars_seen(), wr.is_ready()), (0, false));
+    }
+
+    #[test]
+    fn test_default() {
+        WilliamsR::default();
+    }
+
+    #[test]
+    fn test_display() {
+        let wr = WilliamsR::new(14).unwrap();
+        assert_eq!(format!("{}", wr), "WILLR(14)");
+    }
+}
@@ -0,0 +1,292 @@
+use std::fmt;
+
+use crate::errors::{Result, TaError};
+use crate::{High, Low, Next, Reset};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Kind of a [ZigZag] pivot.
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ZigZagPivotKind {
+    High,
+    Low,
+}
+
+/// A swing high or low confirmed by [ZigZag].
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone, PartialEq)]
+pub struct ZigZagPivot {
+    pub price: f64,
+    pub kind: ZigZagPivotKind,
+    /// Index of the input the pivot is at, counting from 0.
+    pub bar_index: usize,
+}
+
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Swing {
+    // the direction is not known yet, the highest high and the lowest low are tracked
+    Undecided {
+        high: (f64, usize),
+        low: (f64, usize),
+    },
+    // tracking the highest high of the swing up
+    Up {
+        high: (f64, usize),
+    },
+    // tracking the lowest low of the swing down
+    Down {
+        low: (f64, usize),
+    },
+}
+
+/// Zig Zag.
+///
+/// Filters out price movements smaller than a threshold and connects the remaining swing
+/// highs and lows. A swing high is confirmed when the low falls the threshold below the
+/// highest high since the last swing low, and a swing low when the high rises the threshold
+/// above the lowest low since the last swing high. `next` returns the pivot on the input
+/// confirming it and `None` otherwise.
+///
+/// The confirmation lags: the pivot lies at an earlier bar, given by its
+/// [bar_index](ZigZagPivot::bar_index). Until it is confirmed, the last leg of a zig zag
+/// line drawn on a chart is only a candidate and moves with every new extreme, this is
+/// the "repainting" of the zig zag. Confirmed pivots never change.
+///
+/// # Parameters
+///
+/// * _percent_threshold_ - minimum reversal in percent (number greater than 0). Default is 5.
+///
+/// # Example
+///
+/// ```
+/// use ta::indicators::{ZigZag, ZigZagPivotKind};
+/// use ta::{DataItem, Next};
+///
+/// let mut zig_zag = ZigZag::new(5.0).unwrap();
+/// let bar = |price: f64| {
+///     DataItem::builder()
+///         .open(price).high(price).low(price).close(price).volume(1.0)
+///         .build(true).unwrap()
+/// };
+///
+/// assert_eq!(zig_zag.next(&bar(108.0)), None);
+/// assert_eq!(zig_zag.next(&bar(110.0)), None);
+///
+/// // 5% below the high of 110
+/// let pivot = zig_zag.next(&bar(104.0)).unwrap();
+/// assert_eq!(pivot.price, 110.0);
+/// assert_eq!(pivot.kind, ZigZagPivotKind::High);
+/// assert_eq!(pivot.bar_index, 1);
+/// ```
+///
+/// # Links
+///
+/// * [ZigZag Indicator, StockCharts](https://school.stockcharts.com/doku.php?id=technical_indicators:zigzag)
+///
+#[doc(alias = "ZZ")]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone)]
+pub struct ZigZag {
+    threshold: f64,
+    swing: Option<Swing>,
+    index: usize,
+}
+
+impl ZigZag {
+    pub fn new(percent_threshold: f64) -> Result<Self> {
+        let is_valid = percent_threshold.is_finite() && percent_threshold > 0.0;
+        if !is_valid {
+            return Err(TaError::invalid_parameter(
+                "percent_threshold",
+                percent_threshold,
+            ));
+        }
+
+        Ok(Self {
+            threshold: percent_threshold,
+            swing: None,
+            index: 0,
+        })
+    }
+
+    pub fn threshold(&self) -> f64 {
+        self.threshold
+    }
+
+    fn is_reversal_down(&self, high: f64, low: f64) -> bool {
+        low <= high * (1.0 - self.threshold / 100.0)
+    }
+
+    fn is_reversal_up(&self, low: f64, high: f64) -> bool {
+        high >= low * (1.0 + self.threshold / 100.0)
+    }
+}
+
+fn pivot((price, bar_index): (f64, usize), kind: ZigZagPivotKind) -> Option<ZigZagPivot> {
+    Some(ZigZagPivot {
+        price,
+        kind,
+        bar_index,
+    })
+}
+
+impl<T: High + Low> Next<&T> for ZigZag {
+    type Output = Option<ZigZagPivot>;
+
+    fn next(&mut self, input: &T) -> Self::Output {
+        let index = self.index;
+        self.index += 1;
+        let high = (input.high(), index);
+        let low = (input.low(), index);
+
+        let swing = match self.swing {
+            None => Swing::Undecided { high, low },
+            Some(Swing::Undecided {
+                high: max,
+                low: min,
+            }) => Swing::Undecided {
+                high: if high.0 > max.0 { high } else { max },
+                low: if low.0 < min.0 { low } else { min },
+            },
+            Some(Swing::Up { high: max }) => Swing::Up {
+                high: if high.0 > max.0 { high } else { max },
+            },
+            Some(Swing::Down { low: min }) => Swing::Down {
+                low: if low.0 < min.0 { low } else { min },
+            },
+        };
+
+        let (swing, output) = match swing {
+            Swing::Undecided { high: max, .. } if self.is_reversal_down(max.0, low.0) => {
+                (Swing::Down { low }, pivot(max, ZigZagPivotKind::High))
+            }
+            Swing::Undecided { low: min, .. } if self.is_reversal_up(min.0, high.0) => {
+                (Swing::Up { high }, pivot(min, ZigZagPivotKind::Low))
+            }
+            Swing::Up { high: max } if self.is_reversal_down(max.0, low.0) => {
+                (Swing::Down { low }, pivot(max, ZigZagPivotKind::High))
+            }
+            Swing::Down { low: min } if self.is_reversal_up(min.0, high.0) => {
+                (Swing::Up { high }, pivot(min, ZigZagPivotKind::Low))
+            }
+            swing => (swing, None),
+        };
+
+        self.swing = Some(swing);
+        output
+    }
+}
+
+impl Reset for ZigZag {
+    fn reset(&mut self) {
+        self.swing = None;
+        self.index = 0;
+    }
+}
+
+impl Default for ZigZag {
+    fn default() -> Self {
+        Self::new(5.0).unwrap()
+    }
+}
+
+impl fmt::Display for ZigZag {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "ZIGZAG({})", self.threshold)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helper::*;
+
+    use ZigZagPivotKind::{High, Low};
+
+    #[test]
+    fn test_new() {
+        assert!(ZigZag::new(0.0).is_err());
+        assert!(ZigZag::new(-5.0).is_err());
+        assert!(ZigZag::new(f64::INFINITY).is_err());
+        assert!(ZigZag::new(5.0).is_ok());
+    }
+
+    #[test]
+    fn test_next() {
+        let mut zig_zag = ZigZag::new(5.0).unwrap();
+
+        #[rustfmt::skip]
+        let prices = [
+            100.0, 103.0, 106.0, 110.0, 108.0, 104.0,
+            101.0, 99.0, 102.0, 104.0,
+            108.0, 112.0, 107.0, 106.0,
+        ];
+        let pivots: Vec<(usize, ZigZagPivot)> = prices
+            .iter()
+            .enumerate()
+            .filter_map(|(i, &price)| {
+                zig_zag
+                    .next(&Bar::new().high(price).low(price))
+                    .map(|pivot| (i, pivot))
+            })
+            .collect();
+
+        let pivot = |price, kind, bar_index| ZigZagPivot {
+            price,
+            kind,
+            bar_index,
+        };
+        assert_eq!(
+            pivots,
+            [
+                // 106 is 5% above the first price
+                (2, pivot(100.0, Low, 0)),
+                // 104 is 5% below 110
+                (5, pivot(110.0, High, 3)),
+                // 104 is 5% above 99
+                (9, pivot(99.0, Low, 7)),
+                // 106 is 5% below 112, 107 was not
+                (13, pivot(112.0, High, 11)),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_next_undecided() {
+        let mut zig_zag = ZigZag::new(10.0).unwrap();
+
+        // the price falls less than 10% below the first high before it turns up
+        assert_eq!(zig_zag.next(&Bar::new().high(100).low(96)), None);
+        assert_eq!(zig_zag.next(&Bar::new().high(98).low(93)), None);
+        assert_eq!(zig_zag.next(&Bar::new().high(97).low(92)), None);
+
+        let pivot = zig_zag.next(&Bar::new().high(102).low(99)).unwrap();
+        assert_eq!((pivot.price, pivot.kind, pivot.bar_index), (92.0, Low, 2));
+    }
+
+    #[test]
+    fn test_reset() {
+        let mut zig_zag = ZigZag::new(5.0).unwrap();
+
+        zig_zag.next(&Bar::new().high(100).low(100));
+        zig_zag.next(&Bar::new().high(120).low(120));
+        zig_zag.reset();
+
+        assert_eq!(zig_zag.next(&Bar::new().high(100).low(100)), None);
+        let pivot = zig_zag.next(&Bar::new().high(94).low(94)).unwrap();
+        assert_eq!((pivot.price, pivot.kind, pivot.bar_index), (100.0, High, 0));
+    }
+
+    #[test]
+    fn test_default() {
+        assert_eq!(ZigZag::default().threshold(), 5.0);
+    }
+
+    #[test]
+    fn test_display() {
+        let zig_zag = ZigZag::new(2.5).unwrap();
+        assert_eq!(format!("{}", zig_zag), "ZIGZAG(2.5)");
+    }
+}
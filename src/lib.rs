@@ -31,6 +31,20 @@
 //!   * [Simple Moving Average (SMA)](crate::indicators::SimpleMovingAverage)
 //!   * [Weighted Moving Average (WMA)](crate::indicators::WeightedMovingAverage)
 //!   * [Volume Weighted Average Price (VWAP)](crate::indicators::VolumeWeightedAveragePrice)
+//!   * [Parabolic SAR (PSAR)](crate::indicators::ParabolicSar)
+//!   * [SuperTrend](crate::indicators::SuperTrend)
+//!   * [Hull Moving Average (HMA)](crate::indicators::HullMovingAverage)
+//!   * [Kaufman Adaptive Moving Average (KAMA)](crate::indicators::KaufmanAdaptiveMovingAverage)
+//!   * [Linear Regression (LINREG)](crate::indicators::LinearRegression)
+//!   * [Williams Alligator](crate::indicators::Alligator)
+//!   * [Triangular Moving Average (TRIMA)](crate::indicators::TriangularMovingAverage)
+//!   * [Double Exponential Moving Average (DEMA)](crate::indicators::DoubleExponentialMovingAverage)
+//!   * [Triple Exponential Moving Average (TEMA)](crate::indicators::TripleExponentialMovingAverage)
+//!   * [Ichimoku Cloud](crate::indicators::Ichimoku)
+//!   * [Volume Weighted Moving Average (VWMA)](crate::indicators::VolumeWeightedMovingAverage)
+//!   * [Standard Error Bands (SEB)](crate::indicators::StandardErrorBands)
+//!   * [Gann HiLo Activator](crate::indicators::GannHiLo)
+//!   * [Trailing Stop](crate::indicators::TrailingStop)
 //! * Oscillators
 //!   * [Relative Strength Index (RSI)](indicators/struct.RelativeStrengthIndex.html)
 //!   * [Fast Stochastic](indicators/struct.FastStochastic.html)
@@ -39,6 +53,33 @@
 //!   * [Percentage Price Oscillator (PPO)](indicators/struct.PercentagePriceOscillator.html)
 //!   * [Commodity Channel Index (CCI)](indicators/struct.CommodityChannelIndex.html)
 //!   * [Money Flow Index (MFI)](indicators/struct.MoneyFlowIndex.html)
+//!   * [Average Directional Index (ADX)](crate::indicators::AverageDirectionalIndex)
+//!   * [Stochastic Oscillator (STOCH)](crate::indicators::StochasticOscillator)
+//!   * [Williams %R](crate::indicators::WilliamsR)
+//!   * [Aroon](crate::indicators::Aroon)
+//!   * [Vortex Indicator (VI)](crate::indicators::VortexIndicator)
+//!   * [TRIX](crate::indicators::Trix)
+//!   * [Ultimate Oscillator (UO)](crate::indicators::UltimateOscillator)
+//!   * [Awesome Oscillator (AO)](crate::indicators::AwesomeOscillator)
+//!   * [Fisher Transform](crate::indicators::FisherTransform)
+//!   * [CCI Signal](crate::indicators::CciSignal)
+//!   * [Elder Ray Index (ERI)](crate::indicators::ElderRay)
+//!   * [Balance of Power (BOP)](crate::indicators::BalanceOfPower)
+//!   * [Ease of Movement (EMV)](crate::indicators::EaseOfMovement)
+//!   * [Force Index (FI)](crate::indicators::ForceIndex)
+//!   * [Detrended Price Oscillator (DPO)](crate::indicators::DetrendedPriceOscillator)
+//!   * [Coppock Curve](crate::indicators::CoppockCurve)
+//!   * [Know Sure Thing (KST)](crate::indicators::KnowSureThing)
+//!   * [Relative Vigor Index (RVI)](crate::indicators::RelativeVigorIndex)
+//!   * [Schaff Trend Cycle (STC)](crate::indicators::SchaffTrendCycle)
+//!   * [Connors RSI (CRSI)](crate::indicators::ConnorsRsi)
+//!   * [Chaikin Oscillator](crate::indicators::ChaikinOscillator)
+//!   * [Klinger Volume Oscillator](crate::indicators::KlingerOscillator)
+//!   * [Stochastic RSI (StochRSI)](crate::indicators::StochasticRsi)
+//!   * [Quantitative Qualitative Estimation (QQE)](crate::indicators::Qqe)
+//!   * [Disparity Index](crate::indicators::DisparityIndex)
+//!   * [Center of Gravity](crate::indicators::CenterOfGravity)
+//!   * [Volume Oscillator](crate::indicators::VolumeOscillator)
 //! * Other
 //!   * [Standard Deviation (SD)](indicators/struct.StandardDeviation.html)
 //!   * [Mean Absolute Deviation (MAD)](indicators/struct.MeanAbsoluteDeviation.html)
@@ -52,18 +93,51 @@
 //!   * [Efficiency Ratio (ER)](indicators/struct.EfficiencyRatio.html)
 //!   * [Rate of Change (ROC)](indicators/struct.RateOfChange.html)
 //!   * [On Balance Volume (OBV)](indicators/struct.OnBalanceVolume.html)
+//!   * [Donchian Channel (DC)](crate::indicators::DonchianChannel)
+//!   * [Chaikin Money Flow (CMF)](crate::indicators::ChaikinMoneyFlow)
+//!   * [Accumulation/Distribution Line (A/D)](crate::indicators::AccumulationDistribution)
+//!   * [Heikin-Ashi (HA)](crate::indicators::HeikinAshi)
+//!   * [Mass Index (MI)](crate::indicators::MassIndex)
+//!   * [Renko](crate::indicators::Renko)
+//!   * [Zig Zag](crate::indicators::ZigZag)
+//!   * [Volume Profile (VP)](crate::indicators::VolumeProfile)
+//!   * [Median Price](crate::indicators::MedianPrice)
+//!   * [Typical Price](crate::indicators::TypicalPrice)
+//!   * [Weighted Close](crate::indicators::WeightedClose)
+//!   * [Momentum (MOM)](crate::indicators::Momentum)
+//!   * [Choppiness Index (CHOP)](crate::indicators::ChoppinessIndex)
+//!   * [Williams Fractals](crate::indicators::Fractals)
+//!   * [Average True Range Percent (ATRP)](crate::indicators::AverageTrueRangePercent)
+//!   * [Percent Rank](crate::indicators::PercentRank)
+//!   * [Swing Points](crate::indicators::SwingPoints)
+//!   * [Relative Volume](crate::indicators::RelativeVolume)
+//!   * [Historical Volatility](crate::indicators::HistoricalVolatility)
 //!
 #[cfg(test)]
 #[macro_use]
 mod test_helper;
 
 mod helpers;
+mod window;
 
+pub mod adapters;
+pub mod cross;
+#[cfg(feature = "csv")]
+pub mod csv;
 pub mod errors;
 pub mod indicators;
+#[cfg(feature = "rayon")]
+pub mod parallel;
+pub mod pivot_points;
 
 mod traits;
 pub use crate::traits::*;
 
 mod data_item;
 pub use crate::data_item::DataItem;
+
+mod nan_policy;
+pub use crate::nan_policy::NanPolicy;
+
+mod price_source;
+pub use crate::price_source::PriceSource;
@@ -0,0 +1,41 @@
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// How an indicator treats a bar with a NaN value.
+///
+/// Real market data has gaps. By default a NaN is fed through like any other value, so it
+/// shows up in the output, and an indicator accumulating its inputs keeps returning NaN from
+/// then on. Indicators supporting a policy take it with `with_nan_policy`.
+///
+/// # Example
+///
+/// ```
+/// use ta::indicators::VolumeWeightedAveragePrice;
+/// use ta::{DataItem, NanPolicy, Next};
+///
+/// let mut vwap = VolumeWeightedAveragePrice::new().with_nan_policy(NanPolicy::SkipBar);
+///
+/// let di = DataItem::builder()
+///     .open(9.0).high(10.0).low(8.0).close(9.0).volume(1000.0)
+///     .build(true).unwrap();
+/// assert_eq!(vwap.next(&di), 9.0);
+///
+/// // a gap in the data, the bar is ignored
+/// let gap = DataItem::builder()
+///     .open(9.0).high(10.0).low(8.0).close(9.0).volume(f64::NAN)
+///     .build(false).unwrap();
+/// assert_eq!(vwap.next(&gap), 9.0);
+/// ```
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum NanPolicy {
+    /// Feed the NaN through, the output is NaN.
+    #[default]
+    Propagate,
+    /// Ignore the bar, the state is left untouched and the last output is returned again. As
+    /// long as there is no output yet NaN is returned.
+    SkipBar,
+    /// Replace every NaN value with the same value of the last bar without NaNs. As long as
+    /// there is no such bar the bar is skipped.
+    UseLast,
+}
@@ -0,0 +1,116 @@
+//! Parallel computation over independent segments of bars, requires the `rayon` feature.
+//!
+//! Most indicators are inherently sequential: every output depends on all the inputs before
+//! it, so a series cannot be split and computed in parallel. Moving averages, oscillators and
+//! rolling windows are such indicators and are not supported here.
+//!
+//! Some indicators start over at known boundaries, e.g. a session
+//! [VWAP](crate::indicators::VolumeWeightedAveragePrice) is reset at the start of every trading
+//! day. The segments between those boundaries are independent and can be computed in
+//! parallel with [par_next_segments].
+
+use rayon::prelude::*;
+
+use crate::Next;
+
+/// Computes every segment with a fresh indicator in parallel and concatenates the outputs.
+///
+/// The result equals feeding the segments in order to a single indicator that is reset at
+/// the start of every segment. It is only meaningful for indicators that are reset at the
+/// segment boundaries anyway, see the [module documentation](self).
+///
+/// # Example
+///
+/// ```
+/// use ta::indicators::VolumeWeightedAveragePrice;
+/// use ta::parallel::par_next_segments;
+/// use ta::DataItem;
+///
+/// let bar = |price, volume| {
+///     DataItem::builder()
+///         .open(price).high(price).low(price).close(price).volume(volume)
+///         .build(true).unwrap()
+/// };
+///
+/// let monday = [bar(10.0, 100.0), bar(12.0, 100.0)];
+/// let tuesday = [bar(20.0, 100.0), bar(23.0, 200.0)];
+///
+/// let vwap = par_next_segments(&[&monday[..], &tuesday[..]], VolumeWeightedAveragePrice::new);
+/// assert_eq!(vwap, [10.0, 11.0, 20.0, 22.0]);
+/// ```
+pub fn par_next_segments<'a, T, N, F>(segments: &[&'a [T]], new_indicator: F) -> Vec<N::Output>
+where
+    T: Sync,
+    N: Next<&'a T>,
+    N::Output: Send,
+    F: Fn() -> N + Sync,
+{
+    let outputs: Vec<Vec<N::Output>> = segments
+        .par_iter()
+        .map(|segment| {
+            let mut indicator = new_indicator();
+            segment.iter().map(|bar| indicator.next(bar)).collect()
+        })
+        .collect();
+
+    outputs.into_iter().flatten().collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::indicators::VolumeWeightedAveragePrice;
+    use crate::{DataItem, Reset};
+
+    fn bars(count: usize) -> Vec<DataItem> {
+        (0..count)
+            .map(|i| {
+                let x = i as f64;
+                let close = 100.0 + 5.0 * (x * 0.1).sin();
+                DataItem::builder()
+                    .open(close)
+                    .high(close + 1.0)
+                    .low(close - 1.0)
+                    .close(close)
+                    .volume(1000.0 + (x * 0.3).cos() * 500.0)
+                    .build(true)
+                    .unwrap()
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_par_next_segments() {
+        let bars = bars(1000);
+        // sessions of uneven length
+        let segments: Vec<&[DataItem]> = bars.chunks(37).collect();
+
+        let mut vwap = VolumeWeightedAveragePrice::new();
+        let mut expected = vec![];
+        for segment in &segments {
+            vwap.reset();
+            for bar in segment.iter() {
+                expected.push(vwap.next(bar));
+            }
+        }
+
+        let actual = par_next_segments(&segments, VolumeWeightedAveragePrice::new);
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn test_par_next_segments_empty() {
+        let bars = bars(3);
+
+        let actual = par_next_segments::<DataItem, VolumeWeightedAveragePrice, _>(
+            &[],
+            VolumeWeightedAveragePrice::new,
+        );
+        assert!(actual.is_empty());
+
+        // empty segments contribute nothing
+        let segments = [&bars[..0], &bars[..], &bars[..0]];
+        let actual = par_next_segments(&segments, VolumeWeightedAveragePrice::new);
+        assert_eq!(actual.len(), 3);
+    }
+}
@@ -0,0 +1,196 @@
+//! Pivot points, support and resistance levels computed from the previous period.
+//!
+//! Pivot points are computed once per period, usually a day, from the high, low and close of
+//! the previous period. The levels are constant during the period, so the functions take a
+//! single bar summarizing the previous period and keep no state.
+//!
+//! All methods share the pivot _P = (high + low + close) / 3_ and differ in the support and
+//! resistance levels around it, where _range = high - low_:
+//!
+//! | Level | [Classic](classic)     | [Fibonacci](fibonacci) | [Camarilla](camarilla)   |
+//! |-------|------------------------|------------------------|--------------------------|
+//! | R3    | high + 2 * (P - low)   | P + range              | close + range * 1.1 / 4  |
+//! | R2    | P + range              | P + 0.618 * range      | close + range * 1.1 / 6  |
+//! | R1    | 2 * P - low            | P + 0.382 * range      | close + range * 1.1 / 12 |
+//! | S1    | 2 * P - high           | P - 0.382 * range      | close - range * 1.1 / 12 |
+//! | S2    | P - range              | P - 0.618 * range      | close - range * 1.1 / 6  |
+//! | S3    | low - 2 * (high - P)   | P - range              | close - range * 1.1 / 4  |
+//!
+//! # Example
+//!
+//! ```
+//! use ta::pivot_points::{self, PivotKind};
+//! use ta::DataItem;
+//!
+//! let prev_day = DataItem::builder()
+//!     .open(100.0).high(110.0).low(90.0).close(106.0).volume(1000.0)
+//!     .build(true).unwrap();
+//!
+//! let levels = pivot_points::classic(&prev_day);
+//! assert_eq!(levels.pivot, 102.0);
+//! assert_eq!(levels.r1, 114.0);
+//! assert_eq!(levels.s1, 94.0);
+//!
+//! assert_eq!(pivot_points::compute(PivotKind::Classic, &prev_day), levels);
+//! ```
+//!
+//! # Links
+//!
+//! * [Pivot Points, StockCharts](https://school.stockcharts.com/doku.php?id=technical_indicators:pivot_points)
+
+use crate::{Close, High, Low};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Method to compute [PivotLevels] with.
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum PivotKind {
+    #[default]
+    Classic,
+    Fibonacci,
+    Camarilla,
+}
+
+/// The pivot and its resistance (r) and support (s) levels.
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone, PartialEq)]
+pub struct PivotLevels {
+    pub pivot: f64,
+    pub r1: f64,
+    pub r2: f64,
+    pub r3: f64,
+    pub s1: f64,
+    pub s2: f64,
+    pub s3: f64,
+}
+
+/// Computes the levels of the period following `prev` with the given method.
+pub fn compute<T: High + Low + Close>(kind: PivotKind, prev: &T) -> PivotLevels {
+    match kind {
+        PivotKind::Classic => classic(prev),
+        PivotKind::Fibonacci => fibonacci(prev),
+        PivotKind::Camarilla => camarilla(prev),
+    }
+}
+
+/// Classic (floor) pivot points.
+pub fn classic<T: High + Low + Close>(prev: &T) -> PivotLevels {
+    let (high, low) = (prev.high(), prev.low());
+    let pivot = pivot(prev);
+    let range = high - low;
+
+    PivotLevels {
+        pivot,
+        r1: 2.0 * pivot - low,
+        r2: pivot + range,
+        r3: high + 2.0 * (pivot - low),
+        s1: 2.0 * pivot - high,
+        s2: pivot - range,
+        s3: low - 2.0 * (high - pivot),
+    }
+}
+
+/// Fibonacci pivot points, the levels are Fibonacci ratios of the range.
+pub fn fibonacci<T: High + Low + Close>(prev: &T) -> PivotLevels {
+    let pivot = pivot(prev);
+    let range = prev.high() - prev.low();
+
+    PivotLevels {
+        pivot,
+        r1: pivot + 0.382 * range,
+        r2: pivot + 0.618 * range,
+        r3: pivot + range,
+        s1: pivot - 0.382 * range,
+        s2: pivot - 0.618 * range,
+        s3: pivot - range,
+    }
+}
+
+/// Camarilla pivot points, the levels are placed closely around the close.
+pub fn camarilla<T: High + Low + Close>(prev: &T) -> PivotLevels {
+    let close = prev.close();
+    let range = (prev.high() - prev.low()) * 1.1;
+
+    PivotLevels {
+        pivot: pivot(prev),
+        r1: close + range / 12.0,
+        r2: close + range / 6.0,
+        r3: close + range / 4.0,
+        s1: close - range / 12.0,
+        s2: close - range / 6.0,
+        s3: close - range / 4.0,
+    }
+}
+
+fn pivot<T: High + Low + Close>(prev: &T) -> f64 {
+    (prev.high() + prev.low() + prev.close()) / 3.0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helper::*;
+
+    fn levels(levels: PivotLevels) -> [f64; 7] {
+        [
+            levels.pivot,
+            levels.r1,
+            levels.r2,
+            levels.r3,
+            levels.s1,
+            levels.s2,
+            levels.s3,
+        ]
+        .map(round)
+    }
+
+    fn prev() -> Bar {
+        Bar::new().high(110).low(90).close(105)
+    }
+
+    #[test]
+    fn test_classic() {
+        assert_eq!(
+            levels(classic(&prev())),
+            [101.667, 113.333, 121.667, 133.333, 93.333, 81.667, 73.333]
+        );
+    }
+
+    #[test]
+    fn test_fibonacci() {
+        assert_eq!(
+            levels(fibonacci(&prev())),
+            [101.667, 109.307, 114.027, 121.667, 94.027, 89.307, 81.667]
+        );
+    }
+
+    #[test]
+    fn test_camarilla() {
+        assert_eq!(
+            levels(camarilla(&prev())),
+            [101.667, 106.833, 108.667, 110.5, 103.167, 101.333, 99.5]
+        );
+    }
+
+    #[test]
+    fn test_compute() {
+        let prev = prev();
+        assert_eq!(compute(PivotKind::Classic, &prev), classic(&prev));
+        assert_eq!(compute(PivotKind::Fibonacci, &prev), fibonacci(&prev));
+        assert_eq!(compute(PivotKind::Camarilla, &prev), camarilla(&prev));
+        assert_eq!(PivotKind::default(), PivotKind::Classic);
+    }
+
+    #[test]
+    fn test_zero_range() {
+        let prev = Bar::new().high(100).low(100).close(100);
+        for kind in [
+            PivotKind::Classic,
+            PivotKind::Fibonacci,
+            PivotKind::Camarilla,
+        ] {
+            assert_eq!(levels(compute(kind, &prev)), [100.0; 7]);
+        }
+    }
+}
@@ -0,0 +1,95 @@
+use crate::{Close, High, Low, Open};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Which price of a bar an indicator consumes.
+///
+/// Indicators fed with bars take the close. The [Sourced](crate::adapters::Sourced) adapter
+/// feeds any indicator consuming `f64` with another price, the moving averages create it with
+/// `with_source`. The [VWAP](crate::indicators::VolumeWeightedAveragePrice) takes a source
+/// itself, and the price transforms like the
+/// [TypicalPrice](crate::indicators::TypicalPrice) return one of them.
+///
+/// # Example
+///
+/// ```
+/// use ta::indicators::SimpleMovingAverage;
+/// use ta::{DataItem, Next, PriceSource};
+///
+/// let mut sma = SimpleMovingAverage::new(2).unwrap().with_source(PriceSource::High);
+///
+/// let di = DataItem::builder()
+///     .open(9.0).high(10.0).low(8.0).close(9.5).volume(1000.0)
+///     .build(true).unwrap();
+/// assert_eq!(PriceSource::HL2.select(&di), 9.0);
+/// assert_eq!(sma.next(&di), 10.0);
+/// ```
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum PriceSource {
+    /// open
+    Open,
+    /// high
+    High,
+    /// low
+    Low,
+    /// close
+    #[default]
+    Close,
+    /// (high + low) / 2
+    HL2,
+    /// (high + low + close) / 3
+    HLC3,
+    /// (open + high + low + close) / 4
+    OHLC4,
+    /// (high + low + 2 * close) / 4, the weighted close
+    HLCC4,
+}
+
+impl PriceSource {
+    /// The price of the bar.
+    pub fn select<T: Open + High + Low + Close>(&self, d: &T) -> f64 {
+        match self {
+            Self::Open => d.open(),
+            Self::High => d.high(),
+            Self::Low => d.low(),
+            Self::Close => d.close(),
+            Self::HL2 => (d.high() + d.low()) / 2.0,
+            Self::HLC3 => (d.high() + d.low() + d.close()) / 3.0,
+            Self::OHLC4 => (d.open() + d.high() + d.low() + d.close()) / 4.0,
+            Self::HLCC4 => (d.high() + d.low() + 2.0 * d.close()) / 4.0,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::DataItem;
+
+    #[test]
+    fn test_select() {
+        let di = DataItem::builder()
+            .open(10.0)
+            .high(14.0)
+            .low(8.0)
+            .close(12.0)
+            .volume(1000.0)
+            .build(true)
+            .unwrap();
+
+        assert_eq!(PriceSource::Open.select(&di), 10.0);
+        assert_eq!(PriceSource::High.select(&di), 14.0);
+        assert_eq!(PriceSource::Low.select(&di), 8.0);
+        assert_eq!(PriceSource::Close.select(&di), 12.0);
+        assert_eq!(PriceSource::HL2.select(&di), 11.0);
+        assert_eq!(PriceSource::HLC3.select(&di), 34.0 / 3.0);
+        assert_eq!(PriceSource::OHLC4.select(&di), 11.0);
+        assert_eq!(PriceSource::HLCC4.select(&di), 11.5);
+    }
+
+    #[test]
+    fn test_default() {
+        assert_eq!(PriceSource::default(), PriceSource::Close);
+    }
+}
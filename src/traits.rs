@@ -1,3 +1,7 @@
+use std::{fmt, ops};
+
+use crate::adapters::Warmed;
+
 // Indicator traits
 //
 
@@ -25,6 +29,140 @@ pub trait Next<T> {
     fn next(&mut self, input: T) -> Self::Output;
 }
 
+/// Extends [Next](trait.Next.html) with a helper to feed many inputs at once.
+///
+/// It is implemented for every indicator.
+///
+/// # Example
+///
+/// ```
+/// use ta::indicators::SimpleMovingAverage;
+/// use ta::NextExt;
+///
+/// let mut sma = SimpleMovingAverage::new(2).unwrap();
+/// assert_eq!(sma.next_all(vec![1.0, 3.0, 7.0]), vec![1.0, 2.0, 5.0]);
+/// ```
+pub trait NextExt<T>: Next<T> {
+    /// Feeds all the inputs in order and returns the outputs.
+    fn next_all<I: IntoIterator<Item = T>>(&mut self, inputs: I) -> Vec<Self::Output> {
+        inputs.into_iter().map(|input| self.next(input)).collect()
+    }
+}
+
+impl<T, N: Next<T> + ?Sized> NextExt<T> for N {}
+
+/// Warms an indicator up from historical inputs before it is fed live ones.
+///
+/// It is implemented for every indicator.
+///
+/// [seed](Seed::seed) resets the indicator and replays the inputs, discarding the outputs.
+/// The indicator is then in the same state as if it had consumed the inputs with
+/// [next](trait.Next.html#tymethod.next). All inputs are replayed, also for indicators with a
+/// period: moving averages like the EMA and cumulative indicators like the session VWAP
+/// depend on every input they have seen, not only on the last _period_ ones.
+///
+/// # Example
+///
+/// ```
+/// use ta::indicators::SimpleMovingAverage;
+/// use ta::{Next, Seed};
+///
+/// let history = vec![1.0, 3.0, 5.0];
+///
+/// let mut sma = SimpleMovingAverage::new(2).unwrap();
+/// assert_eq!(sma.seed(history).next(7.0), 6.0);
+/// ```
+pub trait Seed<T>: Next<T> + Reset {
+    /// Resets the indicator and feeds all the inputs in order, returning the indicator.
+    fn seed<I: IntoIterator<Item = T>>(&mut self, inputs: I) -> &mut Self {
+        self.reset();
+        for input in inputs {
+            self.next(input);
+        }
+        self
+    }
+}
+
+impl<T, N: Next<T> + Reset + ?Sized> Seed<T> for N {}
+
+/// Returns the output of the last call to [next](trait.Next.html#tymethod.next) again, without
+/// consuming an input.
+///
+/// It is `None` until the first input and again after a reset.
+///
+/// It is implemented by the indicators that keep their last output in their state, like the
+/// moving averages, the [RSI](indicators/struct.RelativeStrengthIndex.html), the
+/// [MACD](indicators/struct.MovingAverageConvergenceDivergence.html), the
+/// [Bollinger Bands](indicators/struct.BollingerBands.html), the
+/// [ATR](indicators/struct.AverageTrueRange.html) and the
+/// [OBV](indicators/struct.OnBalanceVolume.html). [Latest](adapters/struct.Latest.html) adds it
+/// to any other indicator by keeping a copy of the last output.
+///
+/// # Example
+///
+/// ```
+/// use ta::indicators::ExponentialMovingAverage;
+/// use ta::{Current, Next};
+///
+/// let mut ema = ExponentialMovingAverage::new(3).unwrap();
+/// assert_eq!(ema.current(), None);
+///
+/// let value = ema.next(2.0);
+/// assert_eq!(ema.current(), Some(value));
+/// ```
+pub trait Current {
+    type Output;
+    fn current(&self) -> Option<Self::Output>;
+}
+
+/// Tells how many inputs an indicator has consumed and whether its output is meaningful yet.
+///
+/// It is implemented by every indicator with a period, by the
+/// [MACD](indicators/struct.MovingAverageConvergenceDivergence.html) and by the cumulative
+/// indicators. Period based indicators are ready once they consumed _period_ inputs, cumulative
+/// indicators like the [OBV](indicators/struct.OnBalanceVolume.html) after the first input.
+/// Indicators of changes, like the [ROC](indicators/struct.RateOfChange.html) or the
+/// [RSI](indicators/struct.RelativeStrengthIndex.html), need _period_ changes and so one input
+/// more. Composite indicators are ready once all of their components are. Both counts start
+/// over after a reset.
+///
+/// [Warmed](adapters/struct.Warmed.html) hides the outputs until an indicator is ready.
+///
+/// # Example
+///
+/// ```
+/// use ta::indicators::SimpleMovingAverage;
+/// use ta::{Next, Ready};
+///
+/// let mut sma = SimpleMovingAverage::new(2).unwrap();
+/// assert!(!sma.is_ready());
+///
+/// sma.next(1.0);
+/// assert_eq!((sma.bars_seen(), sma.is_ready()), (1, false));
+/// sma.next(2.0);
+/// assert_eq!((sma.bars_seen(), sma.is_ready()), (2, true));
+/// ```
+pub trait Ready {
+    /// Number of inputs consumed since the indicator was created or reset.
+    fn bars_seen(&self) -> usize;
+
+    /// Whether enough inputs were consumed for the output to be meaningful.
+    fn is_ready(&self) -> bool;
+}
+
+/// Extends [Period](trait.Period.html) with adapters that depend on the period.
+///
+/// It is implemented for every indicator with a period and [Ready](trait.Ready.html).
+pub trait PeriodExt: Period + Ready + Sized {
+    /// Wraps the indicator to return `None` until it is ready, see
+    /// [Warmed](adapters/struct.Warmed.html).
+    fn warmed(self) -> Warmed<Self> {
+        Warmed::new(self)
+    }
+}
+
+impl<P: Period + Ready> PeriodExt for P {}
+
 /// Open price of a particular period.
 pub trait Open {
     fn open(&self) -> f64;
@@ -49,3 +187,189 @@ pub trait High {
 pub trait Volume {
     fn volume(&self) -> f64;
 }
+
+/// Floating point type an indicator computes with, either `f64` or `f32`.
+///
+/// The generic indicators, [VolumeWeightedAveragePrice](crate::indicators::VolumeWeightedAveragePrice),
+/// [SimpleMovingAverage](crate::indicators::SimpleMovingAverage) and
+/// [ExponentialMovingAverage](crate::indicators::ExponentialMovingAverage), default to `f64`.
+/// Using `f32` halves the memory of their state, at the cost of precision. Inputs are still read
+/// as `f64` and converted.
+///
+/// The trait is sealed, it cannot be implemented outside of this crate.
+///
+/// # Example
+///
+/// ```
+/// use ta::indicators::VolumeWeightedAveragePrice;
+/// use ta::{DataItem, Next};
+///
+/// let mut vwap = VolumeWeightedAveragePrice::new().with_precision::<f32>();
+///
+/// let di = DataItem::builder()
+///     .open(9.0).high(10.0).low(8.0).close(9.0).volume(1000.0)
+///     .build(true).unwrap();
+///
+/// let value: f32 = vwap.next(&di);
+/// assert_eq!(value, 9.0);
+/// ```
+pub trait Float:
+    private::Sealed
+    + Copy
+    + Default
+    + PartialOrd
+    + fmt::Debug
+    + fmt::Display
+    + ops::Add<Output = Self>
+    + ops::Sub<Output = Self>
+    + ops::Mul<Output = Self>
+    + ops::Div<Output = Self>
+    + ops::AddAssign
+    + ops::SubAssign
+{
+    fn zero() -> Self;
+    fn from_f64(value: f64) -> Self;
+    fn to_f64(self) -> f64;
+    fn sqrt(self) -> Self;
+    fn max(self, other: Self) -> Self;
+}
+
+mod private {
+    pub trait Sealed {}
+
+    impl Sealed for f32 {}
+    impl Sealed for f64 {}
+}
+
+impl Float for f64 {
+    fn zero() -> Self {
+        0.0
+    }
+
+    fn from_f64(value: f64) -> Self {
+        value
+    }
+
+    fn to_f64(self) -> f64 {
+        self
+    }
+
+    fn sqrt(self) -> Self {
+        f64::sqrt(self)
+    }
+
+    fn max(self, other: Self) -> Self {
+        f64::max(self, other)
+    }
+}
+
+impl Float for f32 {
+    fn zero() -> Self {
+        0.0
+    }
+
+    fn from_f64(value: f64) -> Self {
+        value as f32
+    }
+
+    fn to_f64(self) -> f64 {
+        self as f64
+    }
+
+    fn sqrt(self) -> Self {
+        f32::sqrt(self)
+    }
+
+    fn max(self, other: Self) -> Self {
+        f32::max(self, other)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::indicators::{SimpleMovingAverage, VolumeWeightedAveragePrice};
+    use crate::test_helper::*;
+
+    #[test]
+    fn test_next_all() {
+        let inputs = vec![4.0, 5.0, 6.0, 6.0, 2.0];
+
+        let mut sma = SimpleMovingAverage::new(3).unwrap();
+        let expected: Vec<f64> = inputs.iter().map(|&input| sma.next(input)).collect();
+
+        let mut sma = SimpleMovingAverage::new(3).unwrap();
+        assert_eq!(sma.next_all(inputs), expected);
+    }
+
+    #[test]
+    fn test_next_all_with_bars() {
+        let bars = vec![
+            Bar::new().high(10).low(8).close(9).volume(100.0),
+            Bar::new().high(11).low(9).close(10).volume(300.0),
+            Bar::new().high(12).low(9).close(9.5).volume(50.0),
+        ];
+
+        let mut vwap = VolumeWeightedAveragePrice::new();
+        let expected: Vec<f64> = bars.iter().map(|bar| vwap.next(bar)).collect();
+
+        let mut vwap = VolumeWeightedAveragePrice::new();
+        assert_eq!(vwap.next_all(&bars), expected);
+        assert!(vwap.next_all(Vec::<&Bar>::new()).is_empty());
+    }
+
+    fn seed_bars() -> Vec<Bar> {
+        (0..20)
+            .map(|i| {
+                let price = 100.0 + (i % 7) as f64;
+                Bar::new()
+                    .high(price + 1.0)
+                    .low(price - 1.0)
+                    .close(price)
+                    .volume(100.0 + (i * 37 % 11) as f64 * 50.0)
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_seed() {
+        let bars = seed_bars();
+        let (history, live) = bars.split_at(15);
+
+        let mut replayed = VolumeWeightedAveragePrice::new();
+        let expected: Vec<f64> = bars.iter().map(|bar| replayed.next(bar)).collect();
+
+        // the state left over from earlier inputs is discarded
+        let mut vwap = VolumeWeightedAveragePrice::new();
+        vwap.next(&Bar::new().high(50).low(40).close(45).volume(1e6));
+        vwap.seed(history);
+
+        let seeded: Vec<f64> = live.iter().map(|bar| vwap.next(bar)).collect();
+        assert_eq!(seeded, expected[15..]);
+        assert_eq!(vwap.raw_std_dev(), replayed.raw_std_dev());
+    }
+
+    #[test]
+    fn test_seed_rolling() {
+        let bars = seed_bars();
+        let (history, live) = bars.split_at(15);
+
+        let mut replayed = VolumeWeightedAveragePrice::rolling(5).unwrap();
+        let expected: Vec<f64> = bars.iter().map(|bar| replayed.next(bar)).collect();
+
+        let mut vwap = VolumeWeightedAveragePrice::rolling(5).unwrap();
+        vwap.seed(history);
+
+        let seeded: Vec<f64> = live.iter().map(|bar| vwap.next(bar)).collect();
+        assert_eq!(seeded, expected[15..]);
+    }
+
+    #[test]
+    fn test_seed_empty() {
+        let mut sma = SimpleMovingAverage::new(3).unwrap();
+        sma.next(10.0);
+
+        // seeding with no inputs only resets
+        assert_eq!(sma.seed(Vec::<f64>::new()).next(4.0), 4.0);
+    }
+}
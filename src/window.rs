@@ -0,0 +1,169 @@
+use std::collections::VecDeque;
+
+use crate::errors::{Result, TaError};
+use crate::Reset;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Minimum and maximum of the last _period_ values, in O(1) amortized time per value.
+///
+/// Keeps the candidates for the maximum in a deque of strictly decreasing values and the
+/// candidates for the minimum in a deque of strictly increasing ones. A value is dropped as
+/// soon as a later value makes it unable to ever be the extreme again, or when it leaves the
+/// window, so every value is pushed and popped at most once. On ties the latest value wins.
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone)]
+pub struct MonotonicWindow {
+    period: usize,
+    count: usize,
+    // (position, value) pairs
+    highs: VecDeque<(usize, f64)>,
+    lows: VecDeque<(usize, f64)>,
+}
+
+impl MonotonicWindow {
+    pub fn new(period: usize) -> Result<Self> {
+        match period {
+            0 => Err(TaError::invalid_parameter("period", period)),
+            _ => Ok(Self {
+                period,
+                count: 0,
+                highs: VecDeque::with_capacity(period),
+                lows: VecDeque::with_capacity(period),
+            }),
+        }
+    }
+
+    /// Pushes a value and returns the (minimum, maximum) of the window.
+    pub fn push(&mut self, value: f64) -> (f64, f64) {
+        self.push_range(value, value)
+    }
+
+    /// Pushes the low and the high of a bar and returns the (lowest low, highest high) of the
+    /// window.
+    pub fn push_range(&mut self, low: f64, high: f64) -> (f64, f64) {
+        let position = self.count;
+        self.count += 1;
+
+        while matches!(self.highs.back(), Some(&(_, h)) if h <= high) {
+            self.highs.pop_back();
+        }
+        self.highs.push_back((position, high));
+
+        while matches!(self.lows.back(), Some(&(_, l)) if l >= low) {
+            self.lows.pop_back();
+        }
+        self.lows.push_back((position, low));
+
+        while matches!(self.highs.front(), Some(&(i, _)) if i + self.period <= position) {
+            self.highs.pop_front();
+        }
+        while matches!(self.lows.front(), Some(&(i, _)) if i + self.period <= position) {
+            self.lows.pop_front();
+        }
+
+        (self.lows[0].1, self.highs[0].1)
+    }
+
+    /// Number of values pushed after the maximum, 0 when the latest value is the maximum.
+    pub fn since_max(&self) -> usize {
+        self.highs.front().map_or(0, |&(i, _)| self.count - 1 - i)
+    }
+
+    /// Number of values pushed after the minimum, 0 when the latest value is the minimum.
+    pub fn since_min(&self) -> usize {
+        self.lows.front().map_or(0, |&(i, _)| self.count - 1 - i)
+    }
+}
+
+impl Reset for MonotonicWindow {
+    fn reset(&mut self) {
+        self.count = 0;
+        self.highs.clear();
+        self.lows.clear();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::rngs::StdRng;
+    use rand::{Rng, SeedableRng};
+
+    fn naive(values: &[f64]) -> (f64, f64) {
+        values
+            .iter()
+            .fold((f64::INFINITY, f64::NEG_INFINITY), |(min, max), &v| {
+                (min.min(v), max.max(v))
+            })
+    }
+
+    #[test]
+    fn test_new() {
+        assert!(MonotonicWindow::new(0).is_err());
+        assert!(MonotonicWindow::new(1).is_ok());
+    }
+
+    #[test]
+    fn test_push() {
+        let mut window = MonotonicWindow::new(3).unwrap();
+
+        assert_eq!(window.push(4.0), (4.0, 4.0));
+        assert_eq!(window.push(2.0), (2.0, 4.0));
+        assert_eq!(window.push(5.0), (2.0, 5.0));
+        assert_eq!(window.push(3.0), (2.0, 5.0));
+        assert_eq!(window.push(3.0), (3.0, 5.0));
+        assert_eq!(window.push(1.0), (1.0, 3.0));
+    }
+
+    #[test]
+    fn test_push_random() {
+        let mut rng = StdRng::seed_from_u64(322);
+        let values: Vec<f64> = (0..1000).map(|_| rng.gen_range(-100.0, 100.0)).collect();
+
+        for period in [1, 2, 7, 50, 1000] {
+            let mut window = MonotonicWindow::new(period).unwrap();
+            for (i, &value) in values.iter().enumerate() {
+                let start = (i + 1).saturating_sub(period);
+                assert_eq!(window.push(value), naive(&values[start..=i]));
+            }
+        }
+    }
+
+    #[test]
+    fn test_push_range() {
+        let mut window = MonotonicWindow::new(2).unwrap();
+
+        assert_eq!(window.push_range(9.0, 11.0), (9.0, 11.0));
+        assert_eq!(window.push_range(10.0, 13.0), (9.0, 13.0));
+        assert_eq!(window.push_range(11.0, 12.0), (10.0, 13.0));
+        assert_eq!(window.push_range(8.0, 10.0), (8.0, 12.0));
+    }
+
+    #[test]
+    fn test_since() {
+        let mut window = MonotonicWindow::new(3).unwrap();
+
+        window.push(4.0);
+        assert_eq!((window.since_min(), window.since_max()), (0, 0));
+        window.push(2.0);
+        assert_eq!((window.since_min(), window.since_max()), (0, 1));
+        window.push(3.0);
+        assert_eq!((window.since_min(), window.since_max()), (1, 2));
+        window.push(3.0);
+        // the 4 left the window, the latest 3 is the maximum
+        assert_eq!((window.since_min(), window.since_max()), (2, 0));
+    }
+
+    #[test]
+    fn test_reset() {
+        let mut window = MonotonicWindow::new(3).unwrap();
+
+        window.push(4.0);
+        window.push(2.0);
+        window.reset();
+
+        assert_eq!(window.push(3.0), (3.0, 3.0));
+        assert_eq!(window.since_max(), 0);
+    }
+}
@@ -7,8 +7,18 @@ extern crate ta;
 mod test {
     #[cfg(feature = "serde")]
     mod serde {
-        use ta::indicators::SimpleMovingAverage;
-        use ta::Next;
+        use std::fmt::Debug;
+
+        use serde::de::DeserializeOwned;
+        use serde::Serialize;
+        use ta::indicators::{
+            AverageDirectionalIndex, BollingerBands, CciSignal, ChandelierExit, EmaSeed,
+            ExponentialMovingAverage, FisherTransform, HullMovingAverage,
+            KaufmanAdaptiveMovingAverage, KeltnerChannel, MovingAverageConvergenceDivergence,
+            SimpleMovingAverage, StandardDeviation, StochasticOscillator, SuperTrend, Trix,
+            VolumeWeightedAveragePrice, VolumeWeightedAveragePriceBands,
+        };
+        use ta::{DataItem, Next, Ready};
 
         // Simple smoke test that serde works (not sure if this is really necessary)
         #[test]
@@ -19,5 +29,143 @@ mod test {
 
             assert_eq!(deserialized.next(2.0), macd.next(2.0));
         }
+
+        fn bar(high: f64, low: f64, close: f64, volume: f64) -> DataItem {
+            DataItem::builder()
+                .open(close)
+                .high(high)
+                .low(low)
+                .close(close)
+                .volume(volume)
+                .build(true)
+                .unwrap()
+        }
+
+        // Feeds half of the bars, checkpoints the indicator and checks that the restored copy
+        // produces the same outputs as the original for the other half.
+        fn assert_checkpoint<I>(mut indicator: I)
+        where
+            I: Serialize + DeserializeOwned + for<'a> Next<&'a DataItem>,
+            for<'a> <I as Next<&'a DataItem>>::Output: PartialEq + Debug,
+        {
+            let bars: Vec<DataItem> = (0..60)
+                .map(|i| {
+                    let x = i as f64;
+                    let close = 100.0 + 10.0 * (x * 0.3).sin() + x * 0.2;
+                    bar(
+                        close + 1.0 + (x * 0.7).cos().abs(),
+                        close - 1.5,
+                        close,
+                        1000.0 + x,
+                    )
+                })
+                .collect();
+            let (head, tail) = bars.split_at(30);
+
+            for bar in head {
+                indicator.next(bar);
+            }
+
+            // restoring floats exactly needs the `float_roundtrip` feature of serde_json
+            let json = serde_json::to_string(&indicator).unwrap();
+            let mut from_json: I = serde_json::from_str(&json).unwrap();
+            let bytes = bincode::serialize(&indicator).unwrap();
+            let mut from_bincode: I = bincode::deserialize(&bytes).unwrap();
+
+            for bar in tail {
+                let expected = indicator.next(bar);
+                assert_eq!(from_json.next(bar), expected);
+                assert_eq!(from_bincode.next(bar), expected);
+            }
+        }
+
+        #[test]
+        fn test_serde_checkpoint_composed() {
+            assert_checkpoint(AverageDirectionalIndex::default());
+            assert_checkpoint(CciSignal::default());
+            assert_checkpoint(ChandelierExit::default());
+            assert_checkpoint(FisherTransform::default());
+            assert_checkpoint(HullMovingAverage::default());
+            assert_checkpoint(KaufmanAdaptiveMovingAverage::default());
+            assert_checkpoint(KeltnerChannel::default());
+            assert_checkpoint(MovingAverageConvergenceDivergence::default());
+            assert_checkpoint(StochasticOscillator::default());
+            assert_checkpoint(SuperTrend::default());
+            assert_checkpoint(Trix::default());
+            assert_checkpoint(VolumeWeightedAveragePrice::rolling(10).unwrap());
+        }
+
+        // Removes fields added after v0.5.0 from the json of an indicator, like a checkpoint
+        // written by that version. Fields of nested indicators are given as `outer.field`.
+        fn old_checkpoint<I: Serialize + DeserializeOwned>(indicator: &I, fields: &[&str]) -> I {
+            let mut json = serde_json::to_value(indicator).unwrap();
+            for field in fields {
+                let mut object = &mut json;
+                let mut path: Vec<&str> = field.split('.').collect();
+                let last = path.pop().unwrap();
+                for key in path {
+                    object = &mut object[key];
+                }
+                object.as_object_mut().unwrap().remove(last);
+            }
+            serde_json::from_value(json).unwrap()
+        }
+
+        #[test]
+        fn test_serde_checkpoint_without_bars_seen() {
+            let mut sma = SimpleMovingAverage::new(3).unwrap();
+            sma.next(2.0);
+            sma.next(4.0);
+
+            let mut restored = old_checkpoint(&sma, &["bars_seen"]);
+            assert_eq!(restored.bars_seen(), 0);
+            assert_eq!(restored.next(6.0), sma.next(6.0));
+        }
+
+        #[test]
+        fn test_serde_checkpoint_without_seed() {
+            let mut ema = ExponentialMovingAverage::new(3).unwrap();
+            ema.next(2.0);
+
+            let mut restored = old_checkpoint(&ema, &["bars_seen", "seed"]);
+            assert_eq!(restored.seed(), EmaSeed::FirstValue);
+            assert_eq!(restored.next(6.0), ema.next(6.0));
+        }
+
+        #[test]
+        fn test_serde_checkpoint_without_sample() {
+            let mut sd = StandardDeviation::new(3).unwrap();
+            sd.next(2.0);
+            sd.next(4.0);
+
+            let mut restored = old_checkpoint(&sd, &["bars_seen", "sample"]);
+            assert!(!restored.is_sample());
+            assert_eq!(restored.next(6.0), sd.next(6.0));
+
+            let mut bb = BollingerBands::new(3, 2.0).unwrap();
+            bb.next(2.0);
+            bb.next(4.0);
+
+            let mut restored = old_checkpoint(&bb, &["sd.bars_seen", "sd.sample"]);
+            assert_eq!(restored.next(6.0), bb.next(6.0));
+        }
+
+        #[test]
+        fn test_serde_vwap() {
+            let mut vwap = VolumeWeightedAveragePrice::new();
+            vwap.next(&bar(150.39, 150.22, 150.31, 380.0));
+            vwap.next(&bar(150.47, 150.38, 150.41, 5270.0));
+            vwap.next(&bar(150.49, 150.33, 150.46, 990.0));
+
+            let json = serde_json::to_string(&vwap).unwrap();
+            let mut deserialized: VolumeWeightedAveragePrice = serde_json::from_str(&json).unwrap();
+
+            let next_bar = bar(150.63, 150.44, 150.61, 1031.0);
+            assert_eq!(deserialized.next(&next_bar), vwap.next(&next_bar));
+            assert_eq!(
+                deserialized.std_dev(2.0, VolumeWeightedAveragePriceBands::Up),
+                vwap.std_dev(2.0, VolumeWeightedAveragePriceBands::Up)
+            );
+        }
     }
 }